@@ -0,0 +1,95 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Measures the cost of redrawing a large scroll area.
+//!
+//! Children of a [`Portal`] which are scrolled out of view are skipped by the paint
+//! and accessibility passes, so redrawing a portal should be roughly as fast as
+//! redrawing only its visible content, no matter how long the content is.
+//!
+//! Run with `cargo bench -p masonry --bench portal_culling`.
+
+#![expect(clippy::print_stdout, reason = "Benchmark output")]
+
+use std::time::{Duration, Instant};
+
+use masonry::core::Widget;
+use masonry::kurbo::{Affine, Point, Size};
+use masonry::theme::default_property_set;
+use masonry::widgets::{Flex, Label, Portal};
+use masonry_testing::TestHarness;
+
+const LABEL_COUNT: usize = 5_000;
+const FRAME_COUNT: u32 = 50;
+const WINDOW_SIZE: Size = Size::new(400., 400.);
+
+fn label_list() -> Flex {
+    let mut flex = Flex::column();
+    for i in 0..LABEL_COUNT {
+        flex = flex.with_child(Label::new(format!("Label #{i}")));
+    }
+    flex
+}
+
+/// Prints the average time taken by a call to `redraw` after `step` was applied.
+fn measure(
+    name: &str,
+    root_widget: impl Widget,
+    window_size: Size,
+    mut step: impl FnMut(&mut TestHarness, u32),
+) {
+    let mut harness =
+        TestHarness::create_with_size(default_property_set(), root_widget, window_size);
+    let _ = harness.redraw();
+
+    let mut total = Duration::ZERO;
+    for frame in 0..FRAME_COUNT {
+        step(&mut harness, frame);
+        let start = Instant::now();
+        let _ = harness.redraw();
+        total += start.elapsed();
+    }
+    println!("{name:<40} {:>10.3?} / frame", total / FRAME_COUNT);
+}
+
+fn scroll_portal(harness: &mut TestHarness, frame: u32) {
+    harness.edit_root_widget(|mut portal| {
+        let mut portal = portal.downcast::<Portal<Flex>>();
+        Portal::set_viewport_pos(&mut portal, Point::new(0., f64::from(frame) * 10.));
+    });
+}
+
+fn main() {
+    println!("Redrawing {LABEL_COUNT} labels, average over {FRAME_COUNT} frames");
+
+    // The same list, moved without being clipped: every label stays visible,
+    // so every label is visited and has its accessibility node rebuilt.
+    // This is what scrolling used to cost.
+    measure(
+        "unclipped list",
+        label_list(),
+        Size::new(WINDOW_SIZE.width, 1_000_000.),
+        |harness, frame| {
+            harness.edit_root_widget(|mut flex| {
+                flex.set_transform(Affine::translate((0., -f64::from(frame) * 10.)));
+            });
+        },
+    );
+
+    // Scrolling moves every label, but only the visible ones should be
+    // painted and have their accessibility node rebuilt.
+    measure(
+        "scrolled portal",
+        Portal::new(label_list()),
+        WINDOW_SIZE,
+        scroll_portal,
+    );
+
+    // Nothing changes: only cached scenes are reused.
+    measure(
+        "idle portal",
+        Portal::new(label_list()),
+        WINDOW_SIZE,
+        |_, _| {},
+    );
+}
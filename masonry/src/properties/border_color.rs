@@ -3,8 +3,15 @@
 
 use std::any::TypeId;
 
+use vello::Scene;
+use vello::kurbo::RoundedRect;
+
 use crate::core::{Property, UpdateCtx};
+use crate::peniko::Brush;
 use crate::peniko::color::{AlphaColor, Srgb};
+use crate::properties::BorderWidth;
+use crate::properties::types::Gradient;
+use crate::util::stroke;
 
 /// The color of a widget's border.
 #[expect(missing_docs, reason = "field names are self-descriptive")]
@@ -42,6 +49,21 @@ impl Property for HoveredBorderColor {
     }
 }
 
+/// A gradient to paint a widget's border with.
+///
+/// If set, this takes precedence over [`BorderColor`].
+/// Widgets which change their border color on hover or focus use the plain color in those states
+/// (see [`BorderGradient::paint`]).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BorderGradient(pub Option<Gradient>);
+
+impl Property for BorderGradient {
+    fn static_default() -> &'static Self {
+        static DEFAULT: BorderGradient = BorderGradient(None);
+        &DEFAULT
+    }
+}
+
 // ---
 
 // TODO - The default border color in CSS is `currentcolor`,
@@ -81,3 +103,45 @@ impl HoveredBorderColor {
         ctx.request_paint_only();
     }
 }
+
+// ---
+
+impl BorderGradient {
+    /// Create new `BorderGradient` with given gradient.
+    pub fn new(gradient: Gradient) -> Self {
+        Self(Some(gradient))
+    }
+
+    /// Helper function to be called in [`Widget::property_changed`](crate::core::Widget::property_changed).
+    pub fn prop_changed(ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
+        if property_type != TypeId::of::<Self>() {
+            return;
+        }
+        ctx.request_paint_only();
+    }
+
+    /// Helper function to paint a widget's border into a scene.
+    ///
+    /// The border is painted with `state_color` if it's `Some`, e.g. with the
+    /// [`HoveredBorderColor`] of a hovered button.
+    /// Otherwise, it's painted with this gradient if one is set, and with `border_color` if not.
+    /// The gradient covers the border's bounding rect according to CSS spec
+    /// (see [`Gradient::get_peniko_gradient_for_rect`]).
+    pub fn paint(
+        &self,
+        scene: &mut Scene,
+        border_rect: &RoundedRect,
+        border_width: &BorderWidth,
+        border_color: &BorderColor,
+        state_color: Option<AlphaColor<Srgb>>,
+    ) {
+        let brush: Brush = match (state_color, &self.0) {
+            (Some(color), _) => color.into(),
+            (None, Some(gradient)) => gradient
+                .get_peniko_gradient_for_rect(border_rect.rect())
+                .into(),
+            (None, None) => border_color.color.into(),
+        };
+        stroke(scene, border_rect, &brush, border_width.width);
+    }
+}
@@ -5,7 +5,7 @@ use std::any::TypeId;
 
 use vello::kurbo::{Point, RoundedRect, Size, Vec2};
 
-use crate::core::{BoxConstraints, Property, UpdateCtx};
+use crate::core::{BoxConstraints, IntrinsicSize, Property, UpdateCtx};
 use crate::properties::CornerRadius;
 
 /// The width of a widget's border, in logical pixels.
@@ -55,6 +55,20 @@ impl BorderWidth {
         (size, baseline)
     }
 
+    /// Shrinks the length on the cross axis by the border width.
+    ///
+    /// Helper function to be called in [`Widget::measure`](crate::core::Widget::measure).
+    pub fn measure_down(&self, cross_length: Option<f64>) -> Option<f64> {
+        cross_length.map(|length| (length - self.width * 2.).max(0.))
+    }
+
+    /// Expands the intrinsic size by the border width.
+    ///
+    /// Helper function to be called in [`Widget::measure`](crate::core::Widget::measure).
+    pub fn measure_up(&self, size: IntrinsicSize) -> IntrinsicSize {
+        size.expand(self.width * 2.)
+    }
+
     /// Shifts the position by the border width.
     ///
     /// Helper function to be called in [`Widget::layout`](crate::core::Widget::layout).
@@ -6,6 +6,7 @@ use std::any::TypeId;
 use vello::Scene;
 use vello::kurbo::{Affine, Insets, Point, RoundedRect};
 use vello::peniko::color::{AlphaColor, Srgb};
+use vello::peniko::{Color, Compose, Fill, Mix};
 
 use crate::core::{Property, UpdateCtx};
 
@@ -14,15 +15,13 @@ use crate::core::{Property, UpdateCtx};
 // - Paint order: CSS shadows are drawn over neighboring boxes, which means if we want
 // to emulate them, we need to paint them after sibling widgets. This would require
 // adding some kind of post_paint pass.
-// - Inset shadows: CSS shadows can be either drop shadows (behind element) or inset
-// shadows (inside element). We should implement both and add an `inset` attribute.
-// - Spread radius: CSS shadow can change size without changing the blur level using
-// a "spread radius" value. We should implement it and add a `spread_radius` value.
 // - Corner radius: Right now take our widget's corner radii, and average them to draw a shadow with a single corner radius. Ideally we'd like to match individual values.
 
-/// The drop shadow of a Widget.
+/// A single shadow of a Widget.
 ///
 /// Will be invisible if default values are kept.
+///
+/// Shadows are set on a widget through the [`BoxShadows`] property.
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct BoxShadow {
     /// The shadow's color.
@@ -36,22 +35,27 @@ pub struct BoxShadow {
     /// A value of zero means the shadow's edge will be shard.
     /// Negative values will be treated as zero.
     pub blur_radius: f64,
-}
 
-impl Property for BoxShadow {
-    fn static_default() -> &'static Self {
-        static DEFAULT: BoxShadow = BoxShadow {
-            color: AlphaColor::TRANSPARENT,
-            offset: Point::ZERO,
-            blur_radius: 0.,
-        };
-        &DEFAULT
-    }
+    /// How much the shadow grows (or shrinks, if negative) before being blurred.
+    ///
+    /// For inset shadows, a positive value makes the shadow reach further into the widget.
+    pub spread_radius: f64,
+
+    /// If true, the shadow is drawn inside the widget's border box, over its background,
+    /// instead of behind it.
+    pub inset: bool,
 }
 
+/// The shadows of a Widget.
+///
+/// As in CSS, the first shadow in the list is painted on top.
+/// Having several shadows is useful e.g. for layered elevation.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BoxShadows(pub Vec<BoxShadow>);
+
 impl Default for BoxShadow {
     fn default() -> Self {
-        *Self::static_default()
+        Self::new(AlphaColor::TRANSPARENT, Point::ZERO)
     }
 }
 
@@ -62,6 +66,8 @@ impl BoxShadow {
             color,
             offset: offset.into(),
             blur_radius: 0.,
+            spread_radius: 0.,
+            inset: false,
         }
     }
 
@@ -73,13 +79,20 @@ impl BoxShadow {
         }
     }
 
-    /// Helper function to be called in [`Widget::property_changed`](crate::core::Widget::property_changed).
-    pub fn prop_changed(ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
-        if property_type != TypeId::of::<Self>() {
-            return;
+    /// Builder method to change the shadow's spread radius.
+    pub fn spread(self, spread_radius: f64) -> Self {
+        Self {
+            spread_radius,
+            ..self
+        }
+    }
+
+    /// Builder method to make the shadow an inset shadow.
+    pub fn inset(self) -> Self {
+        Self {
+            inset: true,
+            ..self
         }
-        // TODO - request_paint_only?
-        ctx.request_layout();
     }
 
     /// Returns `false` if the shadow can be safely treated as non-existent.
@@ -90,23 +103,27 @@ impl BoxShadow {
         alpha != 0.0
     }
 
-    /// Helper function to paint the shadow into a scene.
+    /// Helper function to paint the shadow into a scene, if it is a drop shadow.
+    ///
+    /// This should be called before painting the widget's background.
+    /// Inset shadows are ignored; see [`paint_inset`](Self::paint_inset).
     pub fn paint(&self, scene: &mut Scene, transform: Affine, rect: RoundedRect) {
-        if !self.is_visible() {
+        if !self.is_visible() || self.inset {
             return;
         }
 
         let transform = transform.pre_translate(self.offset.to_vec2());
         let blur_radius = self.blur_radius.max(0.);
 
-        let radius = (rect.radii().bottom_left
-            + rect.radii().bottom_right
-            + rect.radii().top_left
-            + rect.radii().top_right)
-            / 4.;
+        let shadow_rect = rect.rect().inflate(self.spread_radius, self.spread_radius);
+        if shadow_rect.width() <= 0. || shadow_rect.height() <= 0. {
+            return;
+        }
+        let radius = (average_radius(rect) + self.spread_radius).max(0.);
+
         scene.draw_blurred_rounded_rect(
             transform,
-            rect.rect(),
+            shadow_rect,
             self.color,
             radius,
             // TODO - I'm not sure this is the right std_dev.
@@ -114,14 +131,143 @@ impl BoxShadow {
         );
     }
 
+    /// Helper function to paint the shadow into a scene, if it is an inset shadow.
+    ///
+    /// This should be called after painting the widget's background, and before painting its border.
+    /// Drop shadows are ignored; see [`paint`](Self::paint).
+    pub fn paint_inset(&self, scene: &mut Scene, transform: Affine, rect: RoundedRect) {
+        if !self.is_visible() || !self.inset {
+            return;
+        }
+
+        let blur_radius = self.blur_radius.max(0.);
+
+        // We fill the whole box with the shadow color, then punch out a blurred
+        // rounded rect matching the shadow's "hole".
+        scene.push_layer(Mix::Clip, 1.0, transform, &rect);
+        scene.fill(Fill::NonZero, transform, self.color, None, &rect);
+
+        let hole_rect = rect
+            .rect()
+            .inflate(-self.spread_radius, -self.spread_radius)
+            + self.offset.to_vec2();
+        if hole_rect.width() > 0. && hole_rect.height() > 0. {
+            let radius = (average_radius(rect) - self.spread_radius).max(0.);
+            scene.push_layer(Compose::DestOut, 1.0, transform, &rect);
+            scene.draw_blurred_rounded_rect(
+                transform,
+                hole_rect,
+                Color::BLACK,
+                radius,
+                blur_radius,
+            );
+            scene.pop_layer();
+        }
+
+        scene.pop_layer();
+    }
+
     /// Helper function that returns how much a given shadow expands the paint rect.
     pub fn get_insets(&self) -> Insets {
-        let blur_radius = self.blur_radius.max(0.);
+        if self.inset {
+            return Insets::ZERO;
+        }
+        let extent = self.blur_radius.max(0.) + self.spread_radius;
         Insets {
-            x0: (blur_radius - self.offset.x).max(0.),
-            y0: (blur_radius - self.offset.y).max(0.),
-            x1: (blur_radius + self.offset.x).max(0.),
-            y1: (blur_radius + self.offset.y).max(0.),
+            x0: (extent - self.offset.x).max(0.),
+            y0: (extent - self.offset.y).max(0.),
+            x1: (extent + self.offset.x).max(0.),
+            y1: (extent + self.offset.y).max(0.),
         }
     }
 }
+
+// ---
+
+impl Property for BoxShadows {
+    fn static_default() -> &'static Self {
+        static DEFAULT: BoxShadows = BoxShadows(Vec::new());
+        &DEFAULT
+    }
+}
+
+impl From<BoxShadow> for BoxShadows {
+    fn from(shadow: BoxShadow) -> Self {
+        Self(vec![shadow])
+    }
+}
+
+impl From<Vec<BoxShadow>> for BoxShadows {
+    fn from(shadows: Vec<BoxShadow>) -> Self {
+        Self(shadows)
+    }
+}
+
+impl FromIterator<BoxShadow> for BoxShadows {
+    fn from_iter<I: IntoIterator<Item = BoxShadow>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl BoxShadows {
+    /// Helper function to be called in [`Widget::property_changed`](crate::core::Widget::property_changed).
+    pub fn prop_changed(ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
+        if property_type != TypeId::of::<Self>() {
+            return;
+        }
+        // TODO - request_paint_only?
+        ctx.request_layout();
+    }
+
+    /// Returns `false` if the shadows can be safely treated as non-existent.
+    ///
+    /// May have false positives.
+    pub fn is_visible(&self) -> bool {
+        self.0.iter().any(BoxShadow::is_visible)
+    }
+
+    /// Helper function to paint the drop shadows into a scene.
+    ///
+    /// See [`BoxShadow::paint`].
+    pub fn paint(&self, scene: &mut Scene, transform: Affine, rect: RoundedRect) {
+        // The first shadow is on top, so it's painted last.
+        for shadow in self.0.iter().rev() {
+            shadow.paint(scene, transform, rect);
+        }
+    }
+
+    /// Helper function to paint the inset shadows into a scene.
+    ///
+    /// See [`BoxShadow::paint_inset`].
+    pub fn paint_inset(&self, scene: &mut Scene, transform: Affine, rect: RoundedRect) {
+        for shadow in self.0.iter().rev() {
+            shadow.paint_inset(scene, transform, rect);
+        }
+    }
+
+    /// Helper function that returns how much the shadows expand the paint rect.
+    pub fn get_insets(&self) -> Insets {
+        self.0
+            .iter()
+            .filter(|shadow| shadow.is_visible())
+            .map(BoxShadow::get_insets)
+            .fold(Insets::ZERO, max_insets)
+    }
+}
+
+fn max_insets(a: Insets, b: Insets) -> Insets {
+    Insets {
+        x0: a.x0.max(b.x0),
+        y0: a.y0.max(b.y0),
+        x1: a.x1.max(b.x1),
+        y1: a.y1.max(b.y1),
+    }
+}
+
+fn average_radius(rect: RoundedRect) -> f64 {
+    (rect.radii().bottom_left
+        + rect.radii().bottom_right
+        + rect.radii().top_left
+        + rect.radii().top_right)
+        / 4.
+}
@@ -16,9 +16,33 @@ mod padding;
 pub mod types;
 
 pub use background::{ActiveBackground, Background, DisabledBackground};
-pub use border_color::{BorderColor, HoveredBorderColor};
+pub use border_color::{BorderColor, BorderGradient, HoveredBorderColor};
 pub use border_width::BorderWidth;
-pub use box_shadow::BoxShadow;
+pub use box_shadow::{BoxShadow, BoxShadows};
 pub use checkmark::{CheckmarkColor, CheckmarkStrokeWidth, DisabledCheckmarkColor};
 pub use corner_radius::CornerRadius;
 pub use padding::Padding;
+
+// These are handled by Masonry's paint pass, and work on every widget.
+pub use masonry_core::core::{MixBlendMode, Opacity};
+
+use crate::core::DefaultProperties;
+
+/// Lets the widget inspector and tree snapshots display the properties defined in this module.
+///
+/// This is called by [`default_property_set`](crate::theme::default_property_set).
+pub fn register_property_debug(properties: &mut DefaultProperties) {
+    properties.register_debug::<ActiveBackground>();
+    properties.register_debug::<Background>();
+    properties.register_debug::<DisabledBackground>();
+    properties.register_debug::<BorderColor>();
+    properties.register_debug::<BorderGradient>();
+    properties.register_debug::<HoveredBorderColor>();
+    properties.register_debug::<BorderWidth>();
+    properties.register_debug::<BoxShadows>();
+    properties.register_debug::<CheckmarkColor>();
+    properties.register_debug::<CheckmarkStrokeWidth>();
+    properties.register_debug::<DisabledCheckmarkColor>();
+    properties.register_debug::<CornerRadius>();
+    properties.register_debug::<Padding>();
+}
@@ -5,7 +5,7 @@ use std::any::TypeId;
 
 use vello::kurbo::{Point, Size, Vec2};
 
-use crate::core::{BoxConstraints, Property, UpdateCtx};
+use crate::core::{Axis, BoxConstraints, IntrinsicSize, Property, UpdateCtx};
 
 /// The width of padding between a widget's border and its contents.
 #[derive(Default, Clone, Copy, Debug, PartialEq)]
@@ -149,6 +149,31 @@ impl Padding {
         (size, baseline)
     }
 
+    /// Shrinks the length on the cross axis by the padding amount.
+    ///
+    /// `axis` is the axis being measured.
+    ///
+    /// Helper function to be called in [`Widget::measure`](crate::core::Widget::measure).
+    pub fn measure_down(&self, axis: Axis, cross_length: Option<f64>) -> Option<f64> {
+        let padding = self.length(axis.cross());
+        cross_length.map(|length| (length - padding).max(0.))
+    }
+
+    /// Expands the intrinsic size along the given axis by the padding amount.
+    ///
+    /// Helper function to be called in [`Widget::measure`](crate::core::Widget::measure).
+    pub fn measure_up(&self, axis: Axis, size: IntrinsicSize) -> IntrinsicSize {
+        size.expand(self.length(axis))
+    }
+
+    /// The total padding along the given axis.
+    fn length(&self, axis: Axis) -> f64 {
+        match axis {
+            Axis::Horizontal => self.left + self.right,
+            Axis::Vertical => self.top + self.bottom,
+        }
+    }
+
     /// Shifts the position by the padding amount.
     ///
     /// Helper function to be called in [`Widget::layout`](crate::core::Widget::layout).
@@ -1,10 +1,13 @@
 // Copyright 2025 the Xilem Authors
 // SPDX-License-Identifier: Apache-2.0
 
+use std::f64::consts::{FRAC_PI_2, TAU};
+
 use vello::kurbo::{Point, Rect};
 
 use crate::peniko::color::{ColorSpaceTag, HueDirection};
 use crate::peniko::{ColorStops, ColorStopsSource, Extend};
+use crate::properties::types::UnitPoint;
 
 /// Properties for the supported [`Gradient`] types.
 ///
@@ -24,6 +27,50 @@ pub enum GradientShape {
         /// Zero points upwards and positive angles represent clockwise rotation.
         angle: f64,
     },
+    /// Gradient that transitions between two or more colors radiating from a center point.
+    ///
+    /// This is interpreted like [`radial-gradient()`] in CSS, with a `circle` shape.
+    ///
+    /// [`radial-gradient()`]: https://drafts.csswg.org/css-images-3/#radial-gradients
+    Radial {
+        /// The center of the gradient, relative to the painted rect.
+        center: UnitPoint,
+        /// How far the gradient's ending circle reaches.
+        extent: RadialGradientExtent,
+    },
+    /// Gradient that transitions between two or more colors that rotate around a center
+    /// point.
+    ///
+    /// This is interpreted like [`conic-gradient()`] in CSS.
+    ///
+    /// [`conic-gradient()`]: https://drafts.csswg.org/css-images-4/#conic-gradients
+    Sweep {
+        /// The center of the gradient, relative to the painted rect.
+        center: UnitPoint,
+        /// The angle at which the gradient starts, in radians.
+        /// Zero points upwards and positive angles represent clockwise rotation.
+        start_angle: f64,
+    },
+}
+
+/// The size of a [`Radial`](GradientShape::Radial) gradient's ending circle.
+///
+/// This mirrors the [`<radial-extent>`] keywords in CSS.
+///
+/// [`<radial-extent>`]: https://drafts.csswg.org/css-images-3/#typedef-radial-extent
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RadialGradientExtent {
+    /// The ending circle touches the side of the rect closest to its center.
+    ClosestSide,
+    /// The ending circle passes through the corner of the rect closest to its center.
+    ClosestCorner,
+    /// The ending circle touches the side of the rect farthest from its center.
+    FarthestSide,
+    /// The ending circle passes through the corner of the rect farthest from its center.
+    ///
+    /// This is the CSS default.
+    #[default]
+    FarthestCorner,
 }
 
 /// Definition of a gradient that transitions between two or more colors.
@@ -73,6 +120,53 @@ impl Gradient {
         }
     }
 
+    /// Creates a [`Radial`](GradientShape::Radial) gradient.
+    ///
+    /// The first stop will be at `center`, and the last stop will be on the circle described by `extent`.
+    pub fn new_radial(center: UnitPoint, extent: RadialGradientExtent) -> Self {
+        Self {
+            shape: GradientShape::Radial { center, extent },
+            extend: Extend::default(),
+            interpolation_cs: ColorSpaceTag::Srgb,
+            hue_direction: HueDirection::default(),
+            stops: ColorStops::default(),
+        }
+    }
+
+    /// Creates a [`Sweep`](GradientShape::Sweep) gradient.
+    ///
+    /// `start_angle` is in radians, with zero pointing upwards, and higher values rotating the gradient clockwise.
+    /// This matches how [CSS conic gradients are defined](https://developer.mozilla.org/en-US/docs/Web/CSS/gradient/conic-gradient).
+    ///
+    /// Unlike other gradients, this uses [`Extend::Repeat`] by default, which matches CSS.
+    /// See [`with_extend`](Self::with_extend) for how other extend modes behave.
+    pub fn new_sweep(center: UnitPoint, start_angle: f64) -> Self {
+        Self {
+            shape: GradientShape::Sweep {
+                center,
+                start_angle,
+            },
+            extend: Extend::Repeat,
+            interpolation_cs: ColorSpaceTag::Srgb,
+            hue_direction: HueDirection::default(),
+            stops: ColorStops::default(),
+        }
+    }
+
+    /// Builder method to set the gradient's extend mode.
+    ///
+    /// Use [`Extend::Repeat`] or [`Extend::Reflect`] to get the equivalent of
+    /// CSS's `repeating-*-gradient()` functions.
+    ///
+    /// Sweep gradients cover one full turn from their start angle, but peniko measures angles
+    /// from the positive x axis. The angles between that axis and the start angle are
+    /// painted according to the extend mode: [`Extend::Repeat`] wraps the turn around
+    /// like CSS does, while [`Extend::Pad`] and [`Extend::Reflect`] don't.
+    pub fn with_extend(mut self, extend: Extend) -> Self {
+        self.extend = extend;
+        self
+    }
+
     /// Builder method to set color stops on the gradient.
     pub fn with_stops(mut self, stops: impl ColorStopsSource) -> Self {
         self.stops.clear();
@@ -82,7 +176,9 @@ impl Gradient {
 
     /// Returns gradient brush covering the given Rect.
     ///
-    /// This matches the CSS spec for [`linear-gradient()`](https://drafts.csswg.org/css-images-3/#linear-gradient-syntax).
+    /// This matches the CSS spec for [`linear-gradient()`](https://drafts.csswg.org/css-images-3/#linear-gradient-syntax),
+    /// [`radial-gradient()`](https://drafts.csswg.org/css-images-3/#radial-gradients)
+    /// and [`conic-gradient()`](https://drafts.csswg.org/css-images-4/#conic-gradients).
     pub fn get_peniko_gradient_for_rect(&self, rect: Rect) -> crate::peniko::Gradient {
         crate::peniko::Gradient {
             kind: self.shape.get_peniko_kind_for_rect(rect),
@@ -97,10 +193,19 @@ impl Gradient {
 impl GradientShape {
     /// Returns gradient coordinates for a gradient covering the given Rect.
     ///
-    /// This matches the CSS spec for [`linear-gradient()`](https://drafts.csswg.org/css-images-3/#linear-gradient-syntax).
+    /// This matches the CSS spec for [`linear-gradient()`](https://drafts.csswg.org/css-images-3/#linear-gradient-syntax),
+    /// [`radial-gradient()`](https://drafts.csswg.org/css-images-3/#radial-gradients)
+    /// and [`conic-gradient()`](https://drafts.csswg.org/css-images-4/#conic-gradients).
     pub fn get_peniko_kind_for_rect(&self, rect: Rect) -> crate::peniko::GradientKind {
         match self {
             Self::Linear { angle } => Self::get_peniko_linear_for_rect(*angle, rect),
+            Self::Radial { center, extent } => {
+                Self::get_peniko_radial_for_rect(*center, *extent, rect)
+            }
+            Self::Sweep {
+                center,
+                start_angle,
+            } => Self::get_peniko_sweep_for_rect(*center, *start_angle, rect),
         }
     }
 
@@ -123,4 +228,48 @@ impl GradientShape {
 
         crate::peniko::GradientKind::Linear { start, end }
     }
+
+    fn get_peniko_radial_for_rect(
+        center: UnitPoint,
+        extent: RadialGradientExtent,
+        rect: Rect,
+    ) -> crate::peniko::GradientKind {
+        // See https://drafts.csswg.org/css-images-3/#valdef-radial-extent-closest-side
+        let center = center.resolve(rect);
+        let dx_min = (center.x - rect.x0).abs().min((rect.x1 - center.x).abs());
+        let dx_max = (center.x - rect.x0).abs().max((rect.x1 - center.x).abs());
+        let dy_min = (center.y - rect.y0).abs().min((rect.y1 - center.y).abs());
+        let dy_max = (center.y - rect.y0).abs().max((rect.y1 - center.y).abs());
+
+        let radius = match extent {
+            RadialGradientExtent::ClosestSide => dx_min.min(dy_min),
+            RadialGradientExtent::FarthestSide => dx_max.max(dy_max),
+            RadialGradientExtent::ClosestCorner => dx_min.hypot(dy_min),
+            RadialGradientExtent::FarthestCorner => dx_max.hypot(dy_max),
+        };
+
+        crate::peniko::GradientKind::Radial {
+            start_center: center,
+            start_radius: 0.,
+            end_center: center,
+            end_radius: radius as f32,
+        }
+    }
+
+    fn get_peniko_sweep_for_rect(
+        center: UnitPoint,
+        start_angle: f64,
+        rect: Rect,
+    ) -> crate::peniko::GradientKind {
+        // CSS angles start upwards, whereas peniko angles start at the positive x axis.
+        // Both rotate clockwise in a y-down coordinate space.
+        let start_angle = (start_angle - FRAC_PI_2).rem_euclid(TAU);
+        let end_angle = start_angle + TAU;
+
+        crate::peniko::GradientKind::Sweep {
+            center: center.resolve(rect),
+            start_angle: start_angle as f32,
+            end_angle: end_angle as f32,
+        }
+    }
 }
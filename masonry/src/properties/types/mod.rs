@@ -15,5 +15,5 @@
 mod gradient;
 mod unit_point;
 
-pub use gradient::{Gradient, GradientShape};
+pub use gradient::{Gradient, GradientShape, RadialGradientExtent};
 pub use unit_point::UnitPoint;
@@ -13,7 +13,7 @@ use crate::properties::{
     ActiveBackground, Background, BorderColor, BorderWidth, CheckmarkColor, CheckmarkStrokeWidth,
     CornerRadius, DisabledBackground, DisabledCheckmarkColor, HoveredBorderColor, Padding,
 };
-use crate::widgets::{Button, Checkbox, TextInput};
+use crate::widgets::{Button, Checkbox, Dialog, TextInput};
 
 pub const BORDER_WIDTH: f64 = 1.;
 
@@ -27,6 +27,8 @@ pub const ZYNC_500: Color = Color::from_rgb8(0x71, 0x71, 0x7a);
 pub const ACCENT_COLOR: Color = Color::from_rgb8(0x3b, 0x7e, 0xe4);
 pub const TEXT_COLOR: Color = Color::from_rgb8(0xf0, 0xf0, 0xea);
 pub const DISABLED_TEXT_COLOR: Color = Color::from_rgb8(0xa0, 0xa0, 0x9a);
+pub const ERROR_TEXT_COLOR: Color = Color::from_rgb8(0xf8, 0x71, 0x71);
+pub const DIALOG_BACKDROP_COLOR: Color = Color::from_rgba8(0, 0, 0, 0x99);
 
 // TODO: The following constants are not being used in properties
 pub const TEXT_SIZE_NORMAL: f32 = 15.0;
@@ -45,6 +47,7 @@ pub const WIDGET_CONTROL_COMPONENT_PADDING: f64 = 4.0;
 
 pub fn default_property_set() -> DefaultProperties {
     let mut properties = DefaultProperties::new();
+    crate::properties::register_property_debug(&mut properties);
 
     // Button
     properties.insert::<Button, _>(Padding::from_vh(6., 16.));
@@ -86,6 +89,9 @@ pub fn default_property_set() -> DefaultProperties {
 
     properties.insert::<TextInput, _>(BorderColor { color: ZYNC_600 });
 
+    // Dialog
+    properties.insert::<Dialog, _>(Background::Color(DIALOG_BACKDROP_COLOR));
+
     properties
 }
 
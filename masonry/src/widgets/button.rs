@@ -19,11 +19,11 @@ use crate::core::{
     WidgetId, WidgetMut, WidgetPod,
 };
 use crate::properties::{
-    ActiveBackground, Background, BorderColor, BorderWidth, BoxShadow, CornerRadius,
-    DisabledBackground, HoveredBorderColor, Padding,
+    ActiveBackground, Background, BorderColor, BorderGradient, BorderWidth, BoxShadows,
+    CornerRadius, DisabledBackground, HoveredBorderColor, Padding,
 };
 use crate::theme;
-use crate::util::{fill, stroke};
+use crate::util::fill;
 use crate::widgets::Label;
 
 /// A button with a text label.
@@ -170,10 +170,11 @@ impl Widget for Button {
         Background::prop_changed(ctx, property_type);
         HoveredBorderColor::prop_changed(ctx, property_type);
         BorderColor::prop_changed(ctx, property_type);
+        BorderGradient::prop_changed(ctx, property_type);
         BorderWidth::prop_changed(ctx, property_type);
         CornerRadius::prop_changed(ctx, property_type);
         Padding::prop_changed(ctx, property_type);
-        BoxShadow::prop_changed(ctx, property_type);
+        BoxShadows::prop_changed(ctx, property_type);
     }
 
     fn layout(
@@ -184,7 +185,7 @@ impl Widget for Button {
     ) -> Size {
         let border = props.get::<BorderWidth>();
         let padding = props.get::<Padding>();
-        let shadow = props.get::<BoxShadow>();
+        let shadows = props.get::<BoxShadows>();
 
         let initial_bc = bc;
 
@@ -212,8 +213,8 @@ impl Widget for Button {
 
         // TODO - pos = (size - label_size) / 2
 
-        if shadow.is_visible() {
-            ctx.set_paint_insets(shadow.get_insets());
+        if shadows.is_visible() {
+            ctx.set_paint_insets(shadows.get_insets());
         }
 
         ctx.set_baseline_offset(baseline);
@@ -227,7 +228,7 @@ impl Widget for Button {
 
         let border_width = props.get::<BorderWidth>();
         let border_radius = props.get::<CornerRadius>();
-        let shadow = props.get::<BoxShadow>();
+        let shadows = props.get::<BoxShadows>();
 
         let bg = if ctx.is_disabled() {
             &props.get::<DisabledBackground>().0
@@ -240,24 +241,27 @@ impl Widget for Button {
         let bg_rect = border_width.bg_rect(size, border_radius);
         let border_rect = border_width.border_rect(size, border_radius);
 
-        let mut border_color = if is_hovered && !ctx.is_disabled() {
-            &props.get::<HoveredBorderColor>().0
+        // FIXME - Handle this properly
+        let state_border_color = if ctx.is_focus_target() {
+            Some(Color::WHITE)
+        } else if is_hovered && !ctx.is_disabled() {
+            Some(props.get::<HoveredBorderColor>().0.color)
         } else {
-            props.get::<BorderColor>()
+            None
         };
 
-        // FIXME - Handle this properly
-        if ctx.is_focus_target() {
-            border_color = &BorderColor {
-                color: Color::WHITE,
-            };
-        }
-
-        shadow.paint(scene, Affine::IDENTITY, bg_rect);
+        shadows.paint(scene, Affine::IDENTITY, bg_rect);
 
         let brush = bg.get_peniko_brush_for_rect(bg_rect.rect());
         fill(scene, &bg_rect, &brush);
-        stroke(scene, &border_rect, border_color.color, border_width.width);
+        shadows.paint_inset(scene, Affine::IDENTITY, bg_rect);
+        props.get::<BorderGradient>().paint(
+            scene,
+            &border_rect,
+            border_width,
+            props.get::<BorderColor>(),
+            state_border_color,
+        );
     }
 
     fn accessibility_role(&self) -> Role {
@@ -309,6 +313,7 @@ mod tests {
     use crate::assert_render_snapshot;
     use crate::core::keyboard::NamedKey;
     use crate::core::{PointerButton, StyleProperty};
+    use crate::properties::BoxShadow;
     use crate::testing::{TestHarness, TestWidgetExt, WrapperWidget, widget_ids};
     use crate::theme::{ACCENT_COLOR, default_property_set};
     use crate::widgets::{Grid, GridParams, SizedBox};
@@ -442,28 +447,76 @@ mod tests {
             {
                 let mut button = Grid::child_mut(&mut grid, 0);
                 let mut button = button.downcast::<Button>();
-                button.insert_prop(BoxShadow::new(ORANGE, (10., 10.)));
+                button.insert_prop(BoxShadows::from(BoxShadow::new(ORANGE, (10., 10.))));
             }
 
             {
                 let mut button = Grid::child_mut(&mut grid, 1);
                 let mut button = button.downcast::<Button>();
-                button.insert_prop(BoxShadow::new(ORANGE, (-10., 10.)).blur(5.0));
+                button.insert_prop(BoxShadows::from(
+                    BoxShadow::new(ORANGE, (-10., 10.)).blur(5.0),
+                ));
             }
 
             {
                 let mut button = Grid::child_mut(&mut grid, 2);
                 let mut button = button.downcast::<Button>();
-                button.insert_prop(BoxShadow::new(ORANGE, (-10., -10.)).blur(-5.0));
+                button.insert_prop(BoxShadows::from(
+                    BoxShadow::new(ORANGE, (-10., -10.)).blur(-5.0),
+                ));
             }
 
             {
                 let mut button = Grid::child_mut(&mut grid, 3);
                 let mut button = button.downcast::<Button>();
-                button.insert_prop(BoxShadow::new(ORANGE, (0., 0.)).blur(5.0));
+                button.insert_prop(BoxShadows::from(BoxShadow::new(ORANGE, (0., 0.)).blur(5.0)));
             }
         });
 
         assert_render_snapshot!(harness, "button_shadows");
     }
+
+    #[test]
+    fn with_layered_and_inset_shadows() {
+        use crate::palette::css::{BLACK, ORANGE};
+
+        let grid = Grid::with_dimensions(2, 1)
+            .with_spacing(40.0)
+            .with_child(Button::new("A"), GridParams::new(0, 0, 1, 1))
+            .with_child(Button::new("B"), GridParams::new(1, 0, 1, 1));
+
+        let root_widget =
+            SizedBox::new(grid).with_props(Properties::new().with(Padding::all(20.0)));
+
+        let window_size = Size::new(300.0, 150.0);
+        let mut harness =
+            TestHarness::create_with_size(default_property_set(), root_widget, window_size);
+
+        harness.edit_root_widget(|mut root| {
+            let mut root = root.downcast::<WrapperWidget>();
+            let mut sized_box = WrapperWidget::child_mut(&mut root);
+            let mut sized_box = sized_box.downcast::<SizedBox>();
+            let mut grid = SizedBox::child_mut(&mut sized_box).unwrap();
+            let mut grid = grid.downcast::<Grid>();
+
+            {
+                let mut button = Grid::child_mut(&mut grid, 0);
+                let mut button = button.downcast::<Button>();
+                button.insert_prop(BoxShadows(vec![
+                    BoxShadow::new(BLACK.with_alpha(0.3), (0., 2.)).blur(2.0),
+                    BoxShadow::new(ORANGE, (0., 8.)).blur(8.0).spread(4.0),
+                ]));
+            }
+
+            {
+                let mut button = Grid::child_mut(&mut grid, 1);
+                let mut button = button.downcast::<Button>();
+                button.insert_prop(BoxShadows::from(
+                    BoxShadow::new(ORANGE, (3., 3.)).blur(4.0).inset(),
+                ));
+            }
+        });
+
+        assert_render_snapshot!(harness, "button_layered_shadows");
+    }
 }
@@ -19,11 +19,12 @@ use crate::core::{
     WidgetId, WidgetMut, WidgetPod,
 };
 use crate::properties::{
-    ActiveBackground, Background, BorderColor, BorderWidth, CheckmarkColor, CheckmarkStrokeWidth,
-    CornerRadius, DisabledBackground, DisabledCheckmarkColor, HoveredBorderColor, Padding,
+    ActiveBackground, Background, BorderColor, BorderGradient, BorderWidth, CheckmarkColor,
+    CheckmarkStrokeWidth, CornerRadius, DisabledBackground, DisabledCheckmarkColor,
+    HoveredBorderColor, Padding,
 };
 use crate::theme;
-use crate::util::{fill, stroke};
+use crate::util::fill;
 use crate::widgets::Label;
 
 /// A checkbox that can be toggled.
@@ -168,6 +169,7 @@ impl Widget for Checkbox {
         Background::prop_changed(ctx, property_type);
         HoveredBorderColor::prop_changed(ctx, property_type);
         BorderColor::prop_changed(ctx, property_type);
+        BorderGradient::prop_changed(ctx, property_type);
         BorderWidth::prop_changed(ctx, property_type);
         CornerRadius::prop_changed(ctx, property_type);
         Padding::prop_changed(ctx, property_type);
@@ -227,20 +229,23 @@ impl Widget for Checkbox {
         let bg_rect = border_width.bg_rect(size, border_radius);
         let border_rect = border_width.border_rect(size, border_radius);
 
-        let mut border_color = if is_hovered && !ctx.is_disabled() {
-            &props.get::<HoveredBorderColor>().0
+        // FIXME - Handle this properly
+        let state_border_color = if ctx.is_focus_target() {
+            Some(Color::WHITE)
+        } else if is_hovered && !ctx.is_disabled() {
+            Some(props.get::<HoveredBorderColor>().0.color)
         } else {
-            props.get::<BorderColor>()
+            None
         };
-        // FIXME - Handle this properly
-        if ctx.is_focus_target() {
-            border_color = &BorderColor {
-                color: Color::WHITE,
-            };
-        }
         let brush = bg.get_peniko_brush_for_rect(bg_rect.rect());
         fill(scene, &bg_rect, &brush);
-        stroke(scene, &border_rect, border_color.color, border_width.width);
+        props.get::<BorderGradient>().paint(
+            scene,
+            &border_rect,
+            border_width,
+            props.get::<BorderColor>(),
+            state_border_color,
+        );
 
         if self.checked {
             let checkmark_width = props.get::<CheckmarkStrokeWidth>();
@@ -0,0 +1,293 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! A modal dialog shown over the rest of the window.
+
+use std::any::TypeId;
+
+use accesskit::{Node, Role};
+use smallvec::{SmallVec, smallvec};
+use tracing::{Span, trace_span};
+use vello::Scene;
+use vello::kurbo::{Point, Size};
+
+use crate::core::keyboard::{Key, KeyState, NamedKey};
+use crate::core::{
+    AccessCtx, Action, BoxConstraints, EventCtx, FocusScope, LayoutCtx, PaintCtx, PointerEvent,
+    PropertiesMut, PropertiesRef, RegisterCtx, TextEvent, Update, UpdateCtx, Widget, WidgetId,
+    WidgetMut, WidgetPod,
+};
+use crate::properties::Background;
+use crate::util::fill;
+
+/// A layer which shows a modal dialog over the content behind it.
+///
+/// The dialog should be drawn on top of the rest of the window, e.g. by making it the last
+/// child of a [`ZStack`](crate::widgets::ZStack).
+///
+/// While open, the dialog:
+/// - takes all the space it's given and fills it with its [`Background`], which dims
+///   the content behind it with the default theme,
+/// - centers its child in that space,
+/// - stops all pointer events from reaching the content behind it,
+/// - is a [`FocusScope`]: focus moves to its first focusable widget when it opens,
+///   <kbd>Tab</kbd> navigation stays inside it, and focus goes back to the widget which
+///   had it before when it closes,
+/// - submits [`Action::DialogDismissed`] when <kbd>Escape</kbd> is pressed.
+///
+/// The dialog doesn't close itself: the app should close it in response to that action,
+/// or when one of the dialog's buttons is pressed.
+///
+/// While closed, the dialog takes up the minimum space, and its child is stashed.
+pub struct Dialog {
+    child: WidgetPod<dyn Widget>,
+    open: bool,
+}
+
+// --- MARK: BUILDERS
+impl Dialog {
+    /// Create a new open dialog with the given content.
+    pub fn new(child: impl Widget) -> Self {
+        Self::new_pod(WidgetPod::new(child).erased())
+    }
+
+    /// Create a new open dialog with content already wrapped in a [`WidgetPod`].
+    pub fn new_pod(child: WidgetPod<dyn Widget>) -> Self {
+        Self { child, open: true }
+    }
+
+    /// Builder-style method to set whether the dialog is open.
+    pub fn with_open(mut self, open: bool) -> Self {
+        self.open = open;
+        self
+    }
+
+    /// Returns `true` if the dialog is open.
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl Dialog {
+    /// Open or close the dialog.
+    ///
+    /// Opening the dialog moves focus into it.
+    pub fn set_open(this: &mut WidgetMut<'_, Self>, open: bool) {
+        if this.widget.open == open {
+            return;
+        }
+        this.widget.open = open;
+        this.ctx.set_stashed(&mut this.widget.child, !open);
+        if open {
+            this.insert_prop(FocusScope);
+            let id = this.ctx.widget_id();
+            this.ctx.set_focus_within(id);
+        } else {
+            this.remove_prop::<FocusScope>();
+        }
+        this.ctx.request_layout();
+        this.ctx.request_accessibility_update();
+    }
+
+    /// Get a mutable reference to the content of the dialog.
+    pub fn child_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, dyn Widget> {
+        this.ctx.get_mut(&mut this.widget.child)
+    }
+}
+
+// --- MARK: IMPL WIDGET
+impl Widget for Dialog {
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        _event: &PointerEvent,
+    ) {
+        // Nothing behind the dialog can be interacted with.
+        if self.open {
+            ctx.set_handled();
+        }
+    }
+
+    fn on_text_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &TextEvent,
+    ) {
+        if let TextEvent::Keyboard(key) = event
+            && self.open
+            && key.state == KeyState::Down
+            && key.key == Key::Named(NamedKey::Escape)
+        {
+            ctx.submit_action(Action::DialogDismissed);
+            ctx.set_handled();
+        }
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        ctx.register_child(&mut self.child);
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx<'_>, props: &mut PropertiesMut<'_>, event: &Update) {
+        if let Update::WidgetAdded = event {
+            ctx.set_stashed(&mut self.child, !self.open);
+            if self.open {
+                props.insert(FocusScope);
+                ctx.set_focus_within(ctx.widget_id());
+            }
+        }
+    }
+
+    fn property_changed(&mut self, ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
+        Background::prop_changed(ctx, property_type);
+    }
+
+    fn layout(
+        &mut self,
+        ctx: &mut LayoutCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        bc: &BoxConstraints,
+    ) -> Size {
+        if !self.open {
+            ctx.skip_layout(&mut self.child);
+            return bc.min();
+        }
+
+        let child_size = ctx.run_layout(&mut self.child, &bc.loosen());
+        // Cover all the available space, so that the content behind the dialog is hidden.
+        let mut size = bc.max();
+        if !size.width.is_finite() {
+            size.width = child_size.width;
+        }
+        if !size.height.is_finite() {
+            size.height = child_size.height;
+        }
+        let size = bc.constrain(size);
+        let origin = Point::new(
+            (size.width - child_size.width) / 2.,
+            (size.height - child_size.height) / 2.,
+        );
+        ctx.place_child(&mut self.child, origin);
+        size
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx<'_>, props: &PropertiesRef<'_>, scene: &mut Scene) {
+        if !self.open {
+            return;
+        }
+        let rect = ctx.size().to_rect();
+        let brush = props.get::<Background>().get_peniko_brush_for_rect(rect);
+        fill(scene, &rect, &brush);
+    }
+
+    fn accessibility_role(&self) -> Role {
+        if self.open {
+            Role::Dialog
+        } else {
+            Role::GenericContainer
+        }
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        if self.open {
+            node.set_modal();
+        } else {
+            node.set_hidden();
+        }
+    }
+
+    fn children_ids(&self) -> SmallVec<[WidgetId; 16]> {
+        smallvec![self.child.id()]
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("Dialog", id = id.trace())
+    }
+
+    fn get_debug_text(&self) -> Option<String> {
+        Some(if self.open { "open" } else { "closed" }.into())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::PointerButton;
+    use crate::core::keyboard::KeyboardEvent;
+    use crate::testing::{TestHarness, TestWidgetExt, widget_ids};
+    use crate::theme::default_property_set;
+    use crate::widgets::{Alignment, Button, Flex, ZStack};
+
+    fn press_escape(harness: &mut TestHarness) {
+        harness.process_text_event(TextEvent::Keyboard(KeyboardEvent {
+            key: Key::Named(NamedKey::Escape),
+            ..Default::default()
+        }));
+    }
+
+    fn focused_id(harness: &TestHarness) -> Option<WidgetId> {
+        harness.focused_widget().map(|widget| widget.id())
+    }
+
+    #[test]
+    fn open_and_close() {
+        let [behind, dialog_id, ok, cancel] = widget_ids();
+        let dialog = Dialog::new(
+            Flex::row()
+                .with_child(Button::new("OK").with_id(ok))
+                .with_child(Button::new("Cancel").with_id(cancel)),
+        )
+        .with_open(false);
+        let widget = ZStack::new()
+            .with_child(Button::new("Behind").with_id(behind), Alignment::TopLeft)
+            .with_child_id(dialog, dialog_id, Alignment::Center);
+        let mut harness = TestHarness::create(default_property_set(), widget);
+        harness.focus_on(Some(behind));
+        assert_eq!(harness.focus_order(), [behind]);
+
+        // Opening the dialog moves focus into it, and traps it.
+        harness.edit_widget(dialog_id, |mut dialog| {
+            let mut dialog = dialog.downcast::<Dialog>();
+            Dialog::set_open(&mut dialog, true);
+        });
+        assert_eq!(focused_id(&harness), Some(ok));
+        assert_eq!(harness.focus_order(), [ok, cancel]);
+        assert_eq!(
+            harness.get_widget(dialog_id).ctx().size(),
+            harness.root_widget().ctx().size()
+        );
+
+        // The content behind the dialog doesn't get pointer events.
+        let behind_center = harness.get_widget(behind).ctx().bounding_rect().center();
+        harness.mouse_move(behind_center);
+        assert!(!harness.get_widget(behind).ctx().is_hovered());
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_button_release(PointerButton::Primary);
+        assert_eq!(harness.pop_action(), None);
+        // Clicking the dialog outside of its content doesn't move focus out of it.
+        assert_eq!(focused_id(&harness), Some(dialog_id));
+
+        press_escape(&mut harness);
+        assert_eq!(
+            harness.pop_action(),
+            Some((Action::DialogDismissed, dialog_id))
+        );
+
+        // Closing the dialog gives focus back.
+        harness.edit_widget(dialog_id, |mut dialog| {
+            let mut dialog = dialog.downcast::<Dialog>();
+            Dialog::set_open(&mut dialog, false);
+        });
+        assert_eq!(focused_id(&harness), Some(behind));
+        assert_eq!(harness.focus_order(), [behind]);
+        assert!(harness.get_widget(ok).ctx().is_stashed());
+    }
+}
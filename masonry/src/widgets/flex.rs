@@ -11,15 +11,17 @@ use smallvec::SmallVec;
 use tracing::{Span, trace_span};
 use vello::Scene;
 use vello::kurbo::common::FloatExt;
-use vello::kurbo::{Affine, Line, Point, Rect, Size, Stroke, Vec2};
+use vello::kurbo::{Affine, Line, Point, Size, Stroke};
 
 use crate::core::{
-    AccessCtx, BoxConstraints, LayoutCtx, PaintCtx, PropertiesMut, PropertiesRef, UpdateCtx,
-    Widget, WidgetId, WidgetMut, WidgetPod,
+    AccessCtx, Axis, BoxConstraints, IntrinsicSize, LayoutCtx, MeasureCtx, PaintCtx, PropertiesMut,
+    PropertiesRef, UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
 };
 use crate::debug_panic;
-use crate::properties::{Background, BorderColor, BorderWidth, CornerRadius, Padding};
-use crate::util::{fill, stroke};
+use crate::properties::{
+    Background, BorderColor, BorderGradient, BorderWidth, CornerRadius, Padding,
+};
+use crate::util::fill;
 
 /// A container with either horizontal or vertical layout.
 ///
@@ -31,6 +33,7 @@ pub struct Flex {
     cross_alignment: CrossAxisAlignment,
     main_alignment: MainAxisAlignment,
     fill_major_axis: bool,
+    keep_min_content: bool,
     children: Vec<Child>,
     old_bc: BoxConstraints,
     gap: Option<f64>,
@@ -48,19 +51,6 @@ pub struct FlexParams {
     alignment: Option<CrossAxisAlignment>,
 }
 
-/// An axis in visual space.
-///
-/// Most often used by widgets to describe
-/// the direction in which they grow as their number of children increases.
-/// Has some methods for manipulating geometry with respect to the axis.
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum Axis {
-    /// The x axis
-    Horizontal,
-    /// The y axis
-    Vertical,
-}
-
 /// The alignment of the widgets on the container's cross (or minor) axis.
 ///
 /// If a widget is smaller than the container on the minor axis, this determines
@@ -132,6 +122,7 @@ impl Flex {
             cross_alignment: CrossAxisAlignment::Center,
             main_alignment: MainAxisAlignment::Start,
             fill_major_axis: false,
+            keep_min_content: false,
             old_bc: BoxConstraints::tight(Size::ZERO),
             gap: None,
         }
@@ -171,6 +162,19 @@ impl Flex {
         self
     }
 
+    /// Builder-style method for setting whether flexible children keep their
+    /// min-content size on the main axis.
+    ///
+    /// When this is `true`, flexible children never get less space on the main axis than their
+    /// min-content size (see [`Widget::measure`]), like flex items with `min-width: auto` in CSS.
+    /// If there isn't enough space for them, the container overflows.
+    ///
+    /// The default is `false`.
+    pub fn keep_min_content(mut self, keep: bool) -> Self {
+        self.keep_min_content = keep;
+        self
+    }
+
     /// Builder-style method for setting the spacing along the
     /// major axis between any two elements in logical pixels.
     ///
@@ -337,6 +341,14 @@ impl Flex {
         this.ctx.request_layout();
     }
 
+    /// Set whether flexible children keep their min-content size on the main axis.
+    ///
+    /// See [`Flex::keep_min_content`] for details.
+    pub fn set_keep_min_content(this: &mut WidgetMut<'_, Self>, keep: bool) {
+        this.widget.keep_min_content = keep;
+        this.ctx.request_layout();
+    }
+
     /// Set the spacing along the major axis between any two elements in logical pixels.
     ///
     /// Equivalent to the css [gap] property.
@@ -587,6 +599,21 @@ impl Flex {
         this.ctx.request_layout();
     }
 
+    /// Move the child at `from` to `to`, shifting the children in between.
+    ///
+    /// This child can be a widget or a spacer.
+    /// Unlike removing the child and inserting it again, this keeps the child widget
+    /// in the tree, so it keeps its state, such as focus.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either index is out of bounds.
+    pub fn move_child(this: &mut WidgetMut<'_, Self>, from: usize, to: usize) {
+        let child = this.widget.children.remove(from);
+        this.widget.children.insert(to, child);
+        this.ctx.children_changed();
+    }
+
     /// Returns a mutable reference to the child widget at `idx`.
     ///
     /// Returns `None` if the child at `idx` is a spacer.
@@ -683,97 +710,6 @@ impl Flex {
 }
 
 // --- MARK: OTHER IMPLS---
-impl Axis {
-    /// Get the axis perpendicular to this one.
-    pub fn cross(self) -> Self {
-        match self {
-            Self::Horizontal => Self::Vertical,
-            Self::Vertical => Self::Horizontal,
-        }
-    }
-
-    /// Extract from the argument the magnitude along this axis
-    pub fn major(self, size: Size) -> f64 {
-        match self {
-            Self::Horizontal => size.width,
-            Self::Vertical => size.height,
-        }
-    }
-
-    /// Extract from the argument the magnitude along the perpendicular axis
-    pub fn minor(self, size: Size) -> f64 {
-        self.cross().major(size)
-    }
-
-    /// Extract the extent of the argument in this axis as a pair.
-    pub fn major_span(self, rect: Rect) -> (f64, f64) {
-        match self {
-            Self::Horizontal => (rect.x0, rect.x1),
-            Self::Vertical => (rect.y0, rect.y1),
-        }
-    }
-
-    /// Extract the extent of the argument in the minor axis as a pair.
-    pub fn minor_span(self, rect: Rect) -> (f64, f64) {
-        self.cross().major_span(rect)
-    }
-
-    /// Extract the coordinate locating the argument with respect to this axis.
-    pub fn major_pos(self, pos: Point) -> f64 {
-        match self {
-            Self::Horizontal => pos.x,
-            Self::Vertical => pos.y,
-        }
-    }
-
-    /// Extract the coordinate locating the argument with respect to this axis.
-    pub fn major_vec(self, vec: Vec2) -> f64 {
-        match self {
-            Self::Horizontal => vec.x,
-            Self::Vertical => vec.y,
-        }
-    }
-
-    /// Extract the coordinate locating the argument with respect to the perpendicular axis.
-    pub fn minor_pos(self, pos: Point) -> f64 {
-        self.cross().major_pos(pos)
-    }
-
-    /// Extract the coordinate locating the argument with respect to the perpendicular axis.
-    pub fn minor_vec(self, vec: Vec2) -> f64 {
-        self.cross().major_vec(vec)
-    }
-
-    // TODO - make_pos, make_size, make_rect
-    /// Arrange the major and minor measurements with respect to this axis such that it forms
-    /// an (x, y) pair.
-    pub fn pack(self, major: f64, minor: f64) -> (f64, f64) {
-        match self {
-            Self::Horizontal => (major, minor),
-            Self::Vertical => (minor, major),
-        }
-    }
-
-    /// Generate constraints with new values on the major axis.
-    pub(crate) fn constraints(
-        self,
-        bc: &BoxConstraints,
-        min_major: f64,
-        major: f64,
-    ) -> BoxConstraints {
-        match self {
-            Self::Horizontal => BoxConstraints::new(
-                Size::new(min_major, bc.min().height),
-                Size::new(major, bc.max().height),
-            ),
-            Self::Vertical => BoxConstraints::new(
-                Size::new(bc.min().width, min_major),
-                Size::new(bc.max().width, major),
-            ),
-        }
-    }
-}
-
 impl FlexParams {
     /// Create custom `FlexParams` with a specific `flex_factor` and an optional
     /// [`CrossAxisAlignment`].
@@ -938,6 +874,25 @@ fn axis_default_spacer(axis: Axis) -> f64 {
     }
 }
 
+/// Generate constraints with new values on the major axis.
+fn major_axis_constraints(
+    axis: Axis,
+    bc: &BoxConstraints,
+    min_major: f64,
+    major: f64,
+) -> BoxConstraints {
+    match axis {
+        Axis::Horizontal => BoxConstraints::new(
+            Size::new(min_major, bc.min().height),
+            Size::new(major, bc.max().height),
+        ),
+        Axis::Vertical => BoxConstraints::new(
+            Size::new(bc.min().width, min_major),
+            Size::new(bc.max().width, major),
+        ),
+    }
+}
+
 fn new_flex_child(params: FlexParams, widget: WidgetPod<dyn Widget>) -> Child {
     if let Some(flex) = params.flex {
         if flex.is_normal() && flex > 0.0 {
@@ -974,6 +929,7 @@ impl Widget for Flex {
     fn property_changed(&mut self, ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
         Background::prop_changed(ctx, property_type);
         BorderColor::prop_changed(ctx, property_type);
+        BorderGradient::prop_changed(ctx, property_type);
         BorderWidth::prop_changed(ctx, property_type);
         CornerRadius::prop_changed(ctx, property_type);
         Padding::prop_changed(ctx, property_type);
@@ -1091,8 +1047,18 @@ impl Widget for Flex {
                         let actual_major = desired_major.round();
                         remainder = desired_major - actual_major;
 
+                        let actual_major = if self.keep_min_content {
+                            let min_major = ctx
+                                .measure_child(widget, self.direction, None)
+                                .map_or(0.0, |size| size.min.ceil());
+                            actual_major.max(min_major)
+                        } else {
+                            actual_major
+                        };
+
                         let old_size = ctx.old_size();
-                        let child_bc = self.direction.constraints(&loosened_bc, 0.0, actual_major);
+                        let child_bc =
+                            major_axis_constraints(self.direction, &loosened_bc, 0.0, actual_major);
                         let child_size = ctx.run_layout(widget, &child_bc);
 
                         if old_size != child_size {
@@ -1211,7 +1177,12 @@ impl Widget for Flex {
         }
 
         if flex_sum > MIN_FLEX_SUM {
-            major = total_major;
+            major = if self.keep_min_content {
+                // Flex children may overflow if their min-content size doesn't fit.
+                major.max(total_major)
+            } else {
+                total_major
+            };
         }
 
         // my_size may be larger than the given constraints.
@@ -1244,18 +1215,65 @@ impl Widget for Flex {
         my_size
     }
 
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        props: &PropertiesRef<'_>,
+        axis: Axis,
+        cross_length: Option<f64>,
+    ) -> Option<IntrinsicSize> {
+        let border = props.get::<BorderWidth>();
+        let padding = props.get::<Padding>();
+
+        let cross_length = border.measure_down(cross_length);
+        let cross_length = padding.measure_down(axis, cross_length);
+
+        let mut size = IntrinsicSize::ZERO;
+        if axis == self.direction {
+            // Children are placed one after the other, with gaps between them.
+            let gap = self.gap.unwrap_or(axis_default_spacer(self.direction));
+            let total_gap = self.children.len().saturating_sub(1) as f64 * gap;
+            size = IntrinsicSize::fixed(total_gap);
+            for child in &mut self.children {
+                match child {
+                    Child::Fixed { widget, .. } | Child::Flex { widget, .. } => {
+                        size = size.sum(ctx.measure_child(widget, axis, cross_length)?);
+                    }
+                    Child::FixedSpacer(length, _) => {
+                        size = size.sum(IntrinsicSize::fixed(length.max(0.0)));
+                    }
+                    Child::FlexedSpacer(..) => {}
+                }
+            }
+        } else {
+            // Children are placed side by side.
+            // Flex children may end up with less than `cross_length` on the main axis,
+            // so this is only an approximation for them.
+            for child in &mut self.children {
+                if let Child::Fixed { widget, .. } | Child::Flex { widget, .. } = child {
+                    size = size.max(ctx.measure_child(widget, axis, cross_length)?);
+                }
+            }
+        }
+
+        let size = padding.measure_up(axis, size);
+        let size = border.measure_up(size);
+        Some(size)
+    }
+
     fn paint(&mut self, ctx: &mut PaintCtx<'_>, props: &PropertiesRef<'_>, scene: &mut Scene) {
         let border_width = props.get::<BorderWidth>();
         let border_radius = props.get::<CornerRadius>();
         let bg = props.get::<Background>();
         let border_color = props.get::<BorderColor>();
+        let border_gradient = props.get::<BorderGradient>();
 
         let bg_rect = border_width.bg_rect(ctx.size(), border_radius);
         let border_rect = border_width.border_rect(ctx.size(), border_radius);
 
         let brush = bg.get_peniko_brush_for_rect(bg_rect.rect());
         fill(scene, &bg_rect, &brush);
-        stroke(scene, &border_rect, border_color.color, border_width.width);
+        border_gradient.paint(scene, &border_rect, border_width, border_color, None);
 
         // paint the baseline if we're debugging layout
         if ctx.debug_paint_enabled() && ctx.baseline_offset() != 0.0 {
@@ -1300,7 +1318,7 @@ mod tests {
     use crate::assert_render_snapshot;
     use crate::testing::TestHarness;
     use crate::theme::default_property_set;
-    use crate::widgets::Label;
+    use crate::widgets::{Button, Label};
 
     #[test]
     #[allow(clippy::cognitive_complexity)]
@@ -1706,6 +1724,39 @@ mod tests {
         // TODO - test out-of-bounds access?
     }
 
+    #[test]
+    fn move_flex_child() {
+        let [first, second, third] = [WidgetId::next(), WidgetId::next(), WidgetId::next()];
+        let widget = Flex::column()
+            .with_child_id(Button::new("first"), first)
+            .with_child_id(Button::new("second"), second)
+            .with_child_id(Button::new("third"), third);
+
+        let window_size = Size::new(200.0, 150.0);
+        let mut harness =
+            TestHarness::create_with_size(default_property_set(), widget, window_size);
+        harness.focus_on(Some(first));
+        let first_y = harness.get_widget(first).ctx().window_origin().y;
+
+        harness.edit_root_widget(|mut flex| {
+            let mut flex = flex.downcast::<Flex>();
+            Flex::move_child(&mut flex, 0, 2);
+        });
+
+        let children: Vec<_> = harness
+            .root_widget()
+            .children()
+            .iter()
+            .map(|child| child.id())
+            .collect();
+        assert_eq!(children, [second, third, first]);
+        assert_eq!(harness.get_widget(second).ctx().window_origin().y, first_y);
+        assert_eq!(
+            harness.focused_widget().map(|widget| widget.id()),
+            Some(first)
+        );
+    }
+
     #[test]
     fn divide_by_zero() {
         let widget = Flex::column().with_flex_spacer(0.0);
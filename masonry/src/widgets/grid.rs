@@ -11,21 +11,27 @@ use vello::Scene;
 use vello::kurbo::{Affine, Line, Point, Size, Stroke};
 
 use crate::core::{
-    AccessCtx, BoxConstraints, LayoutCtx, PaintCtx, PropertiesMut, PropertiesRef, RegisterCtx,
-    Widget, WidgetId, WidgetMut, WidgetPod,
+    AccessCtx, Axis, BoxConstraints, IntrinsicSize, LayoutCtx, MeasureCtx, PaintCtx, PropertiesMut,
+    PropertiesRef, RegisterCtx, Widget, WidgetId, WidgetMut, WidgetPod,
 };
 use crate::debug_panic;
-use crate::properties::{Background, BorderColor, BorderWidth, CornerRadius, Padding};
-use crate::util::{fill, stroke};
+use crate::properties::{
+    Background, BorderColor, BorderGradient, BorderWidth, CornerRadius, Padding,
+};
+use crate::util::fill;
 
 /// A widget that arranges its children in a grid.
 ///
+/// By default, all columns have the same width. With [`with_auto_column_widths`](Self::with_auto_column_widths),
+/// columns are sized to fit their content instead.
+///
 #[doc = crate::include_screenshot!("grid_with_changed_spacing.png", "Grid with buttons of various sizes.")]
 pub struct Grid {
     children: Vec<Child>,
     grid_width: i32,
     grid_height: i32,
     grid_spacing: f64,
+    auto_column_widths: bool,
 }
 
 struct Child {
@@ -58,6 +64,7 @@ impl Grid {
             grid_width: width,
             grid_height: height,
             grid_spacing: 0.0,
+            auto_column_widths: false,
         }
     }
 
@@ -67,6 +74,19 @@ impl Grid {
         self
     }
 
+    /// Builder-style method for sizing columns to fit their content.
+    ///
+    /// Each column gets the preferred width of its widest child, as reported by
+    /// [`Widget::measure`]. Remaining space is shared equally between columns.
+    /// If there isn't enough space, columns shrink towards the min width of their children.
+    ///
+    /// Only children spanning a single column are taken into account.
+    /// Children which don't support measurement don't affect the width of their column.
+    pub fn with_auto_column_widths(mut self, auto: bool) -> Self {
+        self.auto_column_widths = auto;
+        self
+    }
+
     /// Builder-style method to add a child widget.
     pub fn with_child(self, child: impl Widget, params: GridParams) -> Self {
         self.with_child_pod(WidgetPod::new(child).erased(), params)
@@ -111,6 +131,83 @@ fn new_grid_child(params: GridParams, widget: WidgetPod<dyn Widget>) -> Child {
     }
 }
 
+// --- MARK: COLUMN WIDTHS
+/// Measure the width of each column, from the children which span a single column.
+///
+/// Returns `None` if `measure_child` returns `None` for one of these children.
+fn measure_columns(
+    children: &mut [Child],
+    column_count: i32,
+    row_height: impl Fn(&Child) -> Option<f64>,
+    mut measure_child: impl FnMut(&mut WidgetPod<dyn Widget>, Option<f64>) -> Option<IntrinsicSize>,
+) -> Option<Vec<IntrinsicSize>> {
+    let mut columns = vec![IntrinsicSize::ZERO; column_count.max(0) as usize];
+    for child in children {
+        if child.width != 1 {
+            continue;
+        }
+        let row_height = row_height(child);
+        let Some(column) = columns.get_mut(child.x as usize) else {
+            continue;
+        };
+        let size = measure_child(&mut child.widget, row_height)?;
+        *column = column.max(size);
+    }
+    Some(columns)
+}
+
+/// Share the available width between columns.
+///
+/// If there is enough space, each column gets its preferred width, and the remaining
+/// space is shared equally. Otherwise, columns shrink towards their min width, then
+/// below it proportionally.
+fn distribute_column_widths(columns: &[IntrinsicSize], available: f64) -> Vec<f64> {
+    if columns.is_empty() {
+        return Vec::new();
+    }
+    let available = available.max(0.0);
+    let total_min: f64 = columns.iter().map(|column| column.min).sum();
+    let total_preferred: f64 = columns.iter().map(|column| column.preferred).sum();
+
+    if total_preferred <= available {
+        let extra = (available - total_preferred) / columns.len() as f64;
+        columns
+            .iter()
+            .map(|column| column.preferred + extra)
+            .collect()
+    } else if total_min <= available {
+        let ratio = (available - total_min) / (total_preferred - total_min);
+        columns
+            .iter()
+            .map(|column| column.min + (column.preferred - column.min) * ratio)
+            .collect()
+    } else {
+        let ratio = available / total_min;
+        columns.iter().map(|column| column.min * ratio).collect()
+    }
+}
+
+/// Returns the x position of each column, plus the position after the last column.
+fn column_offsets(widths: &[f64], spacing: f64) -> Vec<f64> {
+    let mut offsets = Vec::with_capacity(widths.len() + 1);
+    let mut x = 0.0;
+    offsets.push(x);
+    for width in widths {
+        x += width + spacing;
+        offsets.push(x);
+    }
+    offsets
+}
+
+/// Returns the x position and width of a cell starting at column `x` and spanning `width` columns.
+fn column_span(offsets: &[f64], x: i32, width: i32, spacing: f64) -> (f64, f64) {
+    let last = offsets.len() - 1;
+    let start = (x.max(0) as usize).min(last);
+    let end = ((x + width).max(0) as usize).min(last);
+    let pos = offsets[start];
+    (pos, (offsets[end] - pos - spacing).max(0.0))
+}
+
 // --- MARK: IMPL GRIDPARAMS
 impl GridParams {
     /// Create grid parameters with the given values.
@@ -231,6 +328,14 @@ impl Grid {
         this.ctx.request_layout();
     }
 
+    /// Set whether columns are sized to fit their content.
+    ///
+    /// See [`with_auto_column_widths`](Grid::with_auto_column_widths) for details.
+    pub fn set_auto_column_widths(this: &mut WidgetMut<'_, Self>, auto: bool) {
+        this.widget.auto_column_widths = auto;
+        this.ctx.request_layout();
+    }
+
     // TODO - Some of these method names should maybe be changed.
     // "height" and "width" are misleading, since they suggest a pixel size.
     /// Set the number of columns of the grid.
@@ -283,6 +388,20 @@ impl Grid {
         this.ctx.remove_child(child.widget);
         this.ctx.request_layout();
     }
+
+    /// Move the child at `from` to `to`, shifting the children in between.
+    ///
+    /// The position of children in the grid is set by their [`GridParams`], so this only
+    /// changes the order in which they are painted and focused.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either index is out of bounds.
+    pub fn move_child(this: &mut WidgetMut<'_, Self>, from: usize, to: usize) {
+        let child = this.widget.children.remove(from);
+        this.widget.children.insert(to, child);
+        this.ctx.children_changed();
+    }
 }
 
 // --- MARK: IMPL WIDGET---
@@ -296,6 +415,7 @@ impl Widget for Grid {
     fn property_changed(&mut self, ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
         Background::prop_changed(ctx, property_type);
         BorderColor::prop_changed(ctx, property_type);
+        BorderGradient::prop_changed(ctx, property_type);
         BorderWidth::prop_changed(ctx, property_type);
         CornerRadius::prop_changed(ctx, property_type);
         Padding::prop_changed(ctx, property_type);
@@ -326,17 +446,47 @@ impl Widget for Grid {
                 total_size
             );
         }
-        let width_unit = (total_size.width + self.grid_spacing) / (self.grid_width as f64);
-        let height_unit = (total_size.height + self.grid_spacing) / (self.grid_height as f64);
+        let spacing = self.grid_spacing;
+        let width_unit = (total_size.width + spacing) / (self.grid_width as f64);
+        let height_unit = (total_size.height + spacing) / (self.grid_height as f64);
+
+        let column_offsets = if self.auto_column_widths {
+            let columns = measure_columns(
+                &mut self.children,
+                self.grid_width,
+                |child| Some((child.height as f64 * height_unit - spacing).max(0.0)),
+                // Children which can't be measured don't affect column widths.
+                |widget, row_height| {
+                    Some(
+                        ctx.measure_child(widget, Axis::Horizontal, row_height)
+                            .unwrap_or_default(),
+                    )
+                },
+            )
+            .unwrap_or_default();
+            let available = total_size.width - spacing * (self.grid_width - 1).max(0) as f64;
+            let widths = distribute_column_widths(&columns, available);
+            Some(column_offsets(&widths, spacing))
+        } else {
+            None
+        };
+
         for child in &mut self.children {
+            let (x, width) = match &column_offsets {
+                Some(offsets) => column_span(offsets, child.x, child.width, spacing),
+                None => (
+                    child.x as f64 * width_unit,
+                    (child.width as f64 * width_unit - spacing).max(0.0),
+                ),
+            };
             let cell_size = Size::new(
-                (child.width as f64 * width_unit - self.grid_spacing).max(0.0),
-                (child.height as f64 * height_unit - self.grid_spacing).max(0.0),
+                width,
+                (child.height as f64 * height_unit - spacing).max(0.0),
             );
             let child_bc = BoxConstraints::new(cell_size, cell_size);
             let _ = ctx.run_layout(&mut child.widget, &child_bc);
 
-            let child_pos = Point::new(child.x as f64 * width_unit, child.y as f64 * height_unit);
+            let child_pos = Point::new(x, child.y as f64 * height_unit);
             ctx.place_child(&mut child.widget, child_pos + origin);
         }
 
@@ -345,18 +495,106 @@ impl Widget for Grid {
         total_size
     }
 
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        props: &PropertiesRef<'_>,
+        axis: Axis,
+        cross_length: Option<f64>,
+    ) -> Option<IntrinsicSize> {
+        let border = props.get::<BorderWidth>();
+        let padding = props.get::<Padding>();
+
+        let cross_length = border.measure_down(cross_length);
+        let cross_length = padding.measure_down(axis, cross_length);
+
+        let spacing = self.grid_spacing;
+        let size = match axis {
+            Axis::Horizontal => {
+                let height_unit =
+                    cross_length.map(|height| (height + spacing) / self.grid_height as f64);
+                let columns = measure_columns(
+                    &mut self.children,
+                    self.grid_width,
+                    |child| height_unit.map(|unit| (child.height as f64 * unit - spacing).max(0.0)),
+                    |widget, row_height| ctx.measure_child(widget, Axis::Horizontal, row_height),
+                )?;
+                let columns = if self.auto_column_widths {
+                    columns
+                } else {
+                    // All columns are as wide as the widest one.
+                    let widest = columns
+                        .iter()
+                        .fold(IntrinsicSize::ZERO, |widest, column| widest.max(*column));
+                    vec![widest; columns.len()]
+                };
+                let total_spacing = spacing * (self.grid_width - 1).max(0) as f64;
+                columns
+                    .into_iter()
+                    .fold(IntrinsicSize::fixed(total_spacing), IntrinsicSize::sum)
+            }
+            Axis::Vertical => {
+                // The height of each child depends on the width of its cell.
+                let column_offsets = match cross_length {
+                    Some(width) if self.auto_column_widths => {
+                        let columns = measure_columns(
+                            &mut self.children,
+                            self.grid_width,
+                            |_| None,
+                            |widget, row_height| {
+                                ctx.measure_child(widget, Axis::Horizontal, row_height)
+                            },
+                        )?;
+                        let available = width - spacing * (self.grid_width - 1).max(0) as f64;
+                        let widths = distribute_column_widths(&columns, available);
+                        Some(column_offsets(&widths, spacing))
+                    }
+                    _ => None,
+                };
+                let width_unit =
+                    cross_length.map(|width| (width + spacing) / self.grid_width as f64);
+
+                // All rows have the same height, so we only need the tallest one.
+                let mut row = IntrinsicSize::ZERO;
+                for child in &mut self.children {
+                    if child.height != 1 {
+                        continue;
+                    }
+                    let cell_width = match (&column_offsets, width_unit) {
+                        (Some(offsets), _) => {
+                            Some(column_span(offsets, child.x, child.width, spacing).1)
+                        }
+                        (None, Some(unit)) => Some((child.width as f64 * unit - spacing).max(0.0)),
+                        (None, None) => None,
+                    };
+                    let size = ctx.measure_child(&mut child.widget, Axis::Vertical, cell_width)?;
+                    row = row.max(size);
+                }
+                let row_count = self.grid_height.max(0) as f64;
+                let total_spacing = spacing * (row_count - 1.0).max(0.0);
+                IntrinsicSize::new(row.min * row_count, row.preferred * row_count)
+                    .expand(total_spacing)
+            }
+        };
+
+        let size = padding.measure_up(axis, size);
+        let size = border.measure_up(size);
+        Some(size)
+    }
+
     fn paint(&mut self, ctx: &mut PaintCtx<'_>, props: &PropertiesRef<'_>, scene: &mut Scene) {
         let border_width = props.get::<BorderWidth>();
         let border_radius = props.get::<CornerRadius>();
         let bg = props.get::<Background>();
         let border_color = props.get::<BorderColor>();
+        let border_gradient = props.get::<BorderGradient>();
 
         let bg_rect = border_width.bg_rect(ctx.size(), border_radius);
         let border_rect = border_width.border_rect(ctx.size(), border_radius);
 
         let brush = bg.get_peniko_brush_for_rect(bg_rect.rect());
         fill(scene, &bg_rect, &brush);
-        stroke(scene, &border_rect, border_color.color, border_width.width);
+        border_gradient.paint(scene, &border_rect, border_width, border_color, None);
 
         // paint the baseline if we're debugging layout
         if ctx.debug_paint_enabled() && ctx.baseline_offset() != 0.0 {
@@ -12,8 +12,8 @@ use vello::kurbo::{Affine, Size};
 use vello::peniko::{BlendMode, Image as ImageBuf};
 
 use crate::core::{
-    AccessCtx, BoxConstraints, LayoutCtx, ObjectFit, PaintCtx, PropertiesMut, PropertiesRef,
-    RegisterCtx, Update, UpdateCtx, Widget, WidgetId, WidgetMut,
+    AccessCtx, Axis, BoxConstraints, IntrinsicSize, LayoutCtx, MeasureCtx, ObjectFit, PaintCtx,
+    PropertiesMut, PropertiesRef, RegisterCtx, Update, UpdateCtx, Widget, WidgetId, WidgetMut,
 };
 
 // TODO - Resolve name collision between masonry::Image and peniko::Image
@@ -116,6 +116,35 @@ impl Widget for Image {
         }
     }
 
+    fn measure(
+        &mut self,
+        _ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        cross_length: Option<f64>,
+    ) -> Option<IntrinsicSize> {
+        let image_size = Size::new(self.image_data.width as f64, self.image_data.height as f64);
+        let natural_length = axis.major(image_size);
+        if self.object_fit == ObjectFit::None {
+            return Some(IntrinsicSize::fixed(natural_length));
+        }
+        // Modes which keep the aspect ratio scale the image to the length on the other axis.
+        let preferred = match cross_length {
+            Some(cross_length)
+                if self.object_fit != ObjectFit::Fill && !image_size.is_zero_area() =>
+            {
+                let scaled = cross_length * natural_length / axis.minor(image_size);
+                if self.object_fit == ObjectFit::ScaleDown {
+                    scaled.min(natural_length)
+                } else {
+                    scaled
+                }
+            }
+            _ => natural_length,
+        };
+        Some(IntrinsicSize::new(0., preferred))
+    }
+
     fn paint(&mut self, ctx: &mut PaintCtx<'_>, _props: &PropertiesRef<'_>, scene: &mut Scene) {
         let image_size = Size::new(self.image_data.width as f64, self.image_data.height as f64);
         let transform = self.object_fit.affine_to_fill(ctx.size(), image_size);
@@ -2,15 +2,17 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::debug_panic;
-use crate::util::{fill, stroke};
+use crate::util::fill;
 use accesskit::{Node, Role};
 use masonry_core::core::WidgetMut;
 use smallvec::SmallVec;
 use tracing::{Span, trace_span};
-use vello::kurbo::{Affine, Line, Point, Stroke};
+use vello::kurbo::{Affine, Line, Point, Rect, Stroke, Vec2};
 
 use crate::core::{AccessCtx, PropertiesRef, Widget, WidgetId, WidgetPod};
-use crate::properties::{Background, BorderColor, BorderWidth, CornerRadius, Padding};
+use crate::properties::{
+    Background, BorderColor, BorderGradient, BorderWidth, CornerRadius, Padding,
+};
 
 /// A widget that displays only one of its children at a time.
 ///
@@ -26,6 +28,60 @@ pub struct IndexedStack {
     children: Vec<WidgetPod<dyn Widget>>,
     // Note: active_child must be 0 if there are no children
     active_child: usize,
+    transition: Option<ActiveTransition>,
+    /// The previously active child, if it was removed during the transition.
+    ///
+    /// It's only removed from the widget tree when the transition ends.
+    leaving: Option<WidgetPod<dyn Widget>>,
+    /// Whether a clip path was set for a transition and hasn't been cleared yet.
+    clipped: bool,
+}
+
+/// How an [`IndexedStack`] animates a change of its active child.
+///
+/// See [`IndexedStack::transition_to`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum StackTransition {
+    /// The new child is shown immediately.
+    #[default]
+    None,
+    /// The new child slides in from the right, while the previous one slides out to the left.
+    ///
+    /// This is the usual animation for navigating to a new screen.
+    Push,
+    /// The new child slides in from the left, while the previous one slides out to the right.
+    ///
+    /// This is the usual animation for going back to the previous screen.
+    Pop,
+}
+
+/// The duration of a [`StackTransition`], in nanoseconds.
+const TRANSITION_DURATION: u64 = 250_000_000;
+
+struct ActiveTransition {
+    kind: StackTransition,
+    /// The previously active child.
+    from: Option<WidgetId>,
+    /// The time since the transition started, in nanoseconds.
+    elapsed: u64,
+}
+
+impl ActiveTransition {
+    /// The horizontal offsets of the new and previous children, given the stack's width.
+    fn offsets(&self, width: f64) -> (f64, f64) {
+        let t = (self.elapsed as f64 / TRANSITION_DURATION as f64).min(1.0);
+        // Ease out, so that the transition responds quickly.
+        let progress = 1.0 - (1.0 - t).powi(3);
+        let direction = if self.kind == StackTransition::Pop {
+            -1.0
+        } else {
+            1.0
+        };
+        (
+            direction * width * (1.0 - progress),
+            -direction * width * progress,
+        )
+    }
 }
 
 // --- MARK: IMPL INDEXEDSTACK ---
@@ -143,6 +199,38 @@ impl IndexedStack {
     pub fn set_active_child(this: &mut WidgetMut<'_, Self>, idx: usize) {
         assert!((this.widget.children.is_empty() && idx == 0) || idx < this.widget.children.len());
         this.widget.active_child = idx;
+        this.widget.transition = None;
+        Self::remove_leaving_child(this);
+        this.ctx.request_layout();
+    }
+
+    /// Change the active child, animating the change with `transition`.
+    ///
+    /// While the animation runs, the previously active child is shown too, even if it's removed.
+    /// Changing the active child with [`set_active_child`](Self::set_active_child) ends the animation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn transition_to(this: &mut WidgetMut<'_, Self>, idx: usize, transition: StackTransition) {
+        assert!(idx < this.widget.children.len());
+        if idx == this.widget.active_child {
+            return;
+        }
+        Self::remove_leaving_child(this);
+        let from = this.widget.children.get(this.widget.active_child);
+        this.widget.transition = match transition {
+            StackTransition::None => None,
+            kind => Some(ActiveTransition {
+                kind,
+                from: from.map(WidgetPod::id),
+                elapsed: 0,
+            }),
+        };
+        this.widget.active_child = idx;
+        if this.widget.transition.is_some() {
+            this.ctx.request_anim_frame();
+        }
         this.ctx.request_layout();
     }
 
@@ -162,11 +250,19 @@ impl IndexedStack {
     /// Removes a child widget at the given index. If the active is removed,
     /// the first child in the stack will be selected as active.
     ///
+    /// If the child is the one a [transition](Self::transition_to) started from,
+    /// it stays visible until the transition ends.
+    ///
     /// # Panics
     ///
     /// Panics if the index is out of bounds.
     pub fn remove_child(this: &mut WidgetMut<'_, Self>, idx: usize) {
         let child = this.widget.children.remove(idx);
+        let is_leaving = this
+            .widget
+            .transition
+            .as_ref()
+            .is_some_and(|transition| transition.from == Some(child.id()));
         if idx == this.widget.active_child {
             // This is valid even if we are removing the last child,
             // since `active_child` must be 0 in that case
@@ -175,7 +271,41 @@ impl IndexedStack {
             // correct the index to prevent the active element changing
             this.widget.active_child -= 1;
         }
-        this.ctx.remove_child(child);
+        if is_leaving {
+            Self::remove_leaving_child(this);
+            this.widget.leaving = Some(child);
+        } else {
+            this.ctx.remove_child(child);
+        }
+        this.ctx.children_changed();
+        this.ctx.request_layout();
+    }
+
+    fn remove_leaving_child(this: &mut WidgetMut<'_, Self>) {
+        if let Some(child) = this.widget.leaving.take() {
+            this.ctx.remove_child(child);
+            this.ctx.children_changed();
+        }
+    }
+
+    /// Move the child at `from` to `to`, shifting the children in between.
+    ///
+    /// The active child stays the same widget, even if its index changes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either index is out of bounds.
+    pub fn move_child(this: &mut WidgetMut<'_, Self>, from: usize, to: usize) {
+        let child = this.widget.children.remove(from);
+        this.widget.children.insert(to, child);
+        let active = this.widget.active_child;
+        if active == from {
+            this.widget.active_child = to;
+        } else if from < active && active <= to {
+            this.widget.active_child -= 1;
+        } else if to <= active && active < from {
+            this.widget.active_child += 1;
+        }
         this.ctx.children_changed();
         this.ctx.request_layout();
     }
@@ -187,6 +317,9 @@ impl Widget for IndexedStack {
         for child in self.children.iter_mut() {
             ctx.register_child(child);
         }
+        if let Some(child) = &mut self.leaving {
+            ctx.register_child(child);
+        }
     }
 
     fn property_changed(
@@ -196,11 +329,34 @@ impl Widget for IndexedStack {
     ) {
         Background::prop_changed(ctx, property_type);
         BorderColor::prop_changed(ctx, property_type);
+        BorderGradient::prop_changed(ctx, property_type);
         BorderWidth::prop_changed(ctx, property_type);
         CornerRadius::prop_changed(ctx, property_type);
         Padding::prop_changed(ctx, property_type);
     }
 
+    fn on_anim_frame(
+        &mut self,
+        ctx: &mut masonry_core::core::UpdateCtx<'_>,
+        _props: &mut masonry_core::core::PropertiesMut<'_>,
+        interval: u64,
+    ) {
+        let Some(transition) = &mut self.transition else {
+            return;
+        };
+        transition.elapsed += interval;
+        if transition.elapsed >= TRANSITION_DURATION {
+            self.transition = None;
+            if let Some(child) = self.leaving.take() {
+                ctx.remove_child(child);
+                ctx.children_changed();
+            }
+        } else {
+            ctx.request_anim_frame();
+        }
+        ctx.request_layout();
+    }
+
     fn layout(
         &mut self,
         ctx: &mut masonry_core::core::LayoutCtx<'_>,
@@ -227,13 +383,20 @@ impl Widget for IndexedStack {
                 self.children.len()
             );
         }
+        let from = self
+            .transition
+            .as_ref()
+            .and_then(|transition| transition.from);
         let mut child_size = bc.min();
         for (idx, child) in self.children.iter_mut().enumerate() {
             if idx == self.active_child {
                 ctx.set_stashed(child, false);
                 let child_bc = bc;
                 child_size = ctx.run_layout(child, &child_bc);
-                ctx.place_child(child, origin);
+            } else if from == Some(child.id()) {
+                // The previous child stays visible until the transition ends.
+                ctx.set_stashed(child, false);
+                ctx.run_layout(child, &bc);
             } else {
                 // TODO: move set_stashed to a different layout pass when possible,
                 // and remove skip_layout.
@@ -241,6 +404,32 @@ impl Widget for IndexedStack {
                 ctx.skip_layout(child);
             }
         }
+        if let Some(child) = &mut self.leaving {
+            ctx.run_layout(child, &bc);
+        }
+
+        let (active_offset, from_offset) = match &self.transition {
+            Some(transition) => transition.offsets(child_size.width),
+            None => (0.0, 0.0),
+        };
+        for (idx, child) in self.children.iter_mut().enumerate() {
+            if idx == self.active_child {
+                ctx.place_child(child, origin + Vec2::new(active_offset, 0.0));
+            } else if from == Some(child.id()) {
+                ctx.place_child(child, origin + Vec2::new(from_offset, 0.0));
+            }
+        }
+        if let Some(child) = &mut self.leaving {
+            ctx.place_child(child, origin + Vec2::new(from_offset, 0.0));
+        }
+        // Children sliding in and out mustn't be painted outside of the stack.
+        if self.transition.is_some() {
+            ctx.set_clip_path(Rect::from_origin_size(origin, child_size));
+            self.clipped = true;
+        } else if self.clipped {
+            ctx.clear_clip_path();
+            self.clipped = false;
+        }
 
         child_size
     }
@@ -255,13 +444,14 @@ impl Widget for IndexedStack {
         let border_radius = props.get::<CornerRadius>();
         let bg = props.get::<Background>();
         let border_color = props.get::<BorderColor>();
+        let border_gradient = props.get::<BorderGradient>();
 
         let bg_rect = border_width.bg_rect(ctx.size(), border_radius);
         let border_rect = border_width.border_rect(ctx.size(), border_radius);
 
         let brush = bg.get_peniko_brush_for_rect(bg_rect.rect());
         fill(scene, &bg_rect, &brush);
-        stroke(scene, &border_rect, border_color.color, border_width.width);
+        border_gradient.paint(scene, &border_rect, border_width, border_color, None);
 
         // paint the baseline if we're debugging layout
         if ctx.debug_paint_enabled() && ctx.baseline_offset() != 0.0 {
@@ -287,7 +477,11 @@ impl Widget for IndexedStack {
     }
 
     fn children_ids(&self) -> SmallVec<[WidgetId; 16]> {
-        self.children.iter().map(WidgetPod::id).collect()
+        self.children
+            .iter()
+            .chain(&self.leaving)
+            .map(WidgetPod::id)
+            .collect()
     }
 
     fn make_trace_span(&self, id: WidgetId) -> Span {
@@ -392,4 +586,66 @@ mod tests {
         });
         assert_render_snapshot!(harness, "indexed_stack_initial_builder");
     }
+
+    #[test]
+    fn transition_shows_both_children() {
+        let first = WidgetId::next();
+        let second = WidgetId::next();
+        let widget = IndexedStack::new()
+            .with_child_id(button::Button::new("A"), first)
+            .with_child_id(button::Button::new("B"), second);
+        let window_size = Size::new(50.0, 50.0);
+        let mut harness =
+            TestHarness::create_with_size(default_property_set(), widget, window_size);
+        assert!(harness.get_widget(second).ctx().is_stashed());
+
+        harness.edit_root_widget(|mut stack| {
+            let mut stack = stack.downcast::<IndexedStack>();
+            IndexedStack::transition_to(&mut stack, 1, StackTransition::Push);
+        });
+        assert!(!harness.get_widget(first).ctx().is_stashed());
+        assert!(!harness.get_widget(second).ctx().is_stashed());
+
+        harness.animate_ms(300);
+        assert!(harness.get_widget(first).ctx().is_stashed());
+        assert!(!harness.get_widget(second).ctx().is_stashed());
+
+        // Without a transition, the previous child is hidden immediately.
+        harness.edit_root_widget(|mut stack| {
+            let mut stack = stack.downcast::<IndexedStack>();
+            IndexedStack::transition_to(&mut stack, 0, StackTransition::None);
+        });
+        assert!(!harness.get_widget(first).ctx().is_stashed());
+        assert!(harness.get_widget(second).ctx().is_stashed());
+    }
+
+    #[test]
+    fn removed_child_stays_until_transition_ends() {
+        let first = WidgetId::next();
+        let second = WidgetId::next();
+        let widget = IndexedStack::new()
+            .with_child_id(button::Button::new("A"), first)
+            .with_child_id(button::Button::new("B"), second)
+            .with_active_child(1);
+        let window_size = Size::new(50.0, 50.0);
+        let mut harness =
+            TestHarness::create_with_size(default_property_set(), widget, window_size);
+
+        // Popping a screen removes it right after the transition starts.
+        harness.edit_root_widget(|mut stack| {
+            let mut stack = stack.downcast::<IndexedStack>();
+            IndexedStack::transition_to(&mut stack, 0, StackTransition::Pop);
+            IndexedStack::remove_child(&mut stack, 1);
+            assert_eq!(stack.widget.len(), 1);
+        });
+        assert!(!harness.get_widget(second).ctx().is_stashed());
+
+        harness.animate_ms(100);
+        let offset = harness.get_widget(second).ctx().local_layout_rect().x0;
+        assert!(offset > 0.0);
+
+        harness.animate_ms(200);
+        assert!(harness.try_get_widget(second).is_none());
+        assert!(!harness.get_widget(first).ctx().is_stashed());
+    }
 }
@@ -6,7 +6,7 @@
 use std::mem::Discriminant;
 
 use accesskit::{Node, NodeId, Role};
-use parley::{Layout, LayoutAccessibility};
+use parley::{FontContext, Layout, LayoutAccessibility, LayoutContext};
 use smallvec::SmallVec;
 use tracing::{Span, trace_span};
 use vello::Scene;
@@ -14,9 +14,9 @@ use vello::kurbo::{Affine, Size};
 use vello::peniko::{BlendMode, Brush};
 
 use crate::core::{
-    AccessCtx, ArcStr, BoxConstraints, BrushIndex, LayoutCtx, PaintCtx, PropertiesMut,
-    PropertiesRef, RegisterCtx, StyleProperty, StyleSet, Update, UpdateCtx, Widget, WidgetId,
-    WidgetMut, render_text,
+    AccessCtx, ArcStr, Axis, BoxConstraints, BrushIndex, IntrinsicSize, LayoutCtx, MeasureCtx,
+    PaintCtx, PropertiesMut, PropertiesRef, RegisterCtx, StyleProperty, StyleSet, Update,
+    UpdateCtx, Widget, WidgetId, WidgetMut, render_text,
 };
 use crate::debug_panic;
 use crate::theme;
@@ -195,6 +195,40 @@ impl Label {
         self
     }
 
+    /// The width at which lines are broken, given the width available for text.
+    fn max_advance(&self, available_width: Option<f32>) -> Option<f32> {
+        if self.line_break_mode == LineBreaking::WordWrap {
+            available_width
+        } else {
+            None
+        }
+    }
+
+    /// Rebuild the text layout if its styles changed, and break lines at the given width.
+    fn update_text_layout(
+        &mut self,
+        font_ctx: &mut FontContext,
+        layout_ctx: &mut LayoutContext<BrushIndex>,
+        max_advance: Option<f32>,
+    ) {
+        let styles_changed = self.styles_changed;
+        if self.styles_changed {
+            // TODO: Should we use a different scale?
+            let mut builder = layout_ctx.ranged_builder(font_ctx, &self.text, 1.0, true);
+            for prop in self.styles.inner().values() {
+                builder.push_default(prop.to_owned());
+            }
+            builder.build_into(&mut self.text_layout, &self.text);
+            self.styles_changed = false;
+        }
+
+        if max_advance != self.last_max_advance || styles_changed {
+            self.text_layout.break_all_lines(max_advance);
+            self.last_max_advance = max_advance;
+            self.needs_text_alignment = true;
+        }
+    }
+
     /// Shared logic between `with_style` and `insert_style`
     fn insert_style_inner(&mut self, property: StyleProperty) -> Option<StyleProperty> {
         if let StyleProperty::Brush(idx @ BrushIndex(1..))
@@ -348,28 +382,9 @@ impl Widget for Label {
             self.needs_text_alignment = true;
         }
 
-        let max_advance = if self.line_break_mode == LineBreaking::WordWrap {
-            available_width
-        } else {
-            None
-        };
-        let styles_changed = self.styles_changed;
-        if self.styles_changed {
-            let (font_ctx, layout_ctx) = ctx.text_contexts();
-            // TODO: Should we use a different scale?
-            let mut builder = layout_ctx.ranged_builder(font_ctx, &self.text, 1.0, true);
-            for prop in self.styles.inner().values() {
-                builder.push_default(prop.to_owned());
-            }
-            builder.build_into(&mut self.text_layout, &self.text);
-            self.styles_changed = false;
-        }
-
-        if max_advance != self.last_max_advance || styles_changed {
-            self.text_layout.break_all_lines(max_advance);
-            self.last_max_advance = max_advance;
-            self.needs_text_alignment = true;
-        }
+        let max_advance = self.max_advance(available_width);
+        let (font_ctx, layout_ctx) = ctx.text_contexts();
+        self.update_text_layout(font_ctx, layout_ctx, max_advance);
 
         let alignment_width = if self.text_alignment == TextAlign::Start {
             self.text_layout.width()
@@ -408,6 +423,37 @@ impl Widget for Label {
         bc.constrain(label_size)
     }
 
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        cross_length: Option<f64>,
+    ) -> Option<IntrinsicSize> {
+        let (font_ctx, layout_ctx) = ctx.text_contexts();
+        match axis {
+            Axis::Horizontal => {
+                self.update_text_layout(font_ctx, layout_ctx, self.last_max_advance);
+                let widths = self.text_layout.calculate_content_widths();
+                // Without word wrapping, the text can't be narrower than its longest line.
+                let min = if self.line_break_mode == LineBreaking::WordWrap {
+                    widths.min
+                } else {
+                    widths.max
+                };
+                let size = IntrinsicSize::new(min.into(), widths.max.into());
+                Some(size.expand(2. * LABEL_X_PADDING))
+            }
+            Axis::Vertical => {
+                let available_width =
+                    cross_length.map(|width| (width as f32 - 2. * LABEL_X_PADDING as f32).max(0.));
+                let max_advance = self.max_advance(available_width);
+                self.update_text_layout(font_ctx, layout_ctx, max_advance);
+                Some(IntrinsicSize::fixed(self.text_layout.height().into()))
+            }
+        }
+    }
+
     fn paint(&mut self, ctx: &mut PaintCtx<'_>, _props: &PropertiesRef<'_>, scene: &mut Scene) {
         if self.line_break_mode == LineBreaking::Clip {
             let clip_rect = ctx.size().to_rect();
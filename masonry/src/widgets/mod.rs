@@ -9,6 +9,7 @@ mod tests;
 mod align;
 mod button;
 mod checkbox;
+mod dialog;
 mod flex;
 mod grid;
 mod image;
@@ -30,10 +31,11 @@ mod zstack;
 pub use self::align::Align;
 pub use self::button::Button;
 pub use self::checkbox::Checkbox;
-pub use self::flex::{Axis, CrossAxisAlignment, Flex, FlexParams, MainAxisAlignment};
+pub use self::dialog::Dialog;
+pub use self::flex::{CrossAxisAlignment, Flex, FlexParams, MainAxisAlignment};
 pub use self::grid::{Grid, GridParams};
 pub use self::image::Image;
-pub use self::indexed_stack::IndexedStack;
+pub use self::indexed_stack::{IndexedStack, StackTransition};
 pub use self::label::{Label, LineBreaking};
 pub use self::portal::Portal;
 pub use self::progress_bar::ProgressBar;
@@ -47,3 +49,7 @@ pub use self::text_input::TextInput;
 pub use self::variable_label::VariableLabel;
 pub use self::virtual_scroll::{VirtualScroll, VirtualScrollAction};
 pub use self::zstack::{Alignment, ChildAlignment, HorizontalAlignment, VerticalAlignment, ZStack};
+
+// `Axis` used to live in the flex module, and is still re-exported here for compatibility.
+#[doc(no_inline)]
+pub use crate::core::Axis;
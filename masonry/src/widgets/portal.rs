@@ -11,11 +11,11 @@ use vello::Scene;
 use vello::kurbo::{Point, Rect, Size, Vec2};
 
 use crate::core::{
-    AccessCtx, AccessEvent, BoxConstraints, ComposeCtx, EventCtx, FromDynWidget, LayoutCtx,
+    AccessCtx, AccessEvent, Axis, BoxConstraints, ComposeCtx, EventCtx, FromDynWidget, LayoutCtx,
     PaintCtx, PointerEvent, PropertiesMut, PropertiesRef, RegisterCtx, ScrollDelta, TextEvent,
     Update, UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
 };
-use crate::widgets::{Axis, ScrollBar};
+use crate::widgets::ScrollBar;
 
 // TODO - refactor - see https://github.com/linebender/xilem/issues/366
 // TODO - rename "Portal" to "ScrollPortal"?
@@ -105,6 +105,15 @@ impl<W: Widget + ?Sized> Portal<W> {
         self.must_fill = must_fill;
         self
     }
+
+    /// Builder-style method to set the initial position of the viewport.
+    ///
+    /// The position is clamped to the size of the content when the portal is laid out.
+    /// This is useful to restore a scroll position which was saved earlier.
+    pub fn viewport_pos(mut self, position: Point) -> Self {
+        self.viewport_pos = position;
+        self
+    }
 }
 
 fn compute_pan_range(mut viewport: Range<f64>, target: Range<f64>) -> Range<f64> {
@@ -407,7 +416,6 @@ impl<W: Widget + FromDynWidget + ?Sized> Widget for Portal<W> {
         // TODO - document better
         // Recompute the portal offset for the new layout
         self.set_viewport_pos_raw(portal_size, content_size, self.viewport_pos);
-        // TODO - recompute portal progress
 
         ctx.set_clip_path(portal_size.to_rect());
 
@@ -429,9 +437,18 @@ impl<W: Widget + FromDynWidget + ?Sized> Widget for Portal<W> {
 
         if self.scrollbar_horizontal_visible {
             let mut scrollbar = ctx.get_raw_mut(&mut self.scrollbar_horizontal);
+            let cursor_progress = self.viewport_pos.x / (content_size - portal_size).width;
+            let changed = scrollbar.widget().portal_size != portal_size.width
+                || scrollbar.widget().content_size != content_size.width
+                || scrollbar.widget().cursor_progress != cursor_progress;
             scrollbar.widget().portal_size = portal_size.width;
             scrollbar.widget().content_size = content_size.width;
-            // TODO - request paint for scrollbar?
+            scrollbar.widget().cursor_progress = cursor_progress;
+            // The scrollbar's layout doesn't depend on these fields, so it must be
+            // repainted explicitly.
+            if changed {
+                scrollbar.ctx().request_render();
+            }
             std::mem::drop(scrollbar);
 
             let scrollbar_size = ctx.run_layout(&mut self.scrollbar_horizontal, bc);
@@ -444,9 +461,18 @@ impl<W: Widget + FromDynWidget + ?Sized> Widget for Portal<W> {
         }
         if self.scrollbar_vertical_visible {
             let mut scrollbar = ctx.get_raw_mut(&mut self.scrollbar_vertical);
+            let cursor_progress = self.viewport_pos.y / (content_size - portal_size).height;
+            let changed = scrollbar.widget().portal_size != portal_size.height
+                || scrollbar.widget().content_size != content_size.height
+                || scrollbar.widget().cursor_progress != cursor_progress;
             scrollbar.widget().portal_size = portal_size.height;
             scrollbar.widget().content_size = content_size.height;
-            // TODO - request paint for scrollbar?
+            scrollbar.widget().cursor_progress = cursor_progress;
+            // The scrollbar's layout doesn't depend on these fields, so it must be
+            // repainted explicitly.
+            if changed {
+                scrollbar.ctx().request_render();
+            }
             std::mem::drop(scrollbar);
 
             let scrollbar_size = ctx.run_layout(&mut self.scrollbar_vertical, bc);
@@ -518,10 +544,12 @@ impl<W: Widget + FromDynWidget + ?Sized> Widget for Portal<W> {
 mod tests {
 
     use super::*;
+    use accesskit::Toggled;
+
     use crate::assert_render_snapshot;
-    use crate::testing::{TestHarness, widget_ids};
+    use crate::testing::{Record, Recording, TestHarness, TestWidgetExt, widget_ids};
     use crate::theme::default_property_set;
-    use crate::widgets::{Button, Flex, SizedBox};
+    use crate::widgets::{Button, Checkbox, Flex, SizedBox};
 
     fn button(text: &'static str) -> impl Widget {
         SizedBox::new(Button::new(text)).width(70.0).height(40.0)
@@ -594,6 +622,123 @@ mod tests {
         assert_render_snapshot!(harness, "portal_button_list_scroll_to_item_13");
     }
 
+    #[test]
+    fn offscreen_children_are_not_painted() {
+        let record = Recording::default();
+        let widget = Portal::new(
+            Flex::column()
+                .with_child(SizedBox::empty().width(50.0).height(300.0))
+                .with_child(SizedBox::empty().width(50.0).height(50.0).record(&record)),
+        );
+
+        let mut harness =
+            TestHarness::create_with_size(default_property_set(), widget, Size::new(100., 100.));
+        let _ = harness.redraw();
+        let was_painted = |record: &Recording| {
+            record
+                .drain()
+                .iter()
+                .any(|event| matches!(event, Record::Paint))
+        };
+        assert!(!was_painted(&record));
+
+        harness.edit_root_widget(|mut portal| {
+            let mut portal = portal.downcast::<Portal<Flex>>();
+            Portal::set_viewport_pos(&mut portal, Point::new(0.0, 300.0))
+        });
+        let _ = harness.redraw();
+        assert!(was_painted(&record));
+    }
+
+    #[test]
+    fn scrollbar_is_repainted_when_content_resizes() {
+        let [content_id] = widget_ids();
+        let widget = Portal::new(
+            Flex::column().with_child_id(SizedBox::empty().width(50.0).height(300.0), content_id),
+        );
+
+        let mut harness =
+            TestHarness::create_with_size(default_property_set(), widget, Size::new(100., 100.));
+        harness.edit_root_widget(|mut portal| {
+            let mut portal = portal.downcast::<Portal<Flex>>();
+            Portal::set_viewport_pos(&mut portal, Point::new(0.0, 100.0))
+        });
+        let _ = harness.redraw();
+
+        let scrollbar_id = harness.root_widget().children()[1].id();
+        let scrollbar = harness.get_widget(scrollbar_id);
+        let scrollbar_rect = scrollbar.ctx().bounding_rect();
+        let scrollbar = scrollbar.downcast::<ScrollBar>().unwrap();
+        assert_eq!(scrollbar.content_size, 300.0);
+        assert_eq!(scrollbar.cursor_progress(), 0.5);
+
+        // The content grows, so the scrollbar's cursor shrinks and moves up.
+        harness.edit_widget(content_id, |mut sized_box| {
+            let mut sized_box = sized_box.downcast::<SizedBox>();
+            SizedBox::set_height(&mut sized_box, 600.0);
+        });
+        let damage = harness.redraw();
+        assert!(damage.intersects(scrollbar_rect));
+
+        let scrollbar = harness
+            .get_widget(scrollbar_id)
+            .downcast::<ScrollBar>()
+            .unwrap();
+        assert_eq!(scrollbar.content_size, 600.0);
+        assert_eq!(scrollbar.cursor_progress(), 0.2);
+    }
+
+    #[test]
+    fn offscreen_access_nodes_are_hidden_until_updated() {
+        let [checkbox_id] = widget_ids();
+        let widget = Portal::new(
+            Flex::column()
+                .with_child(SizedBox::empty().width(50.0).height(300.0))
+                .with_child(Checkbox::new(false, "Option").with_id(checkbox_id)),
+        );
+
+        let mut harness =
+            TestHarness::create_with_size(default_property_set(), widget, Size::new(100., 100.));
+        let node = harness.access_node(checkbox_id).unwrap();
+        assert!(!node.is_hidden());
+        assert_eq!(node.toggled(), Some(Toggled::False));
+
+        // The checkbox changes while it's clipped out, so its node is hidden.
+        harness.edit_widget(checkbox_id, |mut checkbox| {
+            let mut checkbox = checkbox.downcast::<Checkbox>();
+            Checkbox::set_checked(&mut checkbox, true);
+        });
+        assert!(harness.access_node(checkbox_id).unwrap().is_hidden());
+
+        harness.edit_root_widget(|mut portal| {
+            let mut portal = portal.downcast::<Portal<Flex>>();
+            Portal::set_viewport_pos(&mut portal, Point::new(0.0, 300.0))
+        });
+        let node = harness.access_node(checkbox_id).unwrap();
+        assert!(!node.is_hidden());
+        assert_eq!(node.toggled(), Some(Toggled::True));
+    }
+
+    #[test]
+    fn initial_viewport_pos_is_clamped() {
+        let widget =
+            Portal::new(Flex::column().with_child(SizedBox::empty().width(50.0).height(350.0)))
+                .viewport_pos(Point::new(0.0, 1000.0));
+
+        let harness =
+            TestHarness::create_with_size(default_property_set(), widget, Size::new(100., 100.));
+        let portal = harness.root_widget().downcast::<Portal<Flex>>().unwrap();
+        assert_eq!(portal.get_viewport_pos(), Point::new(0.0, 250.0));
+
+        // The scrollbar shows the restored position.
+        let scrollbar_id = harness.root_widget().children()[1].id();
+        let scrollbar = harness
+            .get_widget(scrollbar_id)
+            .downcast::<ScrollBar>()
+            .unwrap();
+        assert_eq!(scrollbar.cursor_progress(), 1.0);
+    }
+
     // Helper function for panning tests
     fn make_range(repr: &str) -> Range<f64> {
         let repr = &repr[repr.find('_').unwrap()..];
@@ -8,13 +8,12 @@ use vello::Scene;
 use vello::kurbo::{Point, Rect, Size};
 
 use crate::core::{
-    AccessCtx, AccessEvent, AllowRawMut, BoxConstraints, EventCtx, LayoutCtx, PaintCtx,
+    AccessCtx, AccessEvent, AllowRawMut, Axis, BoxConstraints, EventCtx, LayoutCtx, PaintCtx,
     PointerEvent, PropertiesMut, PropertiesRef, RegisterCtx, TextEvent, Update, UpdateCtx, Widget,
     WidgetId, WidgetMut,
 };
 use crate::theme;
 use crate::util::{fill_color, stroke};
-use crate::widgets::Axis;
 
 // TODO
 // - Fade scrollbars? Find out how Linux/macOS/Windows do it
@@ -10,14 +10,15 @@ use masonry_core::util::fill;
 use smallvec::{SmallVec, smallvec};
 use tracing::{Span, trace_span, warn};
 use vello::Scene;
-use vello::kurbo::{Point, Size};
+use vello::kurbo::{Affine, Point, Size};
 
 use crate::core::{
-    AccessCtx, BoxConstraints, LayoutCtx, PaintCtx, PropertiesMut, PropertiesRef, RegisterCtx,
-    UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
+    AccessCtx, Axis, BoxConstraints, IntrinsicSize, LayoutCtx, MeasureCtx, PaintCtx, PropertiesMut,
+    PropertiesRef, RegisterCtx, UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
+};
+use crate::properties::{
+    Background, BorderColor, BorderGradient, BorderWidth, BoxShadows, CornerRadius, Padding,
 };
-use crate::properties::{Background, BorderColor, BorderWidth, CornerRadius, Padding};
-use crate::util::stroke;
 
 /// A widget with predefined size.
 ///
@@ -226,9 +227,11 @@ impl Widget for SizedBox {
     fn property_changed(&mut self, ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
         Background::prop_changed(ctx, property_type);
         BorderColor::prop_changed(ctx, property_type);
+        BorderGradient::prop_changed(ctx, property_type);
         BorderWidth::prop_changed(ctx, property_type);
         CornerRadius::prop_changed(ctx, property_type);
         Padding::prop_changed(ctx, property_type);
+        BoxShadows::prop_changed(ctx, property_type);
     }
 
     fn layout(
@@ -239,6 +242,7 @@ impl Widget for SizedBox {
     ) -> Size {
         let border = props.get::<BorderWidth>();
         let padding = props.get::<Padding>();
+        let shadows = props.get::<BoxShadows>();
 
         let bc = self.child_constraints(bc);
         let bc = border.layout_down(bc);
@@ -263,7 +267,10 @@ impl Widget for SizedBox {
         let (size, _) = padding.layout_up(size, 0.);
         let (size, _) = border.layout_up(size, 0.);
 
-        // TODO - figure out paint insets
+        if shadows.is_visible() {
+            ctx.set_paint_insets(shadows.get_insets());
+        }
+
         // TODO - figure out baseline offset
 
         if size.width.is_infinite() {
@@ -276,18 +283,54 @@ impl Widget for SizedBox {
         size
     }
 
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        props: &PropertiesRef<'_>,
+        axis: Axis,
+        cross_length: Option<f64>,
+    ) -> Option<IntrinsicSize> {
+        let (length, cross_length) = match axis {
+            Axis::Horizontal => (self.width, self.height.or(cross_length)),
+            Axis::Vertical => (self.height, self.width.or(cross_length)),
+        };
+        if let Some(length) = length {
+            return Some(IntrinsicSize::fixed(length));
+        }
+
+        let border = props.get::<BorderWidth>();
+        let padding = props.get::<Padding>();
+
+        let cross_length = border.measure_down(cross_length);
+        let cross_length = padding.measure_down(axis, cross_length);
+
+        let size = match self.child.as_mut() {
+            Some(child) => ctx.measure_child(child, axis, cross_length)?,
+            None => IntrinsicSize::ZERO,
+        };
+
+        let size = padding.measure_up(axis, size);
+        let size = border.measure_up(size);
+        Some(size)
+    }
+
     fn paint(&mut self, ctx: &mut PaintCtx<'_>, props: &PropertiesRef<'_>, scene: &mut Scene) {
         let bg = props.get::<Background>();
         let border_width = props.get::<BorderWidth>();
         let border_color = props.get::<BorderColor>();
+        let border_gradient = props.get::<BorderGradient>();
         let corner_radius = props.get::<CornerRadius>();
+        let shadows = props.get::<BoxShadows>();
 
         let bg_rect = border_width.bg_rect(ctx.size(), corner_radius);
         let border_rect = border_width.border_rect(ctx.size(), corner_radius);
 
+        shadows.paint(scene, Affine::IDENTITY, bg_rect);
+
         let brush = bg.get_peniko_brush_for_rect(bg_rect.rect());
         fill(scene, &bg_rect, &brush);
-        stroke(scene, &border_rect, border_color.color, border_width.width);
+        shadows.paint_inset(scene, Affine::IDENTITY, bg_rect);
+        border_gradient.paint(scene, &border_rect, border_width, border_color, None);
     }
 
     fn accessibility_role(&self) -> Role {
@@ -322,7 +365,8 @@ mod tests {
 
     use super::*;
     use crate::palette;
-    use crate::properties::types::Gradient;
+    use crate::peniko::Extend;
+    use crate::properties::types::{Gradient, RadialGradientExtent, UnitPoint};
     use crate::testing::{
         TestHarness, TestWidgetExt, assert_failing_render_snapshot, assert_render_snapshot,
     };
@@ -464,7 +508,85 @@ mod tests {
         assert_render_snapshot!(harness, "sized_box_empty_box_with_gradient_background");
     }
 
-    // TODO - Test other gradient types.
+    #[test]
+    fn empty_box_with_radial_gradient_background() {
+        let mut box_props = Properties::new();
+
+        let gradient =
+            Gradient::new_radial(UnitPoint::new(0.3, 0.3), RadialGradientExtent::ClosestSide)
+                .with_extend(Extend::Reflect)
+                .with_stops([palette::css::WHITE, palette::css::RED, palette::css::BLACK]);
+        box_props.insert(Background::Gradient(gradient));
+        box_props.insert(CornerRadius::all(10.0));
+
+        let widget = SizedBox::empty()
+            .width(60.)
+            .height(40.)
+            .with_props(box_props);
+
+        let window_size = Size::new(100.0, 100.0);
+        let mut harness =
+            TestHarness::create_with_size(default_property_set(), widget, window_size);
+
+        assert_render_snapshot!(
+            harness,
+            "sized_box_empty_box_with_radial_gradient_background"
+        );
+    }
+
+    #[test]
+    fn empty_box_with_sweep_gradient_background() {
+        let mut box_props = Properties::new();
+
+        let gradient = Gradient::new_sweep(UnitPoint::CENTER, std::f64::consts::FRAC_PI_4)
+            .with_stops([
+                palette::css::RED,
+                palette::css::YELLOW,
+                palette::css::GREEN,
+                palette::css::BLUE,
+                palette::css::RED,
+            ]);
+        box_props.insert(Background::Gradient(gradient));
+
+        let widget = SizedBox::empty()
+            .width(60.)
+            .height(60.)
+            .with_props(box_props);
+
+        let window_size = Size::new(100.0, 100.0);
+        let mut harness =
+            TestHarness::create_with_size(default_property_set(), widget, window_size);
+
+        assert_render_snapshot!(
+            harness,
+            "sized_box_empty_box_with_sweep_gradient_background"
+        );
+    }
+
+    #[test]
+    fn empty_box_with_gradient_border() {
+        let mut box_props = Properties::new();
+
+        let gradient = Gradient::new_sweep(UnitPoint::CENTER, 0.0).with_stops([
+            palette::css::BLUE,
+            palette::css::PLUM,
+            palette::css::BLUE,
+        ]);
+        box_props.insert(BorderGradient::new(gradient));
+        box_props.insert(BorderWidth::all(8.0));
+        box_props.insert(CornerRadius::all(10.0));
+
+        let widget = SizedBox::empty()
+            .width(60.)
+            .height(60.)
+            .with_props(box_props);
+
+        let window_size = Size::new(100.0, 100.0);
+        let mut harness =
+            TestHarness::create_with_size(default_property_set(), widget, window_size);
+
+        assert_render_snapshot!(harness, "sized_box_empty_box_with_gradient_border");
+    }
 
     #[test]
     fn label_box_with_padding_and_background() {
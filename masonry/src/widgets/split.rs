@@ -11,14 +11,13 @@ use vello::Scene;
 use vello::kurbo::{Line, Point, Rect, Size};
 
 use crate::core::{
-    AccessCtx, AccessEvent, BoxConstraints, EventCtx, FromDynWidget, LayoutCtx, PaintCtx,
+    AccessCtx, AccessEvent, Axis, BoxConstraints, EventCtx, FromDynWidget, LayoutCtx, PaintCtx,
     PointerEvent, PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx, TextEvent, Widget, WidgetId,
     WidgetMut, WidgetPod,
 };
 use crate::peniko::Color;
 use crate::theme;
 use crate::util::{fill_color, stroke};
-use crate::widgets::flex::Axis;
 
 /// A container containing two other widgets, splitting the area either horizontally or vertically.
 ///
@@ -94,6 +93,14 @@ impl<ChildA: Widget + ?Sized, ChildB: Widget + ?Sized> Split<ChildA, ChildB> {
         self
     }
 
+    /// The split point chosen by the user or the app, as a fraction of the split axis.
+    ///
+    /// The split point used for layout may differ from this,
+    /// to respect the minimum size of the children.
+    pub fn get_split_point(&self) -> f64 {
+        self.split_point_chosen
+    }
+
     /// Builder-style method to set the minimum size for both sides of the split axis.
     ///
     /// The value must be greater than or equal to `0.0`.
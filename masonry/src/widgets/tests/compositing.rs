@@ -0,0 +1,93 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests related to opacity and blend modes.
+
+use masonry_core::core::Properties;
+use vello::peniko::color::palette;
+use vello::peniko::{BlendMode, Color, Compose, Mix};
+
+use crate::assert_render_snapshot;
+use crate::core::{MixBlendMode, Opacity, PointerButton, Widget};
+use crate::properties::{Background, BorderColor, BorderWidth};
+use crate::testing::{TestHarness, TestWidgetExt, widget_ids};
+use crate::theme::default_property_set;
+use crate::widgets::{Alignment, Button, Label, SizedBox, ZStack};
+
+fn colored_box(color: Color, inner: impl Widget) -> impl Widget {
+    let mut box_props = Properties::new();
+    box_props.insert(Background::Color(color));
+    box_props.insert(BorderColor::new(palette::css::TEAL));
+    box_props.insert(BorderWidth::all(2.0));
+    SizedBox::new(inner)
+        .width(200.)
+        .height(100.)
+        .with_props(box_props)
+}
+
+#[test]
+fn opacity_applies_to_children() {
+    let widget = ZStack::new()
+        .with_child(
+            colored_box(palette::css::BLUE, Label::new("Behind")),
+            Alignment::TopLeft,
+        )
+        .with_child(
+            colored_box(palette::css::RED, Label::new("Faded"))
+                .with_props(Properties::new().with(Opacity(0.5))),
+            Alignment::BottomRight,
+        );
+
+    let mut harness = TestHarness::create(default_property_set(), widget);
+    assert_render_snapshot!(harness, "compositing_opacity");
+}
+
+#[test]
+fn blend_mode() {
+    let widget = ZStack::new()
+        .with_child(
+            colored_box(palette::css::BLUE, Label::new("Behind")),
+            Alignment::TopLeft,
+        )
+        .with_child(
+            colored_box(palette::css::YELLOW, Label::new("Multiplied")).with_props(
+                Properties::new().with(MixBlendMode(BlendMode::new(
+                    Mix::Multiply,
+                    Compose::SrcOver,
+                ))),
+            ),
+            Alignment::BottomRight,
+        );
+
+    let mut harness = TestHarness::create(default_property_set(), widget);
+    assert_render_snapshot!(harness, "compositing_blend_mode");
+}
+
+#[test]
+fn transparent_widgets_are_not_hit() {
+    let [button_id] = widget_ids();
+    let widget =
+        ZStack::new().with_child_id(Button::new("Invisible"), button_id, Alignment::Center);
+
+    let mut harness = TestHarness::create(default_property_set(), widget);
+    let button_center = harness.get_widget(button_id).ctx().bounding_rect().center();
+
+    harness.mouse_move(button_center);
+    assert!(harness.get_widget(button_id).ctx().is_hovered());
+
+    harness.edit_widget(button_id, |mut button| {
+        button.insert_prop(Opacity::TRANSPARENT);
+    });
+    harness.mouse_move(button_center);
+    assert!(!harness.get_widget(button_id).ctx().is_hovered());
+
+    harness.mouse_button_press(PointerButton::Primary);
+    harness.mouse_button_release(PointerButton::Primary);
+    assert!(harness.pop_action().is_none());
+
+    harness.edit_widget(button_id, |mut button| {
+        button.remove_prop::<Opacity>();
+    });
+    harness.mouse_move(button_center);
+    assert!(harness.get_widget(button_id).ctx().is_hovered());
+}
@@ -0,0 +1,66 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests related to damage regions.
+
+use vello::kurbo::Size;
+use vello::peniko::color::palette;
+
+use crate::properties::Background;
+use crate::testing::{TestHarness, widget_ids};
+use crate::theme::default_property_set;
+use crate::widgets::{Flex, SizedBox};
+
+#[test]
+fn first_redraw_damages_window() {
+    let widget = SizedBox::empty().width(50.).height(50.);
+    let window_size = Size::new(200., 200.);
+
+    let mut harness = TestHarness::create_with_size(default_property_set(), widget, window_size);
+    let damage = harness.redraw();
+    assert_eq!(damage.rects(), &[window_size.to_rect()]);
+
+    // Nothing changed since the last redraw.
+    let damage = harness.redraw();
+    assert!(damage.is_empty());
+}
+
+#[test]
+fn repaint_only_damages_widget() {
+    let [box_1, box_2] = widget_ids();
+    let widget = Flex::column()
+        .with_child_id(SizedBox::empty().width(50.).height(50.), box_1)
+        .with_child_id(SizedBox::empty().width(50.).height(50.), box_2);
+
+    let mut harness = TestHarness::create(default_property_set(), widget);
+    let _ = harness.redraw();
+
+    harness.edit_widget(box_1, |mut sized_box| {
+        sized_box.insert_prop(Background::Color(palette::css::RED));
+    });
+    let damage = harness.redraw();
+
+    let box_1_rect = harness.get_widget(box_1).ctx().bounding_rect();
+    let box_2_rect = harness.get_widget(box_2).ctx().bounding_rect();
+    assert_eq!(damage.rects(), &[box_1_rect]);
+    assert!(!damage.intersects(box_2_rect));
+}
+
+#[test]
+fn removed_widget_damages_its_area() {
+    let [box_1, box_2] = widget_ids();
+    let widget = Flex::row()
+        .with_child_id(SizedBox::empty().width(50.).height(50.), box_1)
+        .with_child_id(SizedBox::empty().width(50.).height(50.), box_2);
+
+    let mut harness = TestHarness::create(default_property_set(), widget);
+    let _ = harness.redraw();
+    let box_2_rect = harness.get_widget(box_2).ctx().bounding_rect();
+
+    harness.edit_root_widget(|mut flex| {
+        let mut flex = flex.downcast::<Flex>();
+        Flex::remove_child(&mut flex, 1);
+    });
+    let damage = harness.redraw();
+    assert!(damage.intersects(box_2_rect));
+}
@@ -0,0 +1,36 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests related to the `ErrorDescription` property.
+
+use accesskit::Invalid;
+
+use crate::core::ErrorDescription;
+use crate::testing::{TestHarness, TestWidgetExt, widget_ids};
+use crate::theme::default_property_set;
+use crate::widgets::Checkbox;
+
+#[test]
+fn error_description_marks_node_invalid() {
+    let [checkbox_id] = widget_ids();
+    let widget = Checkbox::new(false, "Accept the terms").with_id(checkbox_id);
+
+    let mut harness = TestHarness::create(default_property_set(), widget);
+    let node = harness.access_node(checkbox_id).unwrap();
+    assert_eq!(node.invalid(), None);
+    assert_eq!(node.description(), None);
+
+    harness.edit_widget(checkbox_id, |mut checkbox| {
+        checkbox.insert_prop(ErrorDescription::new("The terms must be accepted"));
+    });
+    let node = harness.access_node(checkbox_id).unwrap();
+    assert_eq!(node.invalid(), Some(Invalid::True));
+    assert_eq!(node.description(), Some("The terms must be accepted"));
+
+    harness.edit_widget(checkbox_id, |mut checkbox| {
+        checkbox.remove_prop::<ErrorDescription>();
+    });
+    let node = harness.access_node(checkbox_id).unwrap();
+    assert_eq!(node.invalid(), None);
+    assert_eq!(node.description(), None);
+}
@@ -0,0 +1,136 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests related to focus scopes and the order of keyboard navigation.
+
+use crate::core::keyboard::{Key, KeyboardEvent, Modifiers, NamedKey};
+use crate::core::{FocusScope, RovingFocus, TabIndex, TextEvent, WidgetId};
+use crate::testing::{TestHarness, TestWidgetExt, widget_ids};
+use crate::theme::default_property_set;
+use crate::widgets::{Button, Flex};
+
+fn press(harness: &mut TestHarness, key: NamedKey, modifiers: Modifiers) {
+    harness.process_text_event(TextEvent::Keyboard(KeyboardEvent {
+        key: Key::Named(key),
+        modifiers,
+        ..Default::default()
+    }));
+}
+
+fn focused_id(harness: &TestHarness) -> Option<WidgetId> {
+    harness.focused_widget().map(|widget| widget.id())
+}
+
+#[test]
+fn tab_index_order() {
+    let [a, b, c, d] = widget_ids();
+    let widget = Flex::row()
+        .with_child(Button::new("A").with_id(a))
+        .with_child(Button::new("B").with_id(b))
+        .with_child(Button::new("C").with_id(c))
+        .with_child(Button::new("D").with_id(d));
+    let mut harness = TestHarness::create(default_property_set(), widget);
+    assert_eq!(harness.focus_order(), [a, b, c, d]);
+
+    harness.edit_widget(b, |mut button| {
+        button.insert_prop(TabIndex(-1));
+    });
+    harness.edit_widget(c, |mut button| {
+        button.insert_prop(TabIndex(2));
+    });
+    harness.edit_widget(d, |mut button| {
+        button.insert_prop(TabIndex(1));
+    });
+    assert_eq!(harness.focus_order(), [d, c, a]);
+
+    press(&mut harness, NamedKey::Tab, Modifiers::empty());
+    assert_eq!(focused_id(&harness), Some(d));
+    press(&mut harness, NamedKey::Tab, Modifiers::empty());
+    assert_eq!(focused_id(&harness), Some(c));
+    press(&mut harness, NamedKey::Tab, Modifiers::empty());
+    assert_eq!(focused_id(&harness), Some(a));
+    press(&mut harness, NamedKey::Tab, Modifiers::empty());
+    assert_eq!(focused_id(&harness), Some(d));
+
+    // Widgets skipped by Tab navigation can still be focused.
+    harness.focus_on(Some(b));
+    assert_eq!(focused_id(&harness), Some(b));
+}
+
+#[test]
+fn focus_scope_traps_tab() {
+    let [before, scope, inner_1, inner_2, after] = widget_ids();
+    let dialog = Flex::column()
+        .with_child(Button::new("Inner 1").with_id(inner_1))
+        .with_child(Button::new("Inner 2").with_id(inner_2));
+    let widget = Flex::column()
+        .with_child(Button::new("Before").with_id(before))
+        .with_child_id(dialog, scope)
+        .with_child(Button::new("After").with_id(after));
+    let mut harness = TestHarness::create(default_property_set(), widget);
+    harness.edit_widget(scope, |mut dialog| {
+        dialog.insert_prop(FocusScope);
+    });
+
+    // Outside the scope, Tab goes through the whole window.
+    harness.focus_on(Some(before));
+    assert_eq!(harness.focus_order(), [before, inner_1, inner_2, after]);
+
+    // Inside the scope, Tab wraps around at its ends.
+    harness.focus_on(Some(inner_1));
+    assert_eq!(harness.focus_order(), [inner_1, inner_2]);
+    press(&mut harness, NamedKey::Tab, Modifiers::empty());
+    assert_eq!(focused_id(&harness), Some(inner_2));
+    press(&mut harness, NamedKey::Tab, Modifiers::empty());
+    assert_eq!(focused_id(&harness), Some(inner_1));
+    press(&mut harness, NamedKey::Tab, Modifiers::SHIFT);
+    assert_eq!(focused_id(&harness), Some(inner_2));
+
+    // Closing the scope gives focus back to the widget focused before it.
+    harness.edit_root_widget(|mut flex| {
+        let mut flex = flex.downcast::<Flex>();
+        Flex::remove_child(&mut flex, 1);
+    });
+    assert_eq!(focused_id(&harness), Some(before));
+    assert_eq!(harness.focus_order(), [before, after]);
+}
+
+#[test]
+fn roving_focus() {
+    let [first, toolbar, item_1, item_2, item_3, last] = widget_ids();
+    let toolbar_widget = Flex::row()
+        .with_child(Button::new("1").with_id(item_1))
+        .with_child(Button::new("2").with_id(item_2))
+        .with_child(Button::new("3").with_id(item_3));
+    let widget = Flex::column()
+        .with_child(Button::new("First").with_id(first))
+        .with_child_id(toolbar_widget, toolbar)
+        .with_child(Button::new("Last").with_id(last));
+    let mut harness = TestHarness::create(default_property_set(), widget);
+    harness.edit_widget(toolbar, |mut toolbar| {
+        toolbar.insert_prop(RovingFocus::Horizontal);
+    });
+
+    // The group is a single Tab stop.
+    assert_eq!(harness.focus_order(), [first, item_1, last]);
+
+    harness.focus_on(Some(item_1));
+    press(&mut harness, NamedKey::ArrowRight, Modifiers::empty());
+    assert_eq!(focused_id(&harness), Some(item_2));
+    // Vertical arrows aren't used by a horizontal group.
+    press(&mut harness, NamedKey::ArrowDown, Modifiers::empty());
+    assert_eq!(focused_id(&harness), Some(item_2));
+    press(&mut harness, NamedKey::End, Modifiers::empty());
+    assert_eq!(focused_id(&harness), Some(item_3));
+    press(&mut harness, NamedKey::ArrowRight, Modifiers::empty());
+    assert_eq!(focused_id(&harness), Some(item_1));
+    press(&mut harness, NamedKey::ArrowLeft, Modifiers::empty());
+    assert_eq!(focused_id(&harness), Some(item_3));
+
+    // Tab leaves the group, and coming back focuses the item which was last focused.
+    press(&mut harness, NamedKey::Tab, Modifiers::empty());
+    assert_eq!(focused_id(&harness), Some(last));
+    assert_eq!(harness.focus_order(), [first, item_3, last]);
+    press(&mut harness, NamedKey::Tab, Modifiers::SHIFT);
+    assert_eq!(focused_id(&harness), Some(item_3));
+}
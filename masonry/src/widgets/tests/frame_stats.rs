@@ -0,0 +1,48 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests related to frame statistics.
+
+use crate::testing::{TestHarness, widget_ids};
+use crate::theme::default_property_set;
+use crate::widgets::{Flex, Label};
+
+#[test]
+fn frame_stats_count_visited_widgets() {
+    let [label_id] = widget_ids();
+    let widget = Flex::column()
+        .with_child_id(Label::new("Hello"), label_id)
+        .with_child(Label::new("World"));
+
+    let mut harness = TestHarness::create(default_property_set(), widget);
+    let _ = harness.redraw();
+
+    // Every widget is painted and gets an accessibility node in the first frame.
+    let stats = harness.last_frame_stats().unwrap();
+    assert_eq!(stats.paint.runs, 1);
+    assert_eq!(stats.accessibility.runs, 1);
+    assert!(stats.paint.widgets_visited >= 3);
+    assert!(stats.accessibility.widgets_visited >= 3);
+
+    // Nothing changed, so no layout is done.
+    let _ = harness.redraw();
+    let stats = harness.last_frame_stats().unwrap();
+    assert_eq!(stats.layout.runs, 0);
+    assert_eq!(stats.layout.widgets_visited, 0);
+    assert_eq!(stats.compose.runs, 0);
+
+    let layout_count_before = harness.layout_count();
+    harness.edit_widget(label_id, |mut label| {
+        let mut label = label.downcast::<Label>();
+        Label::set_text(&mut label, "Hello, world");
+    });
+    let _ = harness.redraw();
+
+    let stats = harness.last_frame_stats().unwrap();
+    assert_eq!(stats.layout.runs, 1);
+    assert_eq!(
+        stats.layout.widgets_visited,
+        harness.layout_count() - layout_count_before
+    );
+    assert!(stats.layout.widgets_visited > 0);
+}
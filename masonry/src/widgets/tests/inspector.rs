@@ -0,0 +1,59 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests related to the widget inspector.
+
+use vello::kurbo::Size;
+
+use crate::core::keyboard::{Key, NamedKey};
+use crate::core::{PointerButton, TextEvent};
+use crate::testing::{TestHarness, widget_ids};
+use crate::theme::default_property_set;
+use crate::widgets::{Button, SizedBox};
+
+fn toggle_inspector(harness: &mut TestHarness) {
+    harness.process_text_event(TextEvent::key_down(Key::Named(NamedKey::F12)));
+}
+
+#[test]
+fn picker_selects_widget() {
+    let [button_id] = widget_ids();
+    let widget = SizedBox::new_with_id(Button::new("Click me"), button_id).expand();
+    let mut harness =
+        TestHarness::create_with_size(default_property_set(), widget, Size::new(400., 400.));
+
+    harness.process_text_event(TextEvent::key_down(Key::Named(NamedKey::F11)));
+    harness.mouse_click_on(button_id);
+
+    // The click picks the button instead of pressing it.
+    assert_eq!(harness.inspected_widget(), Some(button_id));
+    assert!(harness.is_inspector_open());
+    assert!(harness.pop_action().is_none());
+}
+
+#[test]
+fn tree_panel_selects_widget() {
+    let [button_id] = widget_ids();
+    let widget = SizedBox::new_with_id(Button::new("Click me"), button_id).expand();
+    let mut harness =
+        TestHarness::create_with_size(default_property_set(), widget, Size::new(400., 400.));
+
+    toggle_inspector(&mut harness);
+    assert!(harness.is_inspector_open());
+    // The panel is laid out when it's painted.
+    let _ = harness.redraw();
+
+    // The panel is on the right of the window, and its second row is the button.
+    // Widgets under the panel don't get pointer events.
+    harness.mouse_move((300., 30.));
+    assert!(!harness.get_widget(button_id).ctx().is_hovered());
+    harness.mouse_button_press(PointerButton::Primary);
+    harness.mouse_button_release(PointerButton::Primary);
+    assert_eq!(harness.inspected_widget(), Some(button_id));
+    assert!(harness.pop_action().is_none());
+
+    // Once the inspector is closed, the button can be hovered again.
+    toggle_inspector(&mut harness);
+    assert!(!harness.is_inspector_open());
+    assert!(harness.get_widget(button_id).ctx().is_hovered());
+}
@@ -5,9 +5,11 @@
 
 use vello::kurbo::{Insets, Size};
 
-use crate::testing::{ModularWidget, TestHarness, TestWidgetExt, widget_ids};
+use crate::core::WindowEvent;
+use crate::dpi::PhysicalSize;
+use crate::testing::{ModularWidget, Record, Recording, TestHarness, TestWidgetExt, widget_ids};
 use crate::theme::default_property_set;
-use crate::widgets::{Flex, SizedBox};
+use crate::widgets::{Flex, Label, SizedBox};
 
 #[test]
 fn layout_simple() {
@@ -70,6 +72,52 @@ fn layout_insets() {
     assert_eq!(parent_paint_rect.y1, BOX_WIDTH + 20.0);
 }
 
+#[test]
+fn layout_skips_unchanged_subtrees() {
+    let [label_1, label_2] = widget_ids();
+    let record = Recording::default();
+
+    let widget = Flex::column()
+        .with_child(SizedBox::new_with_id(Label::new("Hello"), label_1).width(100.))
+        .with_child(SizedBox::new_with_id(Label::new("World"), label_2).width(100.))
+        .record(&record);
+
+    let mut harness = TestHarness::create(default_property_set(), widget);
+    let _ = harness.redraw();
+    record.clear();
+
+    let layout_count = harness.layout_count();
+    harness.edit_widget(label_1, |mut label| {
+        let mut label = label.downcast::<Label>();
+        Label::set_text(&mut label, "Goodbye");
+    });
+
+    // Only the label and its ancestors are laid out again.
+    // The second box gets the same constraints as before, so it's skipped.
+    assert_eq!(harness.layout_count() - layout_count, 3);
+
+    // The root didn't change size, so it doesn't need to be repainted.
+    let _ = harness.redraw();
+    let records = record.drain();
+    assert!(records.iter().any(|r| matches!(r, Record::Layout(_))));
+    assert!(!records.iter().any(|r| matches!(r, Record::Paint)));
+}
+
+#[test]
+fn layout_reruns_on_new_constraints() {
+    let widget = Flex::column()
+        .with_child(Label::new("Hello"))
+        .with_child(Label::new("World"));
+
+    let mut harness = TestHarness::create(default_property_set(), widget);
+
+    let layout_count = harness.layout_count();
+    harness.process_window_event(WindowEvent::Resize(PhysicalSize::new(300, 300)));
+
+    // The root gets new constraints, and passes new constraints to its children.
+    assert_eq!(harness.layout_count() - layout_count, 3);
+}
+
 // TODO - insets + flex
 // TODO - viewport
 // TODO - insets + viewport
@@ -0,0 +1,216 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests related to intrinsic size measurement.
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use assert_matches::assert_matches;
+use smallvec::smallvec;
+use vello::kurbo::{Point, Size};
+
+use crate::core::{Axis, IntrinsicSize, Widget, WidgetPod, WindowEvent};
+use crate::dpi::PhysicalSize;
+use crate::testing::{ModularWidget, Record, Recording, TestHarness, TestWidgetExt, widget_ids};
+use crate::theme::default_property_set;
+use crate::widgets::{Flex, Grid, GridParams, Label, LineBreaking, SizedBox};
+
+type MeasureResult = Rc<Cell<Option<IntrinsicSize>>>;
+
+/// A widget which measures its child before laying it out, and stores the result.
+fn measuring_parent<W: Widget>(
+    child: W,
+    axis: Axis,
+    cross_length: Option<f64>,
+    result: &MeasureResult,
+) -> ModularWidget<WidgetPod<W>> {
+    let result = result.clone();
+    ModularWidget::new(WidgetPod::new(child))
+        .register_children_fn(|child, ctx| {
+            ctx.register_child(child);
+        })
+        .layout_fn(move |child, ctx, _, bc| {
+            result.set(ctx.measure_child(child, axis, cross_length));
+            let size = ctx.run_layout(child, bc);
+            ctx.place_child(child, Point::ZERO);
+            size
+        })
+        .children_fn(|child| smallvec![child.id()])
+}
+
+fn measure(widget: impl Widget, axis: Axis, cross_length: Option<f64>) -> IntrinsicSize {
+    let result = MeasureResult::default();
+    let parent = measuring_parent(widget, axis, cross_length, &result);
+    let _harness = TestHarness::create(default_property_set(), parent);
+    result.get().expect("widget should support measurement")
+}
+
+#[test]
+fn label_widths() {
+    let text = "The quick brown fox";
+
+    let wrapping = Label::new(text).with_line_break_mode(LineBreaking::WordWrap);
+    let size = measure(wrapping, Axis::Horizontal, None);
+    assert!(size.min > 0.);
+    assert!(size.min < size.preferred);
+
+    // Without word wrapping, the label can't be narrower than its text.
+    let clipped = Label::new(text).with_line_break_mode(LineBreaking::Clip);
+    let clipped_size = measure(clipped, Axis::Horizontal, None);
+    assert_eq!(clipped_size.min, clipped_size.preferred);
+    assert_eq!(clipped_size.preferred, size.preferred);
+}
+
+#[test]
+fn label_height_depends_on_width() {
+    let label = || Label::new("The quick brown fox").with_line_break_mode(LineBreaking::WordWrap);
+    let widths = measure(label(), Axis::Horizontal, None);
+
+    let one_line = measure(label(), Axis::Vertical, Some(widths.preferred + 10.));
+    let wrapped = measure(label(), Axis::Vertical, Some(widths.min));
+    assert!(wrapped.preferred > one_line.preferred);
+}
+
+#[test]
+fn flex_sums_children_on_main_axis() {
+    let row = || {
+        Flex::row()
+            .gap(10.)
+            .with_child(SizedBox::empty().width(30.).height(20.))
+            .with_child(SizedBox::empty().width(40.).height(50.))
+    };
+
+    let width = measure(row(), Axis::Horizontal, None);
+    assert_eq!(width, IntrinsicSize::fixed(80.));
+
+    let height = measure(row(), Axis::Vertical, None);
+    assert_eq!(height, IntrinsicSize::fixed(50.));
+}
+
+#[test]
+fn flex_children_keep_min_content_width() {
+    let [wide_id, narrow_id] = widget_ids();
+
+    let row = Flex::row()
+        .gap(0.)
+        .keep_min_content(true)
+        .with_flex_child_pod(
+            WidgetPod::new_with_id(SizedBox::empty().width(80.), wide_id).erased(),
+            1.0,
+        )
+        .with_flex_child_pod(
+            WidgetPod::new_with_id(SizedBox::empty().width(10.), narrow_id).erased(),
+            1.0,
+        );
+
+    // Each child is offered 50px, but the wide one needs 80px.
+    let harness = TestHarness::create_with_size(default_property_set(), row, Size::new(100., 50.));
+    let wide_rect = harness.get_widget(wide_id).ctx().local_layout_rect();
+    let narrow_rect = harness.get_widget(narrow_id).ctx().local_layout_rect();
+    assert_eq!(wide_rect.width(), 80.);
+    assert_eq!(narrow_rect.x0, 80.);
+    assert_eq!(narrow_rect.width(), 10.);
+}
+
+#[test]
+fn flex_children_shrink_below_min_content_by_default() {
+    let [wide_id] = widget_ids();
+
+    let row = Flex::row()
+        .gap(0.)
+        .with_flex_child_pod(
+            WidgetPod::new_with_id(SizedBox::empty().width(80.), wide_id).erased(),
+            1.0,
+        )
+        .with_flex_child(SizedBox::empty().width(10.), 1.0);
+
+    let harness = TestHarness::create_with_size(default_property_set(), row, Size::new(100., 50.));
+    let wide_rect = harness.get_widget(wide_id).ctx().local_layout_rect();
+    assert_eq!(wide_rect.width(), 50.);
+}
+
+#[test]
+fn unsupported_widgets_are_not_measured() {
+    let result = MeasureResult::default();
+    let parent = measuring_parent(ModularWidget::new(()), Axis::Horizontal, None, &result);
+    let _harness = TestHarness::create(default_property_set(), parent);
+    assert_eq!(result.get(), None);
+}
+
+#[test]
+fn measurements_are_cached() {
+    let [child_id] = widget_ids();
+    let recording = Recording::default();
+    let result = MeasureResult::default();
+
+    let child = SizedBox::empty()
+        .width(30.)
+        .record(&recording)
+        .with_id(child_id);
+    let parent = measuring_parent(child, Axis::Horizontal, None, &result);
+    let mut harness = TestHarness::create(default_property_set(), parent);
+    assert_eq!(result.get(), Some(IntrinsicSize::fixed(30.)));
+    let records = recording.drain();
+    assert_eq!(
+        records
+            .iter()
+            .filter(|record| matches!(record, Record::Measure(..)))
+            .count(),
+        1
+    );
+
+    // Relayout the parent: the child's measurement is still valid.
+    harness.edit_root_widget(|mut parent| {
+        parent.ctx.request_layout();
+    });
+    assert!(
+        !recording
+            .drain()
+            .iter()
+            .any(|record| matches!(record, Record::Measure(..)))
+    );
+
+    // Relayout the child: it's measured again.
+    harness.edit_widget(child_id, |mut child| {
+        child.ctx.request_layout();
+    });
+    assert_matches!(
+        recording.drain().first(),
+        Some(Record::Measure(Axis::Horizontal, Some(_)))
+    );
+}
+
+#[test]
+fn grid_auto_column_widths() {
+    let [narrow_id, wide_id] = widget_ids();
+
+    let grid = Grid::with_dimensions(2, 1)
+        .with_auto_column_widths(true)
+        .with_child_id(
+            SizedBox::empty().width(100.),
+            wide_id,
+            GridParams::new(0, 0, 1, 1),
+        )
+        .with_child_id(
+            SizedBox::empty().width(20.),
+            narrow_id,
+            GridParams::new(1, 0, 1, 1),
+        );
+
+    // Columns get their preferred width, and share the remaining 80px.
+    let mut harness =
+        TestHarness::create_with_size(default_property_set(), grid, Size::new(200., 50.));
+    let wide_rect = harness.get_widget(wide_id).ctx().local_layout_rect();
+    let narrow_rect = harness.get_widget(narrow_id).ctx().local_layout_rect();
+    assert_eq!(wide_rect.width(), 140.);
+    assert_eq!(narrow_rect.x0, 140.);
+    assert_eq!(narrow_rect.width(), 60.);
+
+    // Without enough space, columns shrink proportionally.
+    harness.process_window_event(WindowEvent::Resize(PhysicalSize::new(60, 50)));
+    let wide_rect = harness.get_widget(wide_id).ctx().local_layout_rect();
+    let narrow_rect = harness.get_widget(narrow_id).ctx().local_layout_rect();
+    assert_eq!(wide_rect.width(), 50.);
+    assert_eq!(narrow_rect.width(), 10.);
+}
@@ -5,12 +5,20 @@
 
 #![allow(clippy::print_stdout, clippy::print_stderr, clippy::dbg_macro)]
 
+mod compositing;
+mod damage;
+mod error_description;
+mod focus_order;
+mod frame_stats;
 mod ime_focused;
+mod inspector;
 mod layout;
 mod lifecycle_basic;
 mod lifecycle_disable;
 mod lifecycle_focus;
+mod measure;
 mod safety_rails;
+mod shortcuts;
 mod status_change;
 mod transforms;
 mod widget_tree;
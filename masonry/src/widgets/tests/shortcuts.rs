@@ -0,0 +1,98 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests related to keyboard shortcuts.
+
+use crate::core::keyboard::KeyboardEvent;
+use crate::core::{KeyChord, Shortcut, ShortcutPrecedence, Shortcuts, TextEvent, WidgetId};
+use crate::testing::{TestHarness, TestWidgetExt, widget_ids};
+use crate::theme::default_property_set;
+use crate::widgets::{Flex, SizedBox, TextArea};
+
+fn press(harness: &mut TestHarness, chord: &str) {
+    let chord: KeyChord = chord.parse().unwrap();
+    harness.process_text_event(TextEvent::Keyboard(KeyboardEvent {
+        key: chord.key().clone(),
+        modifiers: chord.modifiers(),
+        ..Default::default()
+    }));
+}
+
+fn text_of(harness: &TestHarness, id: WidgetId) -> String {
+    let widget = harness.get_widget(id);
+    let area = widget.downcast::<TextArea<true>>().unwrap();
+    area.text().to_string()
+}
+
+#[test]
+fn window_shortcut() {
+    let widget = SizedBox::empty().expand();
+    let mut harness = TestHarness::create(default_property_set(), widget);
+
+    let save = harness.register_shortcut(Shortcut::new("Ctrl+S".parse().unwrap()));
+
+    press(&mut harness, "S");
+    assert_eq!(harness.pop_shortcut(), None);
+    press(&mut harness, "Ctrl+Shift+S");
+    assert_eq!(harness.pop_shortcut(), None);
+
+    press(&mut harness, "Ctrl+S");
+    assert_eq!(harness.pop_shortcut(), Some(save));
+}
+
+#[test]
+fn shortcuts_and_text_input() {
+    let [text_id] = widget_ids();
+    let widget = TextArea::new_editable("hello").with_id(text_id);
+    let mut harness = TestHarness::create(default_property_set(), widget);
+    harness.focus_on(Some(text_id));
+
+    let save = harness.register_shortcut(Shortcut::new("Ctrl+S".parse().unwrap()));
+    harness.register_shortcut(Shortcut::new("Ctrl+A".parse().unwrap()));
+    let escape = harness.register_shortcut(
+        Shortcut::new("Escape".parse().unwrap()).with_precedence(ShortcutPrecedence::BeforeFocused),
+    );
+
+    // The text input doesn't type characters when Ctrl is held, so the shortcut is triggered.
+    press(&mut harness, "Ctrl+S");
+    assert_eq!(harness.pop_shortcut(), Some(save));
+    assert_eq!(text_of(&harness, text_id), "hello");
+
+    // The text input handles Ctrl+A itself, so the shortcut isn't triggered.
+    press(&mut harness, "Ctrl+A");
+    assert_eq!(harness.pop_shortcut(), None);
+
+    // Shortcuts which take precedence are triggered before the text input sees the key.
+    press(&mut harness, "Escape");
+    assert_eq!(harness.pop_shortcut(), Some(escape));
+}
+
+#[test]
+fn subtree_shortcuts() {
+    let [inner_id, text_id, other_text_id] = widget_ids();
+    let widget = Flex::column()
+        .with_child(SizedBox::new_with_id(
+            TextArea::new_editable("").with_id(text_id),
+            inner_id,
+        ))
+        .with_child(TextArea::new_editable("").with_id(other_text_id));
+    let mut harness = TestHarness::create(default_property_set(), widget);
+
+    let window_find = harness.register_shortcut(Shortcut::new("Ctrl+F".parse().unwrap()));
+    let inner_find = Shortcut::new("Ctrl+F".parse().unwrap());
+    let inner_find_id = inner_find.id;
+    harness.edit_widget(inner_id, |mut sized_box| {
+        sized_box.insert_prop(Shortcuts(vec![inner_find]));
+    });
+
+    // The shortcut of the subtree containing the focused widget wins.
+    harness.focus_on(Some(text_id));
+    press(&mut harness, "Ctrl+F");
+    assert_eq!(harness.pop_shortcut(), Some(inner_find_id));
+
+    // Outside that subtree, the window shortcut is used.
+    harness.focus_on(Some(other_text_id));
+    press(&mut harness, "Ctrl+F");
+    assert_eq!(harness.pop_shortcut(), Some(window_find));
+    assert_eq!(harness.pop_shortcut(), None);
+}
@@ -5,7 +5,7 @@ use assert_matches::assert_matches;
 
 use crate::testing::{TestHarness, TestWidgetExt as _, widget_ids};
 use crate::theme::default_property_set;
-use crate::widgets::{Button, Label};
+use crate::widgets::{Button, Flex, Label};
 
 #[test]
 fn downcast_ref_in_harness() {
@@ -17,3 +17,29 @@ fn downcast_ref_in_harness() {
     assert_matches!(harness.get_widget(label_id).downcast::<Label>(), Some(_));
     assert_matches!(harness.get_widget(label_id).downcast::<Button>(), None);
 }
+
+#[test]
+fn find_widgets_in_harness() {
+    let [button_id, label_id] = widget_ids();
+    let widget = Flex::column()
+        .with_child(Button::new("Submit").with_id(button_id))
+        .with_child(Label::new("Status").with_id(label_id));
+
+    let mut harness = TestHarness::create(default_property_set(), widget);
+
+    let found = harness
+        .find_widget_by_debug_text("Status")
+        .map(|widget| widget.id());
+    assert_eq!(found, Some(label_id));
+    assert!(harness.find_widget_by_debug_text("Missing").is_none());
+
+    let found = harness
+        .find_widget_by_accessibility_name("Submit")
+        .map(|widget| widget.id());
+    assert_eq!(found, Some(button_id));
+    assert!(
+        harness
+            .find_widget_by_accessibility_name("Missing")
+            .is_none()
+    );
+}
@@ -3,9 +3,10 @@
 
 use insta::assert_debug_snapshot;
 
+use crate::core::Opacity;
 use crate::testing::{TestHarness, widget_ids};
 use crate::theme::default_property_set;
-use crate::widgets::{Flex, Label};
+use crate::widgets::{CrossAxisAlignment, Flex, Label, SizedBox};
 
 #[test]
 fn access_grandchild_widget() {
@@ -27,3 +28,62 @@ fn access_grandchild_widget() {
 
     assert_debug_snapshot!(harness.root_widget());
 }
+
+#[test]
+fn tree_snapshot() {
+    let widget = Flex::column()
+        .cross_axis_alignment(CrossAxisAlignment::Start)
+        .with_child(SizedBox::empty().width(50.).height(20.));
+
+    let harness = TestHarness::create(default_property_set(), widget);
+
+    assert_eq!(
+        harness.tree_snapshot().to_stable_string(),
+        "Flex (0, 0) 400x400\n  SizedBox (0, 0) 50x20\n"
+    );
+}
+
+#[test]
+fn tree_snapshot_diff() {
+    let [box_id, label_id] = widget_ids();
+
+    let widget = Flex::column()
+        .cross_axis_alignment(CrossAxisAlignment::Start)
+        .with_child_id(SizedBox::empty().width(50.).height(20.), box_id)
+        .with_child_id(Label::new("Old text"), label_id);
+
+    let mut harness = TestHarness::create(default_property_set(), widget);
+    let before = harness.tree_snapshot();
+    assert!(before.diff(&harness.tree_snapshot()).is_empty());
+
+    harness.edit_widget(box_id, |mut sized_box| {
+        sized_box.insert_prop(Opacity(0.5));
+        let mut sized_box = sized_box.downcast::<SizedBox>();
+        SizedBox::set_width(&mut sized_box, 80.);
+    });
+    harness.edit_widget(label_id, |mut label| {
+        let mut label = label.downcast::<Label>();
+        Label::set_text(&mut label, "New text");
+    });
+
+    let after = harness.tree_snapshot();
+    let diff = before.diff(&after);
+    let changes = diff.changes();
+    assert!(changes.contains(&format!(
+        "~ SizedBox {box_id}: layout rect: (0, 0) 50x20 -> (0, 0) 80x20"
+    )));
+    assert!(changes.contains(&format!(
+        "~ SizedBox {box_id}: property added: Opacity(0.5)"
+    )));
+    assert!(changes.contains(&format!(
+        "~ Label {label_id}: debug text: <Old text> -> <New text>"
+    )));
+
+    harness.edit_root_widget(|mut flex| {
+        let mut flex = flex.downcast::<Flex>();
+        Flex::remove_child(&mut flex, 1);
+    });
+    let diff = after.diff(&harness.tree_snapshot());
+    assert_eq!(diff.changes().len(), 1, "{diff}");
+    assert!(diff.changes()[0].starts_with(&format!("- Label {label_id} <New text>")));
+}
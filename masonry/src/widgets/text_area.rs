@@ -16,9 +16,10 @@ use vello::peniko::{Brush, Fill};
 use crate::TextAlign;
 use crate::core::keyboard::{Key, KeyState, NamedKey};
 use crate::core::{
-    AccessCtx, AccessEvent, Action, BoxConstraints, BrushIndex, EventCtx, Ime, LayoutCtx, PaintCtx,
-    PointerButton, PointerEvent, PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx,
-    StyleProperty, TextEvent, Update, UpdateCtx, Widget, WidgetId, WidgetMut, render_text,
+    AccessCtx, AccessEvent, Action, Axis, BoxConstraints, BrushIndex, EventCtx, Ime, IntrinsicSize,
+    LayoutCtx, MeasureCtx, PaintCtx, PointerButton, PointerEvent, PropertiesMut, PropertiesRef,
+    QueryCtx, RegisterCtx, StyleProperty, TextEvent, Update, UpdateCtx, Widget, WidgetId,
+    WidgetMut, render_text,
 };
 use crate::debug_panic;
 use crate::theme::default_text_styles;
@@ -536,6 +537,10 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
                         key_event.modifiers.ctrl()
                     },
                 );
+                // Characters typed with the action modifier are left unhandled, so that
+                // they can trigger shortcuts.
+                // AltGr is reported as Ctrl+Alt on Windows, so we still insert those.
+                let types_text = !action_mod || key_event.modifiers.alt();
                 let (fctx, lctx) = ctx.text_contexts();
                 // Whether the text was changed.
                 let mut edited = false;
@@ -676,7 +681,7 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
 
                         edited = true;
                     }
-                    Key::Character(sp) if EDITABLE && sp.as_str() == " " => {
+                    Key::Character(sp) if EDITABLE && types_text && sp.as_str() == " " => {
                         self.editor
                             .driver(fctx, lctx)
                             .insert_or_replace_selection(" ");
@@ -704,7 +709,7 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
                         // Note that this doesn't allow input of the tab character; we need to be more clever here at some point
                         return;
                     }
-                    Key::Character(text) if EDITABLE => {
+                    Key::Character(text) if EDITABLE && types_text => {
                         self.editor
                             .driver(fctx, lctx)
                             .insert_or_replace_selection(text);
@@ -862,6 +867,38 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
         bc.constrain(area_size)
     }
 
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        cross_length: Option<f64>,
+    ) -> Option<IntrinsicSize> {
+        match axis {
+            Axis::Horizontal => {
+                let (fctx, lctx) = ctx.text_contexts();
+                let widths = self.editor.layout(fctx, lctx).calculate_content_widths();
+                // Without word wrapping, the text can't be narrower than its longest line.
+                let min = if self.word_wrap {
+                    widths.min
+                } else {
+                    widths.max
+                };
+                Some(IntrinsicSize::new(min.into(), widths.max.into()))
+            }
+            Axis::Vertical => {
+                let available_width = cross_length.map(|width| width as f32);
+                if self.last_available_width != available_width && self.word_wrap {
+                    self.editor.set_width(available_width);
+                    self.last_available_width = available_width;
+                }
+                let (fctx, lctx) = ctx.text_contexts();
+                let height = self.editor.layout(fctx, lctx).height();
+                Some(IntrinsicSize::fixed(height.into()))
+            }
+        }
+    }
+
     fn paint(&mut self, ctx: &mut PaintCtx<'_>, _props: &PropertiesRef<'_>, scene: &mut Scene) {
         let layout = if let Some(layout) = self.editor.try_layout() {
             layout
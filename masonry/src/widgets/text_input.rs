@@ -15,9 +15,10 @@ use crate::core::{
     Update, UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
 };
 use crate::properties::{
-    Background, BorderColor, BorderWidth, BoxShadow, CornerRadius, DisabledBackground, Padding,
+    Background, BorderColor, BorderGradient, BorderWidth, BoxShadows, CornerRadius,
+    DisabledBackground, Padding,
 };
-use crate::util::{fill, stroke};
+use crate::util::fill;
 use crate::widgets::TextArea;
 
 /// The text input widget displays text which can be edited by the user,
@@ -112,10 +113,11 @@ impl Widget for TextInput {
         DisabledBackground::prop_changed(ctx, property_type);
         Background::prop_changed(ctx, property_type);
         BorderColor::prop_changed(ctx, property_type);
+        BorderGradient::prop_changed(ctx, property_type);
         BorderWidth::prop_changed(ctx, property_type);
         CornerRadius::prop_changed(ctx, property_type);
         Padding::prop_changed(ctx, property_type);
-        BoxShadow::prop_changed(ctx, property_type);
+        BoxShadows::prop_changed(ctx, property_type);
     }
 
     fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
@@ -137,7 +139,7 @@ impl Widget for TextInput {
     ) -> Size {
         let border = props.get::<BorderWidth>();
         let padding = props.get::<Padding>();
-        let shadow = props.get::<BoxShadow>();
+        let shadows = props.get::<BoxShadows>();
 
         let bc = *bc;
         let bc = border.layout_down(bc);
@@ -155,8 +157,8 @@ impl Widget for TextInput {
         let pos = padding.place_down(pos);
         ctx.place_child(&mut self.text, pos);
 
-        if shadow.is_visible() {
-            ctx.set_paint_insets(shadow.get_insets());
+        if shadows.is_visible() {
+            ctx.set_paint_insets(shadows.get_insets());
         }
 
         if self.clip {
@@ -172,8 +174,7 @@ impl Widget for TextInput {
 
         let border_width = props.get::<BorderWidth>();
         let border_radius = props.get::<CornerRadius>();
-        let shadow = props.get::<BoxShadow>();
-        let mut border_color = props.get::<BorderColor>();
+        let shadows = props.get::<BoxShadows>();
 
         let bg = if ctx.is_disabled() {
             &props.get::<DisabledBackground>().0
@@ -185,17 +186,20 @@ impl Widget for TextInput {
         let border_rect = border_width.border_rect(size, border_radius);
 
         // FIXME - Handle this properly
-        if ctx.has_focus_target() {
-            border_color = &BorderColor {
-                color: Color::WHITE,
-            };
-        }
+        let state_border_color = ctx.has_focus_target().then_some(Color::WHITE);
 
-        shadow.paint(scene, Affine::IDENTITY, bg_rect);
+        shadows.paint(scene, Affine::IDENTITY, bg_rect);
 
         let brush = bg.get_peniko_brush_for_rect(bg_rect.rect());
         fill(scene, &bg_rect, &brush);
-        stroke(scene, &border_rect, border_color.color, border_width.width);
+        shadows.paint_inset(scene, Affine::IDENTITY, bg_rect);
+        props.get::<BorderGradient>().paint(
+            scene,
+            &border_rect,
+            border_width,
+            props.get::<BorderColor>(),
+            state_border_color,
+        );
     }
 
     fn accessibility_role(&self) -> Role {
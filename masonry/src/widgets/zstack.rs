@@ -265,6 +265,19 @@ impl ZStack {
         this.ctx.request_layout();
     }
 
+    /// Move the child at `from` to `to`, shifting the children in between.
+    ///
+    /// Children are drawn in order, so this changes which children are drawn on top.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either index is out of bounds.
+    pub fn move_child(this: &mut WidgetMut<'_, Self>, from: usize, to: usize) {
+        let child = this.widget.children.remove(from);
+        this.widget.children.insert(to, child);
+        this.ctx.children_changed();
+    }
+
     /// Get a mutable reference to a child of the `ZStack`.
     pub fn child_mut<'t>(
         this: &'t mut WidgetMut<'_, Self>,
@@ -0,0 +1,305 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! A [`tracing`] layer which records spans in the Chrome Trace Event format.
+
+use std::fmt::{Debug, Write as _};
+use std::io::Write;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Instant;
+
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::Layer;
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::registry::LookupSpan;
+
+/// Name of the span which covers the work needed to produce a frame.
+///
+/// The trace marks the start of each of these spans as a frame boundary.
+pub(crate) const FRAME_SPAN_NAME: &str = "redraw";
+
+/// A [`Layer`] which writes spans and events as [Chrome Trace Event] JSON.
+///
+/// The output can be opened in [Perfetto](https://ui.perfetto.dev) or `chrome://tracing`.
+/// Each span is a slice on the track of the thread which entered it, and each frame
+/// drawn by [`RenderRoot::redraw`](crate::app::RenderRoot::redraw) is marked with a
+/// global instant event.
+///
+/// The output is flushed at the start of every frame.
+/// The closing bracket of the JSON array is never written, which the format allows,
+/// so that the file is valid even if the app doesn't exit cleanly.
+///
+/// This layer is installed by [`try_init_tracing`](crate::app::try_init_tracing) when
+/// the `MASONRY_CHROME_TRACE` environment variable is set.
+///
+/// [Chrome Trace Event]: https://docs.google.com/document/d/1CvAClvFfyA5R-PhYUmn5OOQtYMH4h6I0nSsKchNAySU
+pub struct ChromeTraceLayer {
+    writer: Mutex<TraceWriter>,
+    start: Instant,
+}
+
+struct TraceWriter {
+    out: Box<dyn Write + Send>,
+    frame_count: u64,
+}
+
+/// The arguments of a span, already formatted as the members of a JSON object.
+struct SpanArgs(String);
+
+impl ChromeTraceLayer {
+    /// Create a layer which writes its trace to `out`.
+    ///
+    /// `out` should be buffered, e.g. with a [`BufWriter`](std::io::BufWriter).
+    pub fn new(out: impl Write + Send + 'static) -> Self {
+        let mut out: Box<dyn Write + Send> = Box::new(out);
+        let _ = out.write_all(b"[\n");
+        Self {
+            writer: Mutex::new(TraceWriter {
+                out,
+                frame_count: 0,
+            }),
+            start: Instant::now(),
+        }
+    }
+
+    /// Write a single trace event.
+    ///
+    /// `args` are the members of the event's `args` object.
+    fn write_event(&self, name: &str, category: &str, phase: &str, args: &str) {
+        let timestamp = self.start.elapsed().as_secs_f64() * 1_000_000.;
+        let mut event = String::new();
+        write!(event, r#"{{"name":"#).unwrap();
+        write_json_string(&mut event, name);
+        write!(event, r#","cat":"#).unwrap();
+        write_json_string(&mut event, category);
+        write!(
+            event,
+            r#","ph":"{phase}","ts":{timestamp:.3},"pid":1,"tid":{}"#,
+            thread_id()
+        )
+        .unwrap();
+        if phase == "i" {
+            write!(event, r#","s":"t""#).unwrap();
+        }
+        if !args.is_empty() {
+            write!(event, r#","args":{{{args}}}"#).unwrap();
+        }
+        event.push_str("},\n");
+
+        let mut writer = self.writer.lock().unwrap();
+        let _ = writer.out.write_all(event.as_bytes());
+    }
+
+    /// Mark the start of a new frame, and flush the events of the previous one.
+    fn write_frame_boundary(&self) {
+        let timestamp = self.start.elapsed().as_secs_f64() * 1_000_000.;
+        let mut writer = self.writer.lock().unwrap();
+        writer.frame_count += 1;
+        let event = format!(
+            r#"{{"name":"Frame","cat":"frame","ph":"i","s":"g","ts":{timestamp:.3},"pid":1,"tid":{},"args":{{"frame":{}}}}},
+"#,
+            thread_id(),
+            writer.frame_count
+        );
+        let _ = writer.out.write_all(event.as_bytes());
+        let _ = writer.out.flush();
+    }
+}
+
+impl<S> Layer<S> for ChromeTraceLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut visitor = ArgsVisitor::default();
+        attrs.record(&mut visitor);
+        span.extensions_mut().insert(SpanArgs(visitor.args));
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut extensions = span.extensions_mut();
+        if let Some(SpanArgs(args)) = extensions.get_mut::<SpanArgs>() {
+            let mut visitor = ArgsVisitor {
+                args: std::mem::take(args),
+                message: None,
+            };
+            values.record(&mut visitor);
+            *args = visitor.args;
+        }
+    }
+
+    fn on_enter(&self, id: &Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let metadata = span.metadata();
+        if metadata.name() == FRAME_SPAN_NAME {
+            self.write_frame_boundary();
+        }
+        let extensions = span.extensions();
+        let args = extensions.get::<SpanArgs>().map_or("", |args| &args.0);
+        self.write_event(metadata.name(), metadata.target(), "B", args);
+    }
+
+    fn on_exit(&self, id: &Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let metadata = span.metadata();
+        // Fields can be recorded after the span was entered, so we write them again.
+        // Trace viewers merge the arguments of both events.
+        let extensions = span.extensions();
+        let args = extensions.get::<SpanArgs>().map_or("", |args| &args.0);
+        self.write_event(metadata.name(), metadata.target(), "E", args);
+    }
+
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let mut visitor = ArgsVisitor::default();
+        event.record(&mut visitor);
+        let metadata = event.metadata();
+        let name = visitor.message.as_deref().unwrap_or(metadata.name());
+        self.write_event(name, metadata.target(), "i", &visitor.args);
+    }
+}
+
+/// Formats fields as the members of a JSON object.
+///
+/// The `message` field of events is kept separately, to be used as the event's name.
+#[derive(Default)]
+struct ArgsVisitor {
+    args: String,
+    message: Option<String>,
+}
+
+impl ArgsVisitor {
+    fn write_key(&mut self, field: &Field) {
+        if !self.args.is_empty() {
+            self.args.push(',');
+        }
+        write_json_string(&mut self.args, field.name());
+        self.args.push(':');
+    }
+}
+
+impl Visit for ArgsVisitor {
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.write_key(field);
+        write!(self.args, "{value}").unwrap();
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.write_key(field);
+        write!(self.args, "{value}").unwrap();
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.write_key(field);
+        write!(self.args, "{value}").unwrap();
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message = Some(value.to_string());
+            return;
+        }
+        self.write_key(field);
+        write_json_string(&mut self.args, value);
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        if field.name() == "message" {
+            self.message = Some(format!("{value:?}"));
+            return;
+        }
+        self.write_key(field);
+        write_json_string(&mut self.args, &format!("{value:?}"));
+    }
+}
+
+fn write_json_string(out: &mut String, value: &str) {
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// A small number identifying the current thread, used as the track of its events.
+fn thread_id() -> u64 {
+    static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+    thread_local! {
+        static ID: u64 = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+    }
+    ID.with(|id| *id)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use tracing::{info, info_span};
+    use tracing_subscriber::prelude::*;
+
+    use super::*;
+
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn records_spans_and_frames() {
+        let buffer = SharedBuffer::default();
+        let subscriber = tracing_subscriber::registry().with(ChromeTraceLayer::new(buffer.clone()));
+
+        tracing::subscriber::with_default(subscriber, || {
+            let _frame = info_span!("redraw").entered();
+            let span = info_span!("layout", widgets = tracing::field::Empty).entered();
+            span.record("widgets", 3);
+            info!(name = "a \"quoted\" name", "Hello");
+        });
+
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines[0], "[");
+        assert!(lines[1].starts_with(r#"{"name":"Frame","cat":"frame","ph":"i","s":"g""#));
+        assert!(lines[1].ends_with(r#""args":{"frame":1}},"#));
+        assert!(lines[2].starts_with(r#"{"name":"redraw","#));
+        assert!(lines[2].contains(r#""ph":"B""#));
+        assert!(lines[3].starts_with(r#"{"name":"layout","#));
+        assert!(!lines[3].contains("args"));
+        assert!(lines[4].starts_with(r#"{"name":"Hello","#));
+        assert!(lines[4].ends_with(r#""args":{"name":"a \"quoted\" name"}},"#));
+        assert!(lines[5].starts_with(r#"{"name":"layout","#));
+        assert!(lines[5].contains(r#""ph":"E""#));
+        assert!(lines[5].ends_with(r#""args":{"widgets":3}},"#));
+        assert!(lines[6].starts_with(r#"{"name":"redraw","#));
+        assert!(lines[6].contains(r#""ph":"E""#));
+        assert_eq!(lines.len(), 7);
+    }
+}
@@ -0,0 +1,146 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use vello::kurbo::Rect;
+
+/// Past this number of rectangles, the region is collapsed into its bounding box.
+///
+/// Tracking many small rects is rarely worth it: the cost of handling each one
+/// quickly outweighs the pixels saved.
+const MAX_DAMAGE_RECTS: usize = 8;
+
+/// The parts of the window which changed since the last redraw.
+///
+/// A damage region is a small set of rectangles, in logical window coordinates.
+/// Rects which overlap are merged together.
+///
+/// This is returned by [`RenderRoot::damage`](crate::app::RenderRoot::damage), so that
+/// platform backends can avoid re-rendering frames which didn't change.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DamageRegion {
+    rects: Vec<Rect>,
+}
+
+impl DamageRegion {
+    /// Create an empty damage region.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a damage region covering the given rect.
+    pub fn from_rect(rect: Rect) -> Self {
+        let mut region = Self::new();
+        region.add_rect(rect);
+        region
+    }
+
+    /// Returns `true` if nothing changed.
+    pub fn is_empty(&self) -> bool {
+        self.rects.is_empty()
+    }
+
+    /// The list of damaged rects.
+    ///
+    /// These rects don't overlap each other.
+    pub fn rects(&self) -> &[Rect] {
+        &self.rects
+    }
+
+    /// The smallest rect containing the whole region, if it isn't empty.
+    pub fn bounding_box(&self) -> Option<Rect> {
+        self.rects.iter().copied().reduce(|a, b| a.union(b))
+    }
+
+    /// Returns `true` if the given rect overlaps the region.
+    pub fn intersects(&self, rect: Rect) -> bool {
+        self.rects.iter().any(|r| overlaps(*r, rect))
+    }
+
+    /// Mark the given rect as damaged.
+    ///
+    /// Empty and non-finite rects are ignored.
+    pub fn add_rect(&mut self, rect: Rect) {
+        let mut rect = rect.abs();
+        if rect.is_zero_area() || !rect.is_finite() {
+            return;
+        }
+
+        // Merge with every rect we overlap, until no overlap is left.
+        let mut i = 0;
+        while i < self.rects.len() {
+            if overlaps(self.rects[i], rect) {
+                rect = rect.union(self.rects.swap_remove(i));
+                i = 0;
+            } else {
+                i += 1;
+            }
+        }
+        self.rects.push(rect);
+
+        if self.rects.len() > MAX_DAMAGE_RECTS {
+            let bbox = self.bounding_box().unwrap();
+            self.rects.clear();
+            self.rects.push(bbox);
+        }
+    }
+
+    /// Mark every rect in the other region as damaged.
+    pub fn union(&mut self, other: &Self) {
+        for rect in &other.rects {
+            self.add_rect(*rect);
+        }
+    }
+
+    /// Restrict the region to the given rect.
+    pub fn clip_to(&mut self, clip: Rect) {
+        let rects = std::mem::take(&mut self.rects);
+        for rect in rects {
+            self.add_rect(rect.intersect(clip));
+        }
+    }
+
+    /// Remove all rects from the region.
+    pub fn clear(&mut self) {
+        self.rects.clear();
+    }
+}
+
+fn overlaps(a: Rect, b: Rect) -> bool {
+    a.x0 < b.x1 && b.x0 < a.x1 && a.y0 < b.y1 && b.y0 < a.y1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merges_overlapping_rects() {
+        let mut region = DamageRegion::new();
+        region.add_rect(Rect::new(0., 0., 10., 10.));
+        region.add_rect(Rect::new(50., 50., 60., 60.));
+        assert_eq!(region.rects().len(), 2);
+
+        region.add_rect(Rect::new(5., 5., 55., 55.));
+        assert_eq!(region.rects(), &[Rect::new(0., 0., 60., 60.)]);
+    }
+
+    #[test]
+    fn ignores_empty_rects() {
+        let mut region = DamageRegion::new();
+        region.add_rect(Rect::ZERO);
+        region.add_rect(Rect::new(10., 10., 10., 20.));
+        assert!(region.is_empty());
+        assert_eq!(region.bounding_box(), None);
+    }
+
+    #[test]
+    fn collapses_many_rects() {
+        let mut region = DamageRegion::new();
+        for i in 0..=MAX_DAMAGE_RECTS {
+            let x = i as f64 * 20.;
+            region.add_rect(Rect::new(x, 0., x + 10., 10.));
+        }
+        let last_x = MAX_DAMAGE_RECTS as f64 * 20.;
+        assert_eq!(region.rects(), &[Rect::new(0., 0., last_x + 10., 10.)]);
+    }
+}
@@ -0,0 +1,65 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::util::{Duration, Instant};
+
+/// Number of frames kept in the history returned by
+/// [`RenderRoot::frame_stats_history`](crate::app::RenderRoot::frame_stats_history).
+pub const FRAME_STATS_HISTORY_LEN: usize = 240;
+
+/// Timing statistics for one kind of pass over the course of a frame.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PassStats {
+    /// Total time spent running the pass.
+    pub duration: Duration,
+    /// Number of times the pass did some work.
+    ///
+    /// Passes which had nothing to do, e.g. a layout pass when no widget requested layout,
+    /// aren't counted.
+    pub runs: u32,
+    /// Number of widgets visited by the pass.
+    ///
+    /// Widgets which are skipped because they didn't need to be updated aren't counted.
+    pub widgets_visited: u64,
+}
+
+/// Statistics about the work done to produce a frame.
+///
+/// A frame covers everything that happened between two calls to
+/// [`RenderRoot::redraw`](crate::app::RenderRoot::redraw): the layout and compose passes run
+/// after each event, then the paint and accessibility passes run by `redraw` itself.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FrameStats {
+    /// Statistics for the [layout pass](crate::doc::doc_05_pass_system#layout-pass).
+    pub layout: PassStats,
+    /// Statistics for the [compose pass](crate::doc::doc_05_pass_system#compose-pass).
+    pub compose: PassStats,
+    /// Statistics for the [paint pass](crate::doc::doc_05_pass_system#render-passes).
+    pub paint: PassStats,
+    /// Statistics for the [accessibility pass](crate::doc::doc_05_pass_system#render-passes).
+    pub accessibility: PassStats,
+    /// Time the platform backend took to render the frame's scene.
+    ///
+    /// This is `None` unless the backend reported it with
+    /// [`RenderRoot::record_render_time`](crate::app::RenderRoot::record_render_time).
+    pub render: Option<Duration>,
+}
+
+impl PassStats {
+    /// Record a run of the pass which started at `start`.
+    pub(crate) fn record_run(&mut self, start: Instant) {
+        self.runs += 1;
+        self.duration += start.elapsed();
+    }
+}
+
+impl FrameStats {
+    /// Total time spent in passes and rendering for this frame.
+    pub fn total(&self) -> Duration {
+        self.layout.duration
+            + self.compose.duration
+            + self.paint.duration
+            + self.accessibility.duration
+            + self.render.unwrap_or_default()
+    }
+}
@@ -0,0 +1,416 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! The widget inspector, a debugging overlay drawn on top of the window.
+//!
+//! The inspector is made of two parts:
+//! - A panel on the right side of the window, which lists the widget tree and the
+//!   details of the selected widget.
+//! - Highlights drawn over the selected widget and the hovered widget, which show
+//!   their layout rect, their paint insets and their baseline.
+//!
+//! It's painted directly by the paint pass, and doesn't use any widget, so that
+//! inspecting the widget tree doesn't change it.
+
+use std::fmt::Write;
+
+use parley::{FontContext, GenericFamily, LayoutContext};
+use vello::Scene;
+use vello::kurbo::{Affine, Line, Point, Rect, Stroke};
+use vello::peniko::{Color, Fill, Mix};
+
+use crate::Handled;
+use crate::app::tree_snapshot::state_flags;
+use crate::app::{DamageRegion, RenderRoot, RenderRootSignal};
+use crate::core::{
+    BrushIndex, PointerEvent, ScrollDelta, StyleProperty, Widget, WidgetId, WidgetRef, render_text,
+};
+
+const PANEL_WIDTH: f64 = 360.;
+const PADDING: f64 = 6.;
+const ROW_HEIGHT: f64 = 16.;
+const INDENT: f64 = 12.;
+const FONT_SIZE: f32 = 12.;
+const LINE_SCROLL: f64 = 3. * ROW_HEIGHT;
+
+const PANEL_COLOR: Color = Color::from_rgba8(20, 20, 24, 235);
+const SEPARATOR_COLOR: Color = Color::from_rgb8(90, 90, 100);
+const TEXT_COLOR: Color = Color::from_rgb8(230, 230, 230);
+const HOVERED_ROW_COLOR: Color = Color::from_rgba8(60, 60, 250, 100);
+const SELECTED_ROW_COLOR: Color = Color::from_rgba8(250, 160, 40, 100);
+const HOVER_FILL_COLOR: Color = Color::from_rgba8(60, 60, 250, 100);
+const SELECTED_FILL_COLOR: Color = Color::from_rgba8(250, 160, 40, 80);
+const INSETS_COLOR: Color = Color::from_rgb8(40, 200, 80);
+const BASELINE_COLOR: Color = Color::from_rgb8(230, 40, 40);
+
+/// State of the widget inspector. Useful for debugging.
+pub(crate) struct InspectorState {
+    /// Whether the inspector panel is shown.
+    pub(crate) is_open: bool,
+    /// Whether the next click selects the widget under the pointer
+    /// instead of being sent to it.
+    pub(crate) is_picking_widget: bool,
+    /// The widget under the pointer, either in the window while picking or in the tree panel.
+    pub(crate) hovered_widget: Option<WidgetId>,
+    /// The widget whose details are shown in the panel.
+    pub(crate) selected_widget: Option<WidgetId>,
+    /// How far the tree panel is scrolled, in logical pixels.
+    pub(crate) scroll_offset: f64,
+    /// The area covered by the panel at the last paint, in window coordinates.
+    pub(crate) panel_rect: Rect,
+    /// The rows of the tree panel visible at the last paint, in window coordinates.
+    pub(crate) rows: Vec<(WidgetId, Rect)>,
+    /// The area covered by the inspector at the last paint.
+    pub(crate) painted_overlay: DamageRegion,
+}
+
+/// A line of the tree panel.
+struct TreeRow {
+    id: WidgetId,
+    depth: usize,
+    text: String,
+}
+
+impl InspectorState {
+    pub(crate) fn new() -> Self {
+        Self {
+            is_open: false,
+            is_picking_widget: false,
+            hovered_widget: None,
+            selected_widget: None,
+            scroll_offset: 0.,
+            panel_rect: Rect::ZERO,
+            rows: Vec::new(),
+            painted_overlay: DamageRegion::new(),
+        }
+    }
+
+    /// Returns `true` if the given position is over the inspector panel.
+    pub(crate) fn covers(&self, pos: Point) -> bool {
+        self.is_open && self.panel_rect.contains(pos)
+    }
+
+    /// Show or hide the inspector panel.
+    pub(crate) fn set_open(&mut self, open: bool) {
+        self.is_open = open;
+        if !open {
+            self.is_picking_widget = false;
+            self.hovered_widget = None;
+            self.panel_rect = Rect::ZERO;
+            self.rows.clear();
+        }
+    }
+
+    fn row_at(&self, pos: Point) -> Option<WidgetId> {
+        self.rows
+            .iter()
+            .find(|(_, rect)| rect.contains(pos))
+            .map(|(id, _)| *id)
+    }
+}
+
+// --- MARK: EVENTS
+
+/// Handle a pointer event over the inspector panel.
+///
+/// Pointer events over the panel are never sent to widgets.
+pub(crate) fn run_on_inspector_pointer_event(
+    root: &mut RenderRoot,
+    event: &PointerEvent,
+    pos: Point,
+) -> Handled {
+    let inspector_state = &mut root.global_state.inspector_state;
+    match event {
+        PointerEvent::Move(..) => {
+            inspector_state.hovered_widget = inspector_state.row_at(pos);
+        }
+        PointerEvent::Down { .. } => {
+            if let Some(id) = inspector_state.row_at(pos) {
+                inspector_state.selected_widget = Some(id);
+                root.global_state
+                    .emit_signal(RenderRootSignal::WidgetSelectedInInspector(id));
+            }
+        }
+        PointerEvent::Scroll { delta, .. } => {
+            let delta = match delta {
+                ScrollDelta::PixelDelta(pos) => -pos.y / root.global_state.scale_factor,
+                ScrollDelta::LineDelta(_, y) => -f64::from(*y) * LINE_SCROLL,
+                _ => 0.,
+            };
+            // The upper bound is enforced when painting, once the number of rows is known.
+            inspector_state.scroll_offset = (inspector_state.scroll_offset + delta).max(0.);
+        }
+        _ => {}
+    }
+    root.root_state_mut().needs_paint = true;
+    Handled::Yes
+}
+
+// --- MARK: PAINT
+
+/// Paint the inspector panel and the widget highlights, and record which parts
+/// of the window they cover.
+pub(crate) fn paint_inspector(root: &mut RenderRoot, scene: &mut Scene) {
+    let inspector_state = &root.global_state.inspector_state;
+    let is_open = inspector_state.is_open;
+    let selected_widget = inspector_state.selected_widget.filter(|_| is_open);
+    let hovered_widget = inspector_state.hovered_widget;
+
+    let mut overlay = DamageRegion::new();
+    if let Some(rect) =
+        selected_widget.and_then(|id| highlight_widget(root, scene, id, SELECTED_FILL_COLOR))
+    {
+        overlay.add_rect(rect);
+    }
+    if let Some(rect) =
+        hovered_widget.and_then(|id| highlight_widget(root, scene, id, HOVER_FILL_COLOR))
+    {
+        overlay.add_rect(rect);
+    }
+    if is_open {
+        overlay.add_rect(paint_panel(root, scene));
+    }
+
+    // The panel's content may change without its area changing, so it's always redrawn.
+    let inspector_state = &mut root.global_state.inspector_state;
+    if is_open || overlay != inspector_state.painted_overlay {
+        root.global_state
+            .damage
+            .union(&inspector_state.painted_overlay);
+        root.global_state.damage.union(&overlay);
+        inspector_state.painted_overlay = overlay;
+    }
+}
+
+/// Draw the layout rect, paint insets and baseline of a widget.
+///
+/// Returns the area covered, in window coordinates.
+fn highlight_widget(
+    root: &RenderRoot,
+    scene: &mut Scene,
+    id: WidgetId,
+    fill_color: Color,
+) -> Option<Rect> {
+    // The widget may have been removed since it was selected.
+    let state = root.widget_arena.states.find(id)?.item;
+    if state.is_stashed {
+        return None;
+    }
+    let transform = state.window_transform;
+    let layout_rect = state.size.to_rect();
+    let paint_rect = layout_rect + state.paint_insets;
+
+    scene.fill(Fill::NonZero, transform, fill_color, None, &layout_rect);
+    if paint_rect != layout_rect {
+        let style = Stroke::new(1.).with_dashes(0., [4., 4.]);
+        scene.stroke(&style, transform, INSETS_COLOR, None, &paint_rect);
+    }
+    if state.baseline_offset != 0. {
+        let baseline = state.size.height - state.baseline_offset;
+        let line = Line::new((0., baseline), (state.size.width, baseline));
+        scene.stroke(&Stroke::new(1.), transform, BASELINE_COLOR, None, &line);
+    }
+
+    // Account for the width of the strokes.
+    Some(transform.transform_rect_bbox(paint_rect).inflate(1., 1.))
+}
+
+/// Draw the tree panel and the details of the selected widget.
+///
+/// Returns the area covered, in window coordinates.
+fn paint_panel(root: &mut RenderRoot, scene: &mut Scene) -> Rect {
+    let mut rows = Vec::new();
+    collect_rows(root.get_root_widget(), 0, &mut rows);
+    let details = root
+        .global_state
+        .inspector_state
+        .selected_widget
+        .and_then(|id| root.get_widget(id))
+        .map(widget_details)
+        .unwrap_or_default();
+
+    let window_size = root.get_kurbo_size();
+    let width = PANEL_WIDTH.min(window_size.width);
+    let panel_rect = Rect::new(
+        window_size.width - width,
+        0.,
+        window_size.width,
+        window_size.height,
+    );
+    let details_height = if details.is_empty() {
+        0.
+    } else {
+        (details.len() as f64 * ROW_HEIGHT + 2. * PADDING).min(panel_rect.height() / 2.)
+    };
+    let tree_rect = Rect::new(
+        panel_rect.x0,
+        panel_rect.y0,
+        panel_rect.x1,
+        panel_rect.y1 - details_height,
+    );
+    let details_rect = Rect::new(panel_rect.x0, tree_rect.y1, panel_rect.x1, panel_rect.y1);
+
+    let global_state = &mut root.global_state;
+    let inspector_state = &mut global_state.inspector_state;
+    let font_ctx = &mut global_state.font_context;
+    let layout_ctx = &mut global_state.text_layout_context;
+
+    let content_height = rows.len() as f64 * ROW_HEIGHT + 2. * PADDING;
+    let max_scroll = (content_height - tree_rect.height()).max(0.);
+    inspector_state.scroll_offset = inspector_state.scroll_offset.clamp(0., max_scroll);
+
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        PANEL_COLOR,
+        None,
+        &panel_rect,
+    );
+
+    // --- Tree ---
+    inspector_state.rows.clear();
+    scene.push_layer(Mix::Clip, 1., Affine::IDENTITY, &tree_rect);
+    for (idx, row) in rows.iter().enumerate() {
+        let y = tree_rect.y0 + PADDING + idx as f64 * ROW_HEIGHT - inspector_state.scroll_offset;
+        let row_rect = Rect::new(panel_rect.x0, y, panel_rect.x1, y + ROW_HEIGHT);
+        if row_rect.y1 <= tree_rect.y0 || row_rect.y0 >= tree_rect.y1 {
+            continue;
+        }
+        if inspector_state.selected_widget == Some(row.id) {
+            scene.fill(
+                Fill::NonZero,
+                Affine::IDENTITY,
+                SELECTED_ROW_COLOR,
+                None,
+                &row_rect,
+            );
+        } else if inspector_state.hovered_widget == Some(row.id) {
+            scene.fill(
+                Fill::NonZero,
+                Affine::IDENTITY,
+                HOVERED_ROW_COLOR,
+                None,
+                &row_rect,
+            );
+        }
+        let x = panel_rect.x0 + PADDING + row.depth as f64 * INDENT;
+        draw_text(scene, font_ctx, layout_ctx, &row.text, Point::new(x, y));
+        inspector_state
+            .rows
+            .push((row.id, row_rect.intersect(tree_rect)));
+    }
+    scene.pop_layer();
+
+    // --- Details ---
+    if !details.is_empty() {
+        let separator = Line::new(
+            (details_rect.x0, details_rect.y0),
+            (details_rect.x1, details_rect.y0),
+        );
+        scene.stroke(
+            &Stroke::new(1.),
+            Affine::IDENTITY,
+            SEPARATOR_COLOR,
+            None,
+            &separator,
+        );
+        scene.push_layer(Mix::Clip, 1., Affine::IDENTITY, &details_rect);
+        for (idx, line) in details.iter().enumerate() {
+            let y = details_rect.y0 + PADDING + idx as f64 * ROW_HEIGHT;
+            let origin = Point::new(details_rect.x0 + PADDING, y);
+            draw_text(scene, font_ctx, layout_ctx, line, origin);
+        }
+        scene.pop_layer();
+    }
+
+    inspector_state.panel_rect = panel_rect;
+    panel_rect
+}
+
+fn draw_text(
+    scene: &mut Scene,
+    font_ctx: &mut FontContext,
+    layout_ctx: &mut LayoutContext<BrushIndex>,
+    text: &str,
+    origin: Point,
+) {
+    let mut builder = layout_ctx.ranged_builder(font_ctx, text, 1.0, true);
+    builder.push_default(StyleProperty::from(GenericFamily::Monospace));
+    builder.push_default(StyleProperty::FontSize(FONT_SIZE));
+    let mut layout = builder.build(text);
+    layout.break_all_lines(None);
+    render_text(
+        scene,
+        Affine::translate(origin.to_vec2()),
+        &layout,
+        &[TEXT_COLOR.into()],
+        true,
+    );
+}
+
+// --- MARK: TREE
+
+fn collect_rows(widget: WidgetRef<'_, dyn Widget>, depth: usize, rows: &mut Vec<TreeRow>) {
+    let mut text = format!("{} {}", widget.short_type_name(), widget.id());
+    if let Some(debug_text) = widget.get_debug_text() {
+        write!(text, " <{debug_text}>").unwrap();
+    }
+    let flags = state_flags(widget.ctx());
+    if !flags.is_empty() {
+        write!(text, " [{}]", flags.join(", ")).unwrap();
+    }
+    rows.push(TreeRow {
+        id: widget.id(),
+        depth,
+        text,
+    });
+
+    for child in widget.children() {
+        collect_rows(child, depth + 1, rows);
+    }
+}
+
+fn widget_details(widget: WidgetRef<'_, dyn Widget>) -> Vec<String> {
+    let ctx = widget.ctx();
+    let layout_rect = ctx.local_layout_rect();
+    let origin = ctx.window_origin();
+    let insets = ctx.widget_state.paint_insets;
+    let flags = state_flags(ctx);
+
+    let mut lines = vec![
+        widget.type_name().to_string(),
+        format!("id: {}", widget.id()),
+    ];
+    if let Some(debug_text) = widget.get_debug_text() {
+        lines.push(format!("debug text: {debug_text}"));
+    }
+    lines.push(format!(
+        "layout rect: ({}, {}) {}x{}",
+        layout_rect.x0,
+        layout_rect.y0,
+        layout_rect.width(),
+        layout_rect.height()
+    ));
+    lines.push(format!("window origin: ({}, {})", origin.x, origin.y));
+    lines.push(format!(
+        "paint insets: {} {} {} {}",
+        insets.x0, insets.y0, insets.x1, insets.y1
+    ));
+    lines.push(format!("baseline offset: {}", ctx.baseline_offset()));
+    if flags.is_empty() {
+        lines.push("flags: none".to_string());
+    } else {
+        lines.push(format!("flags: {}", flags.join(", ")));
+    }
+    let properties = ctx
+        .global_state
+        .default_properties
+        .debug_entries(&ctx.properties);
+    if properties.is_empty() {
+        lines.push("properties: none".to_string());
+    } else {
+        lines.push("properties:".to_string());
+        lines.extend(properties.into_iter().map(|entry| format!("  {entry}")));
+    }
+    lines
+}
@@ -3,10 +3,22 @@
 
 //! Types needed for running a Masonry app.
 
+#[cfg(not(target_arch = "wasm32"))]
+mod chrome_trace;
+mod damage;
+mod frame_stats;
+mod inspector;
 mod render_root;
 mod tracing_backend;
+mod tree_snapshot;
 
+#[cfg(not(target_arch = "wasm32"))]
+pub use chrome_trace::ChromeTraceLayer;
+pub use damage::DamageRegion;
+pub use frame_stats::{FRAME_STATS_HISTORY_LEN, FrameStats, PassStats};
 pub use render_root::{RenderRoot, RenderRootOptions, RenderRootSignal, WindowSizePolicy};
 pub use tracing_backend::{try_init_test_tracing, try_init_tracing};
+pub use tree_snapshot::{SnapshotDiff, WidgetSnapshot};
 
+pub(crate) use inspector::{InspectorState, paint_inspector, run_on_inspector_pointer_event};
 pub(crate) use render_root::{MutateCallback, RenderRootState};
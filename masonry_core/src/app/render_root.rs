@@ -1,7 +1,7 @@
 // Copyright 2019 the Xilem Authors and the Druid Authors
 // SPDX-License-Identifier: Apache-2.0
 
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
 
 use accesskit::{ActionRequest, NodeId, TreeUpdate};
@@ -14,9 +14,13 @@ use vello::Scene;
 use vello::kurbo::{Rect, Size};
 
 use crate::Handled;
+use crate::app::{
+    DamageRegion, FRAME_STATS_HISTORY_LEN, FrameStats, InspectorState, WidgetSnapshot,
+};
 use crate::core::{
-    AccessEvent, Action, BrushIndex, DefaultProperties, Ime, PointerEvent, PropertiesRef, QueryCtx,
-    ResizeDirection, TextEvent, Widget, WidgetArena, WidgetId, WidgetMut, WidgetPod, WidgetRef,
+    AccessEvent, Action, BrushIndex, DefaultProperties, FocusScope, Ime, PointerEvent,
+    PropertiesRef, Property, QueryCtx, ResizeDirection, RovingFocus, Shortcut, ShortcutId,
+    Shortcuts, TabIndex, TextEvent, Widget, WidgetArena, WidgetId, WidgetMut, WidgetPod, WidgetRef,
     WidgetState, WindowEvent,
 };
 use crate::dpi::{LogicalPosition, LogicalSize, PhysicalSize};
@@ -35,7 +39,7 @@ use crate::passes::update::{
     run_update_widget_tree_pass,
 };
 use crate::passes::{PassTracing, recurse_on_children};
-use crate::util::AnyMap;
+use crate::util::{AnyMap, Duration, Instant};
 
 /// We ensure that any valid initial IME area is sent to the platform by storing an invalid initial
 /// IME area as the `last_sent_ime_area`.
@@ -79,6 +83,14 @@ pub struct RenderRoot {
     /// The widget tree; stores widgets and their states.
     pub(crate) widget_arena: WidgetArena,
     pub(crate) debug_paint: bool,
+
+    /// Statistics for the most recent frames, oldest first.
+    pub(crate) frame_stats_history: VecDeque<FrameStats>,
+    /// The damage of the last frame returned by `redraw`.
+    pub(crate) last_damage: DamageRegion,
+
+    /// Shortcuts which apply to the whole window.
+    pub(crate) window_shortcuts: Shortcuts,
 }
 
 /// State shared between passes.
@@ -95,11 +107,23 @@ pub(crate) struct RenderRootState {
     /// Widget that will be focused once the `update_focus` pass is run.
     pub(crate) next_focused_widget: Option<WidgetId>,
 
+    /// Widget whose subtree will get focus once the `update_focus` pass is run.
+    ///
+    /// This takes precedence over `next_focused_widget`.
+    pub(crate) next_focused_subtree: Option<WidgetId>,
+
     /// Most recently clicked widget.
     ///
     /// This is used to pick the focused widget on Tab events.
     pub(crate) most_recently_clicked_widget: Option<WidgetId>,
 
+    /// For each [`FocusScope`] containing the focused widget, the widget which had focus
+    /// before focus moved into the scope.
+    pub(crate) focus_scope_origins: HashMap<WidgetId, Option<WidgetId>>,
+
+    /// For each [`RovingFocus`] group, the item in it which was last focused.
+    pub(crate) roving_focus_items: HashMap<WidgetId, WidgetId>,
+
     /// Whether the window is focused.
     pub(crate) window_focused: bool,
 
@@ -137,9 +161,29 @@ pub(crate) struct RenderRootState {
     /// Whether data set in the pointer pass has been invalidated.
     pub(crate) needs_pointer_pass: bool,
 
+    /// Areas of the window which changed since the last redraw, in window coordinates.
+    pub(crate) damage: DamageRegion,
+
+    /// Whether the whole window must be redrawn, regardless of `damage`.
+    pub(crate) damage_all: bool,
+
+    /// Number of times [`Widget::layout`] has been called.
+    pub(crate) layout_count: u64,
+
+    /// Statistics for the frame currently being built.
+    pub(crate) frame_stats: FrameStats,
+
+    /// Number of layout passes run so far.
+    ///
+    /// Used to know whether cached [`Widget::measure`] results are outdated.
+    pub(crate) layout_pass_count: u64,
+
     /// Pass tracing configuration, used to skip tracing to limit overhead.
     pub(crate) trace: PassTracing,
     pub(crate) inspector_state: InspectorState,
+    /// The same default properties as [`RenderRoot`], used to format properties for the
+    /// widget inspector and tree snapshots.
+    pub(crate) default_properties: Arc<DefaultProperties>,
 
     /// DPI scale factor.
     ///
@@ -229,16 +273,10 @@ pub enum RenderRootSignal {
     Exit,
     /// The window menu is being shown.
     ShowWindowMenu(LogicalPosition<f64>),
-    /// The widget picker has selected this widget.
+    /// A widget was selected in the inspector, either with the widget picker or in the tree panel.
     WidgetSelectedInInspector(WidgetId),
-}
-
-/// State of the widget inspector. Useful for debugging.
-///
-/// Widget inspector is WIP. It should get its own standalone documentation.
-pub(crate) struct InspectorState {
-    pub(crate) is_picking_widget: bool,
-    pub(crate) hovered_widget: Option<WidgetId>,
+    /// A [`Shortcut`] was triggered by a key event.
+    Shortcut(ShortcutId),
 }
 
 impl RenderRoot {
@@ -272,13 +310,16 @@ impl RenderRoot {
             size_policy,
             size: PhysicalSize::new(0, 0),
             last_mouse_pos: None,
-            default_properties,
+            default_properties: default_properties.clone(),
             global_state: RenderRootState {
                 signal_sink: Box::new(signal_sink),
                 focused_widget: None,
                 focused_path: Vec::new(),
                 next_focused_widget: None,
+                next_focused_subtree: None,
                 most_recently_clicked_widget: None,
+                focus_scope_origins: HashMap::new(),
+                roving_focus_items: HashMap::new(),
                 window_focused: true,
                 scroll_request_targets: Vec::new(),
                 hovered_path: Vec::new(),
@@ -297,11 +338,14 @@ impl RenderRoot {
                 last_sent_ime_area: INVALID_IME_AREA,
                 scenes: HashMap::new(),
                 needs_pointer_pass: false,
+                damage: DamageRegion::new(),
+                damage_all: true,
+                layout_count: 0,
+                frame_stats: FrameStats::default(),
+                layout_pass_count: 0,
                 trace: PassTracing::from_env(),
-                inspector_state: InspectorState {
-                    is_picking_widget: false,
-                    hovered_widget: None,
-                },
+                inspector_state: InspectorState::new(),
+                default_properties,
                 scale_factor,
             },
             widget_arena: WidgetArena {
@@ -311,6 +355,9 @@ impl RenderRoot {
             },
             rebuild_access_tree: true,
             debug_paint,
+            frame_stats_history: VecDeque::new(),
+            last_damage: DamageRegion::new(),
+            window_shortcuts: Shortcuts::default(),
         };
 
         if let Some(test_font_data) = test_font {
@@ -353,11 +400,13 @@ impl RenderRoot {
         match event {
             WindowEvent::Rescale(scale_factor) => {
                 self.global_state.scale_factor = scale_factor;
+                self.global_state.damage_all = true;
                 self.request_render_all();
                 Handled::Yes
             }
             WindowEvent::Resize(size) => {
                 self.size = size;
+                self.global_state.damage_all = true;
                 self.root_state_mut().request_layout = true;
                 self.root_state_mut().needs_layout = true;
                 self.run_rewrite_passes();
@@ -436,21 +485,150 @@ impl RenderRoot {
     ///
     /// Returns an update to the accessibility tree and a Vello scene representing
     /// the widget tree's current state.
+    ///
+    /// The scene always contains the entire window.
+    /// Widgets which didn't request a repaint are not painted again:
+    /// their cached scene fragments are reused.
+    /// See [`damage`](Self::damage) for the parts of the window which changed.
     pub fn redraw(&mut self) -> (Scene, TreeUpdate) {
+        // This name is used by `ChromeTraceLayer` to mark frame boundaries.
+        let _span = info_span!("redraw").entered();
         self.run_rewrite_passes();
 
-        // TODO - Handle invalidation regions
+        let start = Instant::now();
         let scene = run_paint_pass(self);
+        self.global_state.frame_stats.paint.record_run(start);
+
+        let start = Instant::now();
         let tree_update = run_accessibility_pass(self, self.global_state.scale_factor);
+        self.global_state
+            .frame_stats
+            .accessibility
+            .record_run(start);
+
+        self.last_damage = self.take_damage();
+
+        if self.frame_stats_history.len() == FRAME_STATS_HISTORY_LEN {
+            self.frame_stats_history.pop_front();
+        }
+        let frame_stats = std::mem::take(&mut self.global_state.frame_stats);
+        self.frame_stats_history.push_back(frame_stats);
+
         (scene, tree_update)
     }
 
+    /// The region of the window which changed in the last frame returned by
+    /// [`redraw`](Self::redraw), compared to the frame before it.
+    ///
+    /// Platform backends can skip rendering a frame whose damage is empty.
+    /// Frames with any damage still need to be rendered in full, as the scene returned
+    /// by `redraw` always contains the entire window.
+    pub fn damage(&self) -> &DamageRegion {
+        &self.last_damage
+    }
+
+    /// Statistics about the work done to produce the last frame returned by [`redraw`](Self::redraw).
+    ///
+    /// Returns `None` if no frame was drawn yet.
+    pub fn last_frame_stats(&self) -> Option<&FrameStats> {
+        self.frame_stats_history.back()
+    }
+
+    /// Statistics about the most recent frames, oldest first.
+    ///
+    /// A limited number of frames is kept, enough to draw a frame time graph.
+    pub fn frame_stats_history(&self) -> impl ExactSizeIterator<Item = &FrameStats> {
+        self.frame_stats_history.iter()
+    }
+
+    /// Record how long the platform backend took to render the last frame returned
+    /// by [`redraw`](Self::redraw).
+    ///
+    /// This is stored in [`FrameStats::render`].
+    pub fn record_render_time(&mut self, duration: Duration) {
+        if let Some(frame_stats) = self.frame_stats_history.back_mut() {
+            frame_stats.render = Some(duration);
+        }
+    }
+
+    /// Return the damage accumulated since the last redraw, clipped to the window.
+    fn take_damage(&mut self) -> DamageRegion {
+        let window_rect = self.get_kurbo_size().to_rect();
+        let mut damage = std::mem::take(&mut self.global_state.damage);
+        if std::mem::take(&mut self.global_state.damage_all) {
+            damage = DamageRegion::from_rect(window_rect);
+        }
+        damage.clip_to(window_rect);
+        damage
+    }
+
+    /// The number of times [`Widget::layout`] has been called since this `RenderRoot` was created.
+    ///
+    /// Widgets whose constraints didn't change since their last layout, and which didn't
+    /// request layout, are skipped and not counted.
+    /// This is mostly useful in tests, to check that layout isn't run more than necessary.
+    pub fn layout_count(&self) -> u64 {
+        self.global_state.layout_count
+    }
+
+    /// Show or hide the widget inspector.
+    ///
+    /// The inspector is an overlay which lists the widget tree, and shows the layout and
+    /// properties of the selected widget.
+    /// Users can also toggle it with the F12 key.
+    pub fn set_inspector_open(&mut self, open: bool) {
+        self.global_state.inspector_state.set_open(open);
+        self.global_state.needs_pointer_pass = true;
+        self.root_state_mut().needs_paint = true;
+        self.run_rewrite_passes();
+    }
+
+    /// Returns `true` if the widget inspector is shown.
+    pub fn is_inspector_open(&self) -> bool {
+        self.global_state.inspector_state.is_open
+    }
+
+    /// Return the [`WidgetId`] of the widget selected in the inspector, if any.
+    pub fn inspected_widget(&self) -> Option<WidgetId> {
+        self.global_state.inspector_state.selected_widget
+    }
+
     /// Get the current icon that the mouse should display.
     pub fn cursor_icon(&self) -> CursorIcon {
         self.global_state.cursor_icon
     }
 
     // --- MARK: ACCESS WIDGETS---
+    /// Register a shortcut which applies to the whole window.
+    ///
+    /// Window shortcuts are checked after the shortcuts of the focused widget and its ancestors,
+    /// see [`Shortcut`] for details.
+    /// When the shortcut is triggered, a [`RenderRootSignal::Shortcut`] is emitted.
+    ///
+    /// If a window shortcut with the same id was already registered, it's replaced.
+    pub fn register_shortcut(&mut self, shortcut: Shortcut) -> ShortcutId {
+        let id = shortcut.id;
+        self.unregister_shortcut(id);
+        self.window_shortcuts.0.push(shortcut);
+        id
+    }
+
+    /// Remove the window shortcut with the given id.
+    ///
+    /// Returns the shortcut if it was registered.
+    pub fn unregister_shortcut(&mut self, id: ShortcutId) -> Option<Shortcut> {
+        let idx = self.window_shortcuts.0.iter().position(|s| s.id == id)?;
+        Some(self.window_shortcuts.0.remove(idx))
+    }
+
+    /// The shortcuts registered with [`register_shortcut`](Self::register_shortcut),
+    /// in registration order.
+    ///
+    /// This can be used to show the shortcut of each command in a menu.
+    pub fn window_shortcuts(&self) -> &[Shortcut] {
+        &self.window_shortcuts.0
+    }
+
     /// Get a [`WidgetRef`] to the root widget.
     pub fn get_root_widget(&self) -> WidgetRef<'_, dyn Widget> {
         let root_state_token = self.widget_arena.states.roots();
@@ -509,6 +687,13 @@ impl RenderRoot {
         Some(WidgetRef { ctx, widget })
     }
 
+    /// Take a snapshot of the whole widget tree.
+    ///
+    /// See [`WidgetSnapshot`] for details.
+    pub fn tree_snapshot(&self) -> WidgetSnapshot {
+        WidgetSnapshot::new(self.get_root_widget())
+    }
+
     /// Checks if a widget with the given id is in the tree.
     pub fn has_widget(&self, id: WidgetId) -> bool {
         self.widget_arena.has(id)
@@ -681,6 +866,7 @@ impl RenderRoot {
             return false;
         }
         self.global_state.next_focused_widget = id;
+        self.global_state.next_focused_subtree = None;
         self.run_rewrite_passes();
         true
     }
@@ -690,32 +876,155 @@ impl RenderRoot {
             .global_state
             .focused_widget
             .or(self.global_state.most_recently_clicked_widget);
+        let focus_order = self.focus_order();
         let focused_idx = focused_widget.and_then(|focused_widget| {
-            self.focus_chain()
+            focus_order
                 .iter()
-                // Find where the focused widget is in the focus chain
+                // Find where the focused widget is in the focus order
                 .position(|id| id == &focused_widget)
         });
 
         if let Some(idx) = focused_idx {
-            // Return the id that's next to it in the focus chain
-            let len = self.focus_chain().len();
+            // Return the id that's next to it in the focus order
+            let len = focus_order.len();
             let new_idx = if forward {
                 (idx + 1) % len
             } else {
                 (idx + len - 1) % len
             };
-            Some(self.focus_chain()[new_idx])
+            Some(focus_order[new_idx])
         } else {
             // If no widget is currently focused or the
-            // currently focused widget isn't in the focus chain,
-            // then we'll just return the first/last entry of the chain, if any.
+            // currently focused widget isn't in the focus order,
+            // then we'll just return the first/last entry of the order, if any.
             if forward {
-                self.focus_chain().first().copied()
+                focus_order.first().copied()
+            } else {
+                focus_order.last().copied()
+            }
+        }
+    }
+
+    /// Returns the widgets which <kbd>Tab</kbd> navigation goes through, in order.
+    ///
+    /// This takes into account the [`FocusScope`] containing the focused widget,
+    /// the [`TabIndex`] of widgets, and [`RovingFocus`] groups, of which only one item is
+    /// included.
+    /// When a widget of a roving group has focus, that widget is the group's item.
+    pub fn focus_order(&self) -> Vec<WidgetId> {
+        let focused_widget = self
+            .global_state
+            .focused_widget
+            .or(self.global_state.most_recently_clicked_widget)
+            .filter(|id| self.widget_arena.has(*id));
+        let scope = focused_widget.and_then(|id| self.innermost_ancestor_with::<FocusScope>(id));
+        self.focus_order_within(scope, focused_widget)
+    }
+
+    /// Returns the <kbd>Tab</kbd> order of the subtree of `subtree`, or of the whole window
+    /// if `subtree` is `None`.
+    pub(crate) fn focus_order_within(
+        &self,
+        subtree: Option<WidgetId>,
+        focused_widget: Option<WidgetId>,
+    ) -> Vec<WidgetId> {
+        let chain = match subtree {
+            Some(subtree) => &self.widget_arena.get_all(subtree).1.item.focus_chain,
+            None => &self.root_state().focus_chain,
+        };
+
+        let mut visited_groups = Vec::new();
+        let mut order = Vec::new();
+        for &id in chain {
+            let group = self
+                .innermost_ancestor_with::<RovingFocus>(id)
+                .filter(|group| Some(*group) != subtree && self.is_in_subtree(*group, subtree));
+            let item = if let Some(group) = group {
+                if visited_groups.contains(&group) {
+                    continue;
+                }
+                visited_groups.push(group);
+                self.roving_focus_item(group, focused_widget)
             } else {
-                self.focus_chain().last().copied()
+                Some(id).filter(|id| self.is_tab_stop(*id))
+            };
+            if let Some(item) = item {
+                order.push((self.tab_index(item).sort_key(), item));
             }
         }
+        // This is a stable sort, so widgets with the same index stay in tree order.
+        order.sort_by_key(|(key, _)| *key);
+        order.into_iter().map(|(_, id)| id).collect()
+    }
+
+    /// Returns the items of the given [`RovingFocus`] group which arrow keys move between.
+    pub(crate) fn roving_focus_items(&self, group: WidgetId) -> Vec<WidgetId> {
+        let (_, state, _) = self.widget_arena.get_all(group);
+        state
+            .item
+            .focus_chain
+            .iter()
+            .copied()
+            .filter(|id| self.is_tab_stop(*id))
+            .collect()
+    }
+
+    /// Returns the item of a [`RovingFocus`] group which gets focus on <kbd>Tab</kbd>.
+    fn roving_focus_item(
+        &self,
+        group: WidgetId,
+        focused_widget: Option<WidgetId>,
+    ) -> Option<WidgetId> {
+        let items = self.roving_focus_items(group);
+        let remembered = self.global_state.roving_focus_items.get(&group).copied();
+        [focused_widget, remembered]
+            .into_iter()
+            .flatten()
+            .find(|id| items.contains(id))
+            .or(items.first().copied())
+    }
+
+    /// Returns the closest ancestor of the given widget, including itself, which has the
+    /// property `P`.
+    pub(crate) fn innermost_ancestor_with<P: Property>(&self, id: WidgetId) -> Option<WidgetId> {
+        let mut current = Some(id);
+        while let Some(id) = current {
+            if self.widget_arena.properties.find(id)?.item.contains::<P>() {
+                return Some(id);
+            }
+            current = self.widget_arena.parent_of(id);
+        }
+        None
+    }
+
+    fn is_in_subtree(&self, id: WidgetId, subtree: Option<WidgetId>) -> bool {
+        let Some(subtree) = subtree else {
+            return true;
+        };
+        let mut current = Some(id);
+        while let Some(id) = current {
+            if id == subtree {
+                return true;
+            }
+            current = self.widget_arena.parent_of(id);
+        }
+        false
+    }
+
+    /// Returns `true` if <kbd>Tab</kbd> navigation can focus the widget.
+    ///
+    /// Stashed widgets are still part of focus chains, so we check it here.
+    fn is_tab_stop(&self, id: WidgetId) -> bool {
+        self.is_still_interactive(id) && !self.tab_index(id).is_skipped()
+    }
+
+    fn tab_index(&self, id: WidgetId) -> TabIndex {
+        let (_, _, properties) = self.widget_arena.get_all(id);
+        properties
+            .item
+            .get::<TabIndex>()
+            .copied()
+            .unwrap_or_default()
     }
 
     // TODO - Store in RenderRootState
@@ -9,11 +9,19 @@
 //! This will include all [`DEBUG`](tracing::Level::DEBUG) messages in debug mode,
 //! and all [`INFO`](tracing::Level::INFO) level messages in release mode.
 //!
+//! If the `MASONRY_CHROME_TRACE` environment variable is set, spans are also recorded
+//! with a [`ChromeTraceLayer`](crate::app::ChromeTraceLayer) to a temporary file, which
+//! can be opened in [Perfetto](https://ui.perfetto.dev).
+//! The variable holds a filter using the same syntax as `RUST_LOG`, e.g. `MASONRY_CHROME_TRACE=info`
+//! records the passes, and `MASONRY_CHROME_TRACE=trace` also records the widget spans
+//! enabled by `MASONRY_TRACE_PASSES`.
+//!
 //! If a `tracing` backend is already configured, this will not overwrite that.
 
 // TODO - Move this code out of masonry.
 
 use std::fs::File;
+use std::io::BufWriter;
 use std::time::UNIX_EPOCH;
 
 use time::macros::format_description;
@@ -81,12 +89,15 @@ fn try_init_layered_tracing(default_level: LevelFilter) -> Result<(), SetGlobalD
         None
     };
 
+    let chrome_trace_layer = chrome_trace_layer();
+
     #[cfg(target_os = "android")]
     let android_trace_layer = tracing_android_trace::AndroidTraceLayer::new();
 
     let registry = tracing_subscriber::registry()
         .with(console_layer)
-        .with(log_file_layer);
+        .with(log_file_layer)
+        .with(chrome_trace_layer);
 
     #[cfg(target_os = "android")]
     let registry = registry.with(android_trace_layer);
@@ -106,6 +117,46 @@ fn try_init_layered_tracing(default_level: LevelFilter) -> Result<(), SetGlobalD
     Ok(())
 }
 
+#[cfg(not(target_arch = "wasm32"))]
+/// Create a layer recording a Chrome trace, if requested with the `MASONRY_CHROME_TRACE`
+/// environment variable.
+fn chrome_trace_layer<S>() -> Option<impl tracing_subscriber::Layer<S>>
+where
+    S: tracing::Subscriber + for<'a> tracing_subscriber::registry::LookupSpan<'a>,
+{
+    let filter = std::env::var("MASONRY_CHROME_TRACE").ok()?;
+    if filter.is_empty() {
+        return None;
+    }
+    let filter = match EnvFilter::try_new(&filter) {
+        Ok(filter) => filter,
+        Err(err) => {
+            #[allow(clippy::print_stderr)]
+            {
+                eprintln!("Failed to parse MASONRY_CHROME_TRACE environment variable: {err}");
+            }
+            return None;
+        }
+    };
+
+    let id = std::time::SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_millis();
+    let tmp_path = std::env::temp_dir().join(format!("masonry-{id:016}-trace.json"));
+    let file = File::create(&tmp_path).unwrap();
+
+    #[allow(clippy::print_stderr)]
+    {
+        // See the log file message above for why this is printed to stderr.
+        eprintln!("---");
+        eprintln!("Writing Chrome trace to {}", tmp_path.display());
+        eprintln!("---");
+    }
+
+    Some(crate::app::ChromeTraceLayer::new(BufWriter::new(file)).with_filter(filter))
+}
+
 #[cfg(target_arch = "wasm32")]
 /// Initialise tracing for the web with the given `max_level`.
 fn try_init_wasm_tracing(max_level: LevelFilter) -> Result<(), SetGlobalDefaultError> {
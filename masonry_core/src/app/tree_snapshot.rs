@@ -0,0 +1,248 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt::{self, Display, Formatter, Write};
+
+use vello::kurbo::Rect;
+
+use crate::core::{QueryCtx, Widget, WidgetId, WidgetRef};
+
+/// A snapshot of a widget and its descendants.
+///
+/// This is a plain copy of the state of the widget tree at a given time, which can be
+/// printed, compared with [`diff`](Self::diff), or checked against a stored snapshot
+/// in tests.
+///
+/// Snapshots are created with [`RenderRoot::tree_snapshot`](crate::app::RenderRoot::tree_snapshot)
+/// or [`WidgetSnapshot::new`].
+///
+/// The [`Display`] impl prints one widget per line, indented by depth, followed by its properties:
+///
+/// ```text
+/// Flex #3 (0, 0) 400x400
+///   Button #4 <Click me> (0, 0) 89x34 [hovered]
+///   | Padding { top: 6.0, bottom: 6.0, left: 16.0, right: 16.0 } (default)
+///     Label #5 <Click me> (16, 6) 57x22
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct WidgetSnapshot {
+    /// The short type name of the widget.
+    pub type_name: &'static str,
+    /// The id of the widget.
+    pub id: WidgetId,
+    /// The layout rect of the widget, in its parent's coordinate space.
+    pub layout_rect: Rect,
+    /// The state flags which are set on the widget, e.g. `"hovered"` or `"disabled"`.
+    pub flags: Vec<&'static str>,
+    /// The value returned by [`Widget::get_debug_text`].
+    pub debug_text: Option<String>,
+    /// A debug representation of each of the widget's properties, including defaults.
+    pub properties: Vec<String>,
+    /// Snapshots of the widget's children.
+    pub children: Vec<Self>,
+}
+
+/// The differences between two [`WidgetSnapshot`]s, as returned by [`WidgetSnapshot::diff`].
+///
+/// Each change is a line of text:
+/// - `+ <widget>` for widgets which were added,
+/// - `- <widget>` for widgets which were removed,
+/// - `~ <widget>: <field>: <old> -> <new>` for widgets which were changed.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SnapshotDiff {
+    changes: Vec<String>,
+}
+
+impl WidgetSnapshot {
+    /// Take a snapshot of the given widget and its descendants.
+    pub fn new(widget: WidgetRef<'_, dyn Widget>) -> Self {
+        let ctx = widget.ctx();
+        Self {
+            type_name: widget.short_type_name(),
+            id: widget.id(),
+            layout_rect: ctx.local_layout_rect(),
+            flags: state_flags(ctx),
+            debug_text: widget.get_debug_text(),
+            properties: ctx
+                .global_state
+                .default_properties
+                .debug_entries(&ctx.properties),
+            children: widget.children().into_iter().map(Self::new).collect(),
+        }
+    }
+
+    /// Find the snapshot of the widget with the given id in this tree.
+    pub fn find(&self, id: WidgetId) -> Option<&Self> {
+        if self.id == id {
+            return Some(self);
+        }
+        self.children.iter().find_map(|child| child.find(id))
+    }
+
+    /// Format the snapshot like its [`Display`] impl, but without widget ids.
+    ///
+    /// Widget ids change from one run to the next, so this is the format to use when
+    /// comparing with a stored snapshot, e.g. with `insta::assert_snapshot`.
+    pub fn to_stable_string(&self) -> String {
+        let mut text = String::new();
+        self.write_tree(&mut text, 0, false).unwrap();
+        text
+    }
+
+    /// Return the changes needed to go from `self` to `new`.
+    ///
+    /// Children are matched by id, so a widget which was replaced by a new widget of
+    /// the same type shows up as a removal and an addition.
+    pub fn diff(&self, new: &Self) -> SnapshotDiff {
+        let mut diff = SnapshotDiff::default();
+        if self.id == new.id {
+            diff_widget(self, new, &mut diff.changes);
+        } else {
+            diff.changes.push(format!("- {}", self.header(true)));
+            diff.changes.push(format!("+ {}", new.header(true)));
+        }
+        diff
+    }
+
+    /// The first line of this widget's text representation.
+    fn header(&self, include_id: bool) -> String {
+        let mut header = self.type_name.to_string();
+        if include_id {
+            write!(header, " {}", self.id).unwrap();
+        }
+        if let Some(debug_text) = &self.debug_text {
+            write!(header, " <{debug_text}>").unwrap();
+        }
+        write!(header, " {}", format_rect(self.layout_rect)).unwrap();
+        if !self.flags.is_empty() {
+            write!(header, " [{}]", self.flags.join(", ")).unwrap();
+        }
+        header
+    }
+
+    /// The type name and id of this widget.
+    fn name(&self) -> String {
+        format!("{} {}", self.type_name, self.id)
+    }
+
+    fn write_tree(&self, f: &mut impl Write, depth: usize, include_ids: bool) -> fmt::Result {
+        let indent = "  ".repeat(depth);
+        writeln!(f, "{indent}{}", self.header(include_ids))?;
+        for property in &self.properties {
+            writeln!(f, "{indent}| {property}")?;
+        }
+        for child in &self.children {
+            child.write_tree(f, depth + 1, include_ids)?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for WidgetSnapshot {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.write_tree(f, 0, true)
+    }
+}
+
+impl SnapshotDiff {
+    /// Returns `true` if the two snapshots are identical.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// The list of changes, one per line.
+    pub fn changes(&self) -> &[String] {
+        &self.changes
+    }
+}
+
+impl Display for SnapshotDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for change in &self.changes {
+            writeln!(f, "{change}")?;
+        }
+        Ok(())
+    }
+}
+
+fn diff_widget(old: &WidgetSnapshot, new: &WidgetSnapshot, changes: &mut Vec<String>) {
+    let name = new.name();
+    let mut changed = |field: &str, before: String, after: String| {
+        if before != after {
+            changes.push(format!("~ {name}: {field}: {before} -> {after}"));
+        }
+    };
+    changed("type", old.type_name.into(), new.type_name.into());
+    changed(
+        "debug text",
+        format_debug_text(old.debug_text.as_deref()),
+        format_debug_text(new.debug_text.as_deref()),
+    );
+    changed(
+        "layout rect",
+        format_rect(old.layout_rect),
+        format_rect(new.layout_rect),
+    );
+    changed("flags", format_flags(&old.flags), format_flags(&new.flags));
+
+    for property in &old.properties {
+        if !new.properties.contains(property) {
+            changes.push(format!("~ {name}: property removed: {property}"));
+        }
+    }
+    for property in &new.properties {
+        if !old.properties.contains(property) {
+            changes.push(format!("~ {name}: property added: {property}"));
+        }
+    }
+
+    for old_child in &old.children {
+        match new.children.iter().find(|child| child.id == old_child.id) {
+            Some(new_child) => diff_widget(old_child, new_child, changes),
+            None => changes.push(format!("- {} in {name}", old_child.header(true))),
+        }
+    }
+    for new_child in &new.children {
+        if !old.children.iter().any(|child| child.id == new_child.id) {
+            changes.push(format!("+ {} in {name}", new_child.header(true)));
+        }
+    }
+}
+
+/// The names of the state flags which are set on a widget.
+pub(crate) fn state_flags(ctx: &QueryCtx<'_>) -> Vec<&'static str> {
+    [
+        (ctx.is_hovered(), "hovered"),
+        (ctx.is_focus_target(), "focused"),
+        (ctx.is_stashed(), "stashed"),
+        (ctx.is_disabled(), "disabled"),
+    ]
+    .into_iter()
+    .filter_map(|(is_set, name)| is_set.then_some(name))
+    .collect()
+}
+
+fn format_rect(rect: Rect) -> String {
+    format!(
+        "({}, {}) {}x{}",
+        rect.x0,
+        rect.y0,
+        rect.width(),
+        rect.height()
+    )
+}
+
+fn format_flags(flags: &[&str]) -> String {
+    if flags.is_empty() {
+        "none".into()
+    } else {
+        flags.join(", ")
+    }
+}
+
+fn format_debug_text(debug_text: Option<&str>) -> String {
+    match debug_text {
+        Some(text) => format!("<{text}>"),
+        None => "none".into(),
+    }
+}
@@ -27,6 +27,8 @@ pub enum Action {
     TextEntered(String),
     /// A checkbox was toggled.
     CheckboxToggled(bool),
+    /// The user asked to close a dialog, e.g. by pressing <kbd>Escape</kbd>.
+    DialogDismissed,
     // FIXME - This is a huge hack
     /// Other.
     Other(Box<dyn AnyDebug + Send>),
@@ -39,6 +41,7 @@ impl PartialEq for Action {
             (Self::TextChanged(l0), Self::TextChanged(r0)) => l0 == r0,
             (Self::TextEntered(l0), Self::TextEntered(r0)) => l0 == r0,
             (Self::CheckboxToggled(l0), Self::CheckboxToggled(r0)) => l0 == r0,
+            (Self::DialogDismissed, Self::DialogDismissed) => true,
             // FIXME
             // (Self::Other(val_l), Self::Other(val_r)) => false,
             _ => false,
@@ -0,0 +1,91 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::TypeId;
+
+use vello::peniko::{BlendMode, Compose, Mix};
+
+use crate::core::Property;
+
+/// The opacity of a widget and all its children.
+///
+/// The widget's paint output and its children's are grouped into a single layer,
+/// which is then composited with the given alpha.
+/// This matches the CSS `opacity` property.
+///
+/// Values are clamped to the `[0.0, 1.0]` range.
+/// Fully transparent widgets and their children aren't painted, and are skipped when
+/// looking for the widget under the pointer.
+///
+/// Unlike most properties, this is handled by the paint pass directly, so widgets don't need
+/// to support it explicitly.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Opacity(pub f32);
+
+/// The blend mode used to composite a widget and all its children onto what's behind them.
+///
+/// This matches the CSS `mix-blend-mode` property.
+///
+/// Unlike most properties, this is handled by the paint pass directly, so widgets don't need
+/// to support it explicitly.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MixBlendMode(pub BlendMode);
+
+impl Property for Opacity {
+    fn static_default() -> &'static Self {
+        static DEFAULT: Opacity = Opacity(1.0);
+        &DEFAULT
+    }
+}
+
+impl Default for Opacity {
+    fn default() -> Self {
+        *Self::static_default()
+    }
+}
+
+impl Opacity {
+    /// Fully opaque.
+    pub const OPAQUE: Self = Self(1.0);
+
+    /// Fully transparent.
+    pub const TRANSPARENT: Self = Self(0.0);
+
+    /// Returns the opacity, clamped to the `[0.0, 1.0]` range.
+    pub fn value(self) -> f32 {
+        self.0.clamp(0.0, 1.0)
+    }
+
+    /// Returns `true` if the widget is fully transparent.
+    pub fn is_transparent(self) -> bool {
+        self.value() == 0.0
+    }
+}
+
+impl Property for MixBlendMode {
+    fn static_default() -> &'static Self {
+        static DEFAULT: MixBlendMode = MixBlendMode(BlendMode {
+            mix: Mix::Normal,
+            compose: Compose::SrcOver,
+        });
+        &DEFAULT
+    }
+}
+
+impl Default for MixBlendMode {
+    fn default() -> Self {
+        *Self::static_default()
+    }
+}
+
+impl MixBlendMode {
+    /// Returns `true` if the blend mode is the default source-over blending.
+    pub fn is_normal(self) -> bool {
+        self == *Self::static_default()
+    }
+}
+
+/// Returns `true` if the given property type is handled by the compose and paint passes.
+pub(crate) fn is_compositing_property(property_type: TypeId) -> bool {
+    property_type == TypeId::of::<Opacity>() || property_type == TypeId::of::<MixBlendMode>()
+}
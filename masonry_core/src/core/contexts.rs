@@ -14,12 +14,12 @@ use vello::kurbo::{Affine, Insets, Point, Rect, Size, Vec2};
 
 use crate::app::{MutateCallback, RenderRootSignal, RenderRootState};
 use crate::core::{
-    Action, AllowRawMut, BoxConstraints, BrushIndex, CreateWidget, DefaultProperties,
-    FromDynWidget, PropertiesMut, PropertiesRef, ResizeDirection, Widget, WidgetId, WidgetMut,
-    WidgetPod, WidgetRef, WidgetState,
+    Action, AllowRawMut, Axis, BoxConstraints, BrushIndex, CreateWidget, DefaultProperties,
+    FromDynWidget, IntrinsicSize, PropertiesMut, PropertiesRef, ResizeDirection, Widget, WidgetId,
+    WidgetMut, WidgetPod, WidgetRef, WidgetState,
 };
 use crate::debug_panic;
-use crate::passes::layout::run_layout_on;
+use crate::passes::layout::{run_layout_on, run_measure_on};
 use crate::peniko::Color;
 use crate::util::{AnyMap, get_debug_color};
 
@@ -111,6 +111,16 @@ pub struct LayoutCtx<'a> {
     pub(crate) default_properties: &'a DefaultProperties,
 }
 
+/// A context provided to [`Widget::measure`] methods.
+pub struct MeasureCtx<'a> {
+    pub(crate) global_state: &'a mut RenderRootState,
+    pub(crate) widget_state: &'a mut WidgetState,
+    pub(crate) widget_state_children: ArenaMutList<'a, WidgetState>,
+    pub(crate) widget_children: ArenaMutList<'a, Box<dyn Widget>>,
+    pub(crate) properties_children: ArenaMutList<'a, AnyMap>,
+    pub(crate) default_properties: &'a DefaultProperties,
+}
+
 /// A context provided to the [`Widget::compose`] method.
 pub struct ComposeCtx<'a> {
     pub(crate) global_state: &'a mut RenderRootState,
@@ -147,6 +157,7 @@ impl_context_method!(
     EventCtx<'_>,
     UpdateCtx<'_>,
     LayoutCtx<'_>,
+    MeasureCtx<'_>,
     ComposeCtx<'_>,
     PaintCtx<'_>,
     AccessCtx<'_>,
@@ -335,6 +346,7 @@ impl_context_method!(
     EventCtx<'_>,
     UpdateCtx<'_>,
     LayoutCtx<'_>,
+    MeasureCtx<'_>,
     ComposeCtx<'_>,
     PaintCtx<'_>,
     AccessCtx<'_>,
@@ -465,6 +477,7 @@ impl EventCtx<'_> {
         // to deliver on the "last focus request wins" promise.
         let id = self.widget_id();
         self.global_state.next_focused_widget = Some(id);
+        self.global_state.next_focused_subtree = None;
     }
 
     /// Transfer [text focus] to the widget with the given `WidgetId`.
@@ -473,6 +486,7 @@ impl EventCtx<'_> {
     pub fn set_focus(&mut self, target: WidgetId) {
         trace!("set_focus target={:?}", target);
         self.global_state.next_focused_widget = Some(target);
+        self.global_state.next_focused_subtree = None;
     }
 
     /// Give up [text focus].
@@ -484,6 +498,7 @@ impl EventCtx<'_> {
         trace!("resign_focus");
         if self.has_focus_target() {
             self.global_state.next_focused_widget = None;
+            self.global_state.next_focused_subtree = None;
         } else {
             warn!(
                 "resign_focus can only be called by the currently focused widget {} \
@@ -500,6 +515,23 @@ impl EventCtx<'_> {
     }
 }
 
+impl_context_method!(MutateCtx<'_>, EventCtx<'_>, UpdateCtx<'_>, {
+    /// Transfer [text focus] to the first widget in the <kbd>Tab</kbd> order of the
+    /// subtree of the widget with the given `WidgetId`.
+    ///
+    /// If no widget in that subtree accepts focus, the widget itself gets focus.
+    ///
+    /// The first widget is picked once the focus chain is up-to-date, so this can be
+    /// called right after adding or un-stashing the subtree.
+    /// This is how dialogs move focus into themselves when they open.
+    ///
+    /// [text focus]: crate::doc::doc_06_masonry_concepts#text-focus
+    pub fn set_focus_within(&mut self, target: WidgetId) {
+        trace!("set_focus_within target={:?}", target);
+        self.global_state.next_focused_subtree = Some(target);
+    }
+});
+
 // --- MARK: ACCESSIBILITY
 impl AccessCtx<'_> {
     // TODO - We need access to the TreeUpdate to create sub-nodes for text runs,
@@ -553,6 +585,30 @@ impl LayoutCtx<'_> {
         run_layout_on(self, child, bc)
     }
 
+    /// Ask a child widget how much space it needs along the given axis.
+    ///
+    /// See [`Widget::measure`] for details. Returns `None` if the child doesn't
+    /// support measurement. Results are cached until the child requests layout.
+    pub fn measure_child(
+        &mut self,
+        child: &mut WidgetPod<impl Widget + ?Sized>,
+        axis: Axis,
+        cross_length: Option<f64>,
+    ) -> Option<IntrinsicSize> {
+        run_measure_on(&mut self.measure_ctx(), child, axis, cross_length)
+    }
+
+    fn measure_ctx(&mut self) -> MeasureCtx<'_> {
+        MeasureCtx {
+            global_state: self.global_state,
+            widget_state: self.widget_state,
+            widget_state_children: self.widget_state_children.reborrow_mut(),
+            widget_children: self.widget_children.reborrow_mut(),
+            properties_children: self.properties_children.reborrow_mut(),
+            default_properties: self.default_properties,
+        }
+    }
+
     /// Set the position of a child widget, in the parent's coordinate space.
     /// This will affect the parent's display rect.
     ///
@@ -705,7 +761,9 @@ impl LayoutCtx<'_> {
     /// This may be removed in the future. Currently it's useful for
     /// stashed children and children whose layout is cached.
     pub fn skip_layout(&mut self, child: &mut WidgetPod<impl Widget + ?Sized>) {
-        self.get_child_state_mut(child).request_layout = false;
+        let child_state = self.get_child_state_mut(child);
+        child_state.request_layout = false;
+        child_state.is_expecting_run_layout_call = false;
     }
 
     /// Gives the widget a clip path.
@@ -739,6 +797,20 @@ impl LayoutCtx<'_> {
         self.widget_state.needs_paint = true;
     }
 
+    /// Request a [`paint`](crate::core::Widget::paint) and an [`accessibility`](crate::core::Widget::accessibility) pass.
+    ///
+    /// Widgets are already repainted when their layout was requested, when their constraints
+    /// change, or when their size changes.
+    /// This is useful when a parent changes a child through [`get_raw_mut`](Self::get_raw_mut)
+    /// in a way which affects its appearance but not its layout.
+    pub fn request_render(&mut self) {
+        trace!("request_render");
+        self.widget_state.request_paint = true;
+        self.widget_state.needs_paint = true;
+        self.widget_state.needs_accessibility = true;
+        self.widget_state.request_accessibility = true;
+    }
+
     #[doc(hidden)]
     /// Return the widget's size at the beginning of the layout pass.
     ///
@@ -748,6 +820,20 @@ impl LayoutCtx<'_> {
     }
 }
 
+impl MeasureCtx<'_> {
+    /// Ask a child widget how much space it needs along the given axis.
+    ///
+    /// See [`LayoutCtx::measure_child`].
+    pub fn measure_child(
+        &mut self,
+        child: &mut WidgetPod<impl Widget + ?Sized>,
+        axis: Axis,
+        cross_length: Option<f64>,
+    ) -> Option<IntrinsicSize> {
+        run_measure_on(self, child, axis, cross_length)
+    }
+}
+
 impl ComposeCtx<'_> {
     // TODO - Remove?
     /// Returns whether [`Widget::compose`] will be called on this widget.
@@ -1083,10 +1169,13 @@ impl_context_method!(MutateCtx<'_>, EventCtx<'_>, UpdateCtx<'_>, {
     pub fn remove_child(&mut self, child: WidgetPod<impl Widget + ?Sized>) {
         // TODO - Send recursive event to child
         let id = child.id();
-        let _ = self
+        let child_state = self
             .widget_state_children
             .remove(id)
             .expect("remove_child: child not found");
+        self.global_state
+            .damage
+            .add_rect(child_state.painted_bounding_rect);
         let _ = self
             .widget_children
             .remove(id)
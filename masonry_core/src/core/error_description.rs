@@ -0,0 +1,38 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::TypeId;
+
+use crate::core::{ArcStr, Property};
+
+/// A description of why the value of a widget is invalid, e.g. a form field which failed validation.
+///
+/// When set, the widget is reported as invalid to accessibility technologies,
+/// with this text as its description.
+/// The default, `None`, means the value is valid.
+///
+/// Unlike most properties, this is handled by the accessibility pass directly, so widgets don't need
+/// to support it explicitly.
+/// It doesn't change how the widget is painted: apps are expected to also show the error,
+/// e.g. in a label next to the widget.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ErrorDescription(pub Option<ArcStr>);
+
+impl Property for ErrorDescription {
+    fn static_default() -> &'static Self {
+        static DEFAULT: ErrorDescription = ErrorDescription(None);
+        &DEFAULT
+    }
+}
+
+impl ErrorDescription {
+    /// Create an error description with the given text.
+    pub fn new(description: impl Into<ArcStr>) -> Self {
+        Self(Some(description.into()))
+    }
+}
+
+/// Returns `true` if the given property type is handled by the accessibility pass.
+pub(crate) fn is_accessibility_property(property_type: TypeId) -> bool {
+    property_type == TypeId::of::<ErrorDescription>()
+}
@@ -0,0 +1,196 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::core::keyboard::{Key, NamedKey};
+use crate::core::{Axis, Property};
+
+/// Marks a widget as a focus scope, such as a dialog.
+///
+/// While the focused widget is inside a focus scope, <kbd>Tab</kbd> navigation
+/// only goes through the focusable widgets of that scope, wrapping around at its ends.
+/// If scopes are nested, the innermost one containing the focused widget is used.
+///
+/// Focus can't leave the scope by being cleared, e.g. by clicking a part of the scope which
+/// doesn't accept focus: the scope widget itself gets focus instead.
+/// If the focused widget goes away, focus moves to the first widget of the scope.
+///
+/// Masonry remembers which widget had focus before focus moved into the scope.
+/// When the scope is closed, i.e. removed, stashed, disabled, or when this property is removed,
+/// focus goes back to that widget.
+///
+/// Moving focus into the scope when it's shown, e.g. with
+/// [`EventCtx::set_focus_within`](crate::core::EventCtx::set_focus_within), is up to the app.
+///
+/// Unlike most properties, this is handled by the focus logic directly, so widgets don't need
+/// to support it explicitly.
+/// Only the presence of the property matters.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FocusScope;
+
+/// The position of a widget in the <kbd>Tab</kbd> navigation order.
+///
+/// This matches the HTML `tabindex` attribute:
+/// - Widgets with a positive index come first, in increasing order of index.
+/// - They are followed by widgets with an index of zero, the default, in tree order.
+/// - Widgets with a negative index can still be focused, e.g. by clicking them,
+///   but are skipped by <kbd>Tab</kbd> navigation.
+///
+/// Widgets with the same index are visited in tree order.
+///
+/// This only has an effect on widgets which [accept focus](crate::core::Widget::accepts_focus).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TabIndex(pub i32);
+
+/// Makes the focusable descendants of a widget a single <kbd>Tab</kbd> stop,
+/// between which focus moves with the arrow keys.
+///
+/// This is the "roving focus" pattern used by composite widgets such as toolbars,
+/// radio groups and tab bars.
+/// When <kbd>Tab</kbd> navigation enters the group, the item which was last focused
+/// in it gets focus, or its first item if there is none.
+/// The arrow keys then move focus to the previous or next item, wrapping around,
+/// and <kbd>Home</kbd> and <kbd>End</kbd> move focus to the first and last items.
+///
+/// The arrow keys are only handled if the focused widget doesn't handle them.
+///
+/// Unlike most properties, this is handled by the focus logic directly, so widgets don't need
+/// to support it explicitly.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum RovingFocus {
+    /// Items are laid out horizontally, and use the <kbd>Left</kbd> and <kbd>Right</kbd> keys.
+    Horizontal,
+    /// Items are laid out vertically, and use the <kbd>Up</kbd> and <kbd>Down</kbd> keys.
+    Vertical,
+    /// All four arrow keys are used.
+    #[default]
+    Both,
+}
+
+/// A move within a [`RovingFocus`] group.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum RovingMove {
+    Previous,
+    Next,
+    First,
+    Last,
+}
+
+impl Property for FocusScope {
+    fn static_default() -> &'static Self {
+        static DEFAULT: FocusScope = FocusScope;
+        &DEFAULT
+    }
+}
+
+impl Property for TabIndex {
+    fn static_default() -> &'static Self {
+        static DEFAULT: TabIndex = TabIndex(0);
+        &DEFAULT
+    }
+}
+
+impl Property for RovingFocus {
+    fn static_default() -> &'static Self {
+        static DEFAULT: RovingFocus = RovingFocus::Both;
+        &DEFAULT
+    }
+}
+
+impl TabIndex {
+    /// Returns `true` if the widget is skipped by <kbd>Tab</kbd> navigation.
+    pub fn is_skipped(self) -> bool {
+        self.0 < 0
+    }
+
+    /// The key used to sort widgets in the <kbd>Tab</kbd> order.
+    pub(crate) fn sort_key(self) -> (bool, i32) {
+        (self.0 <= 0, self.0)
+    }
+}
+
+impl RovingFocus {
+    /// Creates a group which uses the arrow keys along the given axis.
+    pub fn along(axis: Axis) -> Self {
+        match axis {
+            Axis::Horizontal => Self::Horizontal,
+            Axis::Vertical => Self::Vertical,
+        }
+    }
+
+    /// Returns the move that `key` makes in this group, if any.
+    pub(crate) fn move_for_key(self, key: &Key) -> Option<RovingMove> {
+        let Key::Named(key) = key else {
+            return None;
+        };
+        let horizontal = self != Self::Vertical;
+        let vertical = self != Self::Horizontal;
+        match key {
+            NamedKey::ArrowLeft if horizontal => Some(RovingMove::Previous),
+            NamedKey::ArrowRight if horizontal => Some(RovingMove::Next),
+            NamedKey::ArrowUp if vertical => Some(RovingMove::Previous),
+            NamedKey::ArrowDown if vertical => Some(RovingMove::Next),
+            NamedKey::Home => Some(RovingMove::First),
+            NamedKey::End => Some(RovingMove::Last),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tab_index_order() {
+        let mut indices = vec![
+            TabIndex(0),
+            TabIndex(3),
+            TabIndex(1),
+            TabIndex(0),
+            TabIndex(1),
+        ];
+        indices.sort_by_key(|index| index.sort_key());
+        assert_eq!(
+            indices,
+            [
+                TabIndex(1),
+                TabIndex(1),
+                TabIndex(3),
+                TabIndex(0),
+                TabIndex(0)
+            ]
+        );
+        assert!(TabIndex(-1).is_skipped());
+        assert!(!TabIndex::default().is_skipped());
+    }
+
+    #[test]
+    fn roving_keys() {
+        let left = Key::Named(NamedKey::ArrowLeft);
+        let down = Key::Named(NamedKey::ArrowDown);
+        let home = Key::Named(NamedKey::Home);
+
+        assert_eq!(
+            RovingFocus::Horizontal.move_for_key(&left),
+            Some(RovingMove::Previous)
+        );
+        assert_eq!(RovingFocus::Horizontal.move_for_key(&down), None);
+        assert_eq!(RovingFocus::Vertical.move_for_key(&left), None);
+        assert_eq!(
+            RovingFocus::Vertical.move_for_key(&down),
+            Some(RovingMove::Next)
+        );
+        assert_eq!(
+            RovingFocus::Both.move_for_key(&down),
+            Some(RovingMove::Next)
+        );
+        assert_eq!(
+            RovingFocus::Vertical.move_for_key(&home),
+            Some(RovingMove::First)
+        );
+        assert_eq!(
+            RovingFocus::Both.move_for_key(&Key::Character("a".into())),
+            None
+        );
+    }
+}
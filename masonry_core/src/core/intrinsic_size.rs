@@ -0,0 +1,69 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+/// The size a widget would like to have along one axis.
+///
+/// This is returned by [`Widget::measure`](crate::core::Widget::measure), and lets
+/// containers find out how much space a child needs before running its layout.
+///
+/// For text, `min` is the "min-content" size (the width of the longest word) and
+/// `preferred` is the "max-content" size (the width of the text without any line breaks).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct IntrinsicSize {
+    /// The smallest length the widget can be given without overflowing.
+    pub min: f64,
+    /// The length the widget would pick if it had unlimited space.
+    pub preferred: f64,
+}
+
+impl IntrinsicSize {
+    /// An empty widget.
+    pub const ZERO: Self = Self {
+        min: 0.,
+        preferred: 0.,
+    };
+
+    /// Create a new intrinsic size.
+    ///
+    /// `preferred` is raised to `min` if it's smaller.
+    pub fn new(min: f64, preferred: f64) -> Self {
+        Self {
+            min,
+            preferred: preferred.max(min),
+        }
+    }
+
+    /// An intrinsic size for a widget which can only have the given length.
+    pub fn fixed(length: f64) -> Self {
+        Self {
+            min: length,
+            preferred: length,
+        }
+    }
+
+    /// Add the given length to both the min and preferred lengths.
+    ///
+    /// Useful to account for borders and padding.
+    pub fn expand(self, length: f64) -> Self {
+        Self {
+            min: self.min + length,
+            preferred: self.preferred + length,
+        }
+    }
+
+    /// Returns the length of the two sizes placed side by side.
+    pub fn sum(self, other: Self) -> Self {
+        Self {
+            min: self.min + other.min,
+            preferred: self.preferred + other.preferred,
+        }
+    }
+
+    /// Returns the length of the two sizes placed on top of each other.
+    pub fn max(self, other: Self) -> Self {
+        Self {
+            min: self.min.max(other.min),
+            preferred: self.preferred.max(other.preferred),
+        }
+    }
+}
@@ -4,11 +4,17 @@
 //! Basic types and traits Masonry is built on.
 
 mod action;
+mod axis;
 mod box_constraints;
+mod compositing;
 mod contexts;
+mod error_description;
 mod events;
+mod focus;
+mod intrinsic_size;
 mod object_fit;
 mod properties;
+mod shortcut;
 mod text;
 mod widget;
 mod widget_arena;
@@ -18,14 +24,22 @@ mod widget_ref;
 mod widget_state;
 
 pub use action::Action;
+pub use axis::Axis;
 pub use box_constraints::BoxConstraints;
+pub use compositing::{MixBlendMode, Opacity};
 pub use contexts::{
-    AccessCtx, ComposeCtx, EventCtx, IsContext, LayoutCtx, MutateCtx, PaintCtx, QueryCtx,
-    RawWrapper, RawWrapperMut, RegisterCtx, UpdateCtx,
+    AccessCtx, ComposeCtx, EventCtx, IsContext, LayoutCtx, MeasureCtx, MutateCtx, PaintCtx,
+    QueryCtx, RawWrapper, RawWrapperMut, RegisterCtx, UpdateCtx,
 };
+pub use error_description::ErrorDescription;
 pub use events::{AccessEvent, Ime, ResizeDirection, TextEvent, Update, WindowEvent, WindowTheme};
+pub use focus::{FocusScope, RovingFocus, TabIndex};
+pub use intrinsic_size::IntrinsicSize;
 pub use object_fit::ObjectFit;
 pub use properties::{DefaultProperties, Properties, PropertiesMut, PropertiesRef, Property};
+pub use shortcut::{
+    KeyChord, ParseKeyChordError, Shortcut, ShortcutId, ShortcutPrecedence, Shortcuts,
+};
 pub use text::{ArcStr, BrushIndex, StyleProperty, StyleSet, render_text};
 pub use widget::find_widget_under_pointer;
 pub use widget::{AllowRawMut, FromDynWidget, Widget, WidgetId};
@@ -40,6 +54,9 @@ pub use ui_events::pointer::{
 };
 pub use ui_events::{ScrollDelta, keyboard, pointer};
 
+pub(crate) use compositing::is_compositing_property;
+pub(crate) use error_description::is_accessibility_property;
+pub(crate) use focus::RovingMove;
 pub(crate) use widget_arena::WidgetArena;
 pub(crate) use widget_pod::CreateWidget;
 pub(crate) use widget_state::WidgetState;
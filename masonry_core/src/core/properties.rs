@@ -1,11 +1,14 @@
 // Copyright 2025 the Xilem Authors
 // SPDX-License-Identifier: Apache-2.0
 
-use std::any::TypeId;
+use std::any::{Any, TypeId};
 use std::collections::HashMap;
 use std::default::Default;
+use std::fmt::Debug;
 
-use crate::core::Widget;
+use crate::core::{
+    ErrorDescription, FocusScope, MixBlendMode, Opacity, RovingFocus, Shortcuts, TabIndex, Widget,
+};
 use crate::util::AnyMap;
 
 /// A marker trait that indicates that a type is intended to be used as a widget's property.
@@ -33,6 +36,13 @@ pub trait Property: Default + Send + Sync + 'static {
     fn static_default() -> &'static Self;
 }
 
+/// A function which formats a type-erased property.
+type DebugFn = fn(&(dyn Any + Send + Sync)) -> String;
+
+fn debug_fn<P: Debug + 'static>(value: &(dyn Any + Send + Sync)) -> String {
+    format!("{:?}", value.downcast_ref::<P>().unwrap())
+}
+
 // TODO - Implement Debug.
 /// A collection of [properties](Property) that a widget can be created with.
 #[derive(Default)]
@@ -63,11 +73,16 @@ pub struct PropertiesMut<'a> {
 ///
 /// Default property values can be added to this collection for
 /// every `(widget type, property type)` pair.
-#[derive(Default, Debug)]
+#[derive(Debug)]
 pub struct DefaultProperties {
     /// Maps widget types to the default property map for that widget.
     pub(crate) map: HashMap<TypeId, AnyMap>,
     pub(crate) dummy_map: AnyMap,
+    /// Formatters for the property types which can be displayed.
+    ///
+    /// Property maps don't know which types they hold, so this is what lets
+    /// the widget inspector and tree snapshots list the properties of a widget.
+    pub(crate) debug_fns: HashMap<TypeId, DebugFn>,
 }
 
 impl Properties {
@@ -186,20 +201,95 @@ impl DefaultProperties {
     /// have default padding, etc.
     /// You should either add a thorough set of values to this, or start from an existing map.
     pub fn new() -> Self {
-        Self {
+        let mut properties = Self {
             map: HashMap::new(),
             dummy_map: AnyMap::new(),
-        }
+            debug_fns: HashMap::new(),
+        };
+        properties.register_debug::<Opacity>();
+        properties.register_debug::<MixBlendMode>();
+        properties.register_debug::<FocusScope>();
+        properties.register_debug::<TabIndex>();
+        properties.register_debug::<RovingFocus>();
+        properties.register_debug::<Shortcuts>();
+        properties.register_debug::<ErrorDescription>();
+        properties
     }
 
     /// Set the default value of property `P` for widget `W`.
     ///
     /// Widgets for which the property `P` isn't set will get `value` instead.
-    pub fn insert<W: Widget, P: Property>(&mut self, value: P) -> Option<P> {
+    ///
+    /// This also lets the widget inspector and tree snapshots display values of `P`.
+    pub fn insert<W: Widget, P: Property + Debug>(&mut self, value: P) -> Option<P> {
+        self.register_debug::<P>();
         self.map.entry(TypeId::of::<W>()).or_default().insert(value)
     }
 
+    /// Lets the widget inspector and tree snapshots display values of property `P`.
+    ///
+    /// Properties which are neither registered nor [inserted](Self::insert) are left out of those.
+    /// This is only needed for properties without a default value.
+    /// The properties defined in this crate are always registered.
+    pub fn register_debug<P: Property + Debug>(&mut self) {
+        self.debug_fns.insert(TypeId::of::<P>(), debug_fn::<P>);
+    }
+
     pub(crate) fn for_widget(&self, id: TypeId) -> &AnyMap {
         self.map.get(&id).unwrap_or(&self.dummy_map)
     }
+
+    /// Returns a debug representation of every property in `properties`.
+    ///
+    /// Local properties come first, followed by the default properties which they don't
+    /// override, marked as such.
+    pub(crate) fn debug_entries(&self, properties: &PropertiesRef<'_>) -> Vec<String> {
+        let format = |(type_id, value): (&TypeId, &Box<dyn Any + Send + Sync>)| {
+            Some(self.debug_fns.get(type_id)?(&**value))
+        };
+
+        let local_map = properties.map.as_raw();
+        let mut entries: Vec<String> = local_map.iter().filter_map(format).collect();
+        entries.sort();
+        let mut defaults: Vec<String> = properties
+            .default_map
+            .as_raw()
+            .iter()
+            .filter(|(type_id, _)| !local_map.contains_key(type_id))
+            .filter_map(format)
+            .map(|entry| format!("{entry} (default)"))
+            .collect();
+        defaults.sort();
+        entries.extend(defaults);
+        entries
+    }
+}
+
+impl Default for DefaultProperties {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn debug_entries() {
+        let local = Properties::new().with(Opacity(0.5));
+        let defaults = Properties::new()
+            .with(Opacity(1.0))
+            .with(MixBlendMode::default());
+        let props = PropertiesRef {
+            map: &local.map,
+            default_map: &defaults.map,
+        };
+
+        let entries = DefaultProperties::new().debug_entries(&props);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0], "Opacity(0.5)");
+        assert!(entries[1].starts_with("MixBlendMode("));
+        assert!(entries[1].ends_with(" (default)"));
+    }
 }
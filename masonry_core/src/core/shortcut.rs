@@ -0,0 +1,354 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt::{self, Display, Formatter};
+use std::num::NonZeroU64;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::core::keyboard::{Key, KeyState, KeyboardEvent, Modifiers, NamedKey};
+use crate::core::{ArcStr, Property};
+
+/// The modifiers which are part of a [`KeyChord`].
+///
+/// Lock states and less common modifiers are ignored when matching key events.
+const CHORD_MODIFIERS: Modifiers = Modifiers::CONTROL
+    .union(Modifiers::ALT)
+    .union(Modifiers::SHIFT)
+    .union(Modifiers::META);
+
+/// The modifier used for most shortcuts on the current platform.
+///
+/// This is <kbd>Cmd</kbd> on macOS and <kbd>Ctrl</kbd> everywhere else.
+const PRIMARY_MODIFIER: Modifiers = if cfg!(target_os = "macos") {
+    Modifiers::META
+} else {
+    Modifiers::CONTROL
+};
+
+/// A key combination, such as <kbd>Ctrl</kbd>+<kbd>S</kbd>, used to trigger a [`Shortcut`].
+///
+/// Chords can be parsed from strings like `"Ctrl+Shift+P"`.
+/// Modifier names are case-insensitive, and the following are recognized:
+/// - `Ctrl` or `Control`,
+/// - `Alt` or `Option`,
+/// - `Shift`,
+/// - `Meta`, `Cmd`, `Command` or `Super`,
+/// - `Primary`, which is `Cmd` on macOS and `Ctrl` everywhere else.
+///
+/// The key is either a single character, which is matched case-insensitively,
+/// `Space`, or the name of a [`NamedKey`] such as `Enter`, `Escape` or `F5`.
+///
+/// The [`Display`] impl prints chords in the conventional format of the platform,
+/// so that they can be shown in menus and tooltips.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct KeyChord {
+    modifiers: Modifiers,
+    key: Key,
+}
+
+/// The error returned when a string can't be parsed as a [`KeyChord`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseKeyChordError {
+    part: String,
+}
+
+/// Identifies a [`Shortcut`].
+///
+/// This is the value sent with [`RenderRootSignal::Shortcut`](crate::app::RenderRootSignal::Shortcut)
+/// when the shortcut is triggered.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ShortcutId(NonZeroU64);
+
+/// When a shortcut is checked relative to the widget which has focus.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ShortcutPrecedence {
+    /// The key event is first sent to the focused widget, and the shortcut is only
+    /// triggered if that widget doesn't handle it.
+    ///
+    /// This lets text inputs keep the chords they use for editing, such as <kbd>Ctrl</kbd>+<kbd>A</kbd>.
+    #[default]
+    AfterFocused,
+    /// The shortcut is triggered before the key event reaches the focused widget,
+    /// which then never sees the event.
+    BeforeFocused,
+}
+
+/// A key chord bound to a command.
+///
+/// Shortcuts either apply to a whole window, when registered with
+/// [`RenderRoot::register_shortcut`](crate::app::RenderRoot::register_shortcut),
+/// or to a subtree, when added to the [`Shortcuts`] property of the subtree's root widget.
+/// Subtree shortcuts are only active while the focused widget is in the subtree.
+///
+/// When several shortcuts match a key event, the one closest to the focused widget wins,
+/// and window shortcuts come last.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Shortcut {
+    /// The id sent when the shortcut is triggered.
+    pub id: ShortcutId,
+    /// The key chord which triggers the shortcut.
+    pub chord: KeyChord,
+    /// When the shortcut is checked relative to the focused widget.
+    pub precedence: ShortcutPrecedence,
+    /// A human-readable description of the command, e.g. to show it in a menu.
+    pub label: Option<ArcStr>,
+}
+
+/// The shortcuts which are active while the focused widget is this widget or one of its descendants.
+///
+/// See [`Shortcut`] for how shortcuts are resolved.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Shortcuts(pub Vec<Shortcut>);
+
+// --- MARK: IMPL KEYCHORD
+impl KeyChord {
+    /// Create a chord from modifiers and a key.
+    ///
+    /// Character keys are stored in lowercase.
+    pub fn new(modifiers: Modifiers, key: Key) -> Self {
+        let key = match key {
+            Key::Character(c) => Key::Character(c.to_lowercase()),
+            key => key,
+        };
+        Self {
+            modifiers: modifiers & CHORD_MODIFIERS,
+            key,
+        }
+    }
+
+    /// The modifiers which must be held.
+    pub fn modifiers(&self) -> Modifiers {
+        self.modifiers
+    }
+
+    /// The key which must be pressed.
+    pub fn key(&self) -> &Key {
+        &self.key
+    }
+
+    /// Returns `true` if `event` is a press of this chord.
+    ///
+    /// The held modifiers must match exactly, so <kbd>Ctrl</kbd>+<kbd>Shift</kbd>+<kbd>S</kbd>
+    /// doesn't trigger <kbd>Ctrl</kbd>+<kbd>S</kbd>.
+    pub fn matches(&self, event: &KeyboardEvent) -> bool {
+        if event.state != KeyState::Down || event.modifiers & CHORD_MODIFIERS != self.modifiers {
+            return false;
+        }
+        match (&self.key, &event.key) {
+            (Key::Character(key), Key::Character(pressed)) => *key == pressed.to_lowercase(),
+            (key, pressed) => key == pressed,
+        }
+    }
+}
+
+impl FromStr for KeyChord {
+    type Err = ParseKeyChordError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        // The `+` key can't be split on `+`.
+        let (modifier_names, key_name) = if let Some(modifier_names) = s.strip_suffix("++") {
+            (modifier_names, "+")
+        } else {
+            s.rsplit_once('+').unwrap_or(("", s))
+        };
+
+        let mut modifiers = Modifiers::empty();
+        for name in modifier_names.split('+').filter(|name| !name.is_empty()) {
+            modifiers |= match name.trim().to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => Modifiers::CONTROL,
+                "alt" | "option" => Modifiers::ALT,
+                "shift" => Modifiers::SHIFT,
+                "meta" | "cmd" | "command" | "super" => Modifiers::META,
+                "primary" => PRIMARY_MODIFIER,
+                _ => return Err(ParseKeyChordError::new(name)),
+            };
+        }
+
+        let key_name = key_name.trim();
+        let key = if key_name.chars().count() == 1 {
+            Key::Character(key_name.into())
+        } else if key_name.eq_ignore_ascii_case("space") {
+            Key::Character(" ".into())
+        } else if let Ok(named_key) = key_name.parse::<NamedKey>() {
+            Key::Named(named_key)
+        } else {
+            return Err(ParseKeyChordError::new(key_name));
+        };
+        Ok(Self::new(modifiers, key))
+    }
+}
+
+impl Display for KeyChord {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let meta_name = if cfg!(target_os = "macos") {
+            "Cmd"
+        } else {
+            "Super"
+        };
+        let modifier_names = [
+            (Modifiers::CONTROL, "Ctrl"),
+            (Modifiers::ALT, "Alt"),
+            (Modifiers::SHIFT, "Shift"),
+            (Modifiers::META, meta_name),
+        ];
+        for (modifier, name) in modifier_names {
+            if self.modifiers.contains(modifier) {
+                write!(f, "{name}+")?;
+            }
+        }
+        match &self.key {
+            Key::Character(c) if c == " " => f.write_str("Space"),
+            Key::Character(c) => f.write_str(&c.to_uppercase()),
+            Key::Named(key) => write!(f, "{key}"),
+        }
+    }
+}
+
+impl ParseKeyChordError {
+    fn new(part: &str) -> Self {
+        Self { part: part.into() }
+    }
+}
+
+impl Display for ParseKeyChordError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown key or modifier in key chord: `{}`", self.part)
+    }
+}
+
+impl std::error::Error for ParseKeyChordError {}
+
+// --- MARK: IMPL SHORTCUT
+impl ShortcutId {
+    /// Allocate a new, unique `ShortcutId`.
+    pub fn next() -> Self {
+        static SHORTCUT_ID_COUNTER: AtomicU64 = AtomicU64::new(1);
+        let id = SHORTCUT_ID_COUNTER.fetch_add(1, Ordering::Relaxed);
+        Self(id.try_into().unwrap())
+    }
+
+    /// Returns the integer value of the `ShortcutId`.
+    pub fn to_raw(self) -> u64 {
+        self.0.into()
+    }
+}
+
+impl Display for ShortcutId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "#{}", self.0)
+    }
+}
+
+impl Shortcut {
+    /// Create a shortcut for the given chord, with a new id.
+    pub fn new(chord: KeyChord) -> Self {
+        Self {
+            id: ShortcutId::next(),
+            chord,
+            precedence: ShortcutPrecedence::default(),
+            label: None,
+        }
+    }
+
+    /// Builder-style method to set the shortcut's precedence relative to the focused widget.
+    pub fn with_precedence(mut self, precedence: ShortcutPrecedence) -> Self {
+        self.precedence = precedence;
+        self
+    }
+
+    /// Builder-style method to set the shortcut's label.
+    pub fn with_label(mut self, label: impl Into<ArcStr>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+}
+
+impl Property for Shortcuts {
+    fn static_default() -> &'static Self {
+        static DEFAULT: Shortcuts = Shortcuts(Vec::new());
+        &DEFAULT
+    }
+}
+
+impl Shortcuts {
+    /// Returns the first shortcut matching `event` with the given precedence.
+    pub fn find_match(
+        &self,
+        event: &KeyboardEvent,
+        precedence: ShortcutPrecedence,
+    ) -> Option<&Shortcut> {
+        self.0
+            .iter()
+            .rev()
+            .find(|shortcut| shortcut.precedence == precedence && shortcut.chord.matches(event))
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key_down(modifiers: Modifiers, key: Key) -> KeyboardEvent {
+        KeyboardEvent {
+            key,
+            modifiers,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn parse_chords() {
+        let chord: KeyChord = "Ctrl+Shift+P".parse().unwrap();
+        assert_eq!(chord.modifiers(), Modifiers::CONTROL | Modifiers::SHIFT);
+        assert_eq!(chord.key(), &Key::Character("p".into()));
+
+        let chord: KeyChord = "alt+F4".parse().unwrap();
+        assert_eq!(chord.modifiers(), Modifiers::ALT);
+        assert_eq!(chord.key(), &Key::Named(NamedKey::F4));
+
+        let chord: KeyChord = "Ctrl++".parse().unwrap();
+        assert_eq!(chord.key(), &Key::Character("+".into()));
+
+        let chord: KeyChord = "Escape".parse().unwrap();
+        assert_eq!(chord.modifiers(), Modifiers::empty());
+
+        assert!("Hyper+S".parse::<KeyChord>().is_err());
+        assert!("Ctrl+NotAKey".parse::<KeyChord>().is_err());
+    }
+
+    #[test]
+    fn display_chords() {
+        let chord: KeyChord = "shift+ctrl+p".parse().unwrap();
+        assert_eq!(chord.to_string(), "Ctrl+Shift+P");
+
+        let chord: KeyChord = "Alt+Space".parse().unwrap();
+        assert_eq!(chord.to_string(), "Alt+Space");
+
+        let chord: KeyChord = "Ctrl+ArrowUp".parse().unwrap();
+        assert_eq!(chord.to_string(), "Ctrl+ArrowUp");
+    }
+
+    #[test]
+    fn match_chords() {
+        let chord: KeyChord = "Ctrl+S".parse().unwrap();
+        assert!(chord.matches(&key_down(Modifiers::CONTROL, Key::Character("s".into()))));
+        assert!(chord.matches(&key_down(
+            Modifiers::CONTROL | Modifiers::NUM_LOCK,
+            Key::Character("s".into())
+        )));
+        assert!(!chord.matches(&key_down(
+            Modifiers::CONTROL | Modifiers::SHIFT,
+            Key::Character("S".into())
+        )));
+        assert!(!chord.matches(&key_down(Modifiers::empty(), Key::Character("s".into()))));
+
+        let chord: KeyChord = "Ctrl+Shift+S".parse().unwrap();
+        assert!(chord.matches(&key_down(
+            Modifiers::CONTROL | Modifiers::SHIFT,
+            Key::Character("S".into())
+        )));
+    }
+}
@@ -15,9 +15,9 @@ use vello::Scene;
 use vello::kurbo::{Point, Size};
 
 use crate::core::{
-    AccessCtx, AccessEvent, BoxConstraints, ComposeCtx, EventCtx, LayoutCtx, PaintCtx,
-    PointerEvent, PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx, TextEvent, Update,
-    UpdateCtx, WidgetRef,
+    AccessCtx, AccessEvent, Axis, BoxConstraints, ComposeCtx, EventCtx, IntrinsicSize, LayoutCtx,
+    MeasureCtx, Opacity, PaintCtx, PointerEvent, PropertiesMut, PropertiesRef, QueryCtx,
+    RegisterCtx, TextEvent, Update, UpdateCtx, WidgetRef,
 };
 
 /// A unique identifier for a single [`Widget`].
@@ -242,6 +242,35 @@ pub trait Widget: AsDynWidget + Any {
         bc: &BoxConstraints,
     ) -> Size;
 
+    /// Compute how much space the widget needs along the given axis.
+    ///
+    /// This lets container widgets find out the size their children would like to have
+    /// before calling [`LayoutCtx::run_layout`] on them, e.g. to size the columns of a grid
+    /// to fit their content.
+    ///
+    /// `cross_length` is the length the widget will have on the other axis, if known.
+    /// For instance, the height of a label depends on the width available for line wrapping.
+    ///
+    /// Container widgets should measure their children with [`MeasureCtx::measure_child`].
+    /// They don't need to visit every child.
+    /// In their `layout` method, they should measure children before calling
+    /// [`LayoutCtx::run_layout`] on them.
+    ///
+    /// Returning `None` means the widget doesn't support measurement;
+    /// containers should fall back to running its layout.
+    /// This is what the default implementation does.
+    ///
+    /// Results are cached by Masonry until the widget or one of its descendants requests layout.
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        cross_length: Option<f64>,
+    ) -> Option<IntrinsicSize> {
+        None
+    }
+
     /// Runs after the widget's final transform has been computed.
     fn compose(&mut self, ctx: &mut ComposeCtx<'_>) {}
 
@@ -395,6 +424,9 @@ pub fn find_widget_under_pointer<'c>(
     if ctx.is_stashed() {
         return None;
     }
+    if ctx.properties.get::<Opacity>().is_transparent() {
+        return None;
+    }
 
     let local_pos = ctx.window_transform().inverse() * pos;
 
@@ -5,7 +5,9 @@ use std::any::TypeId;
 
 use vello::kurbo::Affine;
 
-use crate::core::{FromDynWidget, MutateCtx, Property, Widget};
+use crate::core::{
+    FromDynWidget, MutateCtx, Property, Widget, is_accessibility_property, is_compositing_property,
+};
 
 /// A rich mutable reference to a [`Widget`].
 ///
@@ -74,8 +76,7 @@ impl<W: Widget + ?Sized> WidgetMut<'_, W> {
     /// This also calls [`Widget::property_changed`] with the matching type id.
     pub fn insert_prop<T: Property>(&mut self, value: T) -> Option<T> {
         let value = self.ctx.properties.insert(value);
-        self.widget
-            .property_changed(&mut self.ctx.update_mut(), TypeId::of::<T>());
+        self.property_changed(TypeId::of::<T>());
         value
     }
 
@@ -86,11 +87,26 @@ impl<W: Widget + ?Sized> WidgetMut<'_, W> {
     /// This also calls [`Widget::property_changed`] with the matching type id.
     pub fn remove_prop<T: Property>(&mut self) -> Option<T> {
         let value = self.ctx.properties.remove::<T>();
-        self.widget
-            .property_changed(&mut self.ctx.update_mut(), TypeId::of::<T>());
+        self.property_changed(TypeId::of::<T>());
         value
     }
 
+    fn property_changed(&mut self, property_type: TypeId) {
+        // Compositing properties are handled by Masonry itself.
+        // A compose pass will also update hit-testing and repaint the widget.
+        if is_compositing_property(property_type) {
+            self.ctx.request_compose();
+            // These affect how the whole subtree is drawn.
+            let bounding_rect = self.ctx.widget_state.painted_bounding_rect;
+            self.ctx.global_state.damage.add_rect(bounding_rect);
+        }
+        if is_accessibility_property(property_type) {
+            self.ctx.request_accessibility_update();
+        }
+        self.widget
+            .property_changed(&mut self.ctx.update_mut(), property_type);
+    }
+
     /// Set the local transform of this widget.
     ///
     /// It behaves similarly as CSS transforms.
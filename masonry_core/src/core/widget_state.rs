@@ -4,7 +4,7 @@
 use tracing::Span;
 use vello::kurbo::{Affine, Insets, Point, Rect, Size, Vec2};
 
-use crate::core::WidgetId;
+use crate::core::{Axis, BoxConstraints, IntrinsicSize, WidgetId};
 
 // TODO - Reduce WidgetState size.
 // See https://github.com/linebender/xilem/issues/706
@@ -59,6 +59,17 @@ pub(crate) struct WidgetState {
     /// The size of the widget; this is the value returned by the widget's layout
     /// method.
     pub(crate) size: Size,
+    /// The constraints passed to the widget's last layout call.
+    ///
+    /// If they're unchanged and no layout was requested, the layout pass skips this widget.
+    pub(crate) layout_constraints: Option<BoxConstraints>,
+    /// The results of recent [`Widget::measure`](crate::core::Widget::measure) calls,
+    /// keyed by axis and cross length.
+    pub(crate) measure_cache: Vec<(Axis, Option<f64>, Option<IntrinsicSize>)>,
+    /// The layout pass during which `measure_cache` was last filled.
+    ///
+    /// If the widget needs layout, entries from previous passes may be outdated.
+    pub(crate) measure_cache_pass: u64,
     /// The origin of the widget in the `window_transform` coordinate space; together with
     /// `size` these constitute the widget's layout rect.
     pub(crate) origin: Point,
@@ -71,6 +82,11 @@ pub(crate) struct WidgetState {
     pub(crate) local_paint_rect: Rect,
     /// An axis aligned bounding rect (AABB in 2D), containing itself and all its descendents in window coordinates. Includes `paint_insets`.
     pub(crate) bounding_rect: Rect,
+    /// The paint rect of this widget (not including its children), in window coordinates,
+    /// as of the last paint pass. Used to compute damage regions.
+    pub(crate) painted_rect: Rect,
+    /// The `bounding_rect` of this widget as of the last paint pass.
+    pub(crate) painted_bounding_rect: Rect,
     /// The offset of the baseline relative to the bottom of the widget.
     ///
     /// In general, this will be zero; the bottom of the widget will be considered
@@ -115,6 +131,9 @@ pub(crate) struct WidgetState {
     /// A flag used to track and debug missing calls to `place_child`.
     pub(crate) is_expecting_place_child_call: bool,
 
+    /// A flag used to track and debug missing calls to `run_layout`.
+    pub(crate) is_expecting_run_layout_call: bool,
+
     /// This widget explicitly requested layout
     pub(crate) request_layout: bool,
     /// This widget or a descendant explicitly requested layout
@@ -134,6 +153,9 @@ pub(crate) struct WidgetState {
     pub(crate) request_accessibility: bool,
     /// The accessibility method must be called on this widget or a descendant
     pub(crate) needs_accessibility: bool,
+    /// This widget is clipped out, and its accessibility node was replaced by a hidden one
+    /// without children. Its subtree must be rebuilt once it comes into view.
+    pub(crate) is_access_offscreen: bool,
 
     /// An animation must run on this widget
     pub(crate) request_anim: bool,
@@ -198,7 +220,11 @@ impl WidgetState {
             id,
             origin: Point::ORIGIN,
             size: Size::ZERO,
+            layout_constraints: None,
+            measure_cache: Vec::new(),
+            measure_cache_pass: 0,
             is_expecting_place_child_call: false,
+            is_expecting_run_layout_call: false,
             paint_insets: Insets::ZERO,
             local_paint_rect: Rect::ZERO,
             accepts_pointer_interaction: true,
@@ -224,6 +250,7 @@ impl WidgetState {
             needs_paint: true,
             request_accessibility: true,
             needs_accessibility: true,
+            is_access_offscreen: false,
             has_focus_target: false,
             request_anim: true,
             needs_anim: true,
@@ -236,6 +263,8 @@ impl WidgetState {
             widget_name,
             window_transform: Affine::IDENTITY,
             bounding_rect: Rect::ZERO,
+            painted_rect: Rect::ZERO,
+            painted_bounding_rect: Rect::ZERO,
             trace_span: Span::none(),
             transform: options.transform,
         }
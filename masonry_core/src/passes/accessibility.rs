@@ -7,8 +7,12 @@ use tree_arena::ArenaMut;
 use vello::kurbo::Rect;
 
 use crate::app::{RenderRoot, RenderRootState};
-use crate::core::{AccessCtx, DefaultProperties, PropertiesRef, Widget, WidgetState};
-use crate::passes::{enter_span_if, recurse_on_children};
+use crate::core::{
+    AccessCtx, DefaultProperties, ErrorDescription, PropertiesRef, Widget, WidgetState,
+};
+use crate::passes::{
+    enter_span_if, is_clipped_out, recurse_on_children, visible_rect_for_children,
+};
 use crate::util::AnyMap;
 
 // --- MARK: BUILD TREE
@@ -21,6 +25,7 @@ fn build_accessibility_tree(
     mut properties: ArenaMut<'_, AnyMap>,
     rebuild_all: bool,
     scale_factor: Option<f64>,
+    visible_rect: Option<Rect>,
 ) {
     let _span = enter_span_if(global_state.trace.access, state.reborrow());
     let id = state.item.id;
@@ -28,6 +33,7 @@ fn build_accessibility_tree(
     if !rebuild_all && !state.item.needs_accessibility {
         return;
     }
+    global_state.frame_stats.accessibility.widgets_visited += 1;
 
     if rebuild_all || state.item.request_accessibility {
         if global_state.trace.access {
@@ -53,6 +59,10 @@ fn build_accessibility_tree(
             default_map: default_properties.for_widget(widget.item.type_id()),
         };
         widget.item.accessibility(&mut ctx, &props, &mut node);
+        if let Some(description) = &props.get::<ErrorDescription>().0 {
+            node.set_invalid(accesskit::Invalid::True);
+            node.set_description(&**description);
+        }
 
         let id: NodeId = ctx.widget_state.id.into();
         if ctx.global_state.trace.access {
@@ -65,6 +75,7 @@ fn build_accessibility_tree(
     state.item.needs_accessibility = false;
 
     let id = state.item.id;
+    let visible_rect = visible_rect_for_children(state.item, visible_rect);
     let parent_state = state.item;
     recurse_on_children(
         id,
@@ -74,6 +85,26 @@ fn build_accessibility_tree(
         |widget, mut state, properties| {
             // TODO - We don't skip updating stashed items because doing so
             // is error-prone. We may want to revisit that decision.
+
+            // Widgets which are entirely clipped out aren't kept up to date, e.g. while
+            // scrolling. If they have pending changes, their node is replaced by a hidden one
+            // without children, so that screen readers don't read outdated content.
+            // Their subtree is rebuilt once they come into view.
+            // The focused widget is always kept up to date.
+            // Their flags aren't merged up, so that they don't request redraws in the meantime.
+            if !rebuild_all
+                && !global_state.focused_path.contains(&state.item.id)
+                && is_clipped_out(state.item, visible_rect)
+            {
+                if state.item.needs_accessibility && !state.item.is_access_offscreen {
+                    let node = build_offscreen_node(&**widget.item, state.item);
+                    tree_update.nodes.push((state.item.id.into(), node));
+                    state.item.is_access_offscreen = true;
+                }
+                return;
+            }
+            let rebuild_subtree = rebuild_all || state.item.is_access_offscreen;
+            state.item.is_access_offscreen = false;
             build_accessibility_tree(
                 global_state,
                 default_properties,
@@ -81,8 +112,9 @@ fn build_accessibility_tree(
                 widget,
                 state.reborrow_mut(),
                 properties,
-                rebuild_all,
+                rebuild_subtree,
                 None,
+                visible_rect,
             );
             parent_state.merge_up(state.item);
         },
@@ -136,6 +168,16 @@ fn build_access_node(
     node
 }
 
+/// The node sent in place of a clipped-out widget whose node is out of date.
+///
+/// The node has no children, so the nodes of its descendants are removed from the tree.
+fn build_offscreen_node(widget: &dyn Widget, state: &WidgetState) -> Node {
+    let mut node = Node::new(widget.accessibility_role());
+    node.set_bounds(to_accesskit_rect(state.size.to_rect()));
+    node.set_hidden();
+    node
+}
+
 fn to_accesskit_rect(r: Rect) -> accesskit::Rect {
     accesskit::Rect::new(r.x0, r.y0, r.x1, r.y1)
 }
@@ -191,11 +233,13 @@ pub(crate) fn run_accessibility_pass(root: &mut RenderRoot, scale_factor: f64) -
         root_properties,
         root.rebuild_access_tree,
         Some(scale_factor),
+        None,
     );
     root.rebuild_access_tree = false;
 
     // TODO: make root node type customizable to support Dialog/AlertDialog roles
     // (should go hand in hand with introducing support for modal windows?)
+    // Dialogs shown inside a window are regular widgets with the Dialog role instead.
     let mut window_node = Node::new(Role::Window);
     window_node.set_children(vec![root.root.id().into()]);
     tree_update.nodes.push((root.window_node_id, window_node));
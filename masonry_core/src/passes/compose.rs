@@ -8,7 +8,7 @@ use vello::kurbo::Affine;
 use crate::app::{RenderRoot, RenderRootState};
 use crate::core::{ComposeCtx, Widget, WidgetState};
 use crate::passes::{enter_span_if, recurse_on_children};
-use crate::util::AnyMap;
+use crate::util::{AnyMap, Instant};
 
 // --- MARK: RECURSE
 fn compose_widget(
@@ -26,6 +26,7 @@ fn compose_widget(
     if !transformed && !state.item.needs_compose {
         return;
     }
+    global_state.frame_stats.compose.widgets_visited += 1;
 
     // the translation needs to be applied *after* applying the transform, as translation by scrolling should be within the transformed coordinate space. Same is true for the (layout) origin, to behave similar as in CSS.
     let local_translation = state.item.scroll_translation + state.item.origin.to_vec2();
@@ -87,6 +88,8 @@ fn compose_widget(
 /// See the [passes documentation](../doc/05_pass_system.md#compose-pass).
 pub(crate) fn run_compose_pass(root: &mut RenderRoot) {
     let _span = info_span!("compose").entered();
+    let start = Instant::now();
+    let widgets_visited_before = root.global_state.frame_stats.compose.widgets_visited;
 
     // If widgets have moved, pointer-related info may be stale.
     // For instance, the "hovered" widget may have moved and no longer be under the pointer.
@@ -103,4 +106,9 @@ pub(crate) fn run_compose_pass(root: &mut RenderRoot) {
         false,
         Affine::IDENTITY,
     );
+
+    let frame_stats = &mut root.global_state.frame_stats;
+    if frame_stats.compose.widgets_visited != widgets_visited_before {
+        frame_stats.compose.record_run(start);
+    }
 }
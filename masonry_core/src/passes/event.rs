@@ -3,13 +3,14 @@
 
 use tracing::{debug, info_span, trace};
 use ui_events::pointer::PointerType;
+use vello::kurbo::Point;
 
 use crate::Handled;
-use crate::app::{RenderRoot, RenderRootSignal};
-use crate::core::keyboard::{Key, KeyState, NamedKey};
+use crate::app::{RenderRoot, RenderRootSignal, run_on_inspector_pointer_event};
+use crate::core::keyboard::{Key, KeyState, KeyboardEvent, NamedKey};
 use crate::core::{
-    AccessEvent, EventCtx, PointerEvent, PointerInfo, PointerUpdate, PropertiesMut, TextEvent,
-    Widget, WidgetId,
+    AccessEvent, EventCtx, PointerEvent, PointerInfo, PointerUpdate, PropertiesMut, RovingFocus,
+    RovingMove, ShortcutId, ShortcutPrecedence, Shortcuts, TextEvent, Widget, WidgetId,
 };
 use crate::debug_panic;
 use crate::dpi::{LogicalPosition, PhysicalPosition};
@@ -39,6 +40,64 @@ fn get_pointer_target(
     None
 }
 
+/// Find the shortcut triggered by `event` among those with the given precedence.
+///
+/// The shortcuts of `target` and its ancestors are checked first, from the innermost
+/// to the outermost, then the window shortcuts.
+/// The shortcuts of disabled widgets are ignored.
+fn find_shortcut(
+    root: &RenderRoot,
+    target: Option<WidgetId>,
+    event: &KeyboardEvent,
+    precedence: ShortcutPrecedence,
+) -> Option<ShortcutId> {
+    let mut current = target.filter(|id| root.widget_arena.has(*id));
+    while let Some(id) = current {
+        let (_, state, properties) = root.widget_arena.get_all(id);
+        if !state.item.is_disabled {
+            let shortcut = properties
+                .item
+                .get::<Shortcuts>()
+                .and_then(|shortcuts| shortcuts.find_match(event, precedence));
+            if let Some(shortcut) = shortcut {
+                return Some(shortcut.id);
+            }
+        }
+        current = root.widget_arena.parent_of(id);
+    }
+    root.window_shortcuts
+        .find_match(event, precedence)
+        .map(|shortcut| shortcut.id)
+}
+
+fn trigger_shortcut(root: &mut RenderRoot, shortcut: ShortcutId) -> Handled {
+    debug!("Triggered shortcut {shortcut}");
+    root.global_state
+        .emit_signal(RenderRootSignal::Shortcut(shortcut));
+    Handled::Yes
+}
+
+/// Find the widget which `key` moves focus to, if the focused widget is in a [`RovingFocus`] group.
+fn roving_focus_target(root: &RenderRoot, focused: WidgetId, key: &Key) -> Option<WidgetId> {
+    let group = root.innermost_ancestor_with::<RovingFocus>(focused)?;
+    let (_, _, properties) = root.widget_arena.get_all(group);
+    let movement = properties.item.get::<RovingFocus>()?.move_for_key(key)?;
+
+    let items = root.roving_focus_items(group);
+    let len = items.len();
+    if len == 0 {
+        return None;
+    }
+    let idx = items.iter().position(|id| *id == focused);
+    let new_idx = match movement {
+        RovingMove::First => 0,
+        RovingMove::Last => len - 1,
+        RovingMove::Next => idx.map_or(0, |idx| (idx + 1) % len),
+        RovingMove::Previous => idx.map_or(len - 1, |idx| (idx + len - 1) % len),
+    };
+    Some(items[new_idx])
+}
+
 /// `true` if this [`PointerEvent`] type is likely to occur every frame.
 fn is_very_frequent(e: &PointerEvent) -> bool {
     matches!(e, PointerEvent::Move(..) | PointerEvent::Scroll { .. })
@@ -156,6 +215,22 @@ pub(crate) fn run_on_pointer_event_pass(root: &mut RenderRoot, event: &PointerEv
         root.last_mouse_pos = event_pos;
     }
 
+    // Pointer events over the inspector panel are handled by the inspector.
+    if let Some(pos) = event_pos {
+        let pos = Point::new(pos.x, pos.y);
+        if root.global_state.inspector_state.covers(pos) {
+            return run_on_inspector_pointer_event(root, event, pos);
+        }
+    }
+    if root.global_state.inspector_state.is_open
+        && !root.global_state.inspector_state.is_picking_widget
+        && root.global_state.inspector_state.hovered_widget.is_some()
+    {
+        // The pointer left the panel.
+        root.global_state.inspector_state.hovered_widget = None;
+        root.root_state_mut().needs_paint = true;
+    }
+
     if root.global_state.inspector_state.is_picking_widget
         && matches!(event, PointerEvent::Move(..))
     {
@@ -170,6 +245,8 @@ pub(crate) fn run_on_pointer_event_pass(root: &mut RenderRoot, event: &PointerEv
     {
         let target_widget_id = get_pointer_target(root, event_pos);
         if let Some(target_widget_id) = target_widget_id {
+            root.global_state.inspector_state.selected_widget = Some(target_widget_id);
+            root.global_state.inspector_state.is_open = true;
             root.global_state
                 .emit_signal(RenderRootSignal::WidgetSelectedInInspector(
                     target_widget_id,
@@ -279,18 +356,38 @@ pub(crate) fn run_on_text_event_pass(root: &mut RenderRoot, event: &TextEvent) -
         }
     });
 
-    let mut handled = run_event_pass(
-        root,
-        target,
-        event,
-        false,
-        |widget, ctx, props, event| {
-            widget.on_text_event(ctx, props, event);
-        },
-        !event.is_high_density(),
-    );
+    // See the `Shortcut` documentation for how shortcuts are resolved.
+    let shortcut_before_focused = match event {
+        TextEvent::Keyboard(key) => {
+            find_shortcut(root, target, key, ShortcutPrecedence::BeforeFocused)
+        }
+        _ => None,
+    };
+
+    let mut handled = if let Some(shortcut) = shortcut_before_focused {
+        trigger_shortcut(root, shortcut)
+    } else {
+        run_event_pass(
+            root,
+            target,
+            event,
+            false,
+            |widget, ctx, props, event| {
+                widget.on_text_event(ctx, props, event);
+            },
+            !event.is_high_density(),
+        )
+    };
 
     if let TextEvent::Keyboard(key) = event {
+        if handled == Handled::No {
+            if let Some(shortcut) =
+                find_shortcut(root, target, key, ShortcutPrecedence::AfterFocused)
+            {
+                handled = trigger_shortcut(root, shortcut);
+            }
+        }
+
         // Handle Tab focus
         if key.key == Key::Named(NamedKey::Tab)
             && key.state == KeyState::Down
@@ -302,6 +399,18 @@ pub(crate) fn run_on_text_event_pass(root: &mut RenderRoot, event: &TextEvent) -
             handled = Handled::Yes;
         }
 
+        // Handle arrow keys in roving focus groups
+        let modifiers = key.modifiers;
+        if key.state == KeyState::Down
+            && handled == Handled::No
+            && !(modifiers.ctrl() || modifiers.alt() || modifiers.meta() || modifiers.shift())
+            && let Some(focused) = root.global_state.focused_widget
+            && let Some(next_focused) = roving_focus_target(root, focused, &key.key)
+        {
+            root.global_state.next_focused_widget = Some(next_focused);
+            handled = Handled::Yes;
+        }
+
         if key.key == Key::Named(NamedKey::F11)
             && key.state == KeyState::Down
             && handled == Handled::No
@@ -318,7 +427,13 @@ pub(crate) fn run_on_text_event_pass(root: &mut RenderRoot, event: &TextEvent) -
             && key.state == KeyState::Down
             && handled == Handled::No
         {
-            root.debug_paint = !root.debug_paint;
+            if key.modifiers.shift() {
+                root.debug_paint = !root.debug_paint;
+            } else {
+                let inspector_state = &mut root.global_state.inspector_state;
+                inspector_state.set_open(!inspector_state.is_open);
+                root.global_state.needs_pointer_pass = true;
+            }
             root.root_state_mut().needs_paint = true;
             handled = Handled::Yes;
         }
@@ -11,9 +11,16 @@ use tracing::{info_span, trace};
 use vello::kurbo::{Point, Rect, Size};
 
 use crate::app::{RenderRoot, RenderRootSignal, WindowSizePolicy};
-use crate::core::{BoxConstraints, LayoutCtx, PropertiesMut, Widget, WidgetPod, WidgetState};
+use crate::core::{
+    Axis, BoxConstraints, IntrinsicSize, LayoutCtx, MeasureCtx, PropertiesMut, PropertiesRef,
+    Widget, WidgetPod, WidgetState,
+};
 use crate::debug_panic;
 use crate::passes::{enter_span_if, recurse_on_children};
+use crate::util::Instant;
+
+/// How many [`Widget::measure`] results are cached for each widget.
+const MAX_MEASURE_CACHE_ENTRIES: usize = 8;
 
 // --- MARK: RUN LAYOUT
 /// Run [`Widget::layout`] method on the widget contained in `pod`.
@@ -31,19 +38,7 @@ pub(crate) fn run_layout_on<W: Widget + ?Sized>(
     let trace = parent_ctx.global_state.trace.layout;
     let _span = enter_span_if(trace, state.reborrow());
 
-    let mut children_ids = SmallVec::new();
-    if cfg!(debug_assertions) {
-        children_ids = widget.item.children_ids();
-
-        // We forcefully set request_layout to true for all children.
-        // This is used below to check that widget.layout(..) visited all of them.
-        for child_id in widget.item.children_ids() {
-            let child_state = state.children.item_mut(child_id).unwrap().item;
-            if !child_state.is_stashed {
-                child_state.request_layout = true;
-            }
-        }
-    }
+    state.item.is_expecting_run_layout_call = false;
 
     // This checks reads is_explicitly_stashed instead of is_stashed because the latter may be outdated.
     // A widget's is_explicitly_stashed flag is controlled by its direct parent.
@@ -61,18 +56,56 @@ pub(crate) fn run_layout_on<W: Widget + ?Sized>(
         return Size::ZERO;
     }
 
-    // TODO - Not everything that has been re-laid out needs to be repainted.
+    // If neither this widget nor its descendants requested layout, and the constraints
+    // are the same as last time, the previous layout is still valid.
+    // We skip the whole subtree.
+    if !state.item.needs_layout && state.item.layout_constraints == Some(*bc) {
+        if trace {
+            trace!("Skipping layout, constraints are unchanged");
+        }
+        state.item.is_expecting_place_child_call = true;
+        return state.item.size;
+    }
+
+    let mut children_ids = SmallVec::new();
+    if cfg!(debug_assertions) {
+        children_ids = widget.item.children_ids();
+
+        // We mark all children as expecting a run_layout call.
+        // This is used below to check that widget.layout(..) visited all of them.
+        for child_id in widget.item.children_ids() {
+            let child_state = state.children.item_mut(child_id).unwrap().item;
+            if !child_state.is_stashed {
+                child_state.is_expecting_run_layout_call = true;
+            }
+        }
+    }
+
+    // If the widget requested layout itself, or got new constraints, we assume its
+    // appearance changed. Otherwise, it's only re-laid out because of a descendant,
+    // and we only need to repaint it if its size changes (see below).
+    let old_size = state.item.size;
+    let invalidated = state.item.request_layout || state.item.layout_constraints != Some(*bc);
+    if invalidated {
+        state.item.request_paint = true;
+        state.item.request_compose = true;
+        state.item.request_accessibility = true;
+    }
     state.item.needs_paint = true;
     state.item.needs_compose = true;
     state.item.needs_accessibility = true;
-    state.item.request_paint = true;
-    state.item.request_compose = true;
-    state.item.request_accessibility = true;
 
     bc.debug_check(widget.item.short_type_name());
     if trace {
         trace!("Computing layout with constraints {:?}", bc);
     }
+    parent_ctx.global_state.layout_count += 1;
+    parent_ctx.global_state.frame_stats.layout.widgets_visited += 1;
+
+    // The widget may have changed since measurements from previous passes were cached.
+    if state.item.measure_cache_pass != parent_ctx.global_state.layout_pass_count {
+        state.item.measure_cache.clear();
+    }
 
     state.item.local_paint_rect = Rect::ZERO;
 
@@ -102,8 +135,6 @@ pub(crate) fn run_layout_on<W: Widget + ?Sized>(
             global_state: parent_ctx.global_state,
         };
 
-        // TODO - If constraints are the same and request_layout isn't set,
-        // skip calling layout
         inner_ctx.widget_state.request_layout = false;
         let mut props = PropertiesMut {
             map: properties.item,
@@ -129,6 +160,13 @@ pub(crate) fn run_layout_on<W: Widget + ?Sized>(
 
     state.item.needs_layout = false;
     state.item.is_expecting_place_child_call = true;
+    state.item.layout_constraints = Some(*bc);
+
+    if new_size != old_size {
+        state.item.request_paint = true;
+        state.item.request_compose = true;
+        state.item.request_accessibility = true;
+    }
 
     state.item.local_paint_rect = state
         .item
@@ -145,7 +183,7 @@ pub(crate) fn run_layout_on<W: Widget + ?Sized>(
                 continue;
             }
 
-            if child_state.request_layout {
+            if child_state.is_expecting_run_layout_call {
                 debug_panic!(
                     "Error in '{}' {}: LayoutCtx::run_layout() was not called with child widget '{}' {}.",
                     name,
@@ -191,6 +229,79 @@ pub(crate) fn run_layout_on<W: Widget + ?Sized>(
     new_size
 }
 
+// --- MARK: RUN MEASURE
+/// Run [`Widget::measure`] method on the widget contained in `pod`, or return a cached result.
+/// This will be called by [`LayoutCtx::measure_child`] and [`MeasureCtx::measure_child`].
+pub(crate) fn run_measure_on<W: Widget + ?Sized>(
+    parent_ctx: &mut MeasureCtx<'_>,
+    pod: &mut WidgetPod<W>,
+    axis: Axis,
+    cross_length: Option<f64>,
+) -> Option<IntrinsicSize> {
+    let id = pod.id();
+    let mut widget = parent_ctx.widget_children.item_mut(id).unwrap();
+    let mut state = parent_ctx.widget_state_children.item_mut(id).unwrap();
+    let properties = parent_ctx.properties_children.item_mut(id).unwrap();
+
+    if state.item.is_explicitly_stashed {
+        return Some(IntrinsicSize::ZERO);
+    }
+
+    // If the widget needs layout, something changed since the cached values were computed,
+    // unless they were computed during this very pass.
+    let pass = parent_ctx.global_state.layout_pass_count;
+    if state.item.needs_layout && state.item.measure_cache_pass != pass {
+        state.item.measure_cache.clear();
+    }
+    let cached = state
+        .item
+        .measure_cache
+        .iter()
+        .find(|(cached_axis, cached_length, _)| {
+            *cached_axis == axis && *cached_length == cross_length
+        });
+    if let Some((_, _, size)) = cached {
+        return *size;
+    }
+
+    let trace = parent_ctx.global_state.trace.layout;
+    let _span = enter_span_if(trace, state.reborrow());
+
+    let size = {
+        let mut inner_ctx = MeasureCtx {
+            widget_state: state.item,
+            widget_state_children: state.children.reborrow_mut(),
+            widget_children: widget.children,
+            properties_children: properties.children,
+            default_properties: parent_ctx.default_properties,
+            global_state: parent_ctx.global_state,
+        };
+        let props = PropertiesRef {
+            map: properties.item,
+            default_map: parent_ctx
+                .default_properties
+                .for_widget(widget.item.type_id()),
+        };
+        widget
+            .item
+            .measure(&mut inner_ctx, &props, axis, cross_length)
+    };
+    if trace {
+        trace!("Measured {axis:?} with cross length {cross_length:?}: {size:?}");
+    }
+
+    // Measuring may have changed the widget's internal state (e.g. where lines of text are broken),
+    // so we make sure its next `run_layout` call isn't skipped.
+    state.item.layout_constraints = None;
+
+    if state.item.measure_cache.len() >= MAX_MEASURE_CACHE_ENTRIES {
+        state.item.measure_cache.remove(0);
+    }
+    state.item.measure_cache.push((axis, cross_length, size));
+    state.item.measure_cache_pass = pass;
+    size
+}
+
 // --- MARK: ROOT
 /// See the [passes documentation](../doc/05_pass_system.md#layout-pass).
 pub(crate) fn run_layout_pass(root: &mut RenderRoot) {
@@ -198,8 +309,11 @@ pub(crate) fn run_layout_pass(root: &mut RenderRoot) {
         return;
     }
 
-    let _span = info_span!("layout").entered();
+    let span = info_span!("layout", widgets_laid_out = tracing::field::Empty).entered();
+    let start = Instant::now();
     root.global_state.needs_pointer_pass = true;
+    root.global_state.layout_pass_count += 1;
+    let layout_count_before = root.global_state.layout_count;
 
     let window_size = root.get_kurbo_size();
     let bc = match root.size_policy {
@@ -224,6 +338,10 @@ pub(crate) fn run_layout_pass(root: &mut RenderRoot) {
     let size = run_layout_on(&mut ctx, &mut root.root, &bc);
     ctx.place_child(&mut root.root, Point::ORIGIN);
 
+    let widgets_laid_out = root.global_state.layout_count - layout_count_before;
+    span.record("widgets_laid_out", widgets_laid_out);
+    root.global_state.frame_stats.layout.record_run(start);
+
     if let WindowSizePolicy::Content = root.size_policy {
         let new_size =
             LogicalSize::new(size.width, size.height).to_physical(root.global_state.scale_factor);
@@ -9,6 +9,7 @@
 
 use tracing::span::EnteredSpan;
 use tree_arena::{ArenaMut, ArenaMutList, ArenaRef};
+use vello::kurbo::Rect;
 
 use crate::core::{Widget, WidgetArena, WidgetId, WidgetState};
 use crate::util::AnyMap;
@@ -70,6 +71,38 @@ pub(crate) fn recurse_on_children(
     }
 }
 
+/// Returns the area of the window, in window coordinates, in which the children
+/// of a widget can be visible.
+///
+/// `visible_rect` is the area in which the widget itself can be visible, or `None`
+/// if none of its ancestors clips it.
+pub(crate) fn visible_rect_for_children(
+    state: &WidgetState,
+    visible_rect: Option<Rect>,
+) -> Option<Rect> {
+    let Some(clip) = state.clip_path else {
+        return visible_rect;
+    };
+    let clip = state.window_transform.transform_rect_bbox(clip);
+    Some(match visible_rect {
+        Some(visible_rect) => visible_rect.intersect(clip),
+        None => clip,
+    })
+}
+
+/// Returns `true` if a widget is entirely outside the area in which it can be visible,
+/// and can therefore be skipped by the paint and accessibility passes.
+pub(crate) fn is_clipped_out(state: &WidgetState, visible_rect: Option<Rect>) -> bool {
+    let Some(visible_rect) = visible_rect else {
+        return false;
+    };
+    let rect = state.bounding_rect;
+    rect.x1 <= visible_rect.x0
+        || rect.x0 >= visible_rect.x1
+        || rect.y1 <= visible_rect.y0
+        || rect.y0 >= visible_rect.y1
+}
+
 pub(crate) fn merge_state_up(arena: &mut WidgetArena, widget_id: WidgetId) {
     let parent_id = arena.parent_of(widget_id);
 
@@ -7,11 +7,16 @@ use tracing::{info_span, trace};
 use tree_arena::ArenaMut;
 use vello::Scene;
 use vello::kurbo::{Affine, Rect};
-use vello::peniko::{Color, Fill, Mix};
+use vello::peniko::Mix;
 
-use crate::app::{RenderRoot, RenderRootState};
-use crate::core::{DefaultProperties, PaintCtx, PropertiesRef, Widget, WidgetId, WidgetState};
-use crate::passes::{enter_span_if, recurse_on_children};
+use crate::app::{RenderRoot, RenderRootState, paint_inspector};
+use crate::core::{
+    DefaultProperties, MixBlendMode, Opacity, PaintCtx, PropertiesRef, Widget, WidgetId,
+    WidgetState,
+};
+use crate::passes::{
+    enter_span_if, is_clipped_out, recurse_on_children, visible_rect_for_children,
+};
 use crate::util::{AnyMap, get_debug_color, stroke};
 
 // --- MARK: PAINT WIDGET
@@ -23,15 +28,15 @@ fn paint_widget(
     mut widget: ArenaMut<'_, Box<dyn Widget>>,
     mut state: ArenaMut<'_, WidgetState>,
     properties: ArenaMut<'_, AnyMap>,
+    visible_rect: Option<Rect>,
     debug_paint: bool,
 ) {
     let trace = global_state.trace.paint;
     let _span = enter_span_if(trace, state.reborrow());
+    global_state.frame_stats.paint.widgets_visited += 1;
 
     let id = state.item.id;
 
-    // TODO - Handle damage regions
-    // https://github.com/linebender/xilem/issues/789
     let mut ctx = PaintCtx {
         global_state,
         widget_state: state.item,
@@ -39,7 +44,12 @@ fn paint_widget(
         widget_children: widget.children.reborrow_mut(),
         debug_paint,
     };
-    if ctx.widget_state.request_paint {
+    let props = PropertiesRef {
+        map: properties.item,
+        default_map: default_properties.for_widget(widget.item.type_id()),
+    };
+    let repainted = ctx.widget_state.request_paint;
+    if repainted {
         if trace {
             trace!("Painting widget '{}' {}", widget.item.short_type_name(), id);
         }
@@ -48,16 +58,49 @@ fn paint_widget(
         // https://github.com/linebender/xilem/issues/524
         let scene = scenes.entry(id).or_default();
         scene.reset();
-        let props = PropertiesRef {
-            map: properties.item,
-            default_map: default_properties.for_widget(widget.item.type_id()),
-        };
         widget.item.paint(&mut ctx, &props, scene);
     }
 
     state.item.request_paint = false;
     state.item.needs_paint = false;
 
+    // Both the area the widget used to cover and the one it covers now need to be redrawn
+    // if its content or its position changed.
+    let paint_rect = state
+        .item
+        .window_transform
+        .transform_rect_bbox(state.item.size.to_rect() + state.item.paint_insets);
+    if repainted || paint_rect != state.item.painted_rect {
+        global_state.damage.add_rect(state.item.painted_rect);
+        global_state.damage.add_rect(paint_rect);
+        state.item.painted_rect = paint_rect;
+    }
+    // Changing the clip changes which parts of the children are visible.
+    if state.item.clip_path.is_some()
+        && state.item.bounding_rect != state.item.painted_bounding_rect
+    {
+        global_state
+            .damage
+            .add_rect(state.item.painted_bounding_rect);
+        global_state.damage.add_rect(state.item.bounding_rect);
+    }
+    state.item.painted_bounding_rect = state.item.bounding_rect;
+
+    let opacity = *props.get::<Opacity>();
+    let blend_mode = *props.get::<MixBlendMode>();
+
+    // The widget and its children are grouped into a layer, which is then
+    // composited onto the scene with the given alpha and blend mode.
+    let has_layer = opacity.value() < 1.0 || !blend_mode.is_normal();
+    if has_layer {
+        complete_scene.push_layer(
+            blend_mode.0,
+            opacity.value(),
+            Affine::IDENTITY,
+            &state.item.bounding_rect,
+        );
+    }
+
     let clip = state.item.clip_path;
     let has_clip = clip.is_some();
     let transform = state.item.window_transform;
@@ -71,6 +114,7 @@ fn paint_widget(
 
     let id = state.item.id;
     let bounding_rect = state.item.bounding_rect;
+    let visible_rect = visible_rect_for_children(state.item, visible_rect);
     let parent_state = state.item;
     recurse_on_children(
         id,
@@ -81,12 +125,24 @@ fn paint_widget(
             // TODO - We skip painting stashed items.
             // This may lead to zombie flags in rare cases, we need to fix this.
             if state.item.is_stashed {
+                forget_painted_rects(global_state, widget, state, properties);
+                return;
+            }
+            // We skip children which are entirely clipped out, e.g. the off-screen
+            // content of a scroll area.
+            // Their paint flags are left as-is, and they aren't merged up, so that they're
+            // painted once they come into view, without requesting redraws in the meantime.
+            // TODO - Once we implement compositor layers, we may want to paint outside of
+            // the clip path anyway in anticipation of user scrolling.
+            if is_clipped_out(state.item, visible_rect) {
+                forget_painted_rects(global_state, widget, state, properties);
+                return;
+            }
+            // Fully transparent children are skipped the same way.
+            if is_transparent(default_properties, &widget, &properties) {
+                forget_painted_rects(global_state, widget, state, properties);
                 return;
             }
-            // TODO: We could skip painting children outside the parent clip path.
-            // There's a few things to consider if we do:
-            // - Some widgets can paint outside of their layout box.
-            // - Once we implement compositor layers, we may want to paint outside of the clip path anyway in anticipation of user scrolling.
             paint_widget(
                 global_state,
                 default_properties,
@@ -95,6 +151,7 @@ fn paint_widget(
                 widget,
                 state.reborrow_mut(),
                 properties,
+                visible_rect,
                 debug_paint,
             );
             parent_state.merge_up(state.item);
@@ -112,6 +169,58 @@ fn paint_widget(
     if has_clip {
         complete_scene.pop_layer();
     }
+    if has_layer {
+        complete_scene.pop_layer();
+    }
+}
+
+/// Returns `true` if the widget has an [`Opacity`] of zero, in which case
+/// neither it nor its children need to be painted.
+fn is_transparent(
+    default_properties: &DefaultProperties,
+    widget: &ArenaMut<'_, Box<dyn Widget>>,
+    properties: &ArenaMut<'_, AnyMap>,
+) -> bool {
+    let props = PropertiesRef {
+        map: &*properties.item,
+        default_map: default_properties.for_widget(widget.item.type_id()),
+    };
+    props.get::<Opacity>().is_transparent()
+}
+
+/// Damage the area covered by a subtree which is no longer painted,
+/// and reset its painted rects so that it's damaged again once it reappears.
+fn forget_painted_rects(
+    global_state: &mut RenderRootState,
+    widget: ArenaMut<'_, Box<dyn Widget>>,
+    state: ArenaMut<'_, WidgetState>,
+    properties: ArenaMut<'_, AnyMap>,
+) {
+    if state.item.painted_bounding_rect == Rect::ZERO && state.item.painted_rect == Rect::ZERO {
+        return;
+    }
+    global_state
+        .damage
+        .add_rect(state.item.painted_bounding_rect);
+    global_state.damage.add_rect(state.item.painted_rect);
+
+    fn reset(
+        mut widget: ArenaMut<'_, Box<dyn Widget>>,
+        state: ArenaMut<'_, WidgetState>,
+        properties: ArenaMut<'_, AnyMap>,
+    ) {
+        state.item.painted_rect = Rect::ZERO;
+        state.item.painted_bounding_rect = Rect::ZERO;
+        let id = state.item.id;
+        recurse_on_children(
+            id,
+            widget.reborrow_mut(),
+            state.children,
+            properties.children,
+            reset,
+        );
+    }
+    reset(widget, state, properties);
 }
 
 // --- MARK: ROOT
@@ -147,32 +256,34 @@ pub(crate) fn run_paint_pass(root: &mut RenderRoot) -> Scene {
     // This should be removed once remove_child is exclusive to MutateCtx.
     let mut scenes = std::mem::take(&mut root.global_state.scenes);
 
-    paint_widget(
-        &mut root.global_state,
-        &root.default_properties,
-        &mut complete_scene,
-        &mut scenes,
-        root_widget,
-        root_state,
-        root_properties,
-        root.debug_paint,
-    );
-    root.global_state.scenes = scenes;
-
-    // Display a rectangle over the hovered widget
-    if let Some(hovered_widget) = root.global_state.inspector_state.hovered_widget {
-        const HOVER_FILL_COLOR: Color = Color::from_rgba8(60, 60, 250, 100);
-        let state = root.widget_arena.get_state(hovered_widget).item;
-        let rect = Rect::from_origin_size(state.window_origin(), state.size);
-
-        complete_scene.fill(
-            Fill::NonZero,
-            Affine::IDENTITY,
-            HOVER_FILL_COLOR,
+    if is_transparent(&root.default_properties, &root_widget, &root_properties) {
+        forget_painted_rects(
+            &mut root.global_state,
+            root_widget,
+            root_state,
+            root_properties,
+        );
+    } else {
+        paint_widget(
+            &mut root.global_state,
+            &root.default_properties,
+            &mut complete_scene,
+            &mut scenes,
+            root_widget,
+            root_state,
+            root_properties,
             None,
-            &rect,
+            root.debug_paint,
         );
     }
+    root.global_state.scenes = scenes;
+
+    // Debug paint draws over every widget's bounding rect, so we don't try to track it.
+    if root.debug_paint {
+        root.global_state.damage_all = true;
+    }
+
+    paint_inspector(root, &mut complete_scene);
 
     complete_scene
 }
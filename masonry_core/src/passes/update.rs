@@ -10,8 +10,9 @@ use ui_events::pointer::PointerType;
 
 use crate::app::{RenderRoot, RenderRootSignal, RenderRootState};
 use crate::core::{
-    DefaultProperties, Ime, PointerEvent, PointerInfo, PropertiesMut, PropertiesRef, QueryCtx,
-    RegisterCtx, TextEvent, Update, UpdateCtx, Widget, WidgetId, WidgetState,
+    DefaultProperties, FocusScope, Ime, PointerEvent, PointerInfo, PropertiesMut, PropertiesRef,
+    QueryCtx, RegisterCtx, RovingFocus, TextEvent, Update, UpdateCtx, Widget, WidgetId,
+    WidgetState,
 };
 use crate::passes::event::{run_on_pointer_event_pass, run_on_text_event_pass};
 use crate::passes::{enter_span, enter_span_if, merge_state_up, recurse_on_children};
@@ -502,6 +503,17 @@ pub(crate) fn run_update_focus_chain_pass(root: &mut RenderRoot) {
 /// See the [focus status documentation](../doc/06_masonry_concepts.md#text-focus).
 pub(crate) fn run_update_focus_pass(root: &mut RenderRoot) {
     let _span = info_span!("update_focus").entered();
+    // Requests to focus a subtree are resolved here, since the focus chain is up-to-date.
+    if let Some(subtree) = root.global_state.next_focused_subtree.take()
+        && root.is_still_interactive(subtree)
+    {
+        let first = root
+            .focus_order_within(Some(subtree), None)
+            .first()
+            .copied();
+        root.global_state.next_focused_widget = Some(first.unwrap_or(subtree));
+    }
+
     // If the next-focused widget is disabled, stashed or removed, we set
     // the focused id to None
     if let Some(id) = root.global_state.next_focused_widget {
@@ -510,6 +522,20 @@ pub(crate) fn run_update_focus_pass(root: &mut RenderRoot) {
         }
     }
 
+    // If the focus scope containing the focused widget was closed, focus goes back
+    // to where it was before entering the scope.
+    // Otherwise, focus can't leave an open focus scope by being cleared.
+    if let Some(prev_focused) = root.global_state.focused_widget {
+        let next_focused = root.global_state.next_focused_widget;
+        if next_focused.is_none() || next_focused == Some(prev_focused) {
+            if let Some(origin) = closed_scope_origin(root) {
+                root.global_state.next_focused_widget = origin;
+            } else if next_focused.is_none() {
+                root.global_state.next_focused_widget = focus_in_scope(root, prev_focused);
+            }
+        }
+    }
+
     let prev_focused = root.global_state.focused_widget;
     let was_ime_active = root.global_state.is_ime_active;
 
@@ -649,10 +675,99 @@ pub(crate) fn run_update_focus_pass(root: &mut RenderRoot) {
         }
     }
 
+    update_focus_scopes(root, prev_focused, &prev_focused_path, &next_focused_path);
+
     root.global_state.focused_widget = next_focused;
     root.global_state.focused_path = next_focused_path;
 }
 
+/// Returns `true` if the widget is a [`FocusScope`] which is still open.
+///
+/// A scope is closed when it's removed, stashed or disabled, or when it loses its property.
+fn is_open_scope(root: &RenderRoot, id: WidgetId) -> bool {
+    root.is_still_interactive(id)
+        && root
+            .widget_arena
+            .properties
+            .find(id)
+            .is_some_and(|properties| properties.item.contains::<FocusScope>())
+}
+
+/// If a [`FocusScope`] containing the focused widget was closed, returns the widget which
+/// had focus before entering the outermost such scope.
+fn closed_scope_origin(root: &RenderRoot) -> Option<Option<WidgetId>> {
+    let origins = &root.global_state.focus_scope_origins;
+    // The focused path starts with the focused widget, so we iterate it in reverse
+    // to find the outermost scope.
+    let closed_scope = root
+        .global_state
+        .focused_path
+        .iter()
+        .rev()
+        .find(|id| origins.contains_key(*id) && !is_open_scope(root, **id))?;
+    Some(origins[closed_scope].filter(|origin| root.is_still_interactive(*origin)))
+}
+
+/// Returns the widget which gets focus when `prev_focused`, inside an open [`FocusScope`],
+/// loses focus without another widget getting it.
+///
+/// Focus stays in the innermost scope: on the scope itself if focus was cleared,
+/// or on its first widget if `prev_focused` went away.
+fn focus_in_scope(root: &RenderRoot, prev_focused: WidgetId) -> Option<WidgetId> {
+    let origins = &root.global_state.focus_scope_origins;
+    let scope = *root
+        .global_state
+        .focused_path
+        .iter()
+        .find(|id| origins.contains_key(*id))?;
+    if root.is_still_interactive(prev_focused) {
+        return Some(scope);
+    }
+    let first = root.focus_order_within(Some(scope), None).first().copied();
+    Some(first.unwrap_or(scope))
+}
+
+/// Remembers where focus came from for the [`FocusScope`]s it entered,
+/// and the focused item of its [`RovingFocus`] group.
+fn update_focus_scopes(
+    root: &mut RenderRoot,
+    prev_focused: Option<WidgetId>,
+    prev_focused_path: &[WidgetId],
+    next_focused_path: &[WidgetId],
+) {
+    let global_state = &mut root.global_state;
+    global_state
+        .focus_scope_origins
+        .retain(|scope, _| next_focused_path.contains(scope));
+    for &id in next_focused_path {
+        if prev_focused_path.contains(&id) {
+            continue;
+        }
+        let Some(properties) = root.widget_arena.properties.find(id) else {
+            continue;
+        };
+        if properties.item.contains::<FocusScope>() {
+            global_state.focus_scope_origins.insert(id, prev_focused);
+        }
+    }
+
+    if let Some(&focused) = next_focused_path.first() {
+        let group = next_focused_path.iter().find(|id| {
+            root.widget_arena
+                .properties
+                .find(**id)
+                .is_some_and(|properties| properties.item.contains::<RovingFocus>())
+        });
+        if let Some(&group) = group {
+            global_state.roving_focus_items.insert(group, focused);
+        }
+    }
+    let widget_arena = &root.widget_arena;
+    global_state
+        .roving_focus_items
+        .retain(|group, _| widget_arena.has(*group));
+}
+
 // ----------------
 
 // --- MARK: SCROLL
@@ -696,7 +811,11 @@ pub(crate) fn run_update_pointer_pass(root: &mut RenderRoot) {
 
     root.global_state.needs_pointer_pass = false;
 
-    let pointer_pos = root.last_mouse_pos.map(|pos| (pos.x, pos.y).into());
+    // Widgets under the inspector panel can't be hovered.
+    let pointer_pos = root
+        .last_mouse_pos
+        .map(|pos| (pos.x, pos.y).into())
+        .filter(|pos| !root.global_state.inspector_state.covers(*pos));
 
     if root.global_state.inspector_state.is_picking_widget {
         if let Some(pos) = pointer_pos {
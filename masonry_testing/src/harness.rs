@@ -14,13 +14,15 @@ use oxipng::{Options, optimize_from_memory};
 use tracing::debug;
 
 use masonry_core::Handled;
+use masonry_core::accesskit::{self, TreeUpdate};
 use masonry_core::app::{
-    RenderRoot, RenderRootOptions, RenderRootSignal, WindowSizePolicy, try_init_test_tracing,
+    DamageRegion, FrameStats, RenderRoot, RenderRootOptions, RenderRootSignal, WidgetSnapshot,
+    WindowSizePolicy, try_init_test_tracing,
 };
 use masonry_core::core::{
     Action, DefaultProperties, Ime, PointerButton, PointerEvent, PointerId, PointerInfo,
-    PointerState, PointerType, PointerUpdate, ScrollDelta, TextEvent, Widget, WidgetId, WidgetMut,
-    WidgetRef, WindowEvent,
+    PointerState, PointerType, PointerUpdate, ScrollDelta, Shortcut, ShortcutId, TextEvent, Widget,
+    WidgetId, WidgetMut, WidgetRef, WindowEvent,
 };
 use masonry_core::core::{Properties, WidgetPod};
 use masonry_core::cursor_icon::CursorIcon;
@@ -72,6 +74,8 @@ pub const PRIMARY_MOUSE: PointerInfo = PointerInfo {
 /// You can do that last part in a few different ways.
 /// You can get a [`WidgetRef`] to a specific widget through methods like [`try_get_widget`](Self::try_get_widget).
 /// [`WidgetRef`] implements `Debug`, so you can check the state of an entire tree with something like the [`insta`] crate.
+/// For a more detailed view, including layout rects, state flags and properties, use
+/// [`tree_snapshot`](Self::tree_snapshot); snapshots can also be diffed against each other.
 ///
 /// You can also render the widget tree directly with the [`render`](Self::render) method.
 /// Masonry also provides the [`assert_render_snapshot`] macro, which performs snapshot testing on the
@@ -135,6 +139,7 @@ pub struct TestHarness {
     window_size: PhysicalSize<u32>,
     background_color: Color,
     action_queue: VecDeque<(Action, WidgetId)>,
+    shortcut_queue: VecDeque<ShortcutId>,
     has_ime_session: bool,
     ime_rect: (LogicalPosition<f64>, LogicalSize<f64>),
     title: String,
@@ -247,6 +252,21 @@ impl TestHarness {
         root_widget: impl Widget,
         root_widget_props: Properties,
         params: TestHarnessParams,
+    ) -> Self {
+        Self::create_with_pod(
+            default_props,
+            WidgetPod::new_with_props(root_widget, root_widget_props).erased(),
+            params,
+        )
+    }
+
+    /// Builds harness with a root widget already wrapped in a [`WidgetPod`].
+    ///
+    /// This is useful for frameworks built on top of Masonry, which create their own widget pods.
+    pub fn create_with_pod(
+        default_props: DefaultProperties,
+        root_widget: WidgetPod<dyn Widget>,
+        params: TestHarnessParams,
     ) -> Self {
         let mouse_state = PointerState::default();
         let window_size = PhysicalSize::new(
@@ -272,7 +292,7 @@ impl TestHarness {
         let mut harness = Self {
             signal_receiver,
             render_root: RenderRoot::new(
-                WidgetPod::new_with_props(root_widget, root_widget_props).erased(),
+                root_widget,
                 move |signal| signal_sender.send(signal).unwrap(),
                 RenderRootOptions {
                     // TODO - Pass the default property set as an input instead.
@@ -288,6 +308,7 @@ impl TestHarness {
             window_size,
             background_color: params.background_color,
             action_queue: VecDeque::new(),
+            shortcut_queue: VecDeque::new(),
             has_ime_session: false,
             ime_rect: Default::default(),
             title: String::new(),
@@ -361,11 +382,32 @@ impl TestHarness {
                 RenderRootSignal::Exit => (),
                 RenderRootSignal::ShowWindowMenu(_) => (),
                 RenderRootSignal::WidgetSelectedInInspector(_) => (),
+                RenderRootSignal::Shortcut(shortcut_id) => {
+                    self.shortcut_queue.push_back(shortcut_id);
+                }
             }
         }
     }
 
     // --- MARK: RENDER
+    /// Runs the paint and accessibility passes without rendering the resulting scene,
+    /// and updates the `accesskit_consumer` tree.
+    ///
+    /// Returns the region of the window which changed since the last redraw.
+    pub fn redraw(&mut self) -> DamageRegion {
+        let (_, tree_update) = self.render_root.redraw();
+        self.update_access_tree(tree_update);
+        self.render_root.damage().clone()
+    }
+
+    fn update_access_tree(&mut self, tree_update: TreeUpdate) {
+        if let Some(access_tree) = &mut self.access_tree {
+            access_tree.update_and_process_changes(tree_update, &mut NoOpTreeChangeHandler);
+        } else {
+            self.access_tree = Some(accesskit_consumer::Tree::new(tree_update, false));
+        }
+    }
+
     // TODO - We add way too many dependencies in this code
     // TODO - Should be async?
     /// Renders the window into an image and updates the `accesskit_consumer` tree.
@@ -373,11 +415,7 @@ impl TestHarness {
     /// The returned image contains a bitmap (an array of pixels) as an 8-bits-per-channel RGB image.
     pub fn render(&mut self) -> RgbaImage {
         let (scene, tree_update) = self.render_root.redraw();
-        if let Some(access_tree) = &mut self.access_tree {
-            access_tree.update_and_process_changes(tree_update, &mut NoOpTreeChangeHandler);
-        } else {
-            self.access_tree = Some(accesskit_consumer::Tree::new(tree_update, false));
-        }
+        self.update_access_tree(tree_update);
         if std::env::var("SKIP_RENDER_TESTS").is_ok_and(|it| !it.is_empty()) {
             return RgbaImage::from_pixel(1, 1, Rgba([255, 255, 255, 255]));
         }
@@ -644,6 +682,13 @@ impl TestHarness {
             .find_widget_by_id(self.render_root.focused_widget()?)
     }
 
+    /// Return the widgets which <kbd>Tab</kbd> navigation goes through, in order.
+    ///
+    /// See [`RenderRoot::focus_order`] for details.
+    pub fn focus_order(&self) -> Vec<WidgetId> {
+        self.render_root.focus_order()
+    }
+
     /// Return a [`WidgetRef`] to the widget which [captures pointer events](masonry_core::doc::doc_06_masonry_concepts#pointer-capture).
     pub fn pointer_capture_target(&self) -> Option<WidgetRef<'_, dyn Widget>> {
         self.render_root
@@ -656,6 +701,67 @@ impl TestHarness {
         self.render_root.pointer_capture_target()
     }
 
+    /// Return the first widget, in depth-first order, whose debug text is `text`.
+    ///
+    /// See [`Widget::get_debug_text`].
+    pub fn find_widget_by_debug_text(&self, text: &str) -> Option<WidgetRef<'_, dyn Widget>> {
+        fn find<'w>(
+            widget: WidgetRef<'w, dyn Widget>,
+            text: &str,
+        ) -> Option<WidgetRef<'w, dyn Widget>> {
+            if widget.get_debug_text().as_deref() == Some(text) {
+                return Some(widget);
+            }
+            widget
+                .children()
+                .into_iter()
+                .find_map(|child| find(child, text))
+        }
+        find(self.root_widget(), text)
+    }
+
+    /// Return the first widget, in depth-first order, whose accessibility label is `name`.
+    ///
+    /// This runs the accessibility pass first, so that the labels are up-to-date.
+    pub fn find_widget_by_accessibility_name(
+        &mut self,
+        name: &str,
+    ) -> Option<WidgetRef<'_, dyn Widget>> {
+        fn find<'w>(
+            widget: WidgetRef<'w, dyn Widget>,
+            tree: &accesskit_consumer::Tree,
+            name: &str,
+        ) -> Option<WidgetRef<'w, dyn Widget>> {
+            // Some widgets, such as buttons, expose their name as their value.
+            let matches = tree
+                .state()
+                .node_by_id(widget.id().into())
+                .is_some_and(|node| {
+                    node.label().as_deref() == Some(name) || node.value().as_deref() == Some(name)
+                });
+            if matches {
+                return Some(widget);
+            }
+            widget
+                .children()
+                .into_iter()
+                .find_map(|child| find(child, tree, name))
+        }
+        self.redraw();
+        let tree = self.access_tree.as_ref()?;
+        find(self.render_root.get_root_widget(), tree, name)
+    }
+
+    /// Return the accessibility node of the widget with the given id, if it has one.
+    ///
+    /// This runs the accessibility pass first, so that the node is up-to-date.
+    pub fn access_node(&mut self, id: WidgetId) -> Option<accesskit::Node> {
+        self.redraw();
+        let tree = self.access_tree.as_ref()?;
+        let node = tree.state().node_by_id(id.into())?;
+        Some(node.data().clone())
+    }
+
     /// Call the provided visitor on every widget in the widget tree.
     pub fn inspect_widgets(&mut self, f: impl Fn(WidgetRef<'_, dyn Widget>) + 'static) {
         fn inspect(
@@ -693,6 +799,36 @@ impl TestHarness {
         ret
     }
 
+    /// Make the fonts in `data` available to the widgets.
+    ///
+    /// See [`RenderRoot::register_fonts`].
+    pub fn register_fonts(&mut self, data: Blob<u8>) {
+        drop(self.render_root.register_fonts(data));
+    }
+
+    /// Run `f` on the [`RenderRoot`] of the simulated window.
+    ///
+    /// This is meant for frameworks built on top of Masonry, which drive the render root
+    /// themselves, e.g. to rebuild the widget tree.
+    /// Signals emitted while `f` runs, such as actions, are processed afterwards.
+    pub fn edit_render_root<R>(&mut self, f: impl FnOnce(&mut RenderRoot) -> R) -> R {
+        let ret = f(&mut self.render_root);
+        self.process_signals();
+        ret
+    }
+
+    /// Register a shortcut which applies to the whole window.
+    ///
+    /// See [`RenderRoot::register_shortcut`].
+    pub fn register_shortcut(&mut self, shortcut: Shortcut) -> ShortcutId {
+        self.render_root.register_shortcut(shortcut)
+    }
+
+    /// Pop the oldest [`Shortcut`] triggered by a key event.
+    pub fn pop_shortcut(&mut self) -> Option<ShortcutId> {
+        self.shortcut_queue.pop_front()
+    }
+
     /// Pop the oldest [`Action`] emitted by the widget tree.
     pub fn pop_action(&mut self) -> Option<(Action, WidgetId)> {
         self.action_queue.pop_front()
@@ -730,6 +866,44 @@ impl TestHarness {
         self.title.clone()
     }
 
+    /// Return the number of times [`Widget::layout`] has been called since the harness was created.
+    ///
+    /// See [`RenderRoot::layout_count`].
+    pub fn layout_count(&self) -> u64 {
+        self.render_root.layout_count()
+    }
+
+    /// Return statistics about the work done to produce the last frame drawn by [`redraw`](Self::redraw).
+    ///
+    /// See [`RenderRoot::last_frame_stats`].
+    pub fn last_frame_stats(&self) -> Option<&FrameStats> {
+        self.render_root.last_frame_stats()
+    }
+
+    /// Take a snapshot of the whole widget tree.
+    ///
+    /// Compare two snapshots with [`WidgetSnapshot::diff`] to see what changed between them,
+    /// or check a snapshot against a stored one with [`WidgetSnapshot::to_stable_string`]:
+    ///
+    /// ```rust,ignore
+    /// insta::assert_snapshot!(harness.tree_snapshot().to_stable_string());
+    /// ```
+    pub fn tree_snapshot(&self) -> WidgetSnapshot {
+        self.render_root.tree_snapshot()
+    }
+
+    /// Return `true` if the widget inspector is shown.
+    ///
+    /// See [`RenderRoot::set_inspector_open`].
+    pub fn is_inspector_open(&self) -> bool {
+        self.render_root.is_inspector_open()
+    }
+
+    /// Return the id of the widget selected in the widget inspector, if any.
+    pub fn inspected_widget(&self) -> Option<WidgetId> {
+        self.render_root.inspected_widget()
+    }
+
     // --- MARK: SNAPSHOT
 
     /// Method used by [`assert_render_snapshot`] and [`assert_failing_render_snapshot`]. Use these macros, not this method.
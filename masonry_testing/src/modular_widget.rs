@@ -7,9 +7,9 @@ use tracing::trace_span;
 
 use masonry_core::accesskit::{Node, Role};
 use masonry_core::core::{
-    AccessCtx, AccessEvent, BoxConstraints, ComposeCtx, EventCtx, LayoutCtx, PaintCtx,
-    PointerEvent, PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx, TextEvent, Update,
-    UpdateCtx, Widget, WidgetId, WidgetRef, find_widget_under_pointer,
+    AccessCtx, AccessEvent, Axis, BoxConstraints, ComposeCtx, EventCtx, IntrinsicSize, LayoutCtx,
+    MeasureCtx, PaintCtx, PointerEvent, PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx,
+    TextEvent, Update, UpdateCtx, Widget, WidgetId, WidgetRef, find_widget_under_pointer,
 };
 use masonry_core::cursor_icon::CursorIcon;
 use masonry_core::kurbo::{Point, Size};
@@ -27,6 +27,13 @@ pub type UpdateFn<S> = dyn FnMut(&mut S, &mut UpdateCtx<'_>, &mut PropertiesMut<
 pub type PropertyChangeFn<S> = dyn FnMut(&mut S, &mut UpdateCtx<'_>, TypeId);
 pub type LayoutFn<S> =
     dyn FnMut(&mut S, &mut LayoutCtx<'_>, &mut PropertiesMut<'_>, &BoxConstraints) -> Size;
+pub type MeasureFn<S> = dyn FnMut(
+    &mut S,
+    &mut MeasureCtx<'_>,
+    &PropertiesRef<'_>,
+    Axis,
+    Option<f64>,
+) -> Option<IntrinsicSize>;
 pub type ComposeFn<S> = dyn FnMut(&mut S, &mut ComposeCtx<'_>);
 pub type PaintFn<S> = dyn FnMut(&mut S, &mut PaintCtx<'_>, &PropertiesRef<'_>, &mut Scene);
 pub type RoleFn<S> = dyn Fn(&S) -> Role;
@@ -49,6 +56,7 @@ pub struct ModularWidget<S> {
     update: Option<Box<UpdateFn<S>>>,
     property_change: Option<Box<PropertyChangeFn<S>>>,
     layout: Option<Box<LayoutFn<S>>>,
+    measure: Option<Box<MeasureFn<S>>>,
     compose: Option<Box<ComposeFn<S>>>,
     paint: Option<Box<PaintFn<S>>>,
     role: Option<Box<RoleFn<S>>>,
@@ -75,6 +83,7 @@ impl<S> ModularWidget<S> {
             update: None,
             property_change: None,
             layout: None,
+            measure: None,
             compose: None,
             paint: None,
             role: None,
@@ -184,6 +193,22 @@ impl<S> ModularWidget<S> {
         self
     }
 
+    /// See [`Widget::measure`]
+    pub fn measure_fn(
+        mut self,
+        f: impl FnMut(
+            &mut S,
+            &mut MeasureCtx<'_>,
+            &PropertiesRef<'_>,
+            Axis,
+            Option<f64>,
+        ) -> Option<IntrinsicSize>
+        + 'static,
+    ) -> Self {
+        self.measure = Some(Box::new(f));
+        self
+    }
+
     /// See [`Widget::compose`]
     pub fn compose_fn(mut self, f: impl FnMut(&mut S, &mut ComposeCtx<'_>) + 'static) -> Self {
         self.compose = Some(Box::new(f));
@@ -301,6 +326,19 @@ impl<S: 'static> Widget for ModularWidget<S> {
             .unwrap_or_else(|| Size::new(100., 100.))
     }
 
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        props: &PropertiesRef<'_>,
+        axis: Axis,
+        cross_length: Option<f64>,
+    ) -> Option<IntrinsicSize> {
+        let Self { state, measure, .. } = self;
+        measure
+            .as_mut()
+            .and_then(|f| f(state, ctx, props, axis, cross_length))
+    }
+
     fn compose(&mut self, ctx: &mut ComposeCtx<'_>) {
         if let Some(f) = self.compose.as_mut() {
             f(&mut self.state, ctx);
@@ -15,9 +15,9 @@ use std::rc::Rc;
 
 use masonry_core::accesskit::{Node, Role};
 use masonry_core::core::{
-    AccessCtx, AccessEvent, BoxConstraints, ComposeCtx, EventCtx, LayoutCtx, PaintCtx,
-    PointerEvent, PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx, TextEvent, Update,
-    UpdateCtx, Widget, WidgetId, WidgetRef,
+    AccessCtx, AccessEvent, Axis, BoxConstraints, ComposeCtx, EventCtx, IntrinsicSize, LayoutCtx,
+    MeasureCtx, PaintCtx, PointerEvent, PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx,
+    TextEvent, Update, UpdateCtx, Widget, WidgetId, WidgetRef,
 };
 use masonry_core::cursor_icon::CursorIcon;
 use masonry_core::kurbo::{Point, Size};
@@ -78,6 +78,8 @@ pub enum Record {
     PC(TypeId),
     /// Layout. Records the size returned by the layout method.
     Layout(Size),
+    /// Measure. Records the axis and the value returned by the measure method.
+    Measure(Axis, Option<IntrinsicSize>),
     /// Compose.
     Compose,
     /// Paint.
@@ -197,6 +199,18 @@ impl<W: Widget> Widget for Recorder<W> {
         size
     }
 
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        props: &PropertiesRef<'_>,
+        axis: Axis,
+        cross_length: Option<f64>,
+    ) -> Option<IntrinsicSize> {
+        let size = self.child.measure(ctx, props, axis, cross_length);
+        self.recording.push(Record::Measure(axis, size));
+        size
+    }
+
     fn compose(&mut self, ctx: &mut ComposeCtx<'_>) {
         self.recording.push(Record::Compose);
         self.child.compose(ctx);
@@ -7,7 +7,7 @@ use std::num::NonZeroU64;
 use std::sync::atomic::{AtomicU64, Ordering};
 
 use masonry_core::app::RenderRoot;
-use masonry_core::core::{Action, Widget, WidgetId, WidgetPod};
+use masonry_core::core::{Action, ShortcutId, Widget, WidgetId, WidgetPod};
 use tracing::field::DisplayValue;
 use winit::event_loop::ActiveEventLoop;
 use winit::window::{Window as WindowHandle, WindowAttributes};
@@ -68,6 +68,17 @@ pub trait AppDriver {
         action: Action,
     );
 
+    /// A hook which will be executed when a [`Shortcut`](masonry_core::core::Shortcut) is triggered.
+    ///
+    /// See [`RenderRoot::register_shortcut`] and [`Shortcuts`](masonry_core::core::Shortcuts).
+    fn on_shortcut(
+        &mut self,
+        window_id: WindowId,
+        ctx: &mut DriverCtx<'_, '_>,
+        shortcut_id: ShortcutId,
+    ) {
+    }
+
     /// A hook which will be executed when the application starts, to allow initial configuration of the `MasonryState`.
     ///
     /// Use cases include loading fonts.
@@ -77,6 +88,11 @@ pub trait AppDriver {
     fn on_close_requested(&mut self, window_id: WindowId, ctx: &mut DriverCtx<'_, '_>) {
         ctx.exit();
     }
+
+    /// A hook called when the event loop is exiting, however it was stopped.
+    ///
+    /// The windows are still open at this point.
+    fn on_exit(&mut self, ctx: &mut DriverCtx<'_, '_>) {}
 }
 
 impl DriverCtx<'_, '_> {
@@ -11,7 +11,7 @@ use std::sync::{Arc, mpsc};
 use accesskit_winit::Adapter;
 use masonry_core::app::{RenderRoot, RenderRootOptions, RenderRootSignal, WindowSizePolicy};
 use masonry_core::core::{DefaultProperties, TextEvent, Widget, WidgetId, WidgetPod, WindowEvent};
-use masonry_core::kurbo::Affine;
+use masonry_core::kurbo::{Affine, Size};
 use masonry_core::peniko::Color;
 use masonry_core::util::Instant;
 use masonry_core::vello::util::{RenderContext, RenderSurface};
@@ -27,6 +27,7 @@ use winit::window::{Window as WindowHandle, WindowAttributes, WindowId as Handle
 
 use crate::app::{AppDriver, DriverCtx, masonry_resize_direction_to_winit, winit_ime_to_masonry};
 use crate::app_driver::WindowId;
+use crate::perf_hud::paint_perf_hud;
 
 #[derive(Debug)]
 pub enum MasonryUserEvent {
@@ -74,6 +75,8 @@ pub(crate) struct Window {
     pub(crate) state: WindowState,
     event_reducer: WindowEventReducer,
     pub(crate) render_root: RenderRoot,
+    /// Whether the frame time graph is painted over the window's content.
+    pub(crate) show_perf_hud: bool,
 }
 
 impl Window {
@@ -86,6 +89,7 @@ impl Window {
     ) -> Self {
         // TODO: We can't know this scale factor until later?
         let scale_factor = 1.0;
+        let show_perf_hud = std::env::var("MASONRY_PERF_HUD").is_ok_and(|it| !it.is_empty());
 
         Self {
             id: window_id,
@@ -104,6 +108,7 @@ impl Window {
                     test_font: None,
                 },
             ),
+            show_perf_hud,
         }
     }
 }
@@ -250,7 +255,8 @@ impl ApplicationHandler<MasonryUserEvent> for MainState<'_> {
     }
 
     fn exiting(&mut self, event_loop: &winit::event_loop::ActiveEventLoop) {
-        self.masonry_state.handle_exiting(event_loop);
+        self.masonry_state
+            .handle_exiting(event_loop, self.app_driver.as_mut());
     }
 
     fn memory_warning(&mut self, event_loop: &winit::event_loop::ActiveEventLoop) {
@@ -444,7 +450,7 @@ impl MasonryState<'_> {
     fn render(
         surface: &mut RenderSurface<'_>,
         window: &mut Window,
-        scene: Scene,
+        mut scene: Scene,
         render_cx: &RenderContext,
         renderer: &mut Option<Renderer>,
     ) {
@@ -452,6 +458,7 @@ impl MasonryState<'_> {
             tracing::warn!("Tried to render whilst suspended or before window created");
             return;
         };
+        let start = Instant::now();
         let scale_factor = handle.scale_factor();
         // https://github.com/rust-windowing/winit/issues/2308
         #[cfg(target_os = "ios")]
@@ -461,10 +468,30 @@ impl MasonryState<'_> {
         let width = size.width;
         let height = size.height;
 
-        if surface.config.width != width || surface.config.height != height {
+        let resized = surface.config.width != width || surface.config.height != height;
+        if resized {
             render_cx.resize_surface(surface, width, height);
         }
 
+        // Vello can't re-render part of a texture, so a frame with any damage is rendered
+        // in full. But if nothing changed, the target texture already holds the current frame,
+        // and we only need to present it again.
+        let needs_render = resized || renderer.is_none() || !window.render_root.damage().is_empty();
+
+        // The graph changes with every frame, so it's always re-rendered.
+        let needs_render = if window.show_perf_hud {
+            let logical_size = size.to_logical::<f64>(scale_factor);
+            let window_size = Size::new(logical_size.width, logical_size.height);
+            paint_perf_hud(
+                &mut scene,
+                window_size,
+                window.render_root.frame_stats_history(),
+            );
+            true
+        } else {
+            needs_render
+        };
+
         let transformed_scene = if scale_factor == 1.0 {
             None
         } else {
@@ -489,33 +516,35 @@ impl MasonryState<'_> {
         };
 
         let _render_span = tracing::info_span!("Rendering using Vello").entered();
-        renderer
-            .get_or_insert_with(|| {
-                #[cfg_attr(not(feature = "tracy"), expect(unused_mut, reason = "cfg"))]
-                let mut renderer = Renderer::new(device, renderer_options).unwrap();
-                #[cfg(feature = "tracy")]
-                {
-                    let new_profiler = wgpu_profiler::GpuProfiler::new_with_tracy_client(
-                        wgpu_profiler::GpuProfilerSettings::default(),
-                        // We don't have access to the adapter until we get  https://github.com/linebender/vello/pull/634
-                        // Luckily, this `backend` is only used for visual display in the profiling, so we can just guess here
-                        wgpu::Backend::Vulkan,
-                        device,
-                        queue,
-                    )
-                    .unwrap_or(renderer.profiler);
-                    renderer.profiler = new_profiler;
-                }
-                renderer
-            })
-            .render_to_texture(
-                device,
-                queue,
-                scene_ref,
-                &surface.target_view,
-                &render_params,
-            )
-            .expect("failed to render to surface");
+        if needs_render {
+            renderer
+                .get_or_insert_with(|| {
+                    #[cfg_attr(not(feature = "tracy"), expect(unused_mut, reason = "cfg"))]
+                    let mut renderer = Renderer::new(device, renderer_options).unwrap();
+                    #[cfg(feature = "tracy")]
+                    {
+                        let new_profiler = wgpu_profiler::GpuProfiler::new_with_tracy_client(
+                            wgpu_profiler::GpuProfilerSettings::default(),
+                            // We don't have access to the adapter until we get  https://github.com/linebender/vello/pull/634
+                            // Luckily, this `backend` is only used for visual display in the profiling, so we can just guess here
+                            wgpu::Backend::Vulkan,
+                            device,
+                            queue,
+                        )
+                        .unwrap_or(renderer.profiler);
+                        renderer.profiler = new_profiler;
+                    }
+                    renderer
+                })
+                .render_to_texture(
+                    device,
+                    queue,
+                    scene_ref,
+                    &surface.target_view,
+                    &render_params,
+                )
+                .expect("failed to render to surface");
+        }
 
         let Ok(surface_texture) = surface.surface.get_current_texture() else {
             tracing::error!("failed to acquire next swapchain texture");
@@ -542,6 +571,7 @@ impl MasonryState<'_> {
                 tracing::info_span!("Waiting for GPU to finish rendering").entered();
             device.poll(wgpu::Maintain::Wait);
         }
+        window.render_root.record_render_time(start.elapsed());
     }
 
     // --- MARK: WINDOW_EVENT
@@ -732,7 +762,9 @@ impl MasonryState<'_> {
 
     pub fn handle_new_events(&mut self, _: &ActiveEventLoop, _: winit::event::StartCause) {}
 
-    pub fn handle_exiting(&mut self, _: &ActiveEventLoop) {}
+    pub fn handle_exiting(&mut self, event_loop: &ActiveEventLoop, app_driver: &mut dyn AppDriver) {
+        app_driver.on_exit(&mut DriverCtx::new(self, event_loop));
+    }
 
     pub fn handle_memory_warning(&mut self, _: &ActiveEventLoop) {}
 
@@ -833,6 +865,15 @@ impl MasonryState<'_> {
                     };
                     info!("Widget selected in inspector: {widget_id} - {display_name}");
                 }
+                RenderRootSignal::Shortcut(shortcut_id) => {
+                    let window_id = window.id;
+                    debug!("Shortcut {shortcut_id} triggered");
+                    app_driver.on_shortcut(
+                        window_id,
+                        &mut DriverCtx::new(self, event_loop),
+                        shortcut_id,
+                    );
+                }
             }
         }
 
@@ -901,6 +942,17 @@ impl MasonryState<'_> {
             .map(|window| &mut window.render_root)
     }
 
+    /// Show or hide the graph of recent frame times in the given window.
+    ///
+    /// The graph is shown by default if the `MASONRY_PERF_HUD` environment variable is set.
+    pub fn set_perf_hud(&mut self, window_id: WindowId, show: bool) {
+        let window = self.window_mut(window_id);
+        window.show_perf_hud = show;
+        if let WindowState::Rendering { handle, .. } = &window.state {
+            handle.request_redraw();
+        }
+    }
+
     pub fn set_present_mode(&mut self, window_id: WindowId, present_mode: wgpu::PresentMode) {
         let handle_id = self.handle_id(window_id);
         let surface = self.surfaces.get_mut(&handle_id).unwrap();
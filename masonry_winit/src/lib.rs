@@ -104,9 +104,12 @@
 //!
 //! ### Debugging features
 //!
-//! Masonry apps currently ship with two debugging features built in:
-//! - A rudimentary widget inspector - toggled by F11 key.
-//! - A debug mode painting widget layout rectangles - toggled by F12 key.
+//! Masonry apps currently ship with four debugging features built in:
+//! - A widget inspector, showing the widget tree and the layout, state and properties of the selected widget - toggled by F12 key.
+//! - A widget picker, selecting the next widget clicked in the inspector - toggled by F11 key.
+//! - A debug mode painting widget layout rectangles - toggled by Shift+F12.
+//! - A graph of the time spent in each pass for recent frames - enabled by setting the `MASONRY_PERF_HUD` environment variable,
+//!   or with `MasonryState::set_perf_hud`.
 //!
 //! [winit]: https://crates.io/crates/winit
 //! [Druid]: https://crates.io/crates/druid
@@ -141,6 +144,7 @@
 mod app_driver;
 mod convert_winit_event;
 mod event_loop_runner;
+mod perf_hud;
 
 pub use winit;
 
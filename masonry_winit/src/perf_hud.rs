@@ -0,0 +1,91 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! An overlay showing a graph of recent frame times.
+
+use masonry_core::app::{FRAME_STATS_HISTORY_LEN, FrameStats};
+use masonry_core::kurbo::{Affine, Line, Rect, Size, Stroke};
+use masonry_core::peniko::{Color, Fill};
+use masonry_core::util::Duration;
+use masonry_core::vello::Scene;
+
+/// Distance between the graph and the bottom-left corner of the window.
+const MARGIN: f64 = 8.;
+const BAR_WIDTH: f64 = 1.;
+const GRAPH_HEIGHT: f64 = 80.;
+/// Frame time shown at the top of the graph.
+const GRAPH_MAX: Duration = Duration::from_micros(33_333);
+/// Frame budget at 60 fps, drawn as a horizontal line.
+const FRAME_BUDGET: Duration = Duration::from_micros(16_667);
+
+const BACKGROUND_COLOR: Color = Color::from_rgba8(0, 0, 0, 180);
+const BUDGET_COLOR: Color = Color::from_rgba8(255, 255, 255, 160);
+const LAYOUT_COLOR: Color = Color::from_rgb8(0x4c, 0x9a, 0xff);
+const COMPOSE_COLOR: Color = Color::from_rgb8(0x9b, 0x6c, 0xff);
+const PAINT_COLOR: Color = Color::from_rgb8(0x3c, 0xd0, 0x70);
+const ACCESSIBILITY_COLOR: Color = Color::from_rgb8(0xff, 0xc8, 0x3c);
+const RENDER_COLOR: Color = Color::from_rgb8(0xff, 0x5c, 0x5c);
+
+/// Paint a graph of the given frames into `scene`, in the bottom-left corner of a window of
+/// size `window_size`.
+///
+/// Each frame is a bar, with one segment per pass, from bottom to top: layout (blue),
+/// compose (purple), paint (green), accessibility (yellow) and render (red).
+///
+/// Returns the area covered by the graph.
+pub(crate) fn paint_perf_hud<'a>(
+    scene: &mut Scene,
+    window_size: Size,
+    history: impl ExactSizeIterator<Item = &'a FrameStats>,
+) -> Rect {
+    let width = FRAME_STATS_HISTORY_LEN as f64 * BAR_WIDTH;
+    let origin_x = MARGIN;
+    let bottom = window_size.height - MARGIN;
+    let rect = Rect::new(origin_x, bottom - GRAPH_HEIGHT, origin_x + width, bottom);
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        BACKGROUND_COLOR,
+        None,
+        &rect,
+    );
+
+    // Newest frames are on the right.
+    let first_x =
+        origin_x + FRAME_STATS_HISTORY_LEN.saturating_sub(history.len()) as f64 * BAR_WIDTH;
+    for (i, frame) in history.enumerate() {
+        let x = first_x + i as f64 * BAR_WIDTH;
+        let mut y = bottom;
+        let segments = [
+            (frame.layout.duration, LAYOUT_COLOR),
+            (frame.compose.duration, COMPOSE_COLOR),
+            (frame.paint.duration, PAINT_COLOR),
+            (frame.accessibility.duration, ACCESSIBILITY_COLOR),
+            (frame.render.unwrap_or_default(), RENDER_COLOR),
+        ];
+        for (duration, color) in segments {
+            let height = duration_to_height(duration).min(y - rect.y0);
+            if height <= 0. {
+                continue;
+            }
+            let segment = Rect::new(x, y - height, x + BAR_WIDTH, y);
+            scene.fill(Fill::NonZero, Affine::IDENTITY, color, None, &segment);
+            y -= height;
+        }
+    }
+
+    let budget_y = bottom - duration_to_height(FRAME_BUDGET);
+    scene.stroke(
+        &Stroke::new(1.),
+        Affine::IDENTITY,
+        BUDGET_COLOR,
+        None,
+        &Line::new((rect.x0, budget_y), (rect.x1, budget_y)),
+    );
+
+    rect
+}
+
+fn duration_to_height(duration: Duration) -> f64 {
+    duration.as_secs_f64() / GRAPH_MAX.as_secs_f64() * GRAPH_HEIGHT
+}
@@ -8,7 +8,7 @@ use xilem::view::{
 };
 use xilem::{FontWeight, TextAlign};
 
-use crate::{Avatars, Placehero, status_html_to_plaintext};
+use crate::{Avatars, Placehero, Route, status_html_to_plaintext};
 
 mod timeline;
 pub(crate) use timeline::timeline;
@@ -62,8 +62,7 @@ fn base_status(avatars: &mut Avatars, status: &Status) -> impl FlexSequence<Plac
                     .unwrap()
                     .send(status_clone.id.clone())
                     .unwrap();
-                state.show_context = Some(status_clone.clone());
-                state.context = None;
+                state.navigator.push(Route::Thread(status_clone.clone()));
             }),
         ))
         // TODO: The "extra space" amount actually ends up being zero, so this doesn't do anything.
@@ -31,6 +31,8 @@ pub(crate) fn timeline(
             ..Padding::all(5.0)
         }),
     )
+    // Keep the scroll position when returning from a thread.
+    .persistence_key("placehero.timeline")
 }
 
 /// The component for a single status in a [`timeline`].
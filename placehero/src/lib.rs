@@ -9,6 +9,7 @@
 
 #![expect(clippy::todo, reason = "Landing intentionally in-progress work.")]
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use components::timeline;
@@ -16,9 +17,9 @@ use megalodon::entities::{Account, Context, Instance, Status};
 use megalodon::megalodon::GetAccountStatusesInputOptions;
 use megalodon::{Megalodon, mastodon};
 use xilem::core::one_of::{Either, OneOf, OneOf4};
-use xilem::core::{NoElement, View, fork, lens};
+use xilem::core::{Navigator, NoElement, View, fork, lens};
 use xilem::tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
-use xilem::view::{button, flex, label, prose, split, task_raw, worker_raw};
+use xilem::view::{button, flex, label, navigator, prose, split, task_raw, worker_raw};
 use xilem::winit::error::EventLoopError;
 use xilem::{EventLoopBuilder, ViewCtx, WidgetView, WindowOptions, Xilem, tokio};
 
@@ -50,11 +51,20 @@ struct Placehero {
     thread_statuses: Vec<Status>,
     account: Option<Account>,
     avatars: Avatars,
-    show_context: Option<Status>,
-    context: Option<Context>,
+    navigator: Navigator<Route>,
+    /// The contexts of the threads in the navigator, by the id of their status.
+    contexts: HashMap<String, Context>,
     context_sender: Option<UnboundedSender<String>>,
 }
 
+/// The screens of the app.
+#[derive(Clone)]
+enum Route {
+    Timeline,
+    /// A status in the context of its thread.
+    Thread(Status),
+}
+
 /// Execute the app in the given winit event loop.
 pub fn run(event_loop: EventLoopBuilder) -> Result<(), EventLoopError> {
     let base_url = "https://mastodon.online".to_string();
@@ -75,8 +85,8 @@ pub fn run(event_loop: EventLoopBuilder) -> Result<(), EventLoopError> {
         account: None,
         thread_statuses: Vec::new(),
         avatars: Avatars::default(),
-        show_context: None,
-        context: None,
+        navigator: Navigator::new(Route::Timeline),
+        contexts: HashMap::new(),
         context_sender: None,
     };
 
@@ -91,13 +101,10 @@ pub fn run(event_loop: EventLoopBuilder) -> Result<(), EventLoopError> {
 impl Placehero {
     fn sidebar(&mut self) -> impl WidgetView<Self> + use<> {
         if let Some(instance) = &self.instance {
-            let back = if self.show_context.is_some() {
+            let back = if self.navigator.can_pop() {
                 // TODO: Make the ⬅️ arrow not be available to screen readers.
-                Some(button("⬅️ Back to Timeline", |app_state: &mut Self| {
-                    // TODO: Maintain scroll state in the timeline.
-                    // TODO: More of a back stack.
-                    app_state.show_context = None;
-                    app_state.context = None;
+                Some(button("⬅️ Back", |app_state: &mut Self| {
+                    app_state.navigator.pop();
                 }))
             } else {
                 None
@@ -113,26 +120,33 @@ impl Placehero {
         }
     }
 
-    fn main_view(&mut self) -> impl WidgetView<Self> + use<> {
-        if let Some(show_context) = self.show_context.as_ref() {
-            if let Some(context) = self.context.as_ref() {
-                // TODO: Display the status until the entire thread loads; this is hard because
-                // the thread's scroll position would jump.
-                OneOf4::A(thread(&mut self.avatars, show_context, context))
-            } else {
-                OneOf::B(prose("Loading thread"))
-            }
-        } else if !self.thread_statuses.is_empty() {
-            OneOf::C(timeline(&mut self.thread_statuses, &mut self.avatars))
-        } else {
-            OneOf::D(prose("No statuses yet loaded"))
-        }
+    fn main_view() -> impl WidgetView<Self> + use<> {
+        navigator(
+            |app_state: &mut Self| &mut app_state.navigator,
+            |app_state: &mut Self, route: &Route| match route {
+                Route::Thread(status) => {
+                    if let Some(context) = app_state.contexts.get(&status.id) {
+                        // TODO: Display the status until the entire thread loads; this is hard because
+                        // the thread's scroll position would jump.
+                        OneOf4::A(thread(&mut app_state.avatars, status, context))
+                    } else {
+                        OneOf::B(prose("Loading thread"))
+                    }
+                }
+                Route::Timeline if !app_state.thread_statuses.is_empty() => OneOf::C(timeline(
+                    &mut app_state.thread_statuses,
+                    &mut app_state.avatars,
+                )),
+                Route::Timeline => OneOf::D(prose("No statuses yet loaded")),
+            },
+        )
+        .animated(true)
     }
 }
 
 fn app_logic(app_state: &mut Placehero) -> impl WidgetView<Placehero> + use<> {
     fork(
-        split(app_state.sidebar(), app_state.main_view()).split_point(0.2),
+        split(app_state.sidebar(), Placehero::main_view()).split_point(0.2),
         (
             load_instance(app_state.mastodon.clone()),
             load_account(app_state.mastodon.clone()),
@@ -173,16 +187,25 @@ fn load_contexts(
         },
         |app_state: &mut Placehero, sender| app_state.context_sender = Some(sender),
         |app_state: &mut Placehero, (event, id)| match event {
-            Ok(context)
-                if app_state
-                    .show_context
-                    .as_ref()
-                    .is_some_and(|it| it.id == id) =>
-            {
-                app_state.context = Some(context.json);
-            }
-            Ok(_) => {
-                tracing::warn!("Dropping context whose request was superseded.");
+            Ok(context) => {
+                let threads = app_state
+                    .navigator
+                    .entries()
+                    .iter()
+                    .filter_map(|entry| match entry.route() {
+                        Route::Thread(status) => Some(&status.id),
+                        Route::Timeline => None,
+                    })
+                    .collect::<Vec<_>>();
+                if threads.contains(&&id) {
+                    // Forget the contexts of the threads which were closed.
+                    app_state
+                        .contexts
+                        .retain(|status_id, _| threads.contains(&status_id));
+                    app_state.contexts.insert(id, context.json);
+                } else {
+                    tracing::warn!("Dropping context of a thread which was closed.");
+                }
             }
             Err(megalodon::error::Error::RequestError(e)) if e.is_connect() => {
                 todo!()
@@ -2,16 +2,18 @@
 // SPDX-License-Identifier: Apache-2.0
 
 //! Modularizing state can be done with `lens` which allows using modular components.
+//! Components which need private state can use `component`.
 
 use masonry::widgets::MainAxisAlignment;
 use winit::error::EventLoopError;
-use xilem::core::lens;
+use xilem::core::{component, lens, map_action};
 use xilem::view::{button, flex, flex_row, label};
 use xilem::{EventLoop, WidgetView, WindowOptions, Xilem};
 
 #[derive(Default)]
 struct AppState {
     modularized_count: i32,
+    step_count: i32,
     global_count: i32,
 }
 
@@ -23,11 +25,36 @@ fn modular_counter(count: &mut i32) -> impl WidgetView<i32> + use<> {
     ))
 }
 
+/// A message from [`step_counter`] to its parent.
+struct AddStep(i32);
+
+/// A counter which owns its step size, and only tells its parent when the step should be added.
+fn step_counter<State: 'static>(count: i32) -> impl WidgetView<State, AddStep> + use<State> {
+    component(
+        count,
+        |_| 1,
+        |count: &i32, step: &mut i32| {
+            flex((
+                label(format!("count: {count}, step: {step}")),
+                button("step +1", |step: &mut i32| {
+                    *step += 1;
+                    None
+                }),
+                button("add step", |step: &mut i32| Some(AddStep(*step))),
+            ))
+        },
+    )
+}
+
 fn app_logic(state: &mut AppState) -> impl WidgetView<AppState> + use<> {
     flex_row((
         lens(modular_counter, |state: &mut AppState| {
             &mut state.modularized_count
         }),
+        map_action(
+            step_counter(state.step_count),
+            |state: &mut AppState, AddStep(step)| state.step_count += step,
+        ),
         button(
             format!("clicked {} times", state.global_count),
             |state: &mut AppState| state.global_count += 1,
@@ -114,7 +114,8 @@ impl ApplicationHandler<MasonryUserEvent> for ExternalApp {
     }
 
     fn exiting(&mut self, event_loop: &winit::event_loop::ActiveEventLoop) {
-        self.masonry_state.handle_exiting(event_loop);
+        self.masonry_state
+            .handle_exiting(event_loop, self.app_driver.as_mut());
     }
 
     fn memory_warning(&mut self, event_loop: &winit::event_loop::ActiveEventLoop) {
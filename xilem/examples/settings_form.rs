@@ -0,0 +1,122 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! A settings screen built with a form, whose fields are validated as they are edited.
+
+use std::time::Duration;
+
+use masonry::core::ArcStr;
+use winit::error::EventLoopError;
+use xilem::view::{
+    CrossAxisAlignment, button, checkbox_field, flex, flex_row, label, portal, submit_button,
+    text_field,
+};
+use xilem::{EventLoop, Form, WidgetView, WindowOptions, Xilem};
+
+#[derive(Default)]
+struct Settings {
+    form: Form,
+    user_name: String,
+    email: String,
+    accept_terms: bool,
+    newsletter: bool,
+    saved: Option<String>,
+}
+
+fn required(value: &str) -> Result<(), ArcStr> {
+    if value.trim().is_empty() {
+        Err("This field is required".into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Pretends to ask a server whether the user name is taken.
+async fn check_user_name(user_name: String) -> Result<(), ArcStr> {
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    if ["admin", "root"].contains(&user_name.as_str()) {
+        Err(format!("The name \"{user_name}\" is already taken").into())
+    } else {
+        Ok(())
+    }
+}
+
+fn app_logic(settings: &mut Settings) -> impl WidgetView<Settings> + use<> {
+    let status = if settings.form.is_validating() {
+        "Checking...".to_string()
+    } else if settings.form.is_dirty() {
+        "Unsaved changes".to_string()
+    } else if let Some(saved) = &settings.saved {
+        format!("Saved as {saved}")
+    } else {
+        String::new()
+    };
+
+    let fields = flex((
+        text_field(
+            "User name",
+            |settings: &mut Settings| &mut settings.form,
+            |settings: &mut Settings| &mut settings.user_name,
+        )
+        .validate(|value| required(value))
+        .validate_async(check_user_name),
+        text_field(
+            "Email",
+            |settings: &mut Settings| &mut settings.form,
+            |settings: &mut Settings| &mut settings.email,
+        )
+        .validate(|value| required(value))
+        .validate(|email| {
+            if email.contains('@') {
+                Ok(())
+            } else {
+                Err("Enter a valid email address".into())
+            }
+        }),
+        checkbox_field(
+            "Send me the newsletter",
+            |settings: &mut Settings| &mut settings.form,
+            |settings: &mut Settings| &mut settings.newsletter,
+        ),
+        checkbox_field(
+            "I accept the terms of use",
+            |settings: &mut Settings| &mut settings.form,
+            |settings: &mut Settings| &mut settings.accept_terms,
+        )
+        .validate(|accepted| {
+            if *accepted {
+                Ok(())
+            } else {
+                Err("The terms must be accepted".into())
+            }
+        }),
+        flex_row((
+            submit_button(
+                "Save",
+                |settings: &mut Settings| &mut settings.form,
+                |settings: &mut Settings| {
+                    settings.saved = Some(settings.user_name.clone());
+                    settings.form.reset();
+                },
+            ),
+            button("Clear", |settings: &mut Settings| {
+                *settings = Settings::default();
+            }),
+            label(status),
+        )),
+    ))
+    .cross_axis_alignment(CrossAxisAlignment::Fill)
+    .gap(12.);
+
+    portal(fields)
+}
+
+fn main() -> Result<(), EventLoopError> {
+    let app = Xilem::new_simple(
+        Settings::default(),
+        app_logic,
+        WindowOptions::new("Settings"),
+    );
+    app.run_in(EventLoop::with_user_event())?;
+    Ok(())
+}
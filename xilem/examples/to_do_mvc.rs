@@ -2,20 +2,26 @@
 // SPDX-License-Identifier: Apache-2.0
 
 //! A to-do-list app, loosely inspired by todomvc.
+//!
+//! Changes to the list can be undone with the buttons, or with <kbd>Ctrl</kbd>+<kbd>Z</kbd>.
 
 // On Windows platform, don't show a console when opening the app.
 #![windows_subsystem = "windows"]
 
 use winit::error::EventLoopError;
 use xilem::style::Style as _;
-use xilem::view::{Axis, button, checkbox, flex, flex_row, text_input};
-use xilem::{EventLoop, EventLoopBuilder, InsertNewline, WidgetView, WindowOptions, Xilem};
+use xilem::view::{Axis, button, checkbox, flex, flex_row, text_input, undoable};
+use xilem::{
+    EventLoop, EventLoopBuilder, InsertNewline, UndoHistory, WidgetView, WindowOptions, Xilem,
+};
 
+#[derive(Clone, PartialEq)]
 struct Task {
     description: String,
     done: bool,
 }
 
+#[derive(Clone, PartialEq)]
 struct TaskList {
     next_task: String,
     tasks: Vec<Task>,
@@ -33,25 +39,47 @@ impl TaskList {
     }
 }
 
-fn app_logic(task_list: &mut TaskList) -> impl WidgetView<TaskList> + use<> {
+fn app_logic(
+    task_list: &mut UndoHistory<TaskList>,
+) -> impl WidgetView<UndoHistory<TaskList>> + use<> {
     let input_box = text_input(
         task_list.next_task.clone(),
-        |task_list: &mut TaskList, new_value| {
+        |task_list: &mut UndoHistory<TaskList>, new_value| {
             task_list.next_task = new_value;
         },
     )
     .insert_newline(InsertNewline::OnShiftEnter)
-    .on_enter(|task_list: &mut TaskList, _| {
+    .on_enter(|task_list: &mut UndoHistory<TaskList>, _| {
+        task_list.set_transaction_name("Add task");
         task_list.add_task();
     });
     let first_line = flex((
         input_box,
-        button("Add task".to_string(), |task_list: &mut TaskList| {
-            task_list.add_task();
-        }),
+        button(
+            "Add task".to_string(),
+            |task_list: &mut UndoHistory<TaskList>| {
+                task_list.set_transaction_name("Add task");
+                task_list.add_task();
+            },
+        ),
     ))
     .direction(Axis::Vertical);
 
+    let undo_label = match task_list.undo_name() {
+        Some(name) => format!("Undo {name}"),
+        None => "Undo".to_string(),
+    };
+    let history_buttons = flex_row((
+        button(undo_label, |task_list: &mut UndoHistory<TaskList>| {
+            task_list.undo();
+        })
+        .disabled(!task_list.can_undo()),
+        button("Redo", |task_list: &mut UndoHistory<TaskList>| {
+            task_list.redo();
+        })
+        .disabled(!task_list.can_redo()),
+    ));
+
     let tasks = task_list
         .tasks
         .iter()
@@ -60,22 +88,26 @@ fn app_logic(task_list: &mut TaskList) -> impl WidgetView<TaskList> + use<> {
             let checkbox = checkbox(
                 task.description.clone(),
                 task.done,
-                move |data: &mut TaskList, checked| {
+                move |data: &mut UndoHistory<TaskList>, checked| {
                     data.tasks[i].done = checked;
                 },
             );
-            let delete_button = button("Delete", move |data: &mut TaskList| {
+            let delete_button = button("Delete", move |data: &mut UndoHistory<TaskList>| {
+                data.set_transaction_name("Delete task");
                 data.tasks.remove(i);
             });
             flex_row((checkbox, delete_button))
         })
         .collect::<Vec<_>>();
 
-    flex((first_line, tasks)).padding(50.)
+    undoable(
+        |task_list: &mut UndoHistory<TaskList>| task_list,
+        flex((first_line, history_buttons, tasks)).padding(50.),
+    )
 }
 
 fn run(event_loop: EventLoopBuilder) -> Result<(), EventLoopError> {
-    let data = TaskList {
+    let data = UndoHistory::new(TaskList {
         // Add a placeholder task for Android, whilst the
         next_task: "My Next Task".into(),
         tasks: vec![
@@ -92,7 +124,7 @@ fn run(event_loop: EventLoopBuilder) -> Result<(), EventLoopError> {
                 done: false,
             },
         ],
-    };
+    });
 
     let app = Xilem::new_simple(data, app_logic, WindowOptions::new("To Do MVC"));
     app.run_in(event_loop)
@@ -5,6 +5,7 @@ use masonry::peniko::Blob;
 use masonry_winit::app::{EventLoopBuilder, WindowId};
 
 use std::iter::Once;
+use std::ops::{Deref, DerefMut};
 use std::sync::Arc;
 
 use masonry::core::{DefaultProperties, Widget, WidgetPod};
@@ -14,6 +15,10 @@ use winit::error::EventLoopError;
 use winit::window::WindowAttributes;
 use xilem_core::map_state;
 
+use crate::file_dialog::{FileDialogService, InWindowFileDialogs};
+#[cfg(feature = "hot_reload")]
+use crate::hot_reload::HotReload;
+use crate::ui_state::{MemoryUiStateStore, UiStateStore};
 use crate::window_options::WindowCallbacks;
 use crate::{AnyWidgetView, MasonryDriver, WidgetView, WindowOptions};
 
@@ -22,10 +27,14 @@ use crate::{AnyWidgetView, MasonryDriver, WidgetView, WindowOptions};
 pub struct Xilem<State, Logic> {
     state: State,
     logic: Logic,
-    runtime: tokio::runtime::Runtime,
-    default_properties: Option<DefaultProperties>,
+    pub(crate) runtime: tokio::runtime::Runtime,
+    pub(crate) default_properties: Option<DefaultProperties>,
+    file_dialogs: Arc<dyn FileDialogService>,
+    ui_state: Arc<dyn UiStateStore>,
     // Font data to include in loading.
-    fonts: Vec<Blob<u8>>,
+    pub(crate) fonts: Vec<Blob<u8>>,
+    #[cfg(feature = "hot_reload")]
+    hot_reload: Option<HotReload<State>>,
 }
 
 /// State type used by [`Xilem::new_simple`].
@@ -40,14 +49,45 @@ impl<S> AppState for ExitOnClose<S> {
     }
 }
 
+impl<S> Deref for ExitOnClose<S> {
+    type Target = S;
+
+    fn deref(&self) -> &S {
+        &self.state
+    }
+}
+
+impl<S> DerefMut for ExitOnClose<S> {
+    fn deref_mut(&mut self) -> &mut S {
+        &mut self.state
+    }
+}
+
+// Only the user's state is saved by hot reloading, the app is always running when it starts.
+#[cfg(feature = "hot_reload")]
+impl<S: serde::Serialize> serde::Serialize for ExitOnClose<S> {
+    fn serialize<Ser: serde::Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        self.state.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "hot_reload")]
+impl<'de, S: serde::Deserialize<'de>> serde::Deserialize<'de> for ExitOnClose<S> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        S::deserialize(deserializer).map(|state| Self {
+            state,
+            running: true,
+        })
+    }
+}
+
 type WindowTuple<State> = (WindowId, WindowOptions<State>, Box<AnyWidgetView<State>>);
 
-impl<State>
-    Xilem<
-        ExitOnClose<State>,
-        Box<dyn FnMut(&mut ExitOnClose<State>) -> Once<WindowTuple<ExitOnClose<State>>>>,
-    >
-{
+/// The app logic type used by [`Xilem::new_simple`].
+pub(crate) type SimpleLogic<State> =
+    Box<dyn FnMut(&mut ExitOnClose<State>) -> Once<WindowTuple<ExitOnClose<State>>>>;
+
+impl<State> Xilem<ExitOnClose<State>, SimpleLogic<State>> {
     /// Create an app builder for a single window app with fixed window attributes
     /// that exits once the window is closed.
     ///
@@ -127,7 +167,11 @@ where
             logic,
             runtime: tokio::runtime::Runtime::new().unwrap(),
             default_properties: None,
+            file_dialogs: Arc::new(InWindowFileDialogs),
+            ui_state: Arc::new(MemoryUiStateStore::default()),
             fonts: Vec::new(),
+            #[cfg(feature = "hot_reload")]
+            hot_reload: None,
         }
     }
 
@@ -146,6 +190,52 @@ where
         self
     }
 
+    /// Sets the service used to show file dialogs.
+    ///
+    /// By default, file dialogs are shown inside the window, using [`InWindowFileDialogs`].
+    /// See the [`file_dialog`](crate::file_dialog) module for details.
+    pub fn with_file_dialogs(mut self, file_dialogs: impl FileDialogService) -> Self {
+        self.file_dialogs = Arc::new(file_dialogs);
+        self
+    }
+
+    /// Sets the store of the UI state of views with a persistence key,
+    /// such as the scroll position of a [`portal`](crate::view::portal).
+    ///
+    /// By default, the UI state is kept in memory, using [`MemoryUiStateStore`].
+    /// See the [`ui_state`](crate::ui_state) module for details.
+    pub fn with_ui_state_store(mut self, store: impl UiStateStore) -> Self {
+        self.ui_state = Arc::new(store);
+        self
+    }
+
+    /// Saves the app state to the file at `path` when the app exits, and restores it
+    /// from that file now, if it exists.
+    ///
+    /// This lets you edit the code of your app, rebuild it and resume where you were.
+    /// A snapshot can also be saved while the app runs, by pressing
+    /// <kbd>Ctrl</kbd>+<kbd>Shift</kbd>+<kbd>F5</kbd>.
+    /// The position and size of the windows which have a
+    /// [persistence key](WindowOptions::with_persistence_key) are saved as well.
+    ///
+    /// If the file can't be read as the current state type, e.g. because a field was added,
+    /// a warning is logged and the initial state is used.
+    /// The state is saved as JSON, so it can be edited by hand.
+    ///
+    /// This should only be used during development, e.g. behind a command line flag.
+    #[cfg(feature = "hot_reload")]
+    pub fn with_hot_reload(mut self, path: impl Into<std::path::PathBuf>) -> Self
+    where
+        State: serde::Serialize + serde::de::DeserializeOwned,
+    {
+        let (hot_reload, state) = HotReload::load(path.into());
+        if let Some(state) = state {
+            self.state = state;
+        }
+        self.hot_reload = Some(hot_reload);
+        self
+    }
+
     /// Run app with custom window attributes.
     pub fn run_in(mut self, mut event_loop: EventLoopBuilder) -> Result<(), EventLoopError> {
         let event_loop = event_loop.build()?;
@@ -170,6 +260,16 @@ where
         MasonryDriver<State, Logic>,
         Vec<(WindowId, WindowAttributes, WidgetPod<dyn Widget>)>,
     ) {
-        MasonryDriver::new(self.state, self.logic, proxy, self.runtime, self.fonts)
+        MasonryDriver::new(
+            self.state,
+            self.logic,
+            proxy,
+            self.runtime,
+            self.file_dialogs,
+            self.ui_state,
+            self.fonts,
+            #[cfg(feature = "hot_reload")]
+            self.hot_reload,
+        )
     }
 }
@@ -7,13 +7,18 @@ use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
 use std::sync::Arc;
 
-use masonry::core::{Widget, WidgetId, WidgetPod};
+use masonry::app::RenderRoot;
+use masonry::core::{ShortcutId, Widget, WidgetId, WidgetPod};
 use masonry::peniko::Blob;
 use masonry_winit::app::{AppDriver, DriverCtx, MasonryState, MasonryUserEvent, WindowId};
 use winit::window::WindowAttributes;
-use xilem_core::{AnyViewState, RawProxy, SendMessage, View};
+use xilem_core::{AnyViewState, RawProxy, Rebuild, SendMessage, View, ViewPathTracker};
 
 use crate::core::{DynMessage, MessageResult, ProxyError, ViewId};
+use crate::file_dialog::FileDialogService;
+#[cfg(feature = "hot_reload")]
+use crate::hot_reload::HotReload;
+use crate::ui_state::UiStateStore;
 use crate::window_view::{CreateWindow, WindowView};
 use crate::{AnyWidgetView, AppState, ViewCtx, WindowOptions};
 
@@ -23,8 +28,12 @@ pub struct MasonryDriver<State, Logic> {
     windows: HashMap<WindowId, Window<State>>,
     proxy: Arc<MasonryProxy>,
     runtime: Arc<tokio::runtime::Runtime>,
+    file_dialogs: Arc<dyn FileDialogService>,
+    ui_state: Arc<dyn UiStateStore>,
     // Fonts which will be registered on startup.
     fonts: Vec<Blob<u8>>,
+    #[cfg(feature = "hot_reload")]
+    hot_reload: Option<HotReload<State>>,
 }
 
 struct Window<State> {
@@ -46,7 +55,10 @@ where
         // (we only ever use it to send MasonryUserEvent::Action with ASYNC_MARKER_WIDGET)
         event_sink: impl Fn(MasonryUserEvent) -> Result<(), MasonryUserEvent> + Send + Sync + 'static,
         runtime: tokio::runtime::Runtime,
+        file_dialogs: Arc<dyn FileDialogService>,
+        ui_state: Arc<dyn UiStateStore>,
         fonts: Vec<Blob<u8>>,
+        #[cfg(feature = "hot_reload")] hot_reload: Option<HotReload<State>>,
     ) -> (
         Self,
         Vec<(WindowId, WindowAttributes, WidgetPod<dyn Widget>)>,
@@ -57,7 +69,11 @@ where
             windows: HashMap::new(),
             proxy: Arc::new(MasonryProxy(Box::new(event_sink))),
             runtime: Arc::new(runtime),
+            file_dialogs,
+            ui_state,
             fonts,
+            #[cfg(feature = "hot_reload")]
+            hot_reload,
         };
         let windows: Vec<_> = (driver.logic)(&mut driver.state)
             .map(|(id, attrs, root_widget_view)| {
@@ -149,9 +165,16 @@ where
         let mut view_ctx = ViewCtx::new(
             Arc::new(WindowProxy(window_id, self.proxy.clone())),
             self.runtime.clone(),
+            self.file_dialogs.clone(),
+            self.ui_state.clone(),
         );
         let (CreateWindow(attrs, root_widget), view_state) =
             view.build(&mut view_ctx, &mut self.state);
+        #[cfg(feature = "hot_reload")]
+        let attrs = match &self.hot_reload {
+            Some(hot_reload) => hot_reload.restore_window(view.options().persistence_key(), attrs),
+            None => attrs,
+        };
         self.windows.insert(
             window_id,
             Window {
@@ -171,6 +194,10 @@ where
     ) {
         let (attrs, root_widget) = self.build_window(window_id, view);
         driver_ctx.create_window(window_id, attrs, root_widget);
+        #[cfg(feature = "hot_reload")]
+        if let Some(hot_reload) = &self.hot_reload {
+            hot_reload.register_shortcut(driver_ctx.render_root(window_id));
+        }
     }
 
     fn close_window(&mut self, window_id: WindowId, ctx: &mut DriverCtx<'_, '_>) {
@@ -185,7 +212,13 @@ where
         ctx.close_window(window_id);
     }
 
-    fn run_logic(&mut self, driver_ctx: &mut DriverCtx<'_, '_>) {
+    /// Runs the app logic, and gives each window it returns to `update_window`.
+    ///
+    /// Returns the ids of these windows.
+    fn for_each_logic_window(
+        &mut self,
+        mut update_window: impl FnMut(&mut Self, WindowId, WindowView<State>),
+    ) -> HashSet<WindowId> {
         let mut returned_ids = HashSet::new();
         for (window_id, next_attrs, next_view) in (self.logic)(&mut self.state) {
             if !returned_ids.insert(window_id) {
@@ -195,9 +228,14 @@ where
                 );
                 continue;
             }
-            let next_view = WindowView::new(next_attrs, next_view);
+            update_window(self, window_id, WindowView::new(next_attrs, next_view));
+        }
+        returned_ids
+    }
 
-            match self.windows.get_mut(&window_id) {
+    fn run_logic(&mut self, driver_ctx: &mut DriverCtx<'_, '_>) {
+        let returned_ids = self.for_each_logic_window(|this, window_id, next_view| {
+            match this.windows.get_mut(&window_id) {
                 Some(Window {
                     view,
                     view_ctx,
@@ -208,13 +246,13 @@ where
                         view_state,
                         view_ctx,
                         driver_ctx.window_handle_and_render_root(window_id),
-                        &mut self.state,
+                        &mut this.state,
                     );
                     *view = next_view;
                 }
-                None => self.create_window(driver_ctx, window_id, next_view),
+                None => this.create_window(driver_ctx, window_id, next_view),
             }
-        }
+        });
 
         let to_be_closed: Vec<_> = self
             .windows
@@ -226,27 +264,86 @@ where
             self.close_window(window_id, driver_ctx);
         }
     }
-}
 
-impl<State, Logic, WindowIter> AppDriver for MasonryDriver<State, Logic>
-where
-    State: AppState + 'static,
-    Logic: FnMut(&mut State) -> WindowIter,
-    WindowIter: Iterator<Item = (WindowId, WindowOptions<State>, Box<AnyWidgetView<State>>)>,
-{
-    fn on_action(
+    fn handle_message_result(
+        &mut self,
+        window_id: WindowId,
+        masonry_ctx: &mut DriverCtx<'_, '_>,
+        message_result: MessageResult<()>,
+    ) {
+        match message_result {
+            // The semantics here haven't exactly been worked out.
+            // This version of the implementation is based on the assumptions that:
+            // 1) `MessageResult::Action` means that the app's state has changed (and so the logic needs to be reran)
+            // 2) `MessageResult::RequestRebuild` requires that the app state is *not* rebuilt; this allows
+            //     avoiding infinite loops.
+            MessageResult::Action(()) => {
+                self.run_logic(masonry_ctx);
+            }
+            MessageResult::RequestRebuild => {
+                self.rebuild_root_widget(window_id, masonry_ctx.render_root(window_id));
+            }
+            MessageResult::Nop => {}
+            MessageResult::Stale(_) => {
+                tracing::info!("Discarding message");
+            }
+        };
+    }
+
+    /// Records the geometry of the windows for hot reloading, if it's enabled.
+    #[cfg(feature = "hot_reload")]
+    fn record_window_geometries(&mut self, driver_ctx: &mut DriverCtx<'_, '_>) {
+        let Some(hot_reload) = &mut self.hot_reload else {
+            return;
+        };
+        for (window_id, window) in &self.windows {
+            if let Some(key) = window.view.options().persistence_key() {
+                hot_reload.record_window(key, driver_ctx.window_handle(*window_id));
+            }
+        }
+    }
+
+    /// Saves the app state for hot reloading, if it's enabled.
+    #[cfg(feature = "hot_reload")]
+    fn save_snapshot(&self) {
+        if let Some(hot_reload) = &self.hot_reload {
+            hot_reload.save(&self.state);
+        }
+    }
+
+    /// Rebuilds the widgets of a window without running the app logic.
+    fn rebuild_root_widget(&mut self, window_id: WindowId, render_root: &mut RenderRoot) {
+        let window = self.windows.get_mut(&window_id).unwrap();
+        window.view_ctx.set_state_changed(false);
+        // Only rebuild the views which asked for it, if we know which ones they are.
+        let partial = window.view_ctx.environment().start_partial_rebuild();
+        window.view.rebuild_root_widget(
+            &window.view,
+            &mut window.view_state,
+            &mut window.view_ctx,
+            render_root,
+            &mut self.state,
+        );
+        if partial {
+            window.view_ctx.environment().finish_partial_rebuild();
+        }
+    }
+
+    /// Routes an action emitted by the widget `widget_id` to the view which handles it.
+    ///
+    /// Returns `None` if there is no such view.
+    fn action_message(
         &mut self,
         window_id: WindowId,
-        masonry_ctx: &mut masonry_winit::app::DriverCtx<'_, '_>,
         widget_id: WidgetId,
         action: masonry::core::Action,
-    ) {
+    ) -> Option<MessageResult<()>> {
         let Some(window) = self.windows.get_mut(&window_id) else {
             tracing::warn!(
                 window_id = window_id.trace(),
                 "call on_action call for unknown window"
             );
-            return;
+            return None;
         };
 
         let message_result = if widget_id == ASYNC_MARKER_WIDGET {
@@ -254,13 +351,20 @@ where
                 panic!();
             };
             let (path, message) = *action.downcast::<MessagePackage>().unwrap();
+            let is_rebuild = message.is::<Rebuild>();
             // Handle an async path
-            window.view.message(
+            let message_result = window.view.message(
                 &mut window.view_state,
                 &path,
                 message.into(),
                 &mut self.state,
-            )
+            );
+            // A view asking to be rebuilt because a value it reads has changed, e.g. a `Signal`.
+            // Only that view needs to be rebuilt.
+            if is_rebuild && matches!(message_result, MessageResult::RequestRebuild) {
+                window.view_ctx.environment().queue_rebuild(path);
+            }
+            message_result
         } else if let Some(id_path) = window.view_ctx.get_id_path(widget_id) {
             let action = match action {
                 masonry::core::Action::Other(other) => other,
@@ -276,32 +380,205 @@ where
             tracing::error!(
                 "Got action {action:?} for unknown widget. Did you forget to use `with_action_widget`?"
             );
-            return;
+            return None;
         };
+        Some(message_result)
+    }
+
+    /// Routes a triggered shortcut to the view which registered it.
+    ///
+    /// Returns `None` if there is no such view.
+    fn shortcut_message(
+        &mut self,
+        window_id: WindowId,
+        shortcut_id: ShortcutId,
+    ) -> Option<MessageResult<()>> {
+        let Some(window) = self.windows.get_mut(&window_id) else {
+            tracing::warn!(
+                window_id = window_id.trace(),
+                "call on_shortcut call for unknown window"
+            );
+            return None;
+        };
+        let Some(id_path) = window.view_ctx.get_shortcut_id_path(shortcut_id) else {
+            tracing::error!("Got unknown shortcut {shortcut_id}");
+            return None;
+        };
+        Some(window.view.message(
+            &mut window.view_state,
+            id_path.as_slice(),
+            DynMessage(Box::new(shortcut_id)),
+            &mut self.state,
+        ))
+    }
+}
+
+// --- MARK: HEADLESS
+// These methods drive a window whose render root isn't managed by `masonry_winit`,
+// which is how `xilem::testing` runs apps.
+#[cfg(feature = "testing")]
+impl<State, Logic, WindowIter> MasonryDriver<State, Logic>
+where
+    State: AppState + 'static,
+    Logic: FnMut(&mut State) -> WindowIter,
+    WindowIter: Iterator<Item = (WindowId, WindowOptions<State>, Box<AnyWidgetView<State>>)>,
+{
+    pub(crate) fn state(&self) -> &State {
+        &self.state
+    }
+
+    pub(crate) fn runtime(&self) -> &Arc<tokio::runtime::Runtime> {
+        &self.runtime
+    }
+
+    /// Lets `f` edit the app state, then runs the app logic.
+    pub(crate) fn edit_state_headless<R>(
+        &mut self,
+        window_id: WindowId,
+        render_root: &mut RenderRoot,
+        f: impl FnOnce(&mut State) -> R,
+    ) -> R {
+        let ret = f(&mut self.state);
+        self.run_logic_headless(window_id, render_root);
+        ret
+    }
+
+    pub(crate) fn on_action_headless(
+        &mut self,
+        window_id: WindowId,
+        render_root: &mut RenderRoot,
+        widget_id: WidgetId,
+        action: masonry::core::Action,
+    ) {
+        if let Some(message_result) = self.action_message(window_id, widget_id, action) {
+            self.handle_message_result_headless(window_id, render_root, message_result);
+        }
+    }
+
+    pub(crate) fn on_shortcut_headless(
+        &mut self,
+        window_id: WindowId,
+        render_root: &mut RenderRoot,
+        shortcut_id: ShortcutId,
+    ) {
+        if let Some(message_result) = self.shortcut_message(window_id, shortcut_id) {
+            self.handle_message_result_headless(window_id, render_root, message_result);
+        }
+    }
+
+    /// Runs the close callback of the window, then the app logic.
+    ///
+    /// Returns `true` if the app should keep running.
+    /// Otherwise, the hot reload snapshot is saved, as when a real app exits.
+    pub(crate) fn on_close_requested_headless(
+        &mut self,
+        window_id: WindowId,
+        render_root: &mut RenderRoot,
+    ) -> bool {
+        let view = &self.windows.get(&window_id).unwrap().view;
+        view.on_close(&mut self.state);
+        self.run_logic_headless(window_id, render_root);
+        let keep_running = self.state.keep_running();
+        #[cfg(feature = "hot_reload")]
+        if !keep_running {
+            self.save_snapshot();
+        }
+        keep_running
+    }
+
+    /// Saves the hot reload snapshot and tears down the window's views, as when a real app exits.
+    pub(crate) fn on_exit_headless(&mut self, window_id: WindowId, render_root: &mut RenderRoot) {
+        #[cfg(feature = "hot_reload")]
+        self.save_snapshot();
+        let mut window = self.windows.remove(&window_id).unwrap();
+        window.view.teardown_root_widget(
+            &mut window.view_state,
+            &mut window.view_ctx,
+            render_root,
+            &mut self.state,
+        );
+    }
+
+    fn handle_message_result_headless(
+        &mut self,
+        window_id: WindowId,
+        render_root: &mut RenderRoot,
+        message_result: MessageResult<()>,
+    ) {
         match message_result {
-            // The semantics here haven't exactly been worked out.
-            // This version of the implementation is based on the assumptions that:
-            // 1) `MessageResult::Action` means that the app's state has changed (and so the logic needs to be reran)
-            // 2) `MessageResult::RequestRebuild` requires that the app state is *not* rebuilt; this allows
-            //     avoiding infinite loops.
-            MessageResult::Action(()) => {
-                self.run_logic(masonry_ctx);
-            }
-            MessageResult::RequestRebuild => {
-                window.view_ctx.set_state_changed(false);
-                window.view.rebuild_root_widget(
-                    &window.view,
-                    &mut window.view_state,
-                    &mut window.view_ctx,
-                    masonry_ctx.render_root(window_id),
-                    &mut self.state,
-                );
-            }
+            MessageResult::Action(()) => self.run_logic_headless(window_id, render_root),
+            MessageResult::RequestRebuild => self.rebuild_root_widget(window_id, render_root),
             MessageResult::Nop => {}
             MessageResult::Stale(_) => {
                 tracing::info!("Discarding message");
             }
-        };
+        }
+    }
+
+    /// Runs the app logic and rebuilds the widgets of the window.
+    ///
+    /// Without a real window, the window attributes aren't updated, and the app logic
+    /// can't open other windows.
+    fn run_logic_headless(&mut self, window_id: WindowId, render_root: &mut RenderRoot) {
+        self.for_each_logic_window(|this, id, next_view| {
+            if id != window_id {
+                tracing::warn!(
+                    window_id = id.trace(),
+                    "headless apps only support a single window, ignoring this one"
+                );
+                return;
+            }
+            let window = this.windows.get_mut(&window_id).unwrap();
+            window.view_ctx.set_state_changed(true);
+            next_view.rebuild_root_widget(
+                &window.view,
+                &mut window.view_state,
+                &mut window.view_ctx,
+                render_root,
+                &mut this.state,
+            );
+            window.view = next_view;
+        });
+    }
+}
+
+impl<State, Logic, WindowIter> AppDriver for MasonryDriver<State, Logic>
+where
+    State: AppState + 'static,
+    Logic: FnMut(&mut State) -> WindowIter,
+    WindowIter: Iterator<Item = (WindowId, WindowOptions<State>, Box<AnyWidgetView<State>>)>,
+{
+    fn on_action(
+        &mut self,
+        window_id: WindowId,
+        masonry_ctx: &mut masonry_winit::app::DriverCtx<'_, '_>,
+        widget_id: WidgetId,
+        action: masonry::core::Action,
+    ) {
+        if let Some(message_result) = self.action_message(window_id, widget_id, action) {
+            self.handle_message_result(window_id, masonry_ctx, message_result);
+        }
+    }
+
+    fn on_shortcut(
+        &mut self,
+        window_id: WindowId,
+        masonry_ctx: &mut masonry_winit::app::DriverCtx<'_, '_>,
+        shortcut_id: ShortcutId,
+    ) {
+        #[cfg(feature = "hot_reload")]
+        if self
+            .hot_reload
+            .as_ref()
+            .is_some_and(|hot_reload| hot_reload.shortcut_id() == shortcut_id)
+        {
+            self.record_window_geometries(masonry_ctx);
+            self.save_snapshot();
+            return;
+        }
+        if let Some(message_result) = self.shortcut_message(window_id, shortcut_id) {
+            self.handle_message_result(window_id, masonry_ctx, message_result);
+        }
     }
 
     fn on_start(&mut self, state: &mut MasonryState<'_>) {
@@ -315,6 +592,10 @@ where
                 // because we don't have an easy way to return this to the application.
                 drop(root.register_fonts(font.clone()));
             }
+            #[cfg(feature = "hot_reload")]
+            if let Some(hot_reload) = &self.hot_reload {
+                hot_reload.register_shortcut(root);
+            }
         }
     }
 
@@ -323,13 +604,29 @@ where
         window_id: WindowId,
         ctx: &mut masonry_winit::app::DriverCtx<'_, '_>,
     ) {
+        // The app logic may close the windows, so their geometry is recorded first.
+        #[cfg(feature = "hot_reload")]
+        self.record_window_geometries(ctx);
         let view = &self.windows.get(&window_id).unwrap().view;
         view.on_close(&mut self.state);
         self.run_logic(ctx);
 
         if !self.state.keep_running() {
-            // TODO: we should probably call teardown for all windows before exiting => introduce AppDriver::on_exit
             ctx.exit();
         }
     }
+
+    fn on_exit(&mut self, ctx: &mut masonry_winit::app::DriverCtx<'_, '_>) {
+        // This is called however the app exits, e.g. also when a widget requests it.
+        #[cfg(feature = "hot_reload")]
+        {
+            self.record_window_geometries(ctx);
+            self.save_snapshot();
+        }
+        // Tear down the remaining windows, so that their views can e.g. save their UI state.
+        let window_ids: Vec<_> = self.windows.keys().copied().collect();
+        for window_id in window_ids {
+            self.close_window(window_id, ctx);
+        }
+    }
 }
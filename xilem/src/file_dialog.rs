@@ -0,0 +1,303 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Services which show file open and save dialogs.
+//!
+//! File dialogs are requested from the view tree using the
+//! [`file_dialog`](crate::view::file_dialog) view.
+//! The way they are shown is decided by the [`FileDialogService`] of the app,
+//! which is set using [`Xilem::with_file_dialogs`](crate::Xilem::with_file_dialogs):
+//!
+//! - By default, [`InWindowFileDialogs`] shows a file browser built from Masonry widgets,
+//!   in a modal dialog inside the window.
+//! - Native dialogs can be shown by implementing [`FileDialogService`] with a crate
+//!   such as `rfd`, which talks to the platform or to the desktop portal.
+//!   Xilem doesn't ship such a service, so that it doesn't depend on these crates.
+//! - [`MockFileDialogs`] answers requests with results given ahead of time,
+//!   which lets apps be exercised in tests without any dialog being shown.
+//!
+//! # Native dialogs
+//!
+//! This is a sketch of a service using `rfd`, which apps can adapt to the platforms they target:
+//!
+//! ```ignore
+//! use xilem::file_dialog::{
+//!     FileDialogFuture, FileDialogKind, FileDialogOptions, FileDialogResult, FileDialogService,
+//! };
+//!
+//! #[derive(Debug)]
+//! struct RfdFileDialogs;
+//!
+//! impl FileDialogService for RfdFileDialogs {
+//!     fn show(&self, options: &FileDialogOptions) -> Option<FileDialogFuture> {
+//!         let mut dialog = rfd::AsyncFileDialog::new();
+//!         if let Some(title) = &options.title {
+//!             dialog = dialog.set_title(title);
+//!         }
+//!         if let Some(directory) = &options.directory {
+//!             dialog = dialog.set_directory(directory);
+//!         }
+//!         if let Some(file_name) = &options.file_name {
+//!             dialog = dialog.set_file_name(file_name);
+//!         }
+//!         for filter in &options.filters {
+//!             dialog = dialog.add_filter(&filter.name, &filter.extensions);
+//!         }
+//!         let (kind, multiple) = (options.kind, options.multiple);
+//!         Some(Box::pin(async move {
+//!             let handles = match kind {
+//!                 FileDialogKind::Open if multiple => dialog.pick_files().await.unwrap_or_default(),
+//!                 FileDialogKind::Open => dialog.pick_file().await.into_iter().collect(),
+//!                 FileDialogKind::Save => dialog.save_file().await.into_iter().collect(),
+//!                 FileDialogKind::PickFolder => dialog.pick_folder().await.into_iter().collect(),
+//!             };
+//!             let paths: Vec<_> = handles.iter().map(|handle| handle.path().to_owned()).collect();
+//!             if paths.is_empty() {
+//!                 FileDialogResult::Cancelled
+//!             } else {
+//!                 FileDialogResult::Picked(paths)
+//!             }
+//!         }))
+//!     }
+//! }
+//! ```
+
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+/// The kind of file dialog to show.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FileDialogKind {
+    /// Pick one or more existing files.
+    Open,
+    /// Pick the path a file should be saved to.
+    Save,
+    /// Pick an existing directory.
+    PickFolder,
+}
+
+/// A set of file extensions which the user can filter files by.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FileFilter {
+    /// The name shown to the user, e.g. "Images".
+    pub name: String,
+    /// The extensions of the matching files, without the leading dot.
+    pub extensions: Vec<String>,
+}
+
+impl FileFilter {
+    /// Returns `true` if the extension of `path` is one of this filter's extensions.
+    ///
+    /// Extensions are compared case-insensitively.
+    pub fn matches(&self, path: &Path) -> bool {
+        let Some(extension) = path.extension().and_then(|ext| ext.to_str()) else {
+            return false;
+        };
+        self.extensions
+            .iter()
+            .any(|candidate| candidate.eq_ignore_ascii_case(extension))
+    }
+}
+
+/// A description of the file dialog an app wants to show.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FileDialogOptions {
+    /// The kind of dialog.
+    pub kind: FileDialogKind,
+    /// Whether several files can be picked. Only used by [`FileDialogKind::Open`].
+    pub multiple: bool,
+    /// The title of the dialog.
+    pub title: Option<String>,
+    /// The directory the dialog starts in.
+    pub directory: Option<PathBuf>,
+    /// The file name the dialog starts with. Only used by [`FileDialogKind::Save`].
+    pub file_name: Option<String>,
+    /// The filters the user can pick from.
+    ///
+    /// If empty, all files are shown.
+    pub filters: Vec<FileFilter>,
+}
+
+impl FileDialogOptions {
+    /// Create the options for a dialog of the given kind.
+    pub fn new(kind: FileDialogKind) -> Self {
+        Self {
+            kind,
+            multiple: false,
+            title: None,
+            directory: None,
+            file_name: None,
+            filters: Vec::new(),
+        }
+    }
+
+    /// Create the options for a dialog which picks a file to open.
+    pub fn open() -> Self {
+        Self::new(FileDialogKind::Open)
+    }
+
+    /// Create the options for a dialog which picks where to save a file.
+    pub fn save() -> Self {
+        Self::new(FileDialogKind::Save)
+    }
+
+    /// Create the options for a dialog which picks a directory.
+    pub fn pick_folder() -> Self {
+        Self::new(FileDialogKind::PickFolder)
+    }
+
+    /// Builder-style method to allow picking several files.
+    pub fn multiple(mut self, multiple: bool) -> Self {
+        self.multiple = multiple;
+        self
+    }
+
+    /// Builder-style method to set the title of the dialog.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Builder-style method to set the directory the dialog starts in.
+    pub fn directory(mut self, directory: impl Into<PathBuf>) -> Self {
+        self.directory = Some(directory.into());
+        self
+    }
+
+    /// Builder-style method to set the file name a save dialog starts with.
+    pub fn file_name(mut self, file_name: impl Into<String>) -> Self {
+        self.file_name = Some(file_name.into());
+        self
+    }
+
+    /// Builder-style method to add a filter.
+    pub fn filter(
+        mut self,
+        name: impl Into<String>,
+        extensions: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.filters.push(FileFilter {
+            name: name.into(),
+            extensions: extensions.into_iter().map(Into::into).collect(),
+        });
+        self
+    }
+
+    /// Returns `true` if files at `path` should be shown, according to the filters.
+    pub fn accepts(&self, path: &Path) -> bool {
+        self.filters.is_empty() || self.filters.iter().any(|filter| filter.matches(path))
+    }
+}
+
+/// The result of a file dialog.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FileDialogResult {
+    /// The user picked these paths.
+    ///
+    /// This contains a single path, unless [`FileDialogOptions::multiple`] was set.
+    Picked(Vec<PathBuf>),
+    /// The user closed the dialog without picking anything.
+    Cancelled,
+}
+
+impl FileDialogResult {
+    /// The picked paths, which is empty if the dialog was cancelled.
+    pub fn paths(&self) -> &[PathBuf] {
+        match self {
+            Self::Picked(paths) => paths,
+            Self::Cancelled => &[],
+        }
+    }
+
+    /// The first picked path, if any.
+    pub fn path(&self) -> Option<&Path> {
+        self.paths().first().map(PathBuf::as_path)
+    }
+}
+
+/// The future returned by a [`FileDialogService`] which shows a dialog itself.
+pub type FileDialogFuture = Pin<Box<dyn Future<Output = FileDialogResult> + Send>>;
+
+/// A way of showing file dialogs, used by the [`file_dialog`](crate::view::file_dialog) view.
+///
+/// See the [module level documentation](self) for the available services.
+pub trait FileDialogService: Debug + Send + Sync + 'static {
+    /// Show the dialog described by `options`.
+    ///
+    /// Returns the future which resolves to the result of the dialog, which is polled
+    /// on the app's async runtime.
+    /// Returns `None` if the service can't show this dialog, in which case the in-window
+    /// file browser is used instead.
+    fn show(&self, options: &FileDialogOptions) -> Option<FileDialogFuture>;
+}
+
+/// The default [`FileDialogService`], which always uses the in-window file browser.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct InWindowFileDialogs;
+
+impl FileDialogService for InWindowFileDialogs {
+    fn show(&self, _options: &FileDialogOptions) -> Option<FileDialogFuture> {
+        None
+    }
+}
+
+/// A [`FileDialogService`] which answers requests with results given ahead of time.
+///
+/// This is meant for tests: no dialog is shown, and the requests can be inspected.
+/// Clones of this value share the same queue, so a clone can be given to the app
+/// while the test keeps the original.
+///
+/// # Examples
+/// ```
+/// use std::path::PathBuf;
+/// use xilem::file_dialog::{FileDialogResult, MockFileDialogs};
+///
+/// let dialogs = MockFileDialogs::new();
+/// dialogs.respond_with(FileDialogResult::Picked(vec![PathBuf::from("notes.txt")]));
+/// // Give `dialogs.clone()` to `Xilem::with_file_dialogs`, then drive the app...
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct MockFileDialogs {
+    inner: Arc<Mutex<MockInner>>,
+}
+
+#[derive(Debug, Default)]
+struct MockInner {
+    responses: VecDeque<FileDialogResult>,
+    requests: Vec<FileDialogOptions>,
+}
+
+impl MockFileDialogs {
+    /// Create a mock with no queued results.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue the result of the next dialog.
+    ///
+    /// Dialogs requested while the queue is empty are cancelled.
+    pub fn respond_with(&self, result: FileDialogResult) {
+        self.inner.lock().unwrap().responses.push_back(result);
+    }
+
+    /// The options of all the dialogs requested so far, in order.
+    pub fn requests(&self) -> Vec<FileDialogOptions> {
+        self.inner.lock().unwrap().requests.clone()
+    }
+}
+
+impl FileDialogService for MockFileDialogs {
+    fn show(&self, options: &FileDialogOptions) -> Option<FileDialogFuture> {
+        let mut inner = self.inner.lock().unwrap();
+        inner.requests.push(options.clone());
+        let result = inner
+            .responses
+            .pop_front()
+            .unwrap_or(FileDialogResult::Cancelled);
+        Some(Box::pin(std::future::ready(result)))
+    }
+}
@@ -0,0 +1,187 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use masonry::core::ArcStr;
+
+/// The validation state of a form, which is stored in the app state.
+///
+/// A form is built from fields, such as [`text_field`](crate::view::text_field),
+/// which each edit a value of the app state and register their status in the `Form`.
+/// Fields are identified by their label, which must be unique within a form.
+///
+/// For each field, the form tracks:
+/// - Whether it is *dirty*, i.e. its value differs from the value it had when it was
+///   built, or when the form was last [`reset`](Self::reset).
+/// - Whether it is *touched*, i.e. the user has edited it.
+/// - Its validation error, if any.
+///
+/// The error of a field is only shown once the field is touched, or once a submit was attempted
+/// using a [`submit_button`](crate::view::submit_button), so that users aren't shown errors
+/// about fields they haven't filled in yet.
+///
+/// # Examples
+///
+/// ```
+/// use xilem::view::{flex, submit_button, text_field};
+/// use xilem::{Form, WidgetView};
+///
+/// #[derive(Default)]
+/// struct Settings {
+///     form: Form,
+///     name: String,
+/// }
+///
+/// fn app_logic(settings: &mut Settings) -> impl WidgetView<Settings> + use<> {
+///     flex((
+///         text_field(
+///             "Name",
+///             |settings: &mut Settings| &mut settings.form,
+///             |settings: &mut Settings| &mut settings.name,
+///         )
+///         .validate(|name| {
+///             if name.is_empty() {
+///                 Err("A name is required".into())
+///             } else {
+///                 Ok(())
+///             }
+///         }),
+///         submit_button(
+///             "Save",
+///             |settings: &mut Settings| &mut settings.form,
+///             |settings: &mut Settings| settings.form.reset(),
+///         ),
+///     ))
+/// }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Form {
+    fields: HashMap<ArcStr, FieldStatus>,
+    submit_attempted: bool,
+    /// The number of times the form was reset, which the fields compare to their own.
+    pub(crate) resets: u64,
+}
+
+/// The status of a field of a [`Form`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FieldStatus {
+    pub(crate) dirty: bool,
+    pub(crate) touched: bool,
+    pub(crate) validating: bool,
+    pub(crate) error: Option<ArcStr>,
+}
+
+impl Form {
+    /// Create a form with no fields.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether no field has an error, or an asynchronous validation which hasn't finished yet.
+    pub fn is_valid(&self) -> bool {
+        self.fields
+            .values()
+            .all(|field| field.error.is_none() && !field.validating)
+    }
+
+    /// Whether any field is dirty.
+    pub fn is_dirty(&self) -> bool {
+        self.fields.values().any(|field| field.dirty)
+    }
+
+    /// Whether any field is being validated asynchronously.
+    pub fn is_validating(&self) -> bool {
+        self.fields.values().any(|field| field.validating)
+    }
+
+    /// Whether a submit was attempted since the form was created or reset.
+    pub fn submit_attempted(&self) -> bool {
+        self.submit_attempted
+    }
+
+    /// The status of the field with the given label, if it's in the view tree.
+    pub fn field(&self, label: &str) -> Option<&FieldStatus> {
+        self.fields.get(label)
+    }
+
+    /// The labels and errors of the fields which have an error.
+    pub fn errors(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.fields
+            .iter()
+            .filter_map(|(label, field)| Some((&**label, field.error.as_deref()?)))
+    }
+
+    /// Set the error of a field, e.g. one reported by a server after submitting the form.
+    ///
+    /// The error is replaced when the value of the field is validated again,
+    /// i.e. when it changes.
+    pub fn set_error(&mut self, label: &str, error: impl Into<ArcStr>) {
+        let field = self.field_mut(label);
+        field.error = Some(error.into());
+        field.validating = false;
+    }
+
+    /// Mark all fields as pristine and untouched, e.g. after the form was submitted.
+    ///
+    /// The current values of the fields become the values they are compared to
+    /// to check whether they are dirty.
+    /// The errors of the fields are kept, as their values haven't changed.
+    pub fn reset(&mut self) {
+        for field in self.fields.values_mut() {
+            field.dirty = false;
+            field.touched = false;
+        }
+        self.submit_attempted = false;
+        self.resets += 1;
+    }
+
+    pub(crate) fn field_mut(&mut self, label: &str) -> &mut FieldStatus {
+        // Avoid allocating a new key for existing fields.
+        if !self.fields.contains_key(label) {
+            self.fields.insert(label.into(), FieldStatus::default());
+        }
+        self.fields.get_mut(label).unwrap()
+    }
+
+    pub(crate) fn remove_field(&mut self, label: &str) {
+        self.fields.remove(label);
+    }
+
+    pub(crate) fn attempt_submit(&mut self) {
+        self.submit_attempted = true;
+    }
+
+    /// The error of the field with the given label, if it should be shown.
+    pub(crate) fn visible_error(&self, label: &str) -> Option<ArcStr> {
+        let field = self.fields.get(label)?;
+        if field.touched || self.submit_attempted {
+            field.error.clone()
+        } else {
+            None
+        }
+    }
+}
+
+impl FieldStatus {
+    /// Whether the value of the field differs from its value when the field was built,
+    /// or when the form was last reset.
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    /// Whether the user has edited the field since it was built, or since the form was last reset.
+    pub fn is_touched(&self) -> bool {
+        self.touched
+    }
+
+    /// Whether the field is being validated asynchronously.
+    pub fn is_validating(&self) -> bool {
+        self.validating
+    }
+
+    /// The validation error of the field, if any.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
@@ -0,0 +1,247 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Saving the app state to disk, so that it can be restored when the app is restarted.
+//!
+//! See [`Xilem::with_hot_reload`](crate::Xilem::with_hot_reload).
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, ErrorKind, Write};
+use std::path::{Path, PathBuf};
+
+use masonry::app::RenderRoot;
+use masonry::core::{Shortcut, ShortcutId, ShortcutPrecedence};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use winit::dpi::{PhysicalPosition, PhysicalSize};
+use winit::window::{Window, WindowAttributes};
+
+/// The key chord which saves a snapshot of the app state while the app is running.
+pub(crate) const SNAPSHOT_CHORD: &str = "Ctrl+Shift+F5";
+
+/// The position and size of a window, in physical pixels.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+struct WindowGeometry {
+    position: Option<(i32, i32)>,
+    size: (u32, u32),
+}
+
+/// The contents of a snapshot file.
+#[derive(Serialize, Deserialize)]
+struct Snapshot<State, Windows = HashMap<String, WindowGeometry>> {
+    state: State,
+    windows: Windows,
+}
+
+type SaveFn<State> = fn(&Path, &State, &HashMap<String, WindowGeometry>) -> std::io::Result<()>;
+
+/// The hot reload configuration of a running app.
+pub(crate) struct HotReload<State> {
+    path: PathBuf,
+    save: SaveFn<State>,
+    /// The geometries of the windows with a persistence key.
+    ///
+    /// These are first the geometries restored from the snapshot, which are used when
+    /// the windows are created, then the geometries recorded while the app runs.
+    windows: HashMap<String, WindowGeometry>,
+    shortcut_id: ShortcutId,
+}
+
+impl<State> HotReload<State> {
+    /// Load the snapshot at `path`, if there's one.
+    ///
+    /// Returns the restored state, if any.
+    pub(crate) fn load(path: PathBuf) -> (Self, Option<State>)
+    where
+        State: Serialize + DeserializeOwned,
+    {
+        let snapshot = match File::open(&path) {
+            Ok(file) => {
+                match serde_json::from_reader::<_, Snapshot<State>>(BufReader::new(file)) {
+                    Ok(snapshot) => Some(snapshot),
+                    Err(err) => {
+                        // This is expected when the type of the state has changed.
+                        tracing::warn!(
+                            "Couldn't restore the app state from {}: {err}",
+                            path.display()
+                        );
+                        None
+                    }
+                }
+            }
+            Err(err) if err.kind() == ErrorKind::NotFound => None,
+            Err(err) => {
+                tracing::warn!("Couldn't open {}: {err}", path.display());
+                None
+            }
+        };
+        let (state, windows) = match snapshot {
+            Some(Snapshot { state, windows }) => (Some(state), windows),
+            None => (None, HashMap::new()),
+        };
+        let hot_reload = Self {
+            path,
+            save: save_snapshot::<State>,
+            windows,
+            shortcut_id: ShortcutId::next(),
+        };
+        (hot_reload, state)
+    }
+
+    pub(crate) fn shortcut_id(&self) -> ShortcutId {
+        self.shortcut_id
+    }
+
+    /// Register the shortcut which saves a snapshot in the given window.
+    pub(crate) fn register_shortcut(&self, render_root: &mut RenderRoot) {
+        render_root.register_shortcut(Shortcut {
+            id: self.shortcut_id,
+            chord: SNAPSHOT_CHORD.parse().unwrap(),
+            precedence: ShortcutPrecedence::BeforeFocused,
+            label: Some("Save app state snapshot".into()),
+        });
+    }
+
+    /// Apply the restored geometry of the window with the given persistence key, if any.
+    pub(crate) fn restore_window(
+        &self,
+        key: Option<&str>,
+        mut attrs: WindowAttributes,
+    ) -> WindowAttributes {
+        let Some(geometry) = key.and_then(|key| self.windows.get(key)) else {
+            return attrs;
+        };
+        if let Some((x, y)) = geometry.position {
+            attrs = attrs.with_position(PhysicalPosition::new(x, y));
+        }
+        let (width, height) = geometry.size;
+        attrs.with_inner_size(PhysicalSize::new(width, height))
+    }
+
+    /// Record the current geometry of the window with the given persistence key.
+    pub(crate) fn record_window(&mut self, key: &str, window: &Window) {
+        let size = window.inner_size();
+        let geometry = WindowGeometry {
+            // This isn't supported on all platforms, e.g. Wayland.
+            position: window.outer_position().ok().map(|pos| (pos.x, pos.y)),
+            size: (size.width, size.height),
+        };
+        self.windows.insert(key.to_string(), geometry);
+    }
+
+    /// Save `state` and the recorded window geometries.
+    pub(crate) fn save(&self, state: &State) {
+        match (self.save)(&self.path, state, &self.windows) {
+            Ok(()) => tracing::info!("Saved the app state to {}", self.path.display()),
+            Err(err) => tracing::error!(
+                "Couldn't save the app state to {}: {err}",
+                self.path.display()
+            ),
+        }
+    }
+}
+
+fn save_snapshot<State: Serialize>(
+    path: &Path,
+    state: &State,
+    windows: &HashMap<String, WindowGeometry>,
+) -> std::io::Result<()> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    serde_json::to_writer_pretty(&mut writer, &Snapshot { state, windows })?;
+    writer.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use winit::dpi::{Position, Size};
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Counter {
+        count: u32,
+    }
+
+    struct TestFile(PathBuf);
+
+    impl TestFile {
+        fn new(name: &str) -> Self {
+            Self(std::env::temp_dir().join(format!(
+                "xilem-hot-reload-{name}-{}.json",
+                std::process::id()
+            )))
+        }
+    }
+
+    impl Drop for TestFile {
+        fn drop(&mut self) {
+            drop(std::fs::remove_file(&self.0));
+        }
+    }
+
+    #[test]
+    fn missing_snapshot() {
+        let file = TestFile::new("missing");
+        let (_, state) = HotReload::<Counter>::load(file.0.clone());
+        assert_eq!(state, None);
+    }
+
+    #[test]
+    fn save_and_load() {
+        let file = TestFile::new("round-trip");
+        let (hot_reload, _) = HotReload::<Counter>::load(file.0.clone());
+        hot_reload.save(&Counter { count: 3 });
+
+        let (_, state) = HotReload::<Counter>::load(file.0.clone());
+        assert_eq!(state, Some(Counter { count: 3 }));
+    }
+
+    #[test]
+    fn changed_state_type() {
+        let file = TestFile::new("changed-type");
+        std::fs::write(&file.0, r#"{ "state": { "total": 3 }, "windows": {} }"#).unwrap();
+        let (_, state) = HotReload::<Counter>::load(file.0.clone());
+        assert_eq!(state, None);
+    }
+
+    #[test]
+    fn window_geometry() {
+        let file = TestFile::new("windows");
+        std::fs::write(
+            &file.0,
+            r#"{
+                "state": { "count": 0 },
+                "windows": { "main": { "position": [10, 20], "size": [300, 200] } }
+            }"#,
+        )
+        .unwrap();
+        let (hot_reload, _) = HotReload::<Counter>::load(file.0.clone());
+
+        let attrs = hot_reload.restore_window(Some("main"), WindowAttributes::default());
+        assert_eq!(
+            attrs.position,
+            Some(Position::Physical(PhysicalPosition::new(10, 20)))
+        );
+        assert_eq!(
+            attrs.inner_size,
+            Some(Size::Physical(PhysicalSize::new(300, 200)))
+        );
+        let attrs = hot_reload.restore_window(Some("other"), WindowAttributes::default());
+        assert_eq!(attrs.position, None);
+        let attrs = hot_reload.restore_window(None, WindowAttributes::default());
+        assert_eq!(attrs.inner_size, None);
+
+        // The geometries are kept when the state is saved.
+        hot_reload.save(&Counter { count: 1 });
+        let (hot_reload, state) = HotReload::<Counter>::load(file.0.clone());
+        assert_eq!(state, Some(Counter { count: 1 }));
+        let attrs = hot_reload.restore_window(Some("main"), WindowAttributes::default());
+        assert_eq!(
+            attrs.inner_size,
+            Some(Size::Physical(PhysicalSize::new(300, 200)))
+        );
+    }
+}
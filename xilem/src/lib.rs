@@ -81,6 +81,7 @@
 //! * [`prose`][crate::view::prose]: displays immutable, selectable text
 //! * [`text_input`][crate::view::text_input]: allows text to be edited by the user
 //! * [`task`][crate::view::task]: launch an async task which will run until the view is no longer in the tree
+//! * [`file_dialog`][crate::view::file_dialog]: ask the user to pick files to open or save
 //! * [`zstack`][crate::view::zstack]: an element that lays out its children on top of each other
 //!
 //! You should also expect to use the adapters from Xilem Core, including:
@@ -147,19 +148,31 @@ pub use winit;
 mod any_view;
 mod app;
 mod driver;
+mod form;
+#[cfg(feature = "hot_reload")]
+mod hot_reload;
 mod one_of;
 mod pod;
 mod property_tuple;
+mod signal;
+mod undo;
 mod view_ctx;
 mod widget_view;
 mod window_options;
 mod window_view;
 
+pub mod file_dialog;
 pub mod style;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod ui_state;
 pub mod view;
 pub use any_view::AnyWidgetView;
 pub use driver::{ASYNC_MARKER_WIDGET, MasonryDriver, async_action};
+pub use form::{FieldStatus, Form};
 pub use property_tuple::PropertyTuple;
+pub use signal::Signal;
+pub use undo::UndoHistory;
 
 pub use app::{AppState, ExitOnClose, Xilem};
 pub use pod::Pod;
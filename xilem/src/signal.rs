@@ -0,0 +1,185 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use crate::core::{RawProxy, Rebuild, Resource, SendMessage, ViewId};
+
+/// A cell of state which only rebuilds the views reading it when it changes.
+///
+/// A signal is made available to a part of the view tree using
+/// [`provides`](crate::core::provides), then read using [`with_signal`](crate::view::with_signal).
+/// When the value of the signal is changed using [`set`](Self::set) or [`update`](Self::update),
+/// the views created by the `with_signal` views reading it are rebuilt, without running
+/// the app logic again.
+/// The view tree is still walked from its root to these views, but the children of
+/// view sequences (e.g. the children of a [`flex`](crate::view::flex)) which don't contain
+/// them are skipped.
+/// Views which aren't in a sequence, such as both panes of a [`split`](crate::view::split),
+/// are rebuilt as usual, so this is cheapest when the readers are nested in sequences.
+///
+/// Signals are cheap to clone, and all clones share the same value.
+/// They can be changed from any thread, e.g. from an async [`task`](crate::view::task).
+///
+/// Note that changing a signal in an event handler which returns an action, such as the
+/// callback of a [`button`](crate::view::button), still runs the app logic afterwards.
+/// Signals are most useful for values which change often, outside of the app state,
+/// such as progress reports from a background task.
+///
+/// # Examples
+///
+/// ```
+/// use xilem::core::provides;
+/// use xilem::view::{flex, label, with_signal};
+/// use xilem::{Signal, WidgetView};
+///
+/// struct AppState {
+///     progress: Signal<f64>,
+/// }
+///
+/// fn app_logic(state: &mut AppState) -> impl WidgetView<AppState> + use<> {
+///     provides(
+///         |state: &mut AppState| state.progress.clone(),
+///         flex((
+///             label("Downloading"),
+///             with_signal(|progress: &f64, _: &mut AppState| {
+///                 label(format!("{:.0}%", progress * 100.))
+///             }),
+///         )),
+///     )
+/// }
+/// ```
+pub struct Signal<T> {
+    inner: Arc<SignalInner<T>>,
+}
+
+struct SignalInner<T> {
+    value: Mutex<T>,
+    subscribers: Mutex<Vec<Subscriber>>,
+}
+
+/// A view reading a signal, which is sent a [`Rebuild`] message when it changes.
+struct Subscriber {
+    path: Arc<[ViewId]>,
+    proxy: Arc<dyn RawProxy>,
+}
+
+impl<T> Signal<T> {
+    /// Create a signal with the given initial value.
+    pub fn new(value: T) -> Self {
+        Self {
+            inner: Arc::new(SignalInner {
+                value: Mutex::new(value),
+                subscribers: Mutex::new(Vec::new()),
+            }),
+        }
+    }
+
+    /// Get a clone of the current value.
+    pub fn get(&self) -> T
+    where
+        T: Clone,
+    {
+        self.lock().clone()
+    }
+
+    /// Run `f` with a reference to the current value.
+    ///
+    /// The signal must not be used inside `f`.
+    pub fn with<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        f(&self.lock())
+    }
+
+    /// Replace the value, and rebuild the views reading it.
+    pub fn set(&self, value: T) {
+        *self.lock() = value;
+        self.notify();
+    }
+
+    /// Let `f` edit the value, then rebuild the views reading it.
+    ///
+    /// The signal must not be used inside `f`.
+    pub fn update<R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
+        let ret = f(&mut self.lock());
+        self.notify();
+        ret
+    }
+
+    /// Returns `true` if both signals share the same value.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, T> {
+        // A panic while the value is borrowed can't leave it in an invalid state,
+        // as it's only changed by whole assignments from the user's point of view.
+        self.inner
+            .value
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn subscribers(&self) -> MutexGuard<'_, Vec<Subscriber>> {
+        self.inner
+            .subscribers
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn notify(&self) {
+        // Subscribers whose driver has stopped can't be rebuilt anymore.
+        self.subscribers().retain(|subscriber| {
+            subscriber
+                .proxy
+                .send_message(subscriber.path.clone(), SendMessage::new(Rebuild))
+                .is_ok()
+        });
+    }
+
+    /// Send a [`Rebuild`] message to the view at `path` when this signal changes.
+    pub(crate) fn subscribe(&self, path: Arc<[ViewId]>, proxy: Arc<dyn RawProxy>) {
+        let mut subscribers = self.subscribers();
+        if !subscribers
+            .iter()
+            .any(|subscriber| subscriber.is(&path, &proxy))
+        {
+            subscribers.push(Subscriber { path, proxy });
+        }
+    }
+
+    /// Undo [`subscribe`](Self::subscribe).
+    pub(crate) fn unsubscribe(&self, path: &Arc<[ViewId]>, proxy: &Arc<dyn RawProxy>) {
+        self.subscribers()
+            .retain(|subscriber| !subscriber.is(path, proxy));
+    }
+}
+
+impl Subscriber {
+    fn is(&self, path: &Arc<[ViewId]>, proxy: &Arc<dyn RawProxy>) -> bool {
+        // Each window has its own proxy, and paths are only unique within a window.
+        self.path == *path && Arc::ptr_eq(&self.proxy, proxy)
+    }
+}
+
+impl<T> Clone for Signal<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T: Default> Default for Signal<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: Debug> Debug for Signal<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Signal").field(&*self.lock()).finish()
+    }
+}
+
+impl<T: Debug + Send + 'static> Resource for Signal<T> {}
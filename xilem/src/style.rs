@@ -4,12 +4,12 @@
 //! Traits used to set custom styles on views.
 
 use masonry::core::Property;
-use vello::peniko::Color;
+use vello::peniko::{BlendMode, Color};
 
-pub use masonry::properties::types::{Gradient, GradientShape};
+pub use masonry::properties::types::{Gradient, GradientShape, RadialGradientExtent, UnitPoint};
 pub use masonry::properties::{
-    ActiveBackground, Background, BorderColor, BorderWidth, BoxShadow, CornerRadius,
-    DisabledBackground, HoveredBorderColor, Padding,
+    ActiveBackground, Background, BorderColor, BorderGradient, BorderWidth, BoxShadow, BoxShadows,
+    CornerRadius, DisabledBackground, HoveredBorderColor, MixBlendMode, Opacity, Padding,
 };
 
 /// Trait implemented by views to signal that a given property can be set on them.
@@ -133,6 +133,15 @@ pub trait Style: Sized {
         self
     }
 
+    /// Set the element's border to a gradient.
+    fn border_gradient(mut self, gradient: Gradient) -> Self
+    where
+        Self: HasProperty<BorderGradient>,
+    {
+        *self.property() = Some(BorderGradient::new(gradient));
+        self
+    }
+
     /// Set the element's border color when hovered.
     fn hovered_border_color(mut self, color: Color) -> Self
     where
@@ -154,9 +163,20 @@ pub trait Style: Sized {
     /// Set the element's box shadow.
     fn box_shadow(mut self, box_shadow: BoxShadow) -> Self
     where
-        Self: HasProperty<BoxShadow>,
+        Self: HasProperty<BoxShadows>,
+    {
+        *self.property() = Some(box_shadow.into());
+        self
+    }
+
+    /// Set a list of box shadows on the element.
+    ///
+    /// The first shadow is painted on top.
+    fn box_shadows(mut self, box_shadows: impl IntoIterator<Item = BoxShadow>) -> Self
+    where
+        Self: HasProperty<BoxShadows>,
     {
-        *self.property() = Some(box_shadow);
+        *self.property() = Some(box_shadows.into_iter().collect());
         self
     }
 
@@ -169,6 +189,26 @@ pub trait Style: Sized {
         self
     }
 
+    /// Set the opacity of the element and its children.
+    ///
+    /// Fully transparent elements don't receive pointer events.
+    fn opacity(mut self, opacity: f32) -> Self
+    where
+        Self: HasProperty<Opacity>,
+    {
+        *self.property() = Some(Opacity(opacity));
+        self
+    }
+
+    /// Set the blend mode used to composite the element and its children onto what's behind them.
+    fn mix_blend_mode(mut self, blend_mode: impl Into<BlendMode>) -> Self
+    where
+        Self: HasProperty<MixBlendMode>,
+    {
+        *self.property() = Some(MixBlendMode(blend_mode.into()));
+        self
+    }
+
     /// Set the element's padding.
     fn padding(mut self, padding: impl Into<Padding>) -> Self
     where
@@ -0,0 +1,426 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Helper tools for testing Xilem apps without a window.
+//!
+//! The main type is [`AppHarness`], which runs an app's [`Xilem`] value headlessly,
+//! on top of Masonry's [`TestHarness`].
+//! Tests can send it input, find the widgets the app created, step the app's async
+//! [`task`](crate::view::task)s and [`worker`](crate::view::worker)s, and then check the
+//! resulting state of the app and the rendered result.
+//!
+//! This module requires the `testing` feature.
+//!
+//! # Example
+//!
+//! ```rust
+//! use xilem::WidgetView;
+//! use xilem::testing::AppHarness;
+//! use xilem::view::{button, flex, label};
+//!
+//! #[derive(Default)]
+//! struct Counter {
+//!     num: i32,
+//! }
+//!
+//! fn app_logic(data: &mut Counter) -> impl WidgetView<Counter> + use<> {
+//!     flex((
+//!         label(format!("Count: {}", data.num)),
+//!         button("Increment", |data: &mut Counter| data.num += 1),
+//!     ))
+//! }
+//!
+//! let mut harness = AppHarness::new_simple(Counter::default(), app_logic);
+//! let button = harness.find_widget_by_accessibility_name("Increment").unwrap().id();
+//! harness.mouse_click_on(button);
+//! assert_eq!(harness.state().num, 1);
+//! assert!(harness.find_widget_by_debug_text("Count: 1").is_some());
+//! ```
+
+use std::time::Duration;
+
+use masonry::core::{PointerButton, PointerEvent, TextEvent, Widget, WidgetId, WidgetRef};
+use masonry::kurbo::{Point, Vec2};
+use masonry::theme::default_property_set;
+use masonry_winit::app::{MasonryUserEvent, WindowId};
+use tokio::sync::mpsc::{UnboundedReceiver, unbounded_channel};
+
+use crate::app::SimpleLogic;
+use crate::{
+    AnyWidgetView, AppState, ExitOnClose, MasonryDriver, WidgetView, WindowOptions, Xilem,
+};
+
+pub use masonry::assert_render_snapshot;
+pub use masonry::testing::{TestHarness, TestHarnessParams};
+
+/// A harness which runs a Xilem app without a window, for tests.
+///
+/// The app must have a single window.
+/// Its widgets are hosted by a Masonry [`TestHarness`], which the input methods of this
+/// type forward to.
+/// The actions emitted by the widgets are then routed to the app's views, and the app logic
+/// is run again, like it would be in a real window.
+///
+/// # Async
+///
+/// The app's async runtime runs on the test's thread, and its clock is paused.
+/// Async tasks only make progress during [`run_tasks`](Self::run_tasks) and
+/// [`advance_time`](Self::advance_time), and the messages they send are delivered in order.
+/// This makes tests of async code deterministic.
+///
+/// # Snapshots
+///
+/// The [`assert_render_snapshot`] macro can be used directly with this type.
+///
+/// # Limitations
+///
+/// The attributes of the window, such as its title, aren't updated after the app is built.
+pub struct AppHarness<State, Logic> {
+    driver: MasonryDriver<State, Logic>,
+    harness: TestHarness,
+    window_id: WindowId,
+    events: UnboundedReceiver<MasonryUserEvent>,
+}
+
+impl<State: 'static> AppHarness<ExitOnClose<State>, SimpleLogic<State>> {
+    /// Builds a harness for a single window app, as created by [`Xilem::new_simple`].
+    ///
+    /// Thanks to [`ExitOnClose`] implementing [`Deref`](std::ops::Deref),
+    /// [`state`](Self::state) gives direct access to `state`.
+    pub fn new_simple<View>(state: State, logic: impl FnMut(&mut State) -> View + 'static) -> Self
+    where
+        View: WidgetView<State>,
+    {
+        Self::new(Xilem::new_simple(
+            state,
+            logic,
+            WindowOptions::new("Xilem test"),
+        ))
+    }
+}
+
+impl<State, Logic, WindowIter> AppHarness<State, Logic>
+where
+    State: AppState + 'static,
+    Logic: FnMut(&mut State) -> WindowIter + 'static,
+    WindowIter: Iterator<Item = (WindowId, WindowOptions<State>, Box<AnyWidgetView<State>>)>,
+{
+    /// Builds a harness running `app`.
+    ///
+    /// Window size will be [`TestHarnessParams::DEFAULT_SIZE`].
+    /// Background color will be [`TestHarnessParams::DEFAULT_BACKGROUND_COLOR`].
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the app doesn't have exactly one window.
+    pub fn new(app: Xilem<State, Logic>) -> Self {
+        Self::new_with(app, TestHarnessParams::default())
+    }
+
+    /// Builds a harness running `app`, with additional parameters.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the app doesn't have exactly one window.
+    pub fn new_with(mut app: Xilem<State, Logic>, params: TestHarnessParams) -> Self {
+        app.runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .start_paused(true)
+            .build()
+            .unwrap();
+        let default_properties = app
+            .default_properties
+            .take()
+            .unwrap_or_else(default_property_set);
+        let fonts = std::mem::take(&mut app.fonts);
+
+        let (sender, events) = unbounded_channel();
+        let (driver, windows) =
+            app.into_driver_and_windows(move |event| sender.send(event).map_err(|err| err.0));
+        let mut windows = windows.into_iter();
+        let Some((window_id, _, root_widget)) = windows.next() else {
+            panic!("the app logic didn't create any window");
+        };
+        assert!(
+            windows.next().is_none(),
+            "AppHarness only supports apps with a single window"
+        );
+
+        let mut harness = TestHarness::create_with_pod(default_properties, root_widget, params);
+        for font in fonts {
+            harness.register_fonts(font);
+        }
+        let mut this = Self {
+            driver,
+            harness,
+            window_id,
+            events,
+        };
+        this.process_actions();
+        this
+    }
+
+    // --- MARK: STATE
+
+    /// The id of the app's window.
+    pub fn window_id(&self) -> WindowId {
+        self.window_id
+    }
+
+    /// The current state of the app.
+    pub fn state(&self) -> &State {
+        self.driver.state()
+    }
+
+    /// Let `f` edit the state of the app, then run the app logic.
+    pub fn edit_state<R>(&mut self, f: impl FnOnce(&mut State) -> R) -> R {
+        let window_id = self.window_id;
+        let driver = &mut self.driver;
+        let ret = self
+            .harness
+            .edit_render_root(|render_root| driver.edit_state_headless(window_id, render_root, f));
+        self.process_actions();
+        ret
+    }
+
+    /// Simulate the user closing the window.
+    ///
+    /// This runs the window's close callback and the app logic.
+    /// Returns `true` if the app would keep running.
+    pub fn request_close(&mut self) -> bool {
+        let window_id = self.window_id;
+        let driver = &mut self.driver;
+        let keep_running = self.harness.edit_render_root(|render_root| {
+            driver.on_close_requested_headless(window_id, render_root)
+        });
+        self.process_actions();
+        keep_running
+    }
+
+    /// Simulate the app exiting for another reason than its window closing,
+    /// e.g. because a widget requested it.
+    ///
+    /// This saves the hot reload snapshot, and tears down the window's views.
+    pub fn exit(mut self) {
+        let window_id = self.window_id;
+        let driver = &mut self.driver;
+        self.harness
+            .edit_render_root(|render_root| driver.on_exit_headless(window_id, render_root));
+    }
+
+    /// The Masonry harness hosting the app's widgets.
+    pub fn masonry_harness(&self) -> &TestHarness {
+        &self.harness
+    }
+
+    /// The Masonry harness hosting the app's widgets.
+    ///
+    /// After sending input to it directly, call [`process_actions`](Self::process_actions)
+    /// so that the app reacts to it.
+    pub fn masonry_harness_mut(&mut self) -> &mut TestHarness {
+        &mut self.harness
+    }
+
+    /// Route the actions emitted by the widgets to the app's views, and run the app logic
+    /// accordingly.
+    ///
+    /// The input methods of this type call this automatically.
+    pub fn process_actions(&mut self) {
+        let window_id = self.window_id;
+        loop {
+            let driver = &mut self.driver;
+            if let Some((action, widget_id)) = self.harness.pop_action() {
+                self.harness.edit_render_root(|render_root| {
+                    driver.on_action_headless(window_id, render_root, widget_id, action);
+                });
+            } else if let Some(shortcut_id) = self.harness.pop_shortcut() {
+                self.harness.edit_render_root(|render_root| {
+                    driver.on_shortcut_headless(window_id, render_root, shortcut_id);
+                });
+            } else {
+                break;
+            }
+        }
+    }
+
+    // --- MARK: ASYNC
+
+    /// Run the app's async tasks until none of them can make progress without time passing,
+    /// and deliver the messages they send.
+    pub fn run_tasks(&mut self) {
+        loop {
+            // On a current-thread runtime, yielding lets all the tasks which are ready run.
+            self.driver
+                .runtime()
+                .block_on(async { tokio::task::yield_now().await });
+            let mut delivered = false;
+            while let Ok(event) = self.events.try_recv() {
+                self.deliver(event);
+                delivered = true;
+            }
+            if !delivered {
+                break;
+            }
+        }
+    }
+
+    /// Advance the clock of the app's async runtime by `duration`, running the async tasks
+    /// and delivering the messages they send along the way.
+    ///
+    /// This doesn't advance the animation clock of the widgets: use
+    /// [`animate_ms`](Self::animate_ms) for that.
+    pub fn advance_time(&mut self, duration: Duration) {
+        self.run_tasks();
+        let runtime = self.driver.runtime().clone();
+        let deadline = runtime.block_on(async { tokio::time::Instant::now() }) + duration;
+        loop {
+            // The paused clock jumps to the next timer once all the tasks are idle,
+            // so this only returns once a message is sent or the deadline is reached.
+            let event = runtime.block_on(tokio::time::timeout_at(deadline, self.events.recv()));
+            let Ok(Some(event)) = event else {
+                break;
+            };
+            self.deliver(event);
+            self.run_tasks();
+        }
+    }
+
+    fn deliver(&mut self, event: MasonryUserEvent) {
+        let MasonryUserEvent::Action(window_id, action, widget_id) = event else {
+            return;
+        };
+        let driver = &mut self.driver;
+        self.harness.edit_render_root(|render_root| {
+            driver.on_action_headless(window_id, render_root, widget_id, action);
+        });
+        self.process_actions();
+    }
+
+    // --- MARK: INPUT
+
+    /// Send a [`PointerEvent`] to the window.
+    pub fn process_pointer_event(&mut self, event: PointerEvent) {
+        self.harness.process_pointer_event(event);
+        self.process_actions();
+    }
+
+    /// Send a [`TextEvent`] to the window.
+    pub fn process_text_event(&mut self, event: TextEvent) {
+        self.harness.process_text_event(event);
+        self.process_actions();
+    }
+
+    /// Move the mouse to the given position. See [`TestHarness::mouse_move`].
+    pub fn mouse_move(&mut self, pos: impl Into<Point>) {
+        self.harness.mouse_move(pos);
+        self.process_actions();
+    }
+
+    /// Press a mouse button. See [`TestHarness::mouse_button_press`].
+    pub fn mouse_button_press(&mut self, button: PointerButton) {
+        self.harness.mouse_button_press(button);
+        self.process_actions();
+    }
+
+    /// Release a mouse button. See [`TestHarness::mouse_button_release`].
+    pub fn mouse_button_release(&mut self, button: PointerButton) {
+        self.harness.mouse_button_release(button);
+        self.process_actions();
+    }
+
+    /// Scroll the mouse wheel. See [`TestHarness::mouse_wheel`].
+    pub fn mouse_wheel(&mut self, delta: Vec2) {
+        self.harness.mouse_wheel(delta);
+        self.process_actions();
+    }
+
+    /// Move the mouse to the given widget. See [`TestHarness::mouse_move_to`].
+    pub fn mouse_move_to(&mut self, id: WidgetId) {
+        self.harness.mouse_move_to(id);
+        self.process_actions();
+    }
+
+    /// Click the primary mouse button on the given widget.
+    /// See [`TestHarness::mouse_click_on`].
+    pub fn mouse_click_on(&mut self, id: WidgetId) {
+        self.harness.mouse_click_on(id);
+        self.process_actions();
+    }
+
+    /// Type the given characters. See [`TestHarness::keyboard_type_chars`].
+    pub fn keyboard_type_chars(&mut self, text: &str) {
+        self.harness.keyboard_type_chars(text);
+        self.process_actions();
+    }
+
+    /// Give focus to the given widget. See [`TestHarness::focus_on`].
+    pub fn focus_on(&mut self, id: Option<WidgetId>) {
+        self.harness.focus_on(id);
+        self.process_actions();
+    }
+
+    /// Advance the animation clock of the widgets. See [`TestHarness::animate_ms`].
+    pub fn animate_ms(&mut self, ms: u64) {
+        self.harness.animate_ms(ms);
+        self.process_actions();
+    }
+
+    // --- MARK: GETTERS
+
+    /// Return a [`WidgetRef`] to the root widget.
+    pub fn root_widget(&self) -> WidgetRef<'_, dyn Widget> {
+        self.harness.root_widget()
+    }
+
+    /// Return a [`WidgetRef`] to the widget with the given id.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if no Widget with this id can be found.
+    #[track_caller]
+    pub fn get_widget(&self, id: WidgetId) -> WidgetRef<'_, dyn Widget> {
+        self.harness.get_widget(id)
+    }
+
+    /// Try to return a [`WidgetRef`] to the widget with the given id.
+    pub fn try_get_widget(&self, id: WidgetId) -> Option<WidgetRef<'_, dyn Widget>> {
+        self.harness.try_get_widget(id)
+    }
+
+    /// Return a [`WidgetRef`] to the focused widget.
+    pub fn focused_widget(&self) -> Option<WidgetRef<'_, dyn Widget>> {
+        self.harness.focused_widget()
+    }
+
+    /// Return the first widget whose debug text is `text`.
+    /// See [`TestHarness::find_widget_by_debug_text`].
+    pub fn find_widget_by_debug_text(&self, text: &str) -> Option<WidgetRef<'_, dyn Widget>> {
+        self.harness.find_widget_by_debug_text(text)
+    }
+
+    /// Return the first widget whose accessibility label is `name`.
+    /// See [`TestHarness::find_widget_by_accessibility_name`].
+    pub fn find_widget_by_accessibility_name(
+        &mut self,
+        name: &str,
+    ) -> Option<WidgetRef<'_, dyn Widget>> {
+        self.harness.find_widget_by_accessibility_name(name)
+    }
+
+    // --- MARK: SNAPSHOT
+
+    /// Method used by [`assert_render_snapshot`]. Use this macro, not this method.
+    ///
+    /// See [`TestHarness::check_render_snapshot`].
+    #[doc(hidden)]
+    #[track_caller]
+    pub fn check_render_snapshot(
+        &mut self,
+        manifest_dir: &str,
+        test_name: &str,
+        expect_failure: bool,
+    ) {
+        self.harness
+            .check_render_snapshot(manifest_dir, test_name, expect_failure);
+    }
+}
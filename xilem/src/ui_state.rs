@@ -0,0 +1,185 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Stores for UI-only state, such as scroll positions, which isn't part of the app state.
+//!
+//! Some views can be given a persistence key, e.g. using
+//! [`Portal::persistence_key`](crate::view::Portal::persistence_key).
+//! These views save their UI state under that key when they are rebuilt or torn down,
+//! and restore it when a view with the same key is built again.
+//! The state is kept in the [`UiStateStore`] of the app, which is set using
+//! [`Xilem::with_ui_state_store`](crate::Xilem::with_ui_state_store):
+//!
+//! - By default, [`MemoryUiStateStore`] keeps the state while the app runs.
+//!   This lets e.g. a list keep its scroll position when the user navigates
+//!   away from it and back.
+//! - With the `persistence` feature, [`JsonFileUiStateStore`] also saves the state to a file,
+//!   so that it's restored the next time the app is run.
+//!
+//! Keys should be unique among the views which are in the view tree at the same time.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::Mutex;
+
+/// A piece of UI state saved by a view.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "persistence", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum UiState {
+    /// The scroll position of a [`portal`](crate::view::portal).
+    ScrollOffset {
+        /// The horizontal offset, in logical pixels.
+        x: f64,
+        /// The vertical offset, in logical pixels.
+        y: f64,
+    },
+    /// The split point of a [`split`](crate::view::split), from 0 to 1.
+    SplitPoint(f64),
+    /// The active child of an [`indexed_stack`](crate::view::indexed_stack).
+    ActiveIndex(usize),
+}
+
+/// A store of the UI state of the views which have a persistence key.
+///
+/// See the [module level documentation](self) for details.
+pub trait UiStateStore: Debug + Send + Sync + 'static {
+    /// The state saved under `key`, if any.
+    fn load(&self, key: &str) -> Option<UiState>;
+
+    /// Save `state` under `key`, replacing any previous state.
+    fn save(&self, key: &str, state: UiState);
+}
+
+/// A [`UiStateStore`] which keeps the state in memory while the app runs.
+#[derive(Debug, Default)]
+pub struct MemoryUiStateStore {
+    states: Mutex<HashMap<String, UiState>>,
+}
+
+impl UiStateStore for MemoryUiStateStore {
+    fn load(&self, key: &str) -> Option<UiState> {
+        self.states.lock().unwrap().get(key).copied()
+    }
+
+    fn save(&self, key: &str, state: UiState) {
+        self.states.lock().unwrap().insert(key.to_string(), state);
+    }
+}
+
+#[cfg(feature = "persistence")]
+pub use json_file::JsonFileUiStateStore;
+
+#[cfg(feature = "persistence")]
+mod json_file {
+    use std::collections::HashMap;
+    use std::fs::File;
+    use std::io::{BufReader, BufWriter, ErrorKind, Write};
+    use std::path::{Path, PathBuf};
+    use std::sync::mpsc::{Receiver, Sender, channel};
+    use std::sync::{Arc, Mutex};
+    use std::thread::JoinHandle;
+    use std::time::Duration;
+
+    use super::{UiState, UiStateStore};
+
+    /// How long the state must stay unchanged before it's written, so that e.g. scrolling
+    /// doesn't write the file on each frame.
+    const WRITE_DELAY: Duration = Duration::from_millis(500);
+
+    /// A [`UiStateStore`] which saves the state to a JSON file, so that it persists
+    /// between runs of the app.
+    ///
+    /// The file is written by a background thread, once the state has stopped changing
+    /// for a short while, and when the store is dropped.
+    /// Errors are logged, and otherwise ignored, as losing UI state isn't critical.
+    #[derive(Debug)]
+    pub struct JsonFileUiStateStore {
+        path: PathBuf,
+        states: Arc<Mutex<HashMap<String, UiState>>>,
+        /// Wakes up the writer thread when a state changes, and stops it when dropped.
+        changes: Option<Sender<()>>,
+        writer: Option<JoinHandle<()>>,
+    }
+
+    impl JsonFileUiStateStore {
+        /// Create a store which saves the state to the file at `path`.
+        ///
+        /// The state previously saved to that file, if any, is loaded now.
+        pub fn new(path: impl Into<PathBuf>) -> Self {
+            let path = path.into();
+            let states = match File::open(&path) {
+                Ok(file) => serde_json::from_reader(BufReader::new(file)).unwrap_or_else(|err| {
+                    tracing::warn!("Couldn't read the UI state from {}: {err}", path.display());
+                    HashMap::new()
+                }),
+                Err(err) if err.kind() == ErrorKind::NotFound => HashMap::new(),
+                Err(err) => {
+                    tracing::warn!("Couldn't open {}: {err}", path.display());
+                    HashMap::new()
+                }
+            };
+            let states = Arc::new(Mutex::new(states));
+            let (changes, receiver) = channel();
+            let writer = std::thread::Builder::new()
+                .name("xilem-ui-state".into())
+                .spawn({
+                    let path = path.clone();
+                    let states = states.clone();
+                    move || run_writer(&path, &states, &receiver)
+                })
+                .expect("failed to spawn the UI state writer thread");
+            Self {
+                path,
+                states,
+                changes: Some(changes),
+                writer: Some(writer),
+            }
+        }
+    }
+
+    impl UiStateStore for JsonFileUiStateStore {
+        fn load(&self, key: &str) -> Option<UiState> {
+            self.states.lock().unwrap().get(key).copied()
+        }
+
+        fn save(&self, key: &str, state: UiState) {
+            let mut states = self.states.lock().unwrap();
+            if states.get(key) == Some(&state) {
+                return;
+            }
+            states.insert(key.to_string(), state);
+            if let Some(changes) = &self.changes {
+                // This only fails if the writer thread panicked, which is logged on drop.
+                drop(changes.send(()));
+            }
+        }
+    }
+
+    impl Drop for JsonFileUiStateStore {
+        fn drop(&mut self) {
+            // Disconnecting the channel makes the writer write any pending change, then stop.
+            drop(self.changes.take());
+            if let Some(Err(_)) = self.writer.take().map(JoinHandle::join) {
+                tracing::error!("The UI state writer thread panicked");
+            }
+        }
+    }
+
+    fn run_writer(path: &Path, states: &Mutex<HashMap<String, UiState>>, changes: &Receiver<()>) {
+        while changes.recv().is_ok() {
+            // Wait for the changes to settle, or for the store to be dropped.
+            while changes.recv_timeout(WRITE_DELAY).is_ok() {}
+            let states = states.lock().unwrap().clone();
+            if let Err(err) = write_states(path, &states) {
+                tracing::error!("Couldn't save the UI state to {}: {err}", path.display());
+            }
+        }
+    }
+
+    fn write_states(path: &Path, states: &HashMap<String, UiState>) -> std::io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        serde_json::to_writer_pretty(&mut writer, states)?;
+        writer.flush()
+    }
+}
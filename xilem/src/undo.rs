@@ -0,0 +1,434 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use std::ops::{Deref, DerefMut};
+
+use masonry::core::ArcStr;
+
+use crate::core::ViewId;
+
+/// The default maximum number of transactions which can be undone.
+const DEFAULT_LIMIT: usize = 100;
+
+/// A value whose changes can be undone and redone.
+///
+/// The changes made to the value by the event handlers inside an
+/// [`undoable`](crate::view::undoable) view are recorded automatically, by taking a snapshot
+/// of the value before each handler runs.
+/// This requires the value to be `Clone`, and `PartialEq` to skip handlers which didn't change it.
+/// The value is read and changed through `Deref` and `DerefMut`.
+///
+/// Each recorded change is a transaction, which is undone as a whole.
+/// Transactions can be given a name, e.g. to show "Undo Delete" in a menu,
+/// using [`set_transaction_name`](Self::set_transaction_name).
+/// Several changes are coalesced into a single transaction:
+/// - When they are made between [`begin_transaction`](Self::begin_transaction)
+///   and [`end_transaction`](Self::end_transaction), e.g. while a slider is dragged.
+/// - When they are consecutive edits of the same text input, such as typing a word.
+///
+/// Changes made outside of an `undoable` view, e.g. in the app logic, aren't recorded.
+///
+/// # Examples
+///
+/// ```
+/// use xilem::view::{button, flex, label, undoable};
+/// use xilem::{UndoHistory, WidgetView};
+///
+/// fn app_logic(count: &mut UndoHistory<i32>) -> impl WidgetView<UndoHistory<i32>> + use<> {
+///     undoable(
+///         |count: &mut UndoHistory<i32>| count,
+///         flex((
+///             label(format!("Count: {}", **count)),
+///             button("Increment", |count: &mut UndoHistory<i32>| {
+///                 count.set_transaction_name("Increment");
+///                 **count += 1;
+///             }),
+///             button("Undo", |count: &mut UndoHistory<i32>| {
+///                 count.undo();
+///             }),
+///         )),
+///     )
+/// }
+/// ```
+pub struct UndoHistory<T> {
+    value: T,
+    undo_stack: VecDeque<Transaction<T>>,
+    redo_stack: Vec<Transaction<T>>,
+    limit: usize,
+    /// The name given to the change being made by the current event handler.
+    pending_name: Option<ArcStr>,
+    /// The transaction started with `begin_transaction`, if any.
+    open_transaction: Option<OpenTransaction>,
+    /// What the last transaction on the undo stack can be coalesced with.
+    coalesce: Option<Coalesce>,
+    /// The number of times the value was restored by `undo` or `redo`.
+    restores: u64,
+    next_transaction_id: u64,
+}
+
+/// A change which can be undone or redone.
+struct Transaction<T> {
+    name: Option<ArcStr>,
+    /// The value before this change, or before undoing it on the redo stack.
+    value: T,
+}
+
+struct OpenTransaction {
+    id: u64,
+    name: ArcStr,
+}
+
+/// The state of an [`UndoHistory`] before an event handler runs.
+pub(crate) struct Checkpoint<T> {
+    value: T,
+    restores: u64,
+}
+
+/// Identifies the changes which are coalesced into a single transaction.
+#[derive(Clone, PartialEq, Eq)]
+enum Coalesce {
+    Transaction(u64),
+    TextInput(Vec<ViewId>),
+}
+
+/// The source of a change recorded by an [`undoable`](crate::view::undoable) view.
+pub(crate) enum ChangeSource<'a> {
+    EventHandler,
+    /// An edit of the text input at the given path, relative to the `undoable` view.
+    TextInput(&'a [ViewId]),
+}
+
+impl<T> UndoHistory<T> {
+    /// Create a history whose current value is `value`, with nothing to undo.
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            undo_stack: VecDeque::new(),
+            redo_stack: Vec::new(),
+            limit: DEFAULT_LIMIT,
+            pending_name: None,
+            open_transaction: None,
+            coalesce: None,
+            restores: 0,
+            next_transaction_id: 0,
+        }
+    }
+
+    /// Set the maximum number of transactions which can be undone.
+    ///
+    /// The oldest transactions are forgotten beyond this limit.
+    /// The default is 100.
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Name the change being made by the current event handler.
+    ///
+    /// If the change is coalesced into an existing transaction, this renames that transaction.
+    pub fn set_transaction_name(&mut self, name: impl Into<ArcStr>) {
+        self.pending_name = Some(name.into());
+    }
+
+    /// Start a transaction, which the following changes are coalesced into until
+    /// [`end_transaction`](Self::end_transaction) is called.
+    ///
+    /// This ends the current transaction, if any.
+    pub fn begin_transaction(&mut self, name: impl Into<ArcStr>) {
+        self.next_transaction_id += 1;
+        self.open_transaction = Some(OpenTransaction {
+            id: self.next_transaction_id,
+            name: name.into(),
+        });
+    }
+
+    /// End the transaction started with [`begin_transaction`](Self::begin_transaction).
+    pub fn end_transaction(&mut self) {
+        self.open_transaction = None;
+        self.coalesce = None;
+    }
+
+    /// Whether there is a transaction to undo.
+    pub fn can_undo(&self) -> bool {
+        !self.undo_stack.is_empty()
+    }
+
+    /// Whether there is a transaction to redo.
+    pub fn can_redo(&self) -> bool {
+        !self.redo_stack.is_empty()
+    }
+
+    /// The name of the transaction which [`undo`](Self::undo) would undo, if it has one.
+    pub fn undo_name(&self) -> Option<&str> {
+        self.undo_stack.back()?.name.as_deref()
+    }
+
+    /// The name of the transaction which [`redo`](Self::redo) would redo, if it has one.
+    pub fn redo_name(&self) -> Option<&str> {
+        self.redo_stack.last()?.name.as_deref()
+    }
+
+    /// Undo the last transaction.
+    ///
+    /// Returns `false` if there was nothing to undo.
+    pub fn undo(&mut self) -> bool {
+        let Some(transaction) = self.undo_stack.pop_back() else {
+            return false;
+        };
+        let transaction = self.restore(transaction);
+        self.redo_stack.push(transaction);
+        true
+    }
+
+    /// Redo the last undone transaction.
+    ///
+    /// Returns `false` if there was nothing to redo.
+    pub fn redo(&mut self) -> bool {
+        let Some(transaction) = self.redo_stack.pop() else {
+            return false;
+        };
+        let transaction = self.restore(transaction);
+        self.undo_stack.push_back(transaction);
+        true
+    }
+
+    /// Forget all transactions, e.g. after the value was saved or replaced.
+    pub fn clear(&mut self) {
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+        self.coalesce = None;
+    }
+
+    /// Set the value to the one stored in `transaction`, and return the transaction
+    /// which reverts that.
+    fn restore(&mut self, transaction: Transaction<T>) -> Transaction<T> {
+        self.restores += 1;
+        self.coalesce = None;
+        self.open_transaction = None;
+        Transaction {
+            name: transaction.name,
+            value: std::mem::replace(&mut self.value, transaction.value),
+        }
+    }
+
+    /// Take a snapshot of the value before an event handler runs.
+    pub(crate) fn checkpoint(&self) -> Checkpoint<T>
+    where
+        T: Clone,
+    {
+        Checkpoint {
+            value: self.value.clone(),
+            restores: self.restores,
+        }
+    }
+
+    /// Record the change made by an event handler since `checkpoint`.
+    ///
+    /// Nothing is recorded if the handler called `undo` or `redo`, as these aren't changes
+    /// which can be undone themselves.
+    pub(crate) fn record(&mut self, checkpoint: Checkpoint<T>, source: ChangeSource<'_>)
+    where
+        T: PartialEq,
+    {
+        let name = self.pending_name.take();
+        let before = checkpoint.value;
+        if checkpoint.restores != self.restores || self.value == before {
+            return;
+        }
+        self.redo_stack.clear();
+
+        let coalesce = match (&self.open_transaction, source) {
+            (Some(transaction), _) => Some(Coalesce::Transaction(transaction.id)),
+            (None, ChangeSource::TextInput(path)) => Some(Coalesce::TextInput(path.to_vec())),
+            (None, ChangeSource::EventHandler) => None,
+        };
+        if coalesce.is_some() && coalesce == self.coalesce {
+            if let (Some(name), Some(last)) = (name, self.undo_stack.back_mut()) {
+                last.name = Some(name);
+            }
+            return;
+        }
+
+        let name = name.or_else(|| match &coalesce {
+            Some(Coalesce::Transaction(_)) => Some(self.open_transaction.as_ref()?.name.clone()),
+            Some(Coalesce::TextInput(_)) => Some("Typing".into()),
+            None => None,
+        });
+        self.undo_stack.push_back(Transaction {
+            name,
+            value: before,
+        });
+        if self.undo_stack.len() > self.limit {
+            self.undo_stack.pop_front();
+        }
+        self.coalesce = coalesce;
+    }
+}
+
+impl<T: Default> Default for UndoHistory<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T> Deref for UndoHistory<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> DerefMut for UndoHistory<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl<T: Debug> Debug for UndoHistory<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UndoHistory")
+            .field("value", &self.value)
+            .field("undo_len", &self.undo_stack.len())
+            .field("redo_len", &self.redo_stack.len())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Run `handler` like an event handler inside an `undoable` view.
+    fn handle(
+        history: &mut UndoHistory<i32>,
+        source: ChangeSource<'_>,
+        handler: impl FnOnce(&mut UndoHistory<i32>),
+    ) {
+        let checkpoint = history.checkpoint();
+        handler(history);
+        history.record(checkpoint, source);
+    }
+
+    fn add(history: &mut UndoHistory<i32>, n: i32) {
+        handle(history, ChangeSource::EventHandler, |history| {
+            **history += n
+        });
+    }
+
+    #[test]
+    fn undo_and_redo() {
+        let mut history = UndoHistory::new(0);
+        add(&mut history, 1);
+        add(&mut history, 2);
+        // Handlers which don't change the value aren't recorded.
+        add(&mut history, 0);
+        assert_eq!(*history, 3);
+
+        assert!(history.undo());
+        assert_eq!(*history, 1);
+        assert!(history.undo());
+        assert_eq!(*history, 0);
+        assert!(!history.undo());
+
+        assert!(history.redo());
+        assert_eq!(*history, 1);
+        assert!(history.redo());
+        assert_eq!(*history, 3);
+        assert!(!history.redo());
+    }
+
+    #[test]
+    fn text_edits_are_coalesced() {
+        let first = [ViewId::new(0)];
+        let second = [ViewId::new(1)];
+        let mut history = UndoHistory::new(0);
+        handle(&mut history, ChangeSource::TextInput(&first), |h| **h = 1);
+        handle(&mut history, ChangeSource::TextInput(&first), |h| **h = 2);
+        assert_eq!(history.undo_name(), Some("Typing"));
+
+        // Editing another input starts a new transaction.
+        handle(&mut history, ChangeSource::TextInput(&second), |h| **h = 3);
+        // So does any other change.
+        add(&mut history, 1);
+        handle(&mut history, ChangeSource::TextInput(&second), |h| **h = 5);
+
+        history.undo();
+        assert_eq!(*history, 4);
+        history.undo();
+        assert_eq!(*history, 3);
+        history.undo();
+        assert_eq!(*history, 2);
+        history.undo();
+        assert_eq!(*history, 0);
+        assert!(!history.can_undo());
+    }
+
+    #[test]
+    fn transactions() {
+        let mut history = UndoHistory::new(0);
+        history.begin_transaction("Drag");
+        add(&mut history, 1);
+        add(&mut history, 2);
+        handle(&mut history, ChangeSource::EventHandler, |history| {
+            history.set_transaction_name("Drag slider");
+            **history += 3;
+        });
+        history.end_transaction();
+        add(&mut history, 4);
+
+        assert!(history.undo());
+        assert_eq!(*history, 6);
+        assert_eq!(history.undo_name(), Some("Drag slider"));
+        assert!(history.undo());
+        assert_eq!(*history, 0);
+        assert!(!history.can_undo());
+    }
+
+    #[test]
+    fn limit() {
+        let mut history = UndoHistory::new(0).with_limit(2);
+        for _ in 0..5 {
+            add(&mut history, 1);
+        }
+        assert!(history.undo());
+        assert!(history.undo());
+        assert!(!history.undo());
+        assert_eq!(*history, 3);
+    }
+
+    #[test]
+    fn new_changes_clear_redo() {
+        let mut history = UndoHistory::new(0);
+        add(&mut history, 1);
+        history.undo();
+        assert!(history.can_redo());
+
+        add(&mut history, 2);
+        assert!(!history.can_redo());
+        assert_eq!(*history, 2);
+        history.undo();
+        assert_eq!(*history, 0);
+    }
+
+    #[test]
+    fn undo_in_handler_is_not_recorded() {
+        let mut history = UndoHistory::new(0);
+        add(&mut history, 1);
+        handle(&mut history, ChangeSource::EventHandler, |history| {
+            history.undo();
+        });
+        assert_eq!(*history, 0);
+        assert!(!history.can_undo());
+        assert!(history.can_redo());
+
+        handle(&mut history, ChangeSource::EventHandler, |history| {
+            history.redo();
+        });
+        assert_eq!(*history, 1);
+        assert!(history.can_undo());
+        assert!(!history.can_redo());
+    }
+}
@@ -3,8 +3,8 @@
 
 pub use masonry::core::PointerButton;
 use masonry::properties::{
-    ActiveBackground, Background, BorderColor, BorderWidth, BoxShadow, CornerRadius,
-    DisabledBackground, HoveredBorderColor, Padding,
+    ActiveBackground, Background, BorderColor, BorderGradient, BorderWidth, BoxShadows,
+    CornerRadius, DisabledBackground, HoveredBorderColor, MixBlendMode, Opacity, Padding,
 };
 use masonry::widgets;
 use xilem_core::ViewPathTracker;
@@ -90,6 +90,25 @@ pub fn button_any_pointer<State, Action>(
     }
 }
 
+/// A button whose `callback` decides the result of the click directly, e.g. to only
+/// produce an action in some cases.
+pub(crate) fn button_with_result<State, Action>(
+    label: impl Into<Label>,
+    callback: impl Fn(&mut State) -> MessageResult<Action> + Send + 'static,
+) -> Button<
+    impl for<'a> Fn(&'a mut State, Option<PointerButton>) -> MessageResult<Action> + Send + 'static,
+> {
+    Button {
+        label: label.into(),
+        callback: move |state: &mut State, button| match button {
+            None | Some(PointerButton::Primary) => callback(state),
+            _ => MessageResult::Nop,
+        },
+        disabled: false,
+        properties: Default::default(),
+    }
+}
+
 /// The [`View`] created by [`button`] from a `label` and a callback.
 ///
 /// See `button` documentation for more context.
@@ -128,12 +147,15 @@ crate::declare_property_tuple!(
     Background, 0;
     BorderColor, 1;
     BorderWidth, 2;
-    BoxShadow, 3;
+    BoxShadows, 3;
     CornerRadius, 4;
     Padding, 5;
     ActiveBackground, 6;
     DisabledBackground, 7;
     HoveredBorderColor, 8;
+    Opacity, 9;
+    MixBlendMode, 10;
+    BorderGradient, 11;
 );
 
 impl<F> ViewMarker for Button<F> {}
@@ -6,7 +6,7 @@ use crate::core::{DynMessage, Mut, ViewMarker};
 use crate::style::Style;
 use crate::{MessageResult, Pod, View, ViewCtx, ViewId};
 
-use masonry::core::ArcStr;
+use masonry::core::{ArcStr, ErrorDescription};
 use masonry::properties::*;
 use masonry::widgets;
 
@@ -41,6 +41,7 @@ where
         callback,
         checked,
         disabled: false,
+        error_description: None,
         properties: Default::default(),
     }
 }
@@ -54,6 +55,7 @@ pub struct Checkbox<F> {
     checked: bool,
     callback: F,
     disabled: bool,
+    error_description: Option<ArcStr>,
     properties: CheckboxProps,
 }
 
@@ -63,6 +65,16 @@ impl<F> Checkbox<F> {
         self.disabled = disabled;
         self
     }
+
+    /// Mark the value as invalid, with a description of the error.
+    ///
+    /// The description is given to accessibility technologies, but isn't shown:
+    /// it should also be displayed next to the checkbox, e.g. with a [`label`](crate::view::label).
+    /// See [`ErrorDescription`] for details.
+    pub fn error_description(mut self, description: Option<ArcStr>) -> Self {
+        self.error_description = description;
+        self
+    }
 }
 
 impl<F> Style for Checkbox<F> {
@@ -88,6 +100,9 @@ crate::declare_property_tuple!(
     CheckmarkStrokeWidth, 8;
     DisabledCheckmarkColor, 9;
     CheckmarkColor, 10;
+    Opacity, 11;
+    MixBlendMode, 12;
+    BorderGradient, 13;
 );
 
 impl<F> ViewMarker for Checkbox<F> {}
@@ -102,6 +117,10 @@ where
         ctx.with_leaf_action_widget(|ctx| {
             let mut pod = ctx.create_pod(widgets::Checkbox::new(self.checked, self.label.clone()));
             pod.properties = self.properties.build_properties();
+            if let Some(description) = &self.error_description {
+                pod.properties
+                    .insert(ErrorDescription::new(description.clone()));
+            }
             pod.options.disabled = self.disabled;
             pod
         })
@@ -120,6 +139,9 @@ where
         if element.ctx.is_disabled() != self.disabled {
             element.ctx.set_disabled(self.disabled);
         }
+        if prev.error_description != self.error_description {
+            element.insert_prop(ErrorDescription(self.error_description.clone()));
+        }
         if prev.label != self.label {
             widgets::Checkbox::set_text(&mut element, self.label.clone());
         }
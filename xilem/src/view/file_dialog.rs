@@ -0,0 +1,662 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use masonry::theme::ZYNC_900;
+use masonry::widgets;
+
+use crate::core::{DynMessage, MessageProxy, Mut, View, ViewId, ViewMarker, ViewPathTracker};
+use crate::file_dialog::{FileDialogKind, FileDialogOptions, FileDialogResult};
+use crate::style::Style as _;
+use crate::view::{
+    CrossAxisAlignment, MainAxisAlignment, button, flex, flex_row, label, portal, sized_box,
+    text_input,
+};
+use crate::{AnyWidgetView, FontWeight, MessageResult, Pod, ViewCtx, WidgetView as _};
+
+/// Ask the user to pick files to open or save, and call `on_result` with their choice.
+///
+/// The dialog is shown by the [`FileDialogService`](crate::file_dialog::FileDialogService)
+/// of the app when this view is built, and stays open until the user picks something
+/// or cancels.
+/// Like [`task`](crate::view::task), changes to `options` aren't taken into account
+/// once the view is built: to show another dialog, remove this view and add it back.
+/// `on_result` should usually update the app state so that the view is removed.
+///
+/// When the service can't show the dialog itself, which is the case of the default
+/// [`InWindowFileDialogs`](crate::file_dialog::InWindowFileDialogs), a file browser
+/// is shown in a modal dialog inside the window instead.
+/// This view should therefore be drawn on top of the rest of the window, e.g. by making it
+/// the last child of a [`zstack`](crate::view::zstack).
+///
+/// # Example
+/// ```ignore
+/// use xilem::file_dialog::FileDialogOptions;
+/// use xilem::view::{button, file_dialog, zstack};
+///
+/// zstack((
+///     button("Open…", |state: &mut State| state.picking = true),
+///     state.picking.then(|| {
+///         file_dialog(
+///             FileDialogOptions::open().filter("Text", ["txt", "md"]),
+///             |state: &mut State, result| {
+///                 state.picking = false;
+///                 if let Some(path) = result.path() {
+///                     state.open(path);
+///                 }
+///             },
+///         )
+///     }),
+/// ))
+/// ```
+pub fn file_dialog<State, Action, F>(
+    options: FileDialogOptions,
+    on_result: F,
+) -> FileDialog<F, State, Action>
+where
+    F: Fn(&mut State, FileDialogResult) -> Action + Send + Sync + 'static,
+{
+    FileDialog {
+        options,
+        on_result,
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`file_dialog`].
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct FileDialog<F, State, Action> {
+    options: FileDialogOptions,
+    on_result: F,
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+const BROWSER_VIEW_ID: ViewId = ViewId::new(0);
+
+mod private {
+    use super::{BrowserView, FileBrowser};
+    use crate::core::AnyViewState;
+
+    #[expect(
+        unnameable_types,
+        reason = "Implementation detail, public because of trait visibility rules"
+    )]
+    pub struct FileDialogState {
+        /// The task waiting for the result of the dialog shown by the service.
+        pub(super) task: Option<tokio::task::JoinHandle<()>>,
+        /// The in-window file browser, if the service didn't show the dialog.
+        pub(super) browser: Option<BrowserState>,
+    }
+
+    pub(super) struct BrowserState {
+        pub(super) browser: FileBrowser,
+        pub(super) view: BrowserView,
+        pub(super) view_state: AnyViewState,
+        pub(super) done: bool,
+        /// The task reading the directory shown by the browser.
+        pub(super) read_task: Option<tokio::task::JoinHandle<()>>,
+    }
+}
+
+impl<F, State, Action> ViewMarker for FileDialog<F, State, Action> {}
+impl<F, State, Action> View<State, Action, ViewCtx> for FileDialog<F, State, Action>
+where
+    State: 'static,
+    Action: 'static,
+    F: Fn(&mut State, FileDialogResult) -> Action + Send + Sync + 'static,
+{
+    type Element = Pod<widgets::Dialog>;
+    type ViewState = private::FileDialogState;
+
+    fn build(&self, ctx: &mut ViewCtx, _: &mut State) -> (Self::Element, Self::ViewState) {
+        let (content, view_state) = if let Some(future) = ctx.file_dialogs().show(&self.options) {
+            let path: Arc<[ViewId]> = ctx.view_path().into();
+            let proxy = MessageProxy::new(ctx.proxy(), path);
+            let task = ctx.runtime().spawn(async move {
+                let result = future.await;
+                drop(proxy.message(result));
+            });
+            let content = ctx.create_pod(widgets::SizedBox::empty());
+            let view_state = private::FileDialogState {
+                task: Some(task),
+                browser: None,
+            };
+            (content.erased_widget_pod(), view_state)
+        } else {
+            let mut browser = FileBrowser::new(self.options.clone());
+            let view = browser.view();
+            let (content, browser_view_state) =
+                ctx.with_id(BROWSER_VIEW_ID, |ctx| view.build(ctx, &mut browser));
+            let mut browser = private::BrowserState {
+                browser,
+                view,
+                view_state: browser_view_state,
+                done: false,
+                read_task: None,
+            };
+            read_directory(&mut browser, ctx);
+            let view_state = private::FileDialogState {
+                task: None,
+                browser: Some(browser),
+            };
+            (content.erased_widget_pod(), view_state)
+        };
+        let open = view_state.browser.is_some();
+        let pod = ctx.with_action_widget(|ctx| {
+            ctx.create_pod(widgets::Dialog::new_pod(content).with_open(open))
+        });
+        (pod, view_state)
+    }
+
+    fn rebuild(
+        &self,
+        _: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        _: &mut State,
+    ) {
+        let Some(browser) = &mut view_state.browser else {
+            return;
+        };
+        if browser.done && element.widget.is_open() {
+            widgets::Dialog::set_open(&mut element, false);
+        }
+        read_directory(browser, ctx);
+        let view = browser.browser.view();
+        ctx.with_id(BROWSER_VIEW_ID, |ctx| {
+            view.rebuild(
+                &browser.view,
+                &mut browser.view_state,
+                ctx,
+                widgets::Dialog::child_mut(&mut element),
+                &mut browser.browser,
+            );
+        });
+        browser.view = view;
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        _: &mut State,
+    ) {
+        if let Some(task) = &view_state.task {
+            task.abort();
+        }
+        if let Some(browser) = &mut view_state.browser {
+            if let Some(task) = browser.read_task.take() {
+                task.abort();
+            }
+            ctx.with_id(BROWSER_VIEW_ID, |ctx| {
+                browser.view.teardown(
+                    &mut browser.view_state,
+                    ctx,
+                    widgets::Dialog::child_mut(&mut element),
+                    &mut browser.browser,
+                );
+            });
+        }
+        ctx.teardown_leaf(element);
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        id_path: &[ViewId],
+        message: DynMessage,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        match id_path.split_first() {
+            Some((&BROWSER_VIEW_ID, rest)) => {
+                let Some(browser) = &mut view_state.browser else {
+                    tracing::warn!("Got browser message in FileDialog without a browser");
+                    return MessageResult::Stale(message);
+                };
+                let result = browser.view.message(
+                    &mut browser.view_state,
+                    rest,
+                    message,
+                    &mut browser.browser,
+                );
+                match result {
+                    MessageResult::Action(BrowserEvent::Done(result)) => {
+                        browser.done = true;
+                        MessageResult::Action((self.on_result)(app_state, result))
+                    }
+                    // The browser's state changed, so its view needs to be rebuilt.
+                    MessageResult::Action(BrowserEvent::Changed)
+                    | MessageResult::RequestRebuild => MessageResult::RequestRebuild,
+                    MessageResult::Nop => MessageResult::Nop,
+                    MessageResult::Stale(message) => MessageResult::Stale(message),
+                }
+            }
+            None => {
+                // The result of a dialog shown by the service.
+                let message = match message.downcast::<FileDialogResult>() {
+                    Ok(result) => {
+                        return MessageResult::Action((self.on_result)(app_state, *result));
+                    }
+                    Err(message) => message,
+                };
+                let message = match message.downcast::<DirectoryListing>() {
+                    Ok(listing) => {
+                        let Some(browser) = &mut view_state.browser else {
+                            return MessageResult::Stale(DynMessage(listing));
+                        };
+                        return if browser.browser.set_listing(*listing) {
+                            browser.read_task = None;
+                            MessageResult::RequestRebuild
+                        } else {
+                            MessageResult::Nop
+                        };
+                    }
+                    Err(message) => message,
+                };
+                match message.downcast::<masonry::core::Action>() {
+                    Ok(action) => {
+                        if let masonry::core::Action::DialogDismissed = *action {
+                            if let Some(browser) = &mut view_state.browser {
+                                browser.done = true;
+                            }
+                            MessageResult::Action((self.on_result)(
+                                app_state,
+                                FileDialogResult::Cancelled,
+                            ))
+                        } else {
+                            tracing::error!("Wrong action type in FileDialog::message: {action:?}");
+                            MessageResult::Stale(DynMessage(action))
+                        }
+                    }
+                    Err(message) => {
+                        tracing::error!("Wrong message type in FileDialog::message: {message:?}");
+                        MessageResult::Stale(message)
+                    }
+                }
+            }
+            _ => {
+                tracing::warn!("Got unexpected id path in FileDialog::message");
+                MessageResult::Stale(message)
+            }
+        }
+    }
+}
+
+// --- MARK: FILE BROWSER
+
+type BrowserView = Box<AnyWidgetView<FileBrowser, BrowserEvent>>;
+
+/// What happened in the in-window file browser.
+enum BrowserEvent {
+    /// The state of the browser changed.
+    Changed,
+    /// The user picked something or cancelled.
+    Done(FileDialogResult),
+}
+
+/// An entry of the directory shown by the file browser.
+#[derive(Debug)]
+struct Entry {
+    name: String,
+    path: PathBuf,
+    is_dir: bool,
+}
+
+/// The state of the in-window file browser.
+///
+/// This is kept by the [`FileDialog`] view, and used as the app state of the browser's views.
+struct FileBrowser {
+    options: FileDialogOptions,
+    directory: PathBuf,
+    entries: Vec<Entry>,
+    selected: Vec<PathBuf>,
+    file_name: String,
+    error: Option<String>,
+    /// Whether `directory` should be read, which is done by the [`FileDialog`] view
+    /// on the async runtime.
+    needs_read: bool,
+}
+
+/// The entries of a directory, which are read on the async runtime so that slow file systems
+/// don't block the UI.
+#[derive(Debug)]
+struct DirectoryListing {
+    directory: PathBuf,
+    entries: Result<Vec<Entry>, String>,
+}
+
+/// Start reading the directory of the file browser, if it changed.
+fn read_directory(browser: &mut private::BrowserState, ctx: &mut ViewCtx) {
+    if !browser.browser.needs_read {
+        return;
+    }
+    browser.browser.needs_read = false;
+    if let Some(task) = browser.read_task.take() {
+        task.abort();
+    }
+    let directory = browser.browser.directory.clone();
+    let options = browser.browser.options.clone();
+    let path: Arc<[ViewId]> = ctx.view_path().into();
+    let proxy = MessageProxy::new(ctx.proxy(), path);
+    browser.read_task = Some(ctx.runtime().spawn_blocking(move || {
+        let entries = list_directory(&directory, &options);
+        drop(proxy.message(DirectoryListing { directory, entries }));
+    }));
+}
+
+/// List the entries of `directory` which can be picked with `options`.
+///
+/// Directories are listed first, and files are only listed if they match the filters.
+fn list_directory(directory: &Path, options: &FileDialogOptions) -> Result<Vec<Entry>, String> {
+    let read_dir = std::fs::read_dir(directory)
+        .map_err(|err| format!("Can't read {}: {err}", directory.display()))?;
+    let mut entries = Vec::new();
+    for entry in read_dir.flatten() {
+        let path = entry.path();
+        let is_dir = path.is_dir();
+        let shown = if is_dir {
+            true
+        } else {
+            options.kind != FileDialogKind::PickFolder && options.accepts(&path)
+        };
+        if shown {
+            entries.push(Entry {
+                name: entry.file_name().to_string_lossy().into_owned(),
+                path,
+                is_dir,
+            });
+        }
+    }
+    entries.sort_by(|a, b| {
+        b.is_dir
+            .cmp(&a.is_dir)
+            .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+    });
+    Ok(entries)
+}
+
+impl FileBrowser {
+    fn new(options: FileDialogOptions) -> Self {
+        let directory = options
+            .directory
+            .clone()
+            .or_else(|| std::env::current_dir().ok())
+            .unwrap_or_else(|| PathBuf::from("/"));
+        Self {
+            file_name: options.file_name.clone().unwrap_or_default(),
+            options,
+            directory,
+            entries: Vec::new(),
+            selected: Vec::new(),
+            error: None,
+            needs_read: true,
+        }
+    }
+
+    /// Show the entries of `listing`, if it's the listing of the current directory.
+    ///
+    /// Returns `false` if the listing is outdated.
+    fn set_listing(&mut self, listing: DirectoryListing) -> bool {
+        if listing.directory != self.directory || self.needs_read {
+            return false;
+        }
+        match listing.entries {
+            Ok(entries) => self.entries = entries,
+            Err(error) => self.error = Some(error),
+        }
+        true
+    }
+
+    fn navigate(&mut self, directory: PathBuf) {
+        self.directory = directory;
+        self.selected.clear();
+        self.entries.clear();
+        self.error = None;
+        self.needs_read = true;
+    }
+
+    /// Open a directory, or select a file.
+    fn activate(&mut self, path: &Path, is_dir: bool) {
+        if is_dir {
+            self.navigate(path.to_path_buf());
+            return;
+        }
+        match self.options.kind {
+            FileDialogKind::Open if self.options.multiple => {
+                if let Some(idx) = self.selected.iter().position(|selected| selected == path) {
+                    self.selected.remove(idx);
+                } else {
+                    self.selected.push(path.to_path_buf());
+                }
+            }
+            FileDialogKind::Open => self.selected = vec![path.to_path_buf()],
+            FileDialogKind::Save => {
+                if let Some(name) = path.file_name() {
+                    self.file_name = name.to_string_lossy().into_owned();
+                }
+            }
+            FileDialogKind::PickFolder => {}
+        }
+    }
+
+    fn go_up(&mut self) {
+        if let Some(parent) = self.directory.parent() {
+            self.navigate(parent.to_path_buf());
+        }
+    }
+
+    /// Finish the dialog with the current choice, if there is one.
+    fn confirm(&self) -> BrowserEvent {
+        let paths = match self.options.kind {
+            FileDialogKind::Open => self.selected.clone(),
+            FileDialogKind::Save => {
+                let file_name = self.file_name.trim();
+                if file_name.is_empty() {
+                    Vec::new()
+                } else {
+                    vec![self.directory.join(file_name)]
+                }
+            }
+            FileDialogKind::PickFolder => vec![self.directory.clone()],
+        };
+        if paths.is_empty() {
+            BrowserEvent::Changed
+        } else {
+            BrowserEvent::Done(FileDialogResult::Picked(paths))
+        }
+    }
+
+    fn view(&self) -> BrowserView {
+        let kind = self.options.kind;
+        let (default_title, confirm_text) = match kind {
+            FileDialogKind::Open => ("Open File", "Open"),
+            FileDialogKind::Save => ("Save File", "Save"),
+            FileDialogKind::PickFolder => ("Select Folder", "Select"),
+        };
+        let title = self.options.title.as_deref().unwrap_or(default_title);
+
+        let entries = self
+            .entries
+            .iter()
+            .map(|entry| {
+                let name = if entry.is_dir {
+                    format!("{}/", entry.name)
+                } else {
+                    entry.name.clone()
+                };
+                let mut name = label(name);
+                if self.selected.contains(&entry.path) {
+                    name = name.weight(FontWeight::BOLD);
+                }
+                let path = entry.path.clone();
+                let is_dir = entry.is_dir;
+                button(name, move |browser: &mut FileBrowser| {
+                    browser.activate(&path, is_dir);
+                    BrowserEvent::Changed
+                })
+            })
+            .collect::<Vec<_>>();
+        let file_name = (kind == FileDialogKind::Save).then(|| {
+            text_input(
+                self.file_name.clone(),
+                |browser: &mut FileBrowser, file_name| {
+                    browser.file_name = file_name;
+                    BrowserEvent::Changed
+                },
+            )
+            .on_enter(|browser: &mut FileBrowser, _| browser.confirm())
+        });
+
+        sized_box(
+            flex((
+                label(title).weight(FontWeight::BOLD),
+                flex_row((
+                    button("Up", |browser: &mut FileBrowser| {
+                        browser.go_up();
+                        BrowserEvent::Changed
+                    }),
+                    label(self.directory.display().to_string()),
+                )),
+                self.error.clone().map(label),
+                sized_box(portal(
+                    flex(entries).cross_axis_alignment(CrossAxisAlignment::Start),
+                ))
+                .height(240.),
+                file_name,
+                flex_row((
+                    button("Cancel", |_: &mut FileBrowser| {
+                        BrowserEvent::Done(FileDialogResult::Cancelled)
+                    }),
+                    button(confirm_text, |browser: &mut FileBrowser| browser.confirm()),
+                ))
+                .main_axis_alignment(MainAxisAlignment::End),
+            ))
+            .cross_axis_alignment(CrossAxisAlignment::Fill),
+        )
+        .width(480.)
+        .padding(16.)
+        .corner_radius(8.)
+        .background_color(ZYNC_900)
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A directory with a few entries, which is removed when dropped.
+    struct TestDir(PathBuf);
+
+    impl TestDir {
+        fn new(name: &str) -> Self {
+            let dir = std::env::temp_dir()
+                .join(format!("xilem-file-browser-{name}-{}", std::process::id()));
+            std::fs::create_dir_all(dir.join("b_dir")).unwrap();
+            for file in ["a.txt", "B.md", "c.png"] {
+                std::fs::write(dir.join(file), "").unwrap();
+            }
+            Self(dir)
+        }
+    }
+
+    impl Drop for TestDir {
+        fn drop(&mut self) {
+            drop(std::fs::remove_dir_all(&self.0));
+        }
+    }
+
+    fn names(entries: &[Entry]) -> Vec<&str> {
+        entries.iter().map(|entry| &*entry.name).collect()
+    }
+
+    fn browser(options: FileDialogOptions, dir: &TestDir) -> FileBrowser {
+        let mut browser = FileBrowser::new(options.directory(&dir.0));
+        let entries = list_directory(&dir.0, &browser.options);
+        browser.needs_read = false;
+        let listing = DirectoryListing {
+            directory: dir.0.clone(),
+            entries,
+        };
+        assert!(browser.set_listing(listing));
+        browser
+    }
+
+    #[test]
+    fn listing_is_filtered_and_sorted() {
+        let dir = TestDir::new("listing");
+        let options = FileDialogOptions::open().filter("Text", ["txt", "md"]);
+        let entries = list_directory(&dir.0, &options).unwrap();
+        assert_eq!(names(&entries), ["b_dir", "a.txt", "B.md"]);
+
+        let entries = list_directory(&dir.0, &FileDialogOptions::open()).unwrap();
+        assert_eq!(names(&entries), ["b_dir", "a.txt", "B.md", "c.png"]);
+
+        let entries = list_directory(&dir.0, &FileDialogOptions::pick_folder()).unwrap();
+        assert_eq!(names(&entries), ["b_dir"]);
+
+        assert!(list_directory(&dir.0.join("missing"), &options).is_err());
+    }
+
+    #[test]
+    fn open_multiple() {
+        let dir = TestDir::new("open");
+        let mut browser = browser(FileDialogOptions::open().multiple(true), &dir);
+        assert!(matches!(browser.confirm(), BrowserEvent::Changed));
+
+        browser.activate(&dir.0.join("a.txt"), false);
+        browser.activate(&dir.0.join("c.png"), false);
+        browser.activate(&dir.0.join("a.txt"), false);
+        match browser.confirm() {
+            BrowserEvent::Done(result) => {
+                assert_eq!(result, FileDialogResult::Picked(vec![dir.0.join("c.png")]));
+            }
+            BrowserEvent::Changed => panic!("the browser should have a selection"),
+        }
+    }
+
+    #[test]
+    fn save() {
+        let dir = TestDir::new("save");
+        let mut browser = browser(FileDialogOptions::save().file_name("notes.txt"), &dir);
+        browser.activate(&dir.0.join("B.md"), false);
+        match browser.confirm() {
+            BrowserEvent::Done(result) => assert_eq!(result.path(), Some(&*dir.0.join("B.md"))),
+            BrowserEvent::Changed => panic!("the browser should have a file name"),
+        }
+
+        browser.file_name = "  ".into();
+        assert!(matches!(browser.confirm(), BrowserEvent::Changed));
+    }
+
+    #[test]
+    fn navigation() {
+        let dir = TestDir::new("navigation");
+        let mut browser = browser(FileDialogOptions::open(), &dir);
+        browser.activate(&dir.0.join("a.txt"), false);
+
+        // Opening a directory clears the selection, and waits for it to be read.
+        browser.activate(&dir.0.join("b_dir"), true);
+        assert_eq!(browser.directory, dir.0.join("b_dir"));
+        assert!(browser.selected.is_empty());
+        assert!(browser.entries.is_empty());
+        assert!(browser.needs_read);
+
+        // Listings of other directories are outdated.
+        browser.needs_read = false;
+        let outdated = DirectoryListing {
+            directory: dir.0.clone(),
+            entries: Ok(Vec::new()),
+        };
+        assert!(!browser.set_listing(outdated));
+
+        browser.go_up();
+        assert_eq!(browser.directory, dir.0);
+        assert!(browser.needs_read);
+    }
+}
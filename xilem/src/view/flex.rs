@@ -5,10 +5,14 @@ use std::marker::PhantomData;
 
 use crate::style::Style;
 
+pub use masonry::core::Axis;
 use masonry::core::{FromDynWidget, Widget, WidgetMut};
-use masonry::properties::{Background, BorderColor, BorderWidth, CornerRadius, Padding};
+use masonry::properties::{
+    Background, BorderColor, BorderGradient, BorderWidth, CornerRadius, MixBlendMode, Opacity,
+    Padding,
+};
 use masonry::widgets::{self};
-pub use masonry::widgets::{Axis, CrossAxisAlignment, FlexParams, MainAxisAlignment};
+pub use masonry::widgets::{CrossAxisAlignment, FlexParams, MainAxisAlignment};
 
 use crate::core::{
     AppendVec, DynMessage, ElementSplice, MessageResult, Mut, SuperElement, View, ViewElement,
@@ -67,6 +71,7 @@ pub fn flex<State, Action, Seq: FlexSequence<State, Action>>(
         cross_axis_alignment: CrossAxisAlignment::Center,
         main_axis_alignment: MainAxisAlignment::Start,
         fill_major_axis: false,
+        keep_min_content: false,
         gap: None,
         properties: Default::default(),
         phantom: PhantomData,
@@ -93,6 +98,7 @@ pub struct Flex<Seq, State, Action = ()> {
     cross_axis_alignment: CrossAxisAlignment,
     main_axis_alignment: MainAxisAlignment,
     fill_major_axis: bool,
+    keep_min_content: bool,
     gap: Option<f64>,
     properties: FlexProps,
     phantom: PhantomData<fn() -> (State, Action)>,
@@ -120,6 +126,13 @@ impl<Seq, State, Action> Flex<Seq, State, Action> {
         self.fill_major_axis = fill_major_axis;
         self
     }
+    /// Set whether flexible children keep their min-content size on the main axis.
+    ///
+    /// See [`widgets::Flex::keep_min_content`] for details.
+    pub fn keep_min_content(mut self, keep: bool) -> Self {
+        self.keep_min_content = keep;
+        self
+    }
 
     /// Set the spacing along the major axis between any two elements in logical pixels.
     ///
@@ -165,6 +178,9 @@ crate::declare_property_tuple!(
     BorderWidth, 2;
     CornerRadius, 3;
     Padding, 4;
+    Opacity, 5;
+    MixBlendMode, 6;
+    BorderGradient, 7;
 );
 
 impl<Seq, State, Action> ViewMarker for Flex<Seq, State, Action> {}
@@ -184,6 +200,7 @@ where
             .raw_gap(self.gap)
             .cross_axis_alignment(self.cross_axis_alignment)
             .must_fill_main_axis(self.fill_major_axis)
+            .keep_min_content(self.keep_min_content)
             .main_axis_alignment(self.main_axis_alignment);
         let seq_state = self.sequence.seq_build(ctx, &mut elements, app_state);
         for child in elements.into_inner() {
@@ -222,6 +239,9 @@ where
         if prev.fill_major_axis != self.fill_major_axis {
             widgets::Flex::set_must_fill_main_axis(&mut element, self.fill_major_axis);
         }
+        if prev.keep_min_content != self.keep_min_content {
+            widgets::Flex::set_keep_min_content(&mut element, self.keep_min_content);
+        }
         if prev.gap != self.gap {
             widgets::Flex::set_raw_gap(&mut element, self.gap);
         }
@@ -404,6 +424,10 @@ impl ElementSplice<FlexElement> for FlexSplice<'_> {
     fn skip(&mut self, n: usize) {
         self.idx += n;
     }
+
+    fn move_from(&mut self, offset: usize) {
+        widgets::Flex::move_child(&mut self.element, self.idx + offset, self.idx);
+    }
 }
 
 /// An ordered sequence of views for a [`Flex`] view.
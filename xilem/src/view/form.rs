@@ -0,0 +1,430 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use masonry::core::{ArcStr, PointerButton};
+use masonry::theme::ERROR_TEXT_COLOR;
+use masonry::widgets;
+use tokio::task::JoinHandle;
+
+use crate::core::{DynMessage, MessageProxy, Mut, View, ViewId, ViewMarker, ViewPathTracker};
+use crate::view::button::button_with_result;
+use crate::view::{
+    Button, Checkbox, CrossAxisAlignment, Flex, Label, TextInput, checkbox, flex, label, text_input,
+};
+use crate::{Form, MessageResult, Pod, ViewCtx, WidgetView};
+
+type InputFn<T, V> = Box<dyn Fn(&ArcStr, &T, Option<ArcStr>) -> V + Send + Sync + 'static>;
+type Validator<T> = Box<dyn Fn(&T) -> Result<(), ArcStr> + Send + Sync + 'static>;
+type ValidationFuture = Pin<Box<dyn Future<Output = Result<(), ArcStr>> + Send + 'static>>;
+type AsyncValidator<T> = Box<dyn Fn(T) -> ValidationFuture + Send + Sync + 'static>;
+
+/// The view shown by a [`FormField`]: its input, followed by its error if it should be shown.
+type FieldView<V, T> = Flex<(V, Option<Label>), (), T>;
+
+/// The input of a [`text_field`].
+pub type TextFieldInput = Flex<(Label, TextInput<(), String>), (), String>;
+
+/// The input of a [`checkbox_field`].
+pub type CheckboxFieldInput = Checkbox<fn(&mut (), bool) -> bool>;
+
+/// A field of a [`Form`], which edits the value of type `T` projected out of the app state by `value`.
+///
+/// `form` and `value` project the form and the value out of the app state, like the function
+/// given to [`lens`](crate::core::lens).
+/// The `label` identifies the field in the form, and must be unique within it.
+///
+/// The field is shown by the view returned by `input`, which is given the label, the current value,
+/// and the error to show, if any.
+/// This view has no state, and its actions are the new values of the field.
+/// It should give the error to its widget as an [error description](masonry::core::ErrorDescription),
+/// so that accessibility technologies report it.
+/// The error is also shown in a label below the input.
+///
+/// Most forms can use [`text_field`] and [`checkbox_field`] instead.
+///
+/// Changing the value, or finishing an asynchronous validation, requests a rebuild of the views
+/// without producing an action.
+pub fn form_field<State, Action, T, V, FormLens, ValueLens>(
+    label: impl Into<ArcStr>,
+    form: FormLens,
+    value: ValueLens,
+    input: impl Fn(&ArcStr, &T, Option<ArcStr>) -> V + Send + Sync + 'static,
+) -> FormField<V, T, FormLens, ValueLens, State, Action>
+where
+    FormLens: Fn(&mut State) -> &mut Form + 'static,
+    ValueLens: Fn(&mut State) -> &mut T + 'static,
+    V: WidgetView<(), T>,
+{
+    FormField {
+        label: label.into(),
+        form,
+        value,
+        input: Box::new(input),
+        validators: Vec::new(),
+        async_validator: None,
+        phantom: PhantomData,
+    }
+}
+
+/// A [`form_field`] which edits a string using a [`text_input`], with its label above it.
+pub fn text_field<State, Action, FormLens, ValueLens>(
+    label: impl Into<ArcStr>,
+    form: FormLens,
+    value: ValueLens,
+) -> FormField<TextFieldInput, String, FormLens, ValueLens, State, Action>
+where
+    FormLens: Fn(&mut State) -> &mut Form + 'static,
+    ValueLens: Fn(&mut State) -> &mut String + 'static,
+{
+    form_field(
+        label,
+        form,
+        value,
+        |name: &ArcStr, value: &String, error| {
+            flex((
+                self::label(name.clone()),
+                text_input(value.clone(), |_: &mut (), text| text).error_description(error),
+            ))
+            .cross_axis_alignment(CrossAxisAlignment::Fill)
+        },
+    )
+}
+
+/// A [`form_field`] which edits a boolean using a [`checkbox`], with the label next to it.
+pub fn checkbox_field<State, Action, FormLens, ValueLens>(
+    label: impl Into<ArcStr>,
+    form: FormLens,
+    value: ValueLens,
+) -> FormField<CheckboxFieldInput, bool, FormLens, ValueLens, State, Action>
+where
+    FormLens: Fn(&mut State) -> &mut Form + 'static,
+    ValueLens: Fn(&mut State) -> &mut bool + 'static,
+{
+    form_field(
+        label,
+        form,
+        value,
+        |name: &ArcStr, checked: &bool, error| {
+            let toggle: fn(&mut (), bool) -> bool = |_, checked| checked;
+            checkbox(name.clone(), *checked, toggle).error_description(error)
+        },
+    )
+}
+
+/// A button which calls `on_submit` if the [`Form`] projected out of the app state by `form` is valid.
+///
+/// Otherwise, it shows the errors of all fields, including those which weren't touched yet,
+/// without calling `on_submit` nor producing an action.
+/// The form is also invalid while an asynchronous validation is running.
+pub fn submit_button<State, Action>(
+    label: impl Into<Label>,
+    form: impl Fn(&mut State) -> &mut Form + Send + 'static,
+    on_submit: impl Fn(&mut State) -> Action + Send + 'static,
+) -> Button<
+    impl for<'a> Fn(&'a mut State, Option<PointerButton>) -> MessageResult<Action> + Send + 'static,
+> {
+    button_with_result(label, move |state: &mut State| {
+        let form = form(state);
+        form.attempt_submit();
+        if form.is_valid() {
+            MessageResult::Action(on_submit(state))
+        } else {
+            MessageResult::RequestRebuild
+        }
+    })
+}
+
+/// The [`View`] created by [`form_field`], [`text_field`] and [`checkbox_field`].
+///
+/// See `form_field` documentation for more context.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct FormField<V, T, FormLens, ValueLens, State, Action> {
+    label: ArcStr,
+    form: FormLens,
+    value: ValueLens,
+    input: InputFn<T, V>,
+    validators: Vec<Validator<T>>,
+    async_validator: Option<AsyncValidator<T>>,
+    phantom: PhantomData<fn(State) -> Action>,
+}
+
+impl<V, T, FormLens, ValueLens, State, Action> FormField<V, T, FormLens, ValueLens, State, Action> {
+    /// Add a validator, which returns the error to show if the value is invalid.
+    ///
+    /// Validators run in the order they were added, and the first error is shown.
+    /// They run each time the value changes, including when it is changed by the app logic.
+    pub fn validate(
+        mut self,
+        validator: impl Fn(&T) -> Result<(), ArcStr> + Send + Sync + 'static,
+    ) -> Self {
+        self.validators.push(Box::new(validator));
+        self
+    }
+
+    /// Set an asynchronous validator, such as one checking that a user name is available.
+    ///
+    /// It runs after the synchronous validators have passed, on the [runtime](ViewCtx::runtime)
+    /// of the app.
+    /// Validations of outdated values are cancelled, and the form is invalid until it is done.
+    pub fn validate_async<F, Fut>(mut self, validator: F) -> Self
+    where
+        F: Fn(T) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), ArcStr>> + Send + 'static,
+    {
+        self.async_validator = Some(Box::new(move |value| Box::pin(validator(value))));
+        self
+    }
+}
+
+mod private {
+    /// The View state for the [`FormField`](super::FormField)
+    #[expect(
+        unnameable_types,
+        reason = "This type has no public API, and is only public due to trait visibility rules"
+    )]
+    pub struct FormFieldState<T, V, ViewState> {
+        pub(super) tracker: super::FieldTracker<T>,
+        pub(super) view: V,
+        pub(super) view_state: ViewState,
+    }
+}
+
+/// What a [`FormField`] remembers about its value.
+struct FieldTracker<T> {
+    /// The value which the field is compared to, to check whether it's dirty.
+    initial: T,
+    /// The last value which was validated.
+    validated: T,
+    /// The number of times the form was reset when `initial` was taken.
+    resets: u64,
+    /// The number of validations started so far.
+    generation: u64,
+    task: Option<AsyncValidationTask>,
+}
+
+struct AsyncValidationTask {
+    generation: u64,
+    handle: JoinHandle<()>,
+}
+
+/// The result of an asynchronous validation, sent to the [`FormField`] which started it.
+#[derive(Debug)]
+struct AsyncValidation {
+    generation: u64,
+    result: Result<(), ArcStr>,
+}
+
+const INPUT_VIEW_ID: ViewId = ViewId::new(0);
+
+impl<V, T, FormLens, ValueLens, State, Action> FormField<V, T, FormLens, ValueLens, State, Action>
+where
+    V: WidgetView<(), T> + ViewMarker,
+    T: Clone + PartialEq + 'static,
+    FormLens: Fn(&mut State) -> &mut Form + 'static,
+    ValueLens: Fn(&mut State) -> &mut T + 'static,
+{
+    fn view(&self, app_state: &mut State) -> FieldView<V, T> {
+        let error = (self.form)(app_state).visible_error(&self.label);
+        let input = (self.input)(&self.label, (self.value)(app_state), error.clone());
+        let error = error.map(|error| label(error).brush(ERROR_TEXT_COLOR));
+        flex((input, error)).cross_axis_alignment(CrossAxisAlignment::Fill)
+    }
+
+    /// Run the validators on `value`, and store the result in the form.
+    fn validate_value(
+        &self,
+        tracker: &mut FieldTracker<T>,
+        ctx: &mut ViewCtx,
+        value: T,
+        app_state: &mut State,
+    ) {
+        if let Some(task) = tracker.task.take() {
+            task.handle.abort();
+        }
+        tracker.generation += 1;
+        let error = self
+            .validators
+            .iter()
+            .find_map(|validator| validator(&value).err());
+        let validating = match &self.async_validator {
+            Some(validator) if error.is_none() => {
+                let future = validator(value.clone());
+                let path: Arc<[ViewId]> = ctx.view_path().into();
+                let proxy = MessageProxy::new(ctx.proxy(), path);
+                let generation = tracker.generation;
+                let handle = ctx.runtime().spawn(async move {
+                    let result = future.await;
+                    drop(proxy.message(AsyncValidation { generation, result }));
+                });
+                tracker.task = Some(AsyncValidationTask { generation, handle });
+                true
+            }
+            _ => false,
+        };
+        tracker.validated = value;
+
+        let field = (self.form)(app_state).field_mut(&self.label);
+        field.error = error;
+        field.validating = validating;
+    }
+}
+
+impl<V, T, FormLens, ValueLens, State, Action> ViewMarker
+    for FormField<V, T, FormLens, ValueLens, State, Action>
+{
+}
+impl<V, T, FormLens, ValueLens, State, Action> View<State, Action, ViewCtx>
+    for FormField<V, T, FormLens, ValueLens, State, Action>
+where
+    V: WidgetView<(), T> + ViewMarker,
+    T: Clone + PartialEq + 'static,
+    FormLens: Fn(&mut State) -> &mut Form + 'static,
+    ValueLens: Fn(&mut State) -> &mut T + 'static,
+    State: 'static,
+    Action: 'static,
+{
+    type Element = Pod<widgets::Flex>;
+    type ViewState = private::FormFieldState<
+        T,
+        FieldView<V, T>,
+        <FieldView<V, T> as View<(), T, ViewCtx>>::ViewState,
+    >;
+
+    fn build(&self, ctx: &mut ViewCtx, app_state: &mut State) -> (Self::Element, Self::ViewState) {
+        let value = (self.value)(app_state).clone();
+        let form = (self.form)(app_state);
+        let resets = form.resets;
+        // A field which is added back to the view tree starts over.
+        form.remove_field(&self.label);
+        let mut tracker = FieldTracker {
+            initial: value.clone(),
+            validated: value.clone(),
+            resets,
+            generation: 0,
+            task: None,
+        };
+        self.validate_value(&mut tracker, ctx, value, app_state);
+
+        let view = self.view(app_state);
+        let (element, view_state) = ctx.with_id(INPUT_VIEW_ID, |ctx| view.build(ctx, &mut ()));
+        let state = private::FormFieldState {
+            tracker,
+            view,
+            view_state,
+        };
+        (element, state)
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        element: Mut<'_, Self::Element>,
+        app_state: &mut State,
+    ) {
+        let value = (self.value)(app_state).clone();
+        let form = (self.form)(app_state);
+        let tracker = &mut state.tracker;
+        if prev.label != self.label {
+            let status = form.field(&prev.label).cloned().unwrap_or_default();
+            form.remove_field(&prev.label);
+            *form.field_mut(&self.label) = status;
+        }
+        if tracker.resets != form.resets {
+            tracker.initial = value.clone();
+            tracker.resets = form.resets;
+        }
+        // The field isn't registered if the app replaced the form.
+        let registered = form.field(&self.label).is_some();
+        form.field_mut(&self.label).dirty = value != tracker.initial;
+        if !registered || value != tracker.validated {
+            self.validate_value(tracker, ctx, value, app_state);
+        }
+
+        let view = self.view(app_state);
+        ctx.with_id(INPUT_VIEW_ID, |ctx| {
+            view.rebuild(&state.view, &mut state.view_state, ctx, element, &mut ());
+        });
+        state.view = view;
+    }
+
+    fn teardown(
+        &self,
+        state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        element: Mut<'_, Self::Element>,
+        app_state: &mut State,
+    ) {
+        if let Some(task) = state.tracker.task.take() {
+            task.handle.abort();
+        }
+        (self.form)(app_state).remove_field(&self.label);
+        ctx.with_id(INPUT_VIEW_ID, |ctx| {
+            state
+                .view
+                .teardown(&mut state.view_state, ctx, element, &mut ());
+        });
+    }
+
+    fn message(
+        &self,
+        state: &mut Self::ViewState,
+        id_path: &[ViewId],
+        message: DynMessage,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        match id_path.split_first() {
+            Some((&INPUT_VIEW_ID, rest)) => {
+                match state
+                    .view
+                    .message(&mut state.view_state, rest, message, &mut ())
+                {
+                    MessageResult::Action(value) => {
+                        // The new value is validated when the field is rebuilt.
+                        let dirty = value != state.tracker.initial;
+                        *(self.value)(app_state) = value;
+                        let field = (self.form)(app_state).field_mut(&self.label);
+                        field.dirty = dirty;
+                        field.touched = true;
+                        MessageResult::RequestRebuild
+                    }
+                    MessageResult::RequestRebuild => MessageResult::RequestRebuild,
+                    MessageResult::Nop => MessageResult::Nop,
+                    MessageResult::Stale(message) => MessageResult::Stale(message),
+                }
+            }
+            None => match message.downcast::<AsyncValidation>() {
+                Ok(validation) => {
+                    let is_current = state
+                        .tracker
+                        .task
+                        .as_ref()
+                        .is_some_and(|task| task.generation == validation.generation);
+                    if !is_current {
+                        // The value changed since this validation was started.
+                        return MessageResult::Nop;
+                    }
+                    state.tracker.task = None;
+                    let field = (self.form)(app_state).field_mut(&self.label);
+                    field.error = validation.result.err();
+                    field.validating = false;
+                    MessageResult::RequestRebuild
+                }
+                Err(message) => {
+                    tracing::error!("Wrong message type in FormField::message: {message:?}");
+                    MessageResult::Stale(message)
+                }
+            },
+            _ => {
+                tracing::warn!("Got unexpected id path in FormField::message");
+                MessageResult::Stale(message)
+            }
+        }
+    }
+}
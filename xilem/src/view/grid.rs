@@ -6,7 +6,10 @@ use std::marker::PhantomData;
 use crate::style::Style;
 
 use masonry::core::{FromDynWidget, Widget, WidgetMut};
-use masonry::properties::{Background, BorderColor, BorderWidth, CornerRadius, Padding};
+use masonry::properties::{
+    Background, BorderColor, BorderGradient, BorderWidth, CornerRadius, MixBlendMode, Opacity,
+    Padding,
+};
 use masonry::widgets;
 
 use crate::core::{
@@ -110,6 +113,9 @@ crate::declare_property_tuple!(
     BorderWidth, 2;
     CornerRadius, 3;
     Padding, 4;
+    Opacity, 5;
+    MixBlendMode, 6;
+    BorderGradient, 7;
 );
 
 impl<Seq, State, Action> ViewMarker for Grid<Seq, State, Action> {}
@@ -294,6 +300,10 @@ impl ElementSplice<GridElement> for GridSplice<'_> {
         widgets::Grid::remove_child(&mut self.element, self.idx);
         ret
     }
+
+    fn move_from(&mut self, offset: usize) {
+        widgets::Grid::move_child(&mut self.element, self.idx + offset, self.idx);
+    }
 }
 
 /// `GridSequence` is what allows an input to the grid that contains all the grid elements.
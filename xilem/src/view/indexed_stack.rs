@@ -7,10 +7,14 @@ use crate::core::{
     AppendVec, DynMessage, ElementSplice, MessageResult, Mut, SuperElement, View, ViewElement,
     ViewId, ViewMarker, ViewSequence,
 };
+use crate::ui_state::UiState;
 use crate::{Pod, PropertyTuple as _, ViewCtx};
-use masonry::core::{FromDynWidget, Widget, WidgetMut};
+use masonry::core::{ArcStr, FromDynWidget, Widget, WidgetMut};
 use masonry::{
-    properties::{Background, BorderColor, BorderWidth, CornerRadius, Padding},
+    properties::{
+        Background, BorderColor, BorderGradient, BorderWidth, CornerRadius, MixBlendMode, Opacity,
+        Padding,
+    },
     widgets,
 };
 
@@ -58,6 +62,7 @@ pub fn indexed_stack<State, Action, Seq: IndexedStackSequence<State, Action>>(
     IndexedStack {
         sequence,
         active_child: 0,
+        persistence_key: None,
         properties: Default::default(),
         phantom: PhantomData,
     }
@@ -70,6 +75,7 @@ pub fn indexed_stack<State, Action, Seq: IndexedStackSequence<State, Action>>(
 pub struct IndexedStack<Seq, State, Action = ()> {
     sequence: Seq,
     active_child: usize,
+    persistence_key: Option<ArcStr>,
     properties: IndexedStackProps,
 
     /// Used to associate the State and Action in the call to `.indexed_stack()` with the State and Action
@@ -87,6 +93,34 @@ impl<Seq, State, Action> IndexedStack<Seq, State, Action> {
         self.active_child = active;
         self
     }
+
+    /// Save the active child of this stack under `key`, and restore it when
+    /// a stack with the same key is built.
+    ///
+    /// The restored child takes precedence over the one set with [`active`](Self::active),
+    /// until the latter is changed.
+    /// As the app isn't told about the restored child, this is best suited for stacks whose
+    /// active child isn't otherwise used by the app.
+    /// See the [`ui_state`](crate::ui_state) module for details.
+    pub fn persistence_key(mut self, key: impl Into<ArcStr>) -> Self {
+        self.persistence_key = Some(key.into());
+        self
+    }
+
+    /// The active child saved under the persistence key, if it's a valid index.
+    fn saved_active_child(&self, ctx: &ViewCtx, len: usize) -> Option<usize> {
+        let key = self.persistence_key.as_deref()?;
+        match ctx.ui_state().load(key)? {
+            UiState::ActiveIndex(idx) if idx < len => Some(idx),
+            _ => None,
+        }
+    }
+
+    fn save_active_child(&self, ctx: &ViewCtx, idx: usize) {
+        if let Some(key) = &self.persistence_key {
+            ctx.ui_state().save(key, UiState::ActiveIndex(idx));
+        }
+    }
 }
 
 impl<Seq, S, A> Style for IndexedStack<Seq, S, A> {
@@ -106,6 +140,9 @@ crate::declare_property_tuple!(
     BorderWidth, 2;
     CornerRadius, 3;
     Padding, 4;
+    Opacity, 5;
+    MixBlendMode, 6;
+    BorderGradient, 7;
 );
 
 impl<Seq, State, Action> ViewMarker for IndexedStack<Seq, State, Action> {}
@@ -127,7 +164,10 @@ where
         for element in elements.into_inner() {
             widget = widget.with_child_pod(element.child.erased_widget_pod());
         }
-        widget = widget.with_active_child(self.active_child);
+        let active_child = self
+            .saved_active_child(ctx, widget.len())
+            .unwrap_or(self.active_child);
+        widget = widget.with_active_child(active_child);
         let mut pod = ctx.create_pod(widget);
         pod.properties = self.properties.build_properties();
         (pod, seq_state)
@@ -152,9 +192,20 @@ where
 
         // set the active child after updating the sequence to
         // ensure the index remains consistent with the children list
-        if self.active_child != element.widget.active_child_index() {
-            widgets::IndexedStack::set_active_child(&mut element, self.active_child);
+        let active_child = if prev.persistence_key != self.persistence_key {
+            prev.save_active_child(ctx, element.widget.active_child_index());
+            self.saved_active_child(ctx, element.widget.len())
+                .unwrap_or(self.active_child)
+        } else if self.persistence_key.is_some() && prev.active_child == self.active_child {
+            // Keep the restored child.
+            element.widget.active_child_index()
+        } else {
+            self.active_child
+        };
+        if active_child != element.widget.active_child_index() {
+            widgets::IndexedStack::set_active_child(&mut element, active_child);
         }
+        self.save_active_child(ctx, active_child);
     }
 
     fn teardown(
@@ -164,6 +215,7 @@ where
         element: Mut<'_, Self::Element>,
         app_state: &mut State,
     ) {
+        self.save_active_child(ctx, element.widget.active_child_index());
         let mut splice = IndexedStackSplice::new(element);
         self.sequence
             .seq_teardown(view_state, ctx, &mut splice, app_state);
@@ -279,6 +331,10 @@ impl ElementSplice<IndexedStackElement> for IndexedStackSplice<'_> {
         widgets::IndexedStack::remove_child(&mut self.element, self.idx);
         ret
     }
+
+    fn move_from(&mut self, offset: usize) {
+        widgets::IndexedStack::move_child(&mut self.element, self.idx + offset, self.idx);
+    }
 }
 
 /// `IndexedStackSequence` is what allows an input to the indexed stack that contains all the stack elements.
@@ -60,5 +60,26 @@ pub use zstack::*;
 mod transform;
 pub use transform::*;
 
+mod shortcut;
+pub use shortcut::*;
+
+mod modal;
+pub use modal::*;
+
+mod navigator;
+pub use navigator::*;
+
+mod file_dialog;
+pub use file_dialog::*;
+
+mod form;
+pub use form::*;
+
 mod split;
 pub use split::*;
+
+mod with_signal;
+pub use with_signal::*;
+
+mod undoable;
+pub use undoable::*;
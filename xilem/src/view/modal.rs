@@ -0,0 +1,159 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+
+use masonry::widgets;
+
+use crate::core::{DynMessage, Mut, View, ViewId, ViewMarker, ViewPathTracker};
+use crate::{MessageResult, Pod, ViewCtx, WidgetView};
+
+/// A modal dialog which shows `content` over the rest of the window while `open` is `true`.
+///
+/// While it's open, the content behind the dialog is dimmed and can't be interacted with,
+/// and keyboard focus is kept inside the dialog.
+/// When the user asks to close the dialog by pressing <kbd>Escape</kbd>, `on_dismiss` is called.
+/// The dialog is only closed when `open` becomes `false`, so `on_dismiss` should usually
+/// update the app state accordingly.
+///
+/// The dialog should be drawn on top of the rest of the window, e.g. by making it the last
+/// child of a [`zstack`](crate::view::zstack).
+///
+/// This corresponds to the Masonry [`Dialog`](masonry::widgets::Dialog) widget.
+///
+/// # Example
+/// ```ignore
+/// use xilem::view::{button, flex, label, modal, zstack};
+///
+/// zstack((
+///     button("Delete", |state: &mut State| state.confirm_delete = true),
+///     modal(
+///         state.confirm_delete,
+///         flex((
+///             label("Delete this file?"),
+///             button("Delete", |state: &mut State| state.delete()),
+///         )),
+///         |state: &mut State| state.confirm_delete = false,
+///     ),
+/// ))
+/// ```
+pub fn modal<State, Action, Content, F>(
+    open: bool,
+    content: Content,
+    on_dismiss: F,
+) -> Modal<Content, F, State, Action>
+where
+    Content: WidgetView<State, Action>,
+    F: Fn(&mut State) -> Action + Send + Sync + 'static,
+{
+    Modal {
+        open,
+        content,
+        on_dismiss,
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`modal`].
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct Modal<V, F, State, Action> {
+    open: bool,
+    content: V,
+    on_dismiss: F,
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+const CONTENT_VIEW_ID: ViewId = ViewId::new(0);
+
+impl<V, F, State, Action> ViewMarker for Modal<V, F, State, Action> {}
+impl<V, F, State, Action> View<State, Action, ViewCtx> for Modal<V, F, State, Action>
+where
+    State: 'static,
+    Action: 'static,
+    V: WidgetView<State, Action>,
+    F: Fn(&mut State) -> Action + Send + Sync + 'static,
+{
+    type Element = Pod<widgets::Dialog>;
+    type ViewState = V::ViewState;
+
+    fn build(&self, ctx: &mut ViewCtx, app_state: &mut State) -> (Self::Element, Self::ViewState) {
+        let (content, content_state) =
+            ctx.with_id(CONTENT_VIEW_ID, |ctx| self.content.build(ctx, app_state));
+        let pod = ctx.with_action_widget(|ctx| {
+            ctx.create_pod(
+                widgets::Dialog::new_pod(content.erased_widget_pod()).with_open(self.open),
+            )
+        });
+        (pod, content_state)
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: &mut State,
+    ) {
+        if self.open != prev.open {
+            widgets::Dialog::set_open(&mut element, self.open);
+        }
+        ctx.with_id(CONTENT_VIEW_ID, |ctx| {
+            let mut content = widgets::Dialog::child_mut(&mut element);
+            self.content.rebuild(
+                &prev.content,
+                view_state,
+                ctx,
+                content.downcast(),
+                app_state,
+            );
+        });
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: &mut State,
+    ) {
+        ctx.with_id(CONTENT_VIEW_ID, |ctx| {
+            let mut content = widgets::Dialog::child_mut(&mut element);
+            self.content
+                .teardown(view_state, ctx, content.downcast(), app_state);
+        });
+        ctx.teardown_leaf(element);
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        id_path: &[ViewId],
+        message: DynMessage,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        match id_path.split_first() {
+            Some((&CONTENT_VIEW_ID, rest)) => {
+                self.content.message(view_state, rest, message, app_state)
+            }
+            None => match message.downcast::<masonry::core::Action>() {
+                Ok(action) => {
+                    if let masonry::core::Action::DialogDismissed = *action {
+                        MessageResult::Action((self.on_dismiss)(app_state))
+                    } else {
+                        tracing::error!("Wrong action type in Modal::message: {action:?}");
+                        MessageResult::Stale(DynMessage(action))
+                    }
+                }
+                Err(message) => {
+                    tracing::error!("Wrong message type in Modal::message: {message:?}");
+                    MessageResult::Stale(message)
+                }
+            },
+            _ => {
+                tracing::warn!("Got unexpected id path in Modal::message");
+                MessageResult::Stale(message)
+            }
+        }
+    }
+}
@@ -0,0 +1,314 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+
+use masonry::core::{Shortcut, ShortcutId, ShortcutPrecedence, Shortcuts};
+use masonry::widgets::{self, StackTransition};
+use xilem_core::ViewPathTracker;
+
+use crate::core::{DynMessage, Mut, Navigator, RouteKey, View, ViewMarker};
+use crate::{MessageResult, Pod, ViewCtx, ViewId, WidgetView};
+
+/// A view which shows the current route of a [`Navigator`], and goes back to the previous
+/// route with the back button.
+///
+/// `navigator` projects the navigator out of the app state, like the function given to
+/// [`lens`](crate::core::lens), and `route_view` creates the view of a route.
+/// Event handlers change the screen by pushing, popping or replacing routes of the navigator.
+///
+/// The views of the routes below the current one are kept while they are in the stack, but are
+/// neither shown nor rebuilt.
+/// Their widgets keep their state, so that e.g. the scroll position of a [`portal`](crate::view::portal)
+/// is restored when the routes above it are popped.
+///
+/// The back shortcuts are the <kbd>BrowserBack</kbd> and <kbd>GoBack</kbd> keys, which include
+/// the back button of Android devices, and <kbd>Alt</kbd>+<kbd>ArrowLeft</kbd>.
+/// They are only triggered while the focus is in the navigator, and if the focused widget
+/// doesn't handle these chords itself.
+/// Going back rebuilds the view tree, without producing an action.
+///
+/// # Examples
+///
+/// ```
+/// use xilem::core::Navigator;
+/// use xilem::view::{button, flex, label, navigator};
+/// use xilem::WidgetView;
+///
+/// #[derive(Clone)]
+/// enum Route {
+///     Home,
+///     Details(u32),
+/// }
+///
+/// struct State {
+///     navigator: Navigator<Route>,
+/// }
+///
+/// fn app_logic(state: &mut State) -> impl WidgetView<State> + use<> {
+///     navigator(
+///         |state: &mut State| &mut state.navigator,
+///         |_: &mut State, route: &Route| match route {
+///             Route::Home => button("Show details", |state: &mut State| {
+///                 state.navigator.push(Route::Details(1));
+///             })
+///             .boxed(),
+///             Route::Details(id) => flex((
+///                 label(format!("Details of {id}")),
+///                 button("Back", |state: &mut State| {
+///                     state.navigator.pop();
+///                 }),
+///             ))
+///             .boxed(),
+///         },
+///     )
+///     .animated(true)
+/// }
+/// ```
+pub fn navigator<State, Action, R, N, F, V>(
+    navigator: N,
+    route_view: F,
+) -> NavigatorView<N, F, State, Action, R>
+where
+    N: Fn(&mut State) -> &mut Navigator<R> + 'static,
+    F: Fn(&mut State, &R) -> V + 'static,
+    V: WidgetView<State, Action>,
+    R: Clone + 'static,
+{
+    NavigatorView {
+        navigator,
+        route_view,
+        animated: false,
+        phantom: PhantomData,
+    }
+}
+
+/// The view for [`navigator`].
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct NavigatorView<N, F, State, Action, R> {
+    navigator: N,
+    route_view: F,
+    animated: bool,
+    phantom: PhantomData<fn(State, R) -> Action>,
+}
+
+impl<N, F, State, Action, R> NavigatorView<N, F, State, Action, R> {
+    /// Set whether pushing and popping routes slide the screens horizontally.
+    ///
+    /// Replacing a route is never animated.
+    pub fn animated(mut self, animated: bool) -> Self {
+        self.animated = animated;
+        self
+    }
+}
+
+mod private {
+    use masonry::core::ShortcutId;
+
+    use crate::core::RouteKey;
+
+    /// The View state for the [`NavigatorView`](super::NavigatorView)
+    #[expect(
+        unnameable_types,
+        reason = "This type has no public API, and is only public due to trait visibility rules"
+    )]
+    pub struct NavigatorState<V, RouteState> {
+        /// The views of the routes of the stack, from the root to the current route.
+        pub(super) routes: Vec<(RouteKey, V, RouteState)>,
+        pub(super) back: [ShortcutId; 3],
+    }
+}
+
+impl<V, RouteState> private::NavigatorState<V, RouteState> {
+    fn shortcuts(&self) -> Vec<Shortcut> {
+        let shortcut = |id, chord: &str| Shortcut {
+            id,
+            chord: chord.parse().unwrap(),
+            precedence: ShortcutPrecedence::AfterFocused,
+            label: Some("Back".into()),
+        };
+        vec![
+            shortcut(self.back[0], "BrowserBack"),
+            shortcut(self.back[1], "GoBack"),
+            shortcut(self.back[2], "Alt+ArrowLeft"),
+        ]
+    }
+}
+
+fn route_view_id(key: RouteKey) -> ViewId {
+    ViewId::new(key.to_raw())
+}
+
+/// The keys and routes of the stack, which are cloned so that the app state can be
+/// borrowed by the route views.
+fn route_entries<R: Clone>(navigator: &Navigator<R>) -> Vec<(RouteKey, R)> {
+    navigator
+        .entries()
+        .iter()
+        .map(|entry| (entry.key(), entry.route().clone()))
+        .collect()
+}
+
+impl<N, F, State, Action, R> ViewMarker for NavigatorView<N, F, State, Action, R> {}
+impl<N, F, State, Action, R, V> View<State, Action, ViewCtx>
+    for NavigatorView<N, F, State, Action, R>
+where
+    N: Fn(&mut State) -> &mut Navigator<R> + 'static,
+    F: Fn(&mut State, &R) -> V + 'static,
+    V: WidgetView<State, Action>,
+    State: 'static,
+    Action: 'static,
+    R: Clone + 'static,
+{
+    type Element = Pod<widgets::IndexedStack>;
+    type ViewState = private::NavigatorState<V, V::ViewState>;
+
+    fn build(&self, ctx: &mut ViewCtx, app_state: &mut State) -> (Self::Element, Self::ViewState) {
+        let back = [ShortcutId::next(), ShortcutId::next(), ShortcutId::next()];
+        for id in back {
+            ctx.record_shortcut(id);
+        }
+
+        let mut widget = widgets::IndexedStack::new();
+        let mut routes = Vec::new();
+        for (key, route) in route_entries((self.navigator)(app_state)) {
+            let view = (self.route_view)(app_state, &route);
+            let (child, child_state) =
+                ctx.with_id(route_view_id(key), |ctx| view.build(ctx, app_state));
+            widget = widget.with_child_pod(child.erased_widget_pod());
+            routes.push((key, view, child_state));
+        }
+        widget = widget.with_active_child(routes.len() - 1);
+
+        let state = private::NavigatorState { routes, back };
+        let mut pod = ctx.create_pod(widget);
+        pod.properties.insert(Shortcuts(state.shortcuts()));
+        (pod, state)
+    }
+
+    fn rebuild(
+        &self,
+        _prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: &mut State,
+    ) {
+        let entries = route_entries((self.navigator)(app_state));
+        let old_len = view_state.routes.len();
+        let common = view_state
+            .routes
+            .iter()
+            .zip(&entries)
+            .take_while(|((old_key, ..), (key, _))| old_key == key)
+            .count();
+
+        // The new routes are added before the old ones are removed,
+        // so that the stack can transition from the previous route.
+        for (key, route) in &entries[common..] {
+            let view = (self.route_view)(app_state, route);
+            let (child, child_state) =
+                ctx.with_id(route_view_id(*key), |ctx| view.build(ctx, app_state));
+            widgets::IndexedStack::add_child_pod(&mut element, child.erased_widget_pod());
+            view_state.routes.push((*key, view, child_state));
+        }
+        let current = if entries.len() > common {
+            view_state.routes.len() - 1
+        } else {
+            common - 1
+        };
+        if current != element.widget.active_child_index() {
+            let transition = if !self.animated || entries.len() == old_len {
+                StackTransition::None
+            } else if entries.len() > old_len {
+                StackTransition::Push
+            } else {
+                StackTransition::Pop
+            };
+            widgets::IndexedStack::transition_to(&mut element, current, transition);
+        }
+
+        for idx in (common..old_len).rev() {
+            let (key, view, mut child_state) = view_state.routes.remove(idx);
+            {
+                let mut child = widgets::IndexedStack::child_mut(&mut element, idx);
+                ctx.with_id(route_view_id(key), |ctx| {
+                    view.teardown(&mut child_state, ctx, child.downcast(), app_state);
+                });
+            }
+            widgets::IndexedStack::remove_child(&mut element, idx);
+        }
+
+        // Only the current route is shown, so the others don't need to be rebuilt.
+        let current = entries.len() - 1;
+        if current < common {
+            let (key, route) = &entries[current];
+            let view = (self.route_view)(app_state, route);
+            let (_, prev_view, child_state) = &mut view_state.routes[current];
+            let mut child = widgets::IndexedStack::child_mut(&mut element, current);
+            ctx.with_id(route_view_id(*key), |ctx| {
+                view.rebuild(prev_view, child_state, ctx, child.downcast(), app_state);
+            });
+            *prev_view = view;
+        }
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: &mut State,
+    ) {
+        for id in view_state.back {
+            ctx.teardown_shortcut(id);
+        }
+        for (idx, (key, view, child_state)) in view_state.routes.iter_mut().enumerate().rev() {
+            let mut child = widgets::IndexedStack::child_mut(&mut element, idx);
+            ctx.with_id(route_view_id(*key), |ctx| {
+                view.teardown(child_state, ctx, child.downcast(), app_state);
+            });
+        }
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        id_path: &[ViewId],
+        message: DynMessage,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        match id_path.split_first() {
+            Some((first, rest)) => {
+                // Routes below the current one can still get messages, e.g. from tasks.
+                let route = view_state
+                    .routes
+                    .iter_mut()
+                    .find(|(key, ..)| route_view_id(*key) == *first);
+                match route {
+                    Some((_, view, child_state)) => {
+                        view.message(child_state, rest, message, app_state)
+                    }
+                    None => MessageResult::Stale(message),
+                }
+            }
+            None => match message.downcast::<ShortcutId>() {
+                Ok(shortcut_id) if view_state.back.contains(&*shortcut_id) => {
+                    if (self.navigator)(app_state).pop().is_some() {
+                        MessageResult::RequestRebuild
+                    } else {
+                        MessageResult::Nop
+                    }
+                }
+                Ok(shortcut_id) => {
+                    tracing::error!("Wrong shortcut in NavigatorView::message: {shortcut_id}");
+                    MessageResult::Stale(DynMessage(shortcut_id))
+                }
+                Err(message) => {
+                    tracing::error!("Wrong message type in NavigatorView::message: {message:?}");
+                    MessageResult::Stale(message)
+                }
+            },
+        }
+    }
+}
@@ -3,9 +3,12 @@
 
 use std::marker::PhantomData;
 
+use masonry::core::ArcStr;
+use masonry::kurbo::Point;
 use masonry::widgets;
 
 use crate::core::{DynMessage, Mut, ViewMarker};
+use crate::ui_state::UiState;
 use crate::{MessageResult, Pod, View, ViewCtx, ViewId, WidgetView};
 
 /// A view which puts `child` into a scrollable region.
@@ -17,6 +20,7 @@ where
 {
     Portal {
         child,
+        persistence_key: None,
         phantom: PhantomData,
     }
 }
@@ -25,9 +29,39 @@ where
 #[must_use = "View values do nothing unless provided to Xilem."]
 pub struct Portal<V, State, Action> {
     child: V,
+    persistence_key: Option<ArcStr>,
     phantom: PhantomData<(State, Action)>,
 }
 
+impl<V, State, Action> Portal<V, State, Action> {
+    /// Save the scroll position of this portal under `key`, and restore it when
+    /// a portal with the same key is built.
+    ///
+    /// See the [`ui_state`](crate::ui_state) module for details.
+    pub fn persistence_key(mut self, key: impl Into<ArcStr>) -> Self {
+        self.persistence_key = Some(key.into());
+        self
+    }
+
+    fn saved_scroll_offset(&self, ctx: &ViewCtx) -> Option<Point> {
+        let key = self.persistence_key.as_deref()?;
+        match ctx.ui_state().load(key)? {
+            UiState::ScrollOffset { x, y } => Some(Point::new(x, y)),
+            _ => None,
+        }
+    }
+
+    fn save_scroll_offset(&self, ctx: &ViewCtx, offset: Point) {
+        if let Some(key) = &self.persistence_key {
+            let state = UiState::ScrollOffset {
+                x: offset.x,
+                y: offset.y,
+            };
+            ctx.ui_state().save(key, state);
+        }
+    }
+}
+
 impl<V, State, Action> ViewMarker for Portal<V, State, Action> {}
 impl<Child, State, Action> View<State, Action, ViewCtx> for Portal<Child, State, Action>
 where
@@ -42,7 +76,11 @@ where
         // The Portal `View` doesn't get any messages directly (yet - scroll events?), so doesn't need to
         // use ctx.with_id.
         let (child, child_state) = self.child.build(ctx, app_state);
-        let widget_pod = ctx.create_pod(widgets::Portal::new_pod(child.into_widget_pod()));
+        let mut widget = widgets::Portal::new_pod(child.into_widget_pod());
+        if let Some(offset) = self.saved_scroll_offset(ctx) {
+            widget = widget.viewport_pos(offset);
+        }
+        let widget_pod = ctx.create_pod(widget);
         (widget_pod, child_state)
     }
 
@@ -54,9 +92,20 @@ where
         mut element: Mut<'_, Self::Element>,
         app_state: &mut State,
     ) {
-        let child_element = widgets::Portal::child_mut(&mut element);
-        self.child
-            .rebuild(&prev.child, view_state, ctx, child_element, app_state);
+        if prev.persistence_key != self.persistence_key {
+            prev.save_scroll_offset(ctx, element.widget.get_viewport_pos());
+            // A new key usually means new content, which starts at the top if it wasn't seen before.
+            if self.persistence_key.is_some() {
+                let offset = self.saved_scroll_offset(ctx).unwrap_or(Point::ORIGIN);
+                widgets::Portal::set_viewport_pos(&mut element, offset);
+            }
+        }
+        {
+            let child_element = widgets::Portal::child_mut(&mut element);
+            self.child
+                .rebuild(&prev.child, view_state, ctx, child_element, app_state);
+        }
+        self.save_scroll_offset(ctx, element.widget.get_viewport_pos());
     }
 
     fn teardown(
@@ -66,6 +115,7 @@ where
         mut element: Mut<'_, Self::Element>,
         app_state: &mut State,
     ) {
+        self.save_scroll_offset(ctx, element.widget.get_viewport_pos());
         let child_element = widgets::Portal::child_mut(&mut element);
         self.child
             .teardown(view_state, ctx, child_element, app_state);
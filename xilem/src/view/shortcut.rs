@@ -0,0 +1,212 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+
+use masonry::core::{ArcStr, KeyChord, Shortcut, ShortcutId, ShortcutPrecedence, Shortcuts};
+use xilem_core::ViewPathTracker;
+
+use crate::core::{DynMessage, Mut, View, ViewMarker};
+use crate::{MessageResult, Pod, ViewCtx, ViewId, WidgetView};
+
+/// A view which calls `callback` when `chord` is pressed while the focus is in `child`.
+///
+/// If no widget has focus, shortcuts on the window's root view are still triggered.
+/// See [`Shortcut`] for how shortcuts are resolved when several of them use the same chord.
+///
+/// The shortcut can be configured using the methods on the return type.
+///
+/// # Examples
+///
+/// ```ignore
+/// use xilem::view::{flex, on_shortcut};
+///
+/// on_shortcut(flex((/* ... */)), "Ctrl+S".parse().unwrap(), |state: &mut State| {
+///     state.save();
+/// })
+/// .label("Save")
+/// ```
+pub fn on_shortcut<Child, State, Action, F>(
+    child: Child,
+    chord: KeyChord,
+    callback: F,
+) -> OnShortcut<Child, F, State, Action>
+where
+    Child: WidgetView<State, Action>,
+    F: Fn(&mut State) -> Action + Send + Sync + 'static,
+{
+    OnShortcut {
+        child,
+        chord,
+        precedence: ShortcutPrecedence::default(),
+        label: None,
+        callback,
+        phantom: PhantomData,
+    }
+}
+
+/// The view for [`on_shortcut`].
+pub struct OnShortcut<V, F, State, Action> {
+    child: V,
+    chord: KeyChord,
+    precedence: ShortcutPrecedence,
+    label: Option<ArcStr>,
+    callback: F,
+    phantom: PhantomData<fn(State) -> Action>,
+}
+
+impl<V, F, State, Action> OnShortcut<V, F, State, Action> {
+    /// Set when the shortcut is checked relative to the focused widget.
+    ///
+    /// By default, a focused text input gets the key event first.
+    #[must_use]
+    pub fn precedence(mut self, precedence: ShortcutPrecedence) -> Self {
+        self.precedence = precedence;
+        self
+    }
+
+    /// Set a human-readable description of the shortcut's command.
+    #[must_use]
+    pub fn label(mut self, label: impl Into<ArcStr>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    fn shortcut(&self, id: ShortcutId) -> Shortcut {
+        Shortcut {
+            id,
+            chord: self.chord.clone(),
+            precedence: self.precedence,
+            label: self.label.clone(),
+        }
+    }
+}
+
+mod private {
+    use masonry::core::ShortcutId;
+
+    /// The View state for the [`OnShortcut`](super::OnShortcut)
+    #[expect(
+        unnameable_types,
+        reason = "This type has no public API, and is only public due to trait visibility rules"
+    )]
+    pub struct OnShortcutState<ChildState> {
+        pub(super) child: ChildState,
+        pub(super) shortcut_id: ShortcutId,
+    }
+}
+
+const CHILD_VIEW_ID: ViewId = ViewId::new(0);
+
+impl<V, F, State, Action> ViewMarker for OnShortcut<V, F, State, Action> {}
+impl<Child, F, State, Action> View<State, Action, ViewCtx> for OnShortcut<Child, F, State, Action>
+where
+    Child: WidgetView<State, Action>,
+    F: Fn(&mut State) -> Action + Send + Sync + 'static,
+    State: 'static,
+    Action: 'static,
+{
+    type Element = Pod<Child::Widget>;
+    type ViewState = private::OnShortcutState<Child::ViewState>;
+
+    fn build(&self, ctx: &mut ViewCtx, app_state: &mut State) -> (Self::Element, Self::ViewState) {
+        let shortcut_id = ShortcutId::next();
+        ctx.record_shortcut(shortcut_id);
+        let (mut child_pod, child_state) =
+            ctx.with_id(CHILD_VIEW_ID, |ctx| self.child.build(ctx, app_state));
+
+        // The child may already have shortcuts, e.g. from another `OnShortcut`.
+        let mut shortcuts = child_pod
+            .properties
+            .remove::<Shortcuts>()
+            .unwrap_or_default();
+        shortcuts.0.push(self.shortcut(shortcut_id));
+        child_pod.properties.insert(shortcuts);
+
+        let state = private::OnShortcutState {
+            child: child_state,
+            shortcut_id,
+        };
+        (child_pod, state)
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: &mut State,
+    ) {
+        ctx.with_id(CHILD_VIEW_ID, |ctx| {
+            self.child.rebuild(
+                &prev.child,
+                &mut view_state.child,
+                ctx,
+                element.reborrow_mut(),
+                app_state,
+            );
+        });
+
+        // We don't compare with `prev`, because the child may have replaced the widget's
+        // properties, e.g. if it's a `OneOf` which changed variant.
+        let shortcut = self.shortcut(view_state.shortcut_id);
+        let shortcuts = element.get_prop::<Shortcuts>();
+        if !shortcuts.0.contains(&shortcut) {
+            let mut shortcuts = shortcuts.clone();
+            shortcuts.0.retain(|s| s.id != shortcut.id);
+            shortcuts.0.push(shortcut);
+            element.insert_prop(shortcuts);
+        }
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: &mut State,
+    ) {
+        let mut shortcuts = element.get_prop::<Shortcuts>().clone();
+        shortcuts.0.retain(|s| s.id != view_state.shortcut_id);
+        element.insert_prop(shortcuts);
+        ctx.teardown_shortcut(view_state.shortcut_id);
+
+        ctx.with_id(CHILD_VIEW_ID, |ctx| {
+            self.child
+                .teardown(&mut view_state.child, ctx, element, app_state);
+        });
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        id_path: &[ViewId],
+        message: DynMessage,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        match id_path.split_first() {
+            Some((&CHILD_VIEW_ID, rest)) => {
+                self.child
+                    .message(&mut view_state.child, rest, message, app_state)
+            }
+            None => match message.downcast::<ShortcutId>() {
+                Ok(shortcut_id) if *shortcut_id == view_state.shortcut_id => {
+                    MessageResult::Action((self.callback)(app_state))
+                }
+                Ok(shortcut_id) => {
+                    tracing::error!("Wrong shortcut in OnShortcut::message: {shortcut_id}");
+                    MessageResult::Stale(DynMessage(shortcut_id))
+                }
+                Err(message) => {
+                    tracing::error!("Wrong message type in OnShortcut::message: {message:?}");
+                    MessageResult::Stale(message)
+                }
+            },
+            _ => {
+                tracing::warn!("Got unexpected id path in OnShortcut::message");
+                MessageResult::Stale(message)
+            }
+        }
+    }
+}
@@ -1,7 +1,10 @@
 // Copyright 2024 the Xilem Authors
 // SPDX-License-Identifier: Apache-2.0
 
-use masonry::properties::{Background, BorderColor, BorderWidth, CornerRadius, Padding};
+use masonry::properties::{
+    Background, BorderColor, BorderGradient, BorderWidth, BoxShadows, CornerRadius, MixBlendMode,
+    Opacity, Padding,
+};
 use std::marker::PhantomData;
 
 use crate::property_tuple::PropertyTuple;
@@ -118,6 +121,10 @@ crate::declare_property_tuple!(
     BorderWidth, 2;
     CornerRadius, 3;
     Padding, 4;
+    BoxShadows, 5;
+    Opacity, 6;
+    MixBlendMode, 7;
+    BorderGradient, 8;
 );
 
 impl<V, State, Action> ViewMarker for SizedBox<V, State, Action> {}
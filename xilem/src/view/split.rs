@@ -3,9 +3,11 @@
 
 use std::marker::PhantomData;
 
-use masonry::widgets::{self, Axis};
+use masonry::core::{ArcStr, Axis};
+use masonry::widgets;
 use xilem_core::{DynMessage, MessageResult, View, ViewId, ViewMarker, ViewPathTracker};
 
+use crate::ui_state::UiState;
 use crate::{Pod, ViewCtx, WidgetView};
 
 /// A container containing two other widgets, splitting the area either horizontally or vertically.
@@ -52,6 +54,7 @@ where
         min_bar_area: 6.0,
         solid_bar: false,
         draggable: true,
+        persistence_key: None,
         child1,
         child2,
         phantom: PhantomData,
@@ -70,6 +73,7 @@ pub struct Split<ChildA, ChildB, State, Action = ()> {
     min_bar_area: f64,    // Integers only
     solid_bar: bool,
     draggable: bool,
+    persistence_key: Option<ArcStr>,
     child1: ChildA,
     child2: ChildB,
     phantom: PhantomData<fn() -> (State, Action)>,
@@ -158,6 +162,33 @@ impl<ChildA, ChildB, State, Action> Split<ChildA, ChildB, State, Action> {
         self.solid_bar = solid;
         self
     }
+
+    /// Save the split point of this view under `key`, and restore it when
+    /// a split view with the same key is built.
+    ///
+    /// The restored split point takes precedence over the one set with
+    /// [`split_point`](Self::split_point), until the latter is changed.
+    /// See the [`ui_state`](crate::ui_state) module for details.
+    pub fn persistence_key(mut self, key: impl Into<ArcStr>) -> Self {
+        self.persistence_key = Some(key.into());
+        self
+    }
+
+    fn saved_split_point(&self, ctx: &ViewCtx) -> Option<f64> {
+        let key = self.persistence_key.as_deref()?;
+        match ctx.ui_state().load(key)? {
+            UiState::SplitPoint(split_point) if (0.0..=1.0).contains(&split_point) => {
+                Some(split_point)
+            }
+            _ => None,
+        }
+    }
+
+    fn save_split_point(&self, ctx: &ViewCtx, split_point: f64) {
+        if let Some(key) = &self.persistence_key {
+            ctx.ui_state().save(key, UiState::SplitPoint(split_point));
+        }
+    }
 }
 
 const CHILD1_VIEW_ID: ViewId = ViewId::new(0);
@@ -185,7 +216,7 @@ where
         let widget_pod = ctx.create_pod(
             widgets::Split::new_pod(child1.into_widget_pod(), child2.into_widget_pod())
                 .split_axis(self.split_axis)
-                .split_point(self.split_point)
+                .split_point(self.saved_split_point(ctx).unwrap_or(self.split_point))
                 .min_size(self.min_size.0, self.min_size.1)
                 .bar_size(self.bar_size)
                 .min_bar_area(self.min_bar_area)
@@ -208,6 +239,14 @@ where
             widgets::Split::set_split_axis(&mut element, self.split_axis);
         }
 
+        if prev.persistence_key != self.persistence_key {
+            prev.save_split_point(ctx, element.widget.get_split_point());
+            if self.persistence_key.is_some() {
+                let split_point = self.saved_split_point(ctx).unwrap_or(self.split_point);
+                widgets::Split::set_split_point(&mut element, split_point);
+            }
+        }
+
         if prev.split_point != self.split_point {
             widgets::Split::set_split_point(&mut element, self.split_point);
         }
@@ -253,6 +292,8 @@ where
                 app_state,
             );
         });
+
+        self.save_split_point(ctx, element.widget.get_split_point());
     }
 
     fn teardown(
@@ -262,6 +303,8 @@ where
         mut element: xilem_core::Mut<'_, Self::Element>,
         app_state: &mut State,
     ) {
+        self.save_split_point(ctx, element.widget.get_split_point());
+
         let child1_element = widgets::Split::child1_mut(&mut element);
         self.child1
             .teardown(&mut view_state.0, ctx, child1_element, app_state);
@@ -1,9 +1,10 @@
 // Copyright 2024 the Xilem Authors
 // SPDX-License-Identifier: Apache-2.0
 
-use masonry::core::{WidgetOptions, WidgetPod};
+use masonry::core::{ArcStr, ErrorDescription, Properties, WidgetId, WidgetOptions, WidgetPod};
 use masonry::properties::{
-    Background, BorderColor, BorderWidth, BoxShadow, CornerRadius, DisabledBackground, Padding,
+    Background, BorderColor, BorderGradient, BorderWidth, BoxShadows, CornerRadius,
+    DisabledBackground, MixBlendMode, Opacity, Padding,
 };
 use masonry::widgets;
 use vello::kurbo::Affine;
@@ -34,6 +35,7 @@ where
         text_alignment: TextAlign::default(),
         insert_newline: InsertNewline::default(),
         disabled: false,
+        error_description: None,
         properties: Default::default(),
     }
 }
@@ -48,6 +50,7 @@ pub struct TextInput<State, Action> {
     text_alignment: TextAlign,
     insert_newline: InsertNewline,
     disabled: bool,
+    error_description: Option<ArcStr>,
     properties: TextInputProps,
     // TODO: add more attributes of `masonry::widgets::TextInput`
 }
@@ -86,6 +89,16 @@ impl<State, Action> TextInput<State, Action> {
         self.disabled = disabled;
         self
     }
+
+    /// Mark the text as invalid, with a description of the error.
+    ///
+    /// The description is given to accessibility technologies, but isn't shown:
+    /// it should also be displayed next to the input, e.g. with a [`label`](crate::view::label).
+    /// See [`ErrorDescription`] for details.
+    pub fn error_description(mut self, description: Option<ArcStr>) -> Self {
+        self.error_description = description;
+        self
+    }
 }
 
 impl<S, A> Style for TextInput<S, A> {
@@ -104,9 +117,12 @@ crate::declare_property_tuple!(
     DisabledBackground, 1;
     BorderColor, 2;
     BorderWidth, 3;
-    BoxShadow, 4;
+    BoxShadows, 4;
     CornerRadius, 5;
     Padding, 6;
+    Opacity, 7;
+    MixBlendMode, 8;
+    BorderGradient, 9;
 );
 
 impl<State, Action> ViewMarker for TextInput<State, Action> {}
@@ -120,12 +136,19 @@ impl<State: 'static, Action: 'static> View<State, Action, ViewCtx> for TextInput
             .with_brush(self.text_brush.clone())
             .with_text_alignment(self.text_alignment)
             .with_insert_newline(self.insert_newline);
-        let text_input = widgets::TextInput::from_text_area_pod(WidgetPod::new_with_options(
+        // The inner TextArea is the focused widget, so it is the one which is marked as invalid.
+        let mut area_props = Properties::new();
+        if let Some(description) = &self.error_description {
+            area_props.insert(ErrorDescription::new(description.clone()));
+        }
+        let text_input = widgets::TextInput::from_text_area_pod(WidgetPod::new_with(
             text_area.into(),
+            WidgetId::next(),
             WidgetOptions {
                 disabled: self.disabled,
                 transform: Affine::default(),
             },
+            area_props,
         ));
 
         // Ensure that the actions from the *inner* TextArea get routed correctly.
@@ -172,6 +195,9 @@ impl<State: 'static, Action: 'static> View<State, Action, ViewCtx> for TextInput
         if prev.insert_newline != self.insert_newline {
             widgets::TextArea::set_insert_newline(&mut text_area, self.insert_newline);
         }
+        if prev.error_description != self.error_description {
+            text_area.insert_prop(ErrorDescription(self.error_description.clone()));
+        }
     }
 
     fn teardown(
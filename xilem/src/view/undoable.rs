@@ -0,0 +1,235 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+
+use masonry::core::{Shortcut, ShortcutId, ShortcutPrecedence, Shortcuts};
+use xilem_core::ViewPathTracker;
+
+use crate::core::{DynMessage, Mut, View, ViewMarker};
+use crate::undo::ChangeSource;
+use crate::{MessageResult, Pod, UndoHistory, ViewCtx, ViewId, WidgetView};
+
+/// A view which records the changes made to an [`UndoHistory`] by the event handlers in `child`,
+/// and binds the standard undo and redo shortcuts.
+///
+/// `history` projects the history out of the app state, like the function given to
+/// [`lens`](crate::core::lens).
+/// See [`UndoHistory`] for how the changes are grouped into transactions.
+///
+/// The shortcuts are <kbd>Ctrl</kbd>+<kbd>Z</kbd> to undo, and
+/// <kbd>Ctrl</kbd>+<kbd>Shift</kbd>+<kbd>Z</kbd> or <kbd>Ctrl</kbd>+<kbd>Y</kbd> to redo
+/// (using <kbd>Cmd</kbd> on macOS).
+/// They are only triggered if the focused widget doesn't handle these chords itself.
+/// Masonry's text inputs don't have an edit history of their own, so their edits are undone
+/// through the `UndoHistory` like other changes.
+/// Undoing or redoing with these shortcuts requests a rebuild of the views, without producing
+/// an action.
+///
+/// Text inputs update their text when the value they show is restored.
+/// Consecutive edits of the same text input are coalesced into a single "Typing" transaction.
+pub fn undoable<State, Action, T, F, Child>(
+    history: F,
+    child: Child,
+) -> Undoable<Child, F, State, Action, T>
+where
+    F: Fn(&mut State) -> &mut UndoHistory<T> + 'static,
+    Child: WidgetView<State, Action>,
+    T: Clone + PartialEq,
+{
+    Undoable {
+        child,
+        history,
+        phantom: PhantomData,
+    }
+}
+
+/// The view for [`undoable`].
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct Undoable<V, F, State, Action, T> {
+    child: V,
+    history: F,
+    phantom: PhantomData<fn(State, T) -> Action>,
+}
+
+mod private {
+    use masonry::core::ShortcutId;
+
+    /// The View state for the [`Undoable`](super::Undoable)
+    #[expect(
+        unnameable_types,
+        reason = "This type has no public API, and is only public due to trait visibility rules"
+    )]
+    pub struct UndoableState<ChildState> {
+        pub(super) child: ChildState,
+        pub(super) undo: ShortcutId,
+        pub(super) redo: [ShortcutId; 2],
+    }
+}
+
+const CHILD_VIEW_ID: ViewId = ViewId::new(0);
+
+impl<ChildState> private::UndoableState<ChildState> {
+    fn shortcuts(&self) -> [Shortcut; 3] {
+        let shortcut = |id, chord: &str, label: &str| Shortcut {
+            id,
+            chord: chord.parse().unwrap(),
+            precedence: ShortcutPrecedence::AfterFocused,
+            label: Some(label.into()),
+        };
+        [
+            shortcut(self.undo, "Primary+Z", "Undo"),
+            shortcut(self.redo[0], "Primary+Shift+Z", "Redo"),
+            shortcut(self.redo[1], "Primary+Y", "Redo"),
+        ]
+    }
+
+    fn is_own_shortcut(&self, id: ShortcutId) -> bool {
+        id == self.undo || self.redo.contains(&id)
+    }
+}
+
+impl<V, F, State, Action, T> ViewMarker for Undoable<V, F, State, Action, T> {}
+impl<Child, F, State, Action, T> View<State, Action, ViewCtx>
+    for Undoable<Child, F, State, Action, T>
+where
+    Child: WidgetView<State, Action>,
+    F: Fn(&mut State) -> &mut UndoHistory<T> + 'static,
+    State: 'static,
+    Action: 'static,
+    T: Clone + PartialEq + 'static,
+{
+    type Element = Pod<Child::Widget>;
+    type ViewState = private::UndoableState<Child::ViewState>;
+
+    fn build(&self, ctx: &mut ViewCtx, app_state: &mut State) -> (Self::Element, Self::ViewState) {
+        let undo = ShortcutId::next();
+        let redo = [ShortcutId::next(), ShortcutId::next()];
+        for id in [undo, redo[0], redo[1]] {
+            ctx.record_shortcut(id);
+        }
+        let (mut child_pod, child_state) =
+            ctx.with_id(CHILD_VIEW_ID, |ctx| self.child.build(ctx, app_state));
+
+        let state = private::UndoableState {
+            child: child_state,
+            undo,
+            redo,
+        };
+        // The child may already have shortcuts, e.g. from an `OnShortcut`.
+        let mut shortcuts = child_pod
+            .properties
+            .remove::<Shortcuts>()
+            .unwrap_or_default();
+        shortcuts.0.extend(state.shortcuts());
+        child_pod.properties.insert(shortcuts);
+
+        (child_pod, state)
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: &mut State,
+    ) {
+        ctx.with_id(CHILD_VIEW_ID, |ctx| {
+            self.child.rebuild(
+                &prev.child,
+                &mut view_state.child,
+                ctx,
+                element.reborrow_mut(),
+                app_state,
+            );
+        });
+
+        // As in `OnShortcut`, the child may have replaced the widget's properties.
+        let shortcuts = element.get_prop::<Shortcuts>();
+        let own_shortcuts = view_state.shortcuts();
+        if !own_shortcuts.iter().all(|s| shortcuts.0.contains(s)) {
+            let mut shortcuts = shortcuts.clone();
+            shortcuts.0.retain(|s| !view_state.is_own_shortcut(s.id));
+            shortcuts.0.extend(own_shortcuts);
+            element.insert_prop(shortcuts);
+        }
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: &mut State,
+    ) {
+        let mut shortcuts = element.get_prop::<Shortcuts>().clone();
+        shortcuts.0.retain(|s| !view_state.is_own_shortcut(s.id));
+        element.insert_prop(shortcuts);
+        for id in [view_state.undo, view_state.redo[0], view_state.redo[1]] {
+            ctx.teardown_shortcut(id);
+        }
+
+        ctx.with_id(CHILD_VIEW_ID, |ctx| {
+            self.child
+                .teardown(&mut view_state.child, ctx, element, app_state);
+        });
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        id_path: &[ViewId],
+        message: DynMessage,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        match id_path.split_first() {
+            Some((&CHILD_VIEW_ID, rest)) => {
+                // Text inputs send their text with each edit.
+                let (message, source) = match message.downcast::<masonry::core::Action>() {
+                    Ok(action) => {
+                        let source = match *action {
+                            masonry::core::Action::TextChanged(_) => ChangeSource::TextInput(rest),
+                            _ => ChangeSource::EventHandler,
+                        };
+                        (DynMessage(action), source)
+                    }
+                    Err(message) => (message, ChangeSource::EventHandler),
+                };
+                let checkpoint = (self.history)(app_state).checkpoint();
+                let result = self
+                    .child
+                    .message(&mut view_state.child, rest, message, app_state);
+                (self.history)(app_state).record(checkpoint, source);
+                result
+            }
+            None => match message.downcast::<ShortcutId>() {
+                Ok(shortcut_id) if view_state.is_own_shortcut(*shortcut_id) => {
+                    let history = (self.history)(app_state);
+                    let changed = if *shortcut_id == view_state.undo {
+                        history.undo()
+                    } else {
+                        history.redo()
+                    };
+                    if changed {
+                        MessageResult::RequestRebuild
+                    } else {
+                        MessageResult::Nop
+                    }
+                }
+                Ok(shortcut_id) => {
+                    tracing::error!("Wrong shortcut in Undoable::message: {shortcut_id}");
+                    MessageResult::Stale(DynMessage(shortcut_id))
+                }
+                Err(message) => {
+                    tracing::error!("Wrong message type in Undoable::message: {message:?}");
+                    MessageResult::Stale(message)
+                }
+            },
+            _ => {
+                tracing::warn!("Got unexpected id path in Undoable::message");
+                MessageResult::Stale(message)
+            }
+        }
+    }
+}
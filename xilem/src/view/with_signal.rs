@@ -0,0 +1,184 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use crate::core::{
+    DynMessage, MessageResult, Mut, Rebuild, View, ViewId, ViewMarker, ViewPathTracker,
+};
+use crate::{Signal, ViewCtx};
+
+/// A view which reads the closest [`Signal`] of type `T` provided by an ancestor.
+///
+/// The child view is created by calling `child` with the current value of the signal.
+/// When the signal changes, this view is rebuilt without running the app logic, and the
+/// sibling views in the sequences containing it are skipped.
+/// See the documentation of [`Signal`] for an example.
+///
+/// # Panics
+///
+/// If no `Signal<T>` has been provided using [`provides`](crate::core::provides).
+pub fn with_signal<T, State, Action, Child, ChildView>(
+    child: Child,
+) -> WithSignal<T, State, Action, Child>
+where
+    T: Clone + Debug + Send + 'static,
+    Child: Fn(&T, &mut State) -> ChildView,
+    ChildView: View<State, Action, ViewCtx>,
+{
+    WithSignal {
+        child,
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`with_signal`].
+///
+/// See `with_signal`'s docs for more details.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct WithSignal<T, State, Action, Child> {
+    child: Child,
+    phantom: PhantomData<fn(T, State) -> Action>,
+}
+
+mod private {
+    use std::sync::Arc;
+
+    use crate::Signal;
+    use crate::core::{RawProxy, ViewId};
+
+    #[expect(
+        unnameable_types,
+        reason = "Implementation detail, public because of trait visibility rules"
+    )]
+    pub struct WithSignalState<T, ChildView, ChildState> {
+        pub(super) prev: ChildView,
+        pub(super) child_state: ChildState,
+        pub(super) signal: Signal<T>,
+        pub(super) path: Arc<[ViewId]>,
+        pub(super) proxy: Arc<dyn RawProxy>,
+    }
+}
+
+const WITH_SIGNAL_CHILD: ViewId = ViewId::new(0);
+
+fn get_signal<T: Debug + Send + 'static>(ctx: &mut ViewCtx) -> Signal<T> {
+    let Some(signal) = ctx.environment().get_mut::<Signal<T>>() else {
+        panic!(
+            "Xilem: Tried to read {}, but it hasn't been provided. Did you forget to wrap this view with `xilem_core::environment::provides`?",
+            core::any::type_name::<Signal<T>>()
+        );
+    };
+    signal.clone()
+}
+
+impl<T, State, Action, Child> ViewMarker for WithSignal<T, State, Action, Child> {}
+impl<T, State, Action, Child, ChildView> View<State, Action, ViewCtx>
+    for WithSignal<T, State, Action, Child>
+where
+    T: Clone + Debug + Send + 'static,
+    Child: Fn(&T, &mut State) -> ChildView,
+    ChildView: View<State, Action, ViewCtx>,
+    Self: 'static,
+{
+    type Element = ChildView::Element;
+
+    type ViewState = private::WithSignalState<T, ChildView, ChildView::ViewState>;
+
+    fn build(&self, ctx: &mut ViewCtx, app_state: &mut State) -> (Self::Element, Self::ViewState) {
+        let signal = get_signal::<T>(ctx);
+        let path: Arc<[ViewId]> = ctx.view_path().into();
+        let proxy = ctx.proxy();
+        signal.subscribe(path.clone(), proxy.clone());
+
+        // The value is cloned so that the signal isn't locked while the child is built.
+        let value = signal.get();
+        let child_view = (self.child)(&value, app_state);
+        let (element, child_state) =
+            ctx.with_id(WITH_SIGNAL_CHILD, |ctx| child_view.build(ctx, app_state));
+        let state = private::WithSignalState {
+            prev: child_view,
+            child_state,
+            signal,
+            path,
+            proxy,
+        };
+        (element, state)
+    }
+
+    fn rebuild(
+        &self,
+        _: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        element: Mut<'_, Self::Element>,
+        app_state: &mut State,
+    ) {
+        let signal = get_signal::<T>(ctx);
+        if !signal.ptr_eq(&view_state.signal) {
+            view_state
+                .signal
+                .unsubscribe(&view_state.path, &view_state.proxy);
+            signal.subscribe(view_state.path.clone(), view_state.proxy.clone());
+            view_state.signal = signal;
+        }
+
+        let value = view_state.signal.get();
+        let child_view = (self.child)(&value, app_state);
+        ctx.with_id(WITH_SIGNAL_CHILD, |ctx| {
+            child_view.rebuild(
+                &view_state.prev,
+                &mut view_state.child_state,
+                ctx,
+                element,
+                app_state,
+            );
+        });
+        view_state.prev = child_view;
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        element: Mut<'_, Self::Element>,
+        app_state: &mut State,
+    ) {
+        view_state
+            .signal
+            .unsubscribe(&view_state.path, &view_state.proxy);
+        ctx.with_id(WITH_SIGNAL_CHILD, |ctx| {
+            view_state
+                .prev
+                .teardown(&mut view_state.child_state, ctx, element, app_state);
+        });
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        id_path: &[ViewId],
+        message: DynMessage,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        let Some((first, rest)) = id_path.split_first() else {
+            // Sent by the signal when it changes.
+            match message.downcast::<Rebuild>() {
+                Ok(_) => return MessageResult::RequestRebuild,
+                Err(message) => {
+                    tracing::warn!("Expected `Rebuild` in WithSignal::Message, got {message:?}");
+                    return MessageResult::Stale(message);
+                }
+            }
+        };
+        debug_assert_eq!(
+            *first, WITH_SIGNAL_CHILD,
+            "Message should have been routed properly."
+        );
+        view_state
+            .prev
+            .message(&mut view_state.child_state, rest, message, app_state)
+    }
+}
@@ -376,4 +376,8 @@ impl ElementSplice<ZStackElement> for ZStackSplice<'_> {
         widgets::ZStack::remove_child(&mut self.element, self.idx);
         ret
     }
+
+    fn move_from(&mut self, offset: usize) {
+        widgets::ZStack::move_child(&mut self.element, self.idx + offset, self.idx);
+    }
 }
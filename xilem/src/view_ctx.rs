@@ -4,10 +4,12 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use masonry::core::{FromDynWidget, Widget, WidgetId, WidgetMut};
+use masonry::core::{FromDynWidget, ShortcutId, Widget, WidgetId, WidgetMut};
 
 use crate::Pod;
 use crate::core::{AsyncCtx, Environment, RawProxy, ViewId, ViewPathTracker};
+use crate::file_dialog::FileDialogService;
+use crate::ui_state::UiStateStore;
 
 /// A context type passed to various methods of Xilem traits.
 pub struct ViewCtx {
@@ -15,9 +17,13 @@ pub struct ViewCtx {
     ///
     /// This includes only the widgets which might send actions
     widget_map: HashMap<WidgetId, Vec<ViewId>>,
+    /// The map from a shortcut's id to the position of the view which registered it.
+    shortcut_map: HashMap<ShortcutId, Vec<ViewId>>,
     id_path: Vec<ViewId>,
     proxy: Arc<dyn RawProxy>,
     runtime: Arc<tokio::runtime::Runtime>,
+    file_dialogs: Arc<dyn FileDialogService>,
+    ui_state: Arc<dyn UiStateStore>,
     state_changed: bool,
     environment: Environment,
 }
@@ -42,12 +48,20 @@ impl ViewPathTracker for ViewCtx {
 
 // Private items
 impl ViewCtx {
-    pub(crate) fn new(proxy: Arc<dyn RawProxy>, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+    pub(crate) fn new(
+        proxy: Arc<dyn RawProxy>,
+        runtime: Arc<tokio::runtime::Runtime>,
+        file_dialogs: Arc<dyn FileDialogService>,
+        ui_state: Arc<dyn UiStateStore>,
+    ) -> Self {
         Self {
             widget_map: HashMap::default(),
+            shortcut_map: HashMap::default(),
             id_path: Vec::new(),
             proxy,
             runtime,
+            file_dialogs,
+            ui_state,
             state_changed: true,
             environment: Environment::new(),
         }
@@ -60,6 +74,10 @@ impl ViewCtx {
     pub(crate) fn get_id_path(&self, widget_id: WidgetId) -> Option<&Vec<ViewId>> {
         self.widget_map.get(&widget_id)
     }
+
+    pub(crate) fn get_shortcut_id_path(&self, shortcut_id: ShortcutId) -> Option<&Vec<ViewId>> {
+        self.shortcut_map.get(&shortcut_id)
+    }
 }
 
 #[expect(missing_docs, reason = "TODO - Document these items")]
@@ -90,6 +108,19 @@ impl ViewCtx {
         self.widget_map.insert(id, path);
     }
 
+    /// Record that the shortcut `id` should be routed to this view.
+    ///
+    /// The view receives the [`ShortcutId`] as its message when the shortcut is triggered.
+    pub fn record_shortcut(&mut self, id: ShortcutId) {
+        let path = self.id_path.clone();
+        self.shortcut_map.insert(id, path);
+    }
+
+    /// Stop routing the shortcut `id`, once the view which recorded it is torn down.
+    pub fn teardown_shortcut(&mut self, id: ShortcutId) {
+        self.shortcut_map.remove(&id);
+    }
+
     /// Whether the app's state changed since the last rebuild.
     ///
     /// This is useful for views whose current value depends on current app state.
@@ -109,6 +140,18 @@ impl ViewCtx {
     pub fn proxy(&self) -> Arc<dyn RawProxy + 'static> {
         self.proxy.clone()
     }
+
+    /// The service used to show file dialogs, set with
+    /// [`Xilem::with_file_dialogs`](crate::Xilem::with_file_dialogs).
+    pub fn file_dialogs(&self) -> &Arc<dyn FileDialogService> {
+        &self.file_dialogs
+    }
+
+    /// The store of the UI state of views with a persistence key, set with
+    /// [`Xilem::with_ui_state_store`](crate::Xilem::with_ui_state_store).
+    pub fn ui_state(&self) -> &Arc<dyn UiStateStore> {
+        &self.ui_state
+    }
 }
 
 impl AsyncCtx for ViewCtx {
@@ -3,10 +3,10 @@
 
 use masonry::kurbo::Affine;
 
-use masonry::core::{FromDynWidget, Widget};
+use masonry::core::{FromDynWidget, KeyChord, Widget};
 
 use crate::core::{View, ViewSequence};
-use crate::view::{Transformed, transformed};
+use crate::view::{OnShortcut, Transformed, on_shortcut, transformed};
 use crate::{AnyWidgetView, Pod, ViewCtx};
 
 #[expect(missing_docs, reason = "TODO - Document these items")]
@@ -46,6 +46,27 @@ pub trait WidgetView<State, Action = ()>:
     {
         transformed(self).transform(by)
     }
+
+    /// Call `callback` when `chord` is pressed while the focus is in this view's widget.
+    ///
+    /// See [`on_shortcut`] for details.
+    /// The return type is the same as for `on_shortcut`, and so also has its builder methods.
+    ///
+    /// # Examples
+    /// ```ignore
+    /// use xilem::{view::flex, WidgetView};
+    ///
+    /// flex((/* ... */))
+    ///     .on_shortcut("Ctrl+S".parse().unwrap(), |state: &mut State| state.save())
+    ///     .label("Save")
+    /// ```
+    fn on_shortcut<F>(self, chord: KeyChord, callback: F) -> OnShortcut<Self, F, State, Action>
+    where
+        Self: Sized,
+        F: Fn(&mut State) -> Action + Send + Sync + 'static,
+    {
+        on_shortcut(self, chord, callback)
+    }
 }
 
 impl<V, State, Action, W> WidgetView<State, Action> for V
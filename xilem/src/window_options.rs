@@ -35,6 +35,8 @@ pub(crate) struct InitialAttrs {
     position: Option<Position>,
     // TODO: move window_icon to ReactiveWindowAttrs once the winit type implements PartialEq
     window_icon: Option<Icon>,
+    #[cfg(feature = "hot_reload")]
+    persistence_key: Option<String>,
 }
 
 pub(crate) struct WindowCallbacks<State> {
@@ -61,6 +63,8 @@ impl<State> WindowOptions<State> {
                 inner_size: None,
                 position: None,
                 window_icon: None,
+                #[cfg(feature = "hot_reload")]
+                persistence_key: None,
             },
             callbacks: WindowCallbacks::default(),
         }
@@ -118,6 +122,23 @@ impl<State> WindowOptions<State> {
         self
     }
 
+    /// Sets the key under which the position and size of the window are saved
+    /// by [`Xilem::with_hot_reload`](crate::Xilem::with_hot_reload).
+    ///
+    /// When the app is restarted, a window with the same key is created with the saved
+    /// position and size, instead of the initial ones.
+    /// The key must be unique among the windows of the app.
+    #[cfg(feature = "hot_reload")]
+    pub fn with_persistence_key(mut self, key: impl Into<String>) -> Self {
+        self.initial.persistence_key = Some(key.into());
+        self
+    }
+
+    #[cfg(feature = "hot_reload")]
+    pub(crate) fn persistence_key(&self) -> Option<&str> {
+        self.initial.persistence_key.as_deref()
+    }
+
     pub(crate) fn build_initial_attrs(&self) -> WindowAttributes {
         let mut attrs = WindowAttributes::default()
             .with_title(self.reactive.title.clone())
@@ -23,6 +23,11 @@ impl<State> WindowView<State> {
             root_widget_view,
         }
     }
+
+    #[cfg(feature = "hot_reload")]
+    pub(crate) fn options(&self) -> &WindowOptions<State> {
+        &self.options
+    }
 }
 
 pub(crate) struct CreateWindow(pub WindowAttributes, pub WidgetPod<dyn Widget>);
@@ -71,10 +76,7 @@ where
         (_, render_root): xilem_core::Mut<'_, Self::Element>,
         app_state: &mut State,
     ) {
-        render_root.edit_root_widget(|mut root| {
-            self.root_widget_view
-                .teardown(view_state, ctx, root.downcast(), app_state);
-        });
+        self.teardown_root_widget(view_state, ctx, render_root, app_state);
     }
 
     fn message(
@@ -115,6 +117,19 @@ where
         }
     }
 
+    pub(crate) fn teardown_root_widget(
+        &self,
+        root_widget_view_state: &mut AnyViewState,
+        ctx: &mut ViewCtx,
+        render_root: &mut RenderRoot,
+        app_state: &mut State,
+    ) {
+        render_root.edit_root_widget(|mut root| {
+            self.root_widget_view
+                .teardown(root_widget_view_state, ctx, root.downcast(), app_state);
+        });
+    }
+
     pub(crate) fn on_close(&self, state: &mut State) {
         if let Some(on_close) = &self.options.callbacks.on_close {
             on_close(state);
@@ -0,0 +1,91 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for [`AppHarness`], which the other tests in this directory are built on.
+
+use std::time::Duration;
+
+use xilem::core::fork;
+use xilem::testing::AppHarness;
+use xilem::view::{button, flex, label, task};
+use xilem::{WidgetView, WindowOptions, Xilem};
+
+#[derive(Default)]
+struct Counter {
+    num: i32,
+    ticks: u32,
+    ticking: bool,
+}
+
+fn app_logic(state: &mut Counter) -> impl WidgetView<Counter> + use<> {
+    let content = flex((
+        label(format!("Count: {}", state.num)),
+        button("Increment", |state: &mut Counter| state.num += 1),
+    ));
+    let ticker = state.ticking.then(|| {
+        task(
+            |proxy| async move {
+                let mut interval = tokio::time::interval(Duration::from_secs(1));
+                loop {
+                    interval.tick().await;
+                    if proxy.message(()).is_err() {
+                        break;
+                    }
+                }
+            },
+            |state: &mut Counter, ()| state.ticks += 1,
+        )
+    });
+    fork(content, ticker)
+}
+
+#[test]
+fn input_runs_the_app_logic() {
+    let mut harness = AppHarness::new_simple(Counter::default(), app_logic);
+    let increment = harness
+        .find_widget_by_accessibility_name("Increment")
+        .unwrap()
+        .id();
+    harness.mouse_click_on(increment);
+    harness.mouse_click_on(increment);
+    assert_eq!(harness.state().num, 2);
+    assert!(harness.find_widget_by_debug_text("Count: 2").is_some());
+
+    harness.edit_state(|state| state.num = 10);
+    assert!(harness.find_widget_by_debug_text("Count: 10").is_some());
+}
+
+#[test]
+fn time_only_passes_when_advanced() {
+    let mut harness = AppHarness::new_simple(Counter::default(), app_logic);
+    harness.edit_state(|state| state.ticking = true);
+    // The first tick of an interval is immediate.
+    harness.run_tasks();
+    assert_eq!(harness.state().ticks, 1);
+    harness.run_tasks();
+    assert_eq!(harness.state().ticks, 1);
+
+    harness.advance_time(Duration::from_millis(3500));
+    assert_eq!(harness.state().ticks, 4);
+
+    // Removing the task stops it.
+    harness.edit_state(|state| state.ticking = false);
+    harness.advance_time(Duration::from_secs(3));
+    assert_eq!(harness.state().ticks, 4);
+}
+
+#[test]
+fn closing_the_window() {
+    let mut harness = AppHarness::new_simple(Counter::default(), app_logic);
+    assert!(!harness.request_close());
+
+    // The close callback of the window runs before the app exits.
+    let app = Xilem::new_simple(
+        Counter::default(),
+        app_logic,
+        WindowOptions::new("Test").on_close(|state: &mut Counter| state.num = -1),
+    );
+    let mut harness = AppHarness::new(app);
+    assert!(!harness.request_close());
+    assert_eq!(harness.state().num, -1);
+}
@@ -0,0 +1,65 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for the [`file_dialog`] view, using [`MockFileDialogs`].
+
+use std::path::PathBuf;
+
+use xilem::file_dialog::{FileDialogKind, FileDialogOptions, FileDialogResult, MockFileDialogs};
+use xilem::testing::AppHarness;
+use xilem::view::{button, file_dialog, flex};
+use xilem::{WidgetView, WindowOptions, Xilem};
+
+#[derive(Default)]
+struct State {
+    picking: bool,
+    results: Vec<FileDialogResult>,
+}
+
+fn app_logic(state: &mut State) -> impl WidgetView<State> + use<> {
+    flex((
+        button("Open…", |state: &mut State| state.picking = true),
+        state.picking.then(|| {
+            file_dialog(
+                FileDialogOptions::open().filter("Text", ["txt"]),
+                |state: &mut State, result| {
+                    state.picking = false;
+                    state.results.push(result);
+                },
+            )
+        }),
+    ))
+}
+
+#[test]
+fn mock_results_are_delivered() {
+    let dialogs = MockFileDialogs::new();
+    dialogs.respond_with(FileDialogResult::Picked(vec![PathBuf::from("notes.txt")]));
+    let app = Xilem::new_simple(State::default(), app_logic, WindowOptions::new("Test"))
+        .with_file_dialogs(dialogs.clone());
+    let mut harness = AppHarness::new(app);
+
+    let open = harness
+        .find_widget_by_accessibility_name("Open…")
+        .unwrap()
+        .id();
+    harness.mouse_click_on(open);
+    assert!(harness.state().picking);
+    harness.run_tasks();
+    assert!(!harness.state().picking);
+    assert_eq!(
+        harness.state().results[0].path(),
+        Some(PathBuf::from("notes.txt").as_path())
+    );
+
+    let requests = dialogs.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].kind, FileDialogKind::Open);
+    assert_eq!(requests[0].filters[0].extensions, ["txt"]);
+
+    // Without a queued result, the dialog is cancelled.
+    harness.mouse_click_on(open);
+    harness.run_tasks();
+    assert_eq!(harness.state().results[1], FileDialogResult::Cancelled);
+    assert_eq!(dialogs.requests().len(), 2);
+}
@@ -0,0 +1,191 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for [`Form`] and its fields.
+
+use std::time::Duration;
+
+use masonry::core::{Widget, WidgetId, WidgetRef};
+use masonry::widgets;
+use xilem::testing::{AppHarness, TestHarness};
+use xilem::view::{flex, submit_button, text_field};
+use xilem::{Form, WidgetView};
+
+const REQUIRED: &str = "A name is required";
+const TAKEN: &str = "This name is taken";
+
+struct State {
+    form: Form,
+    name: String,
+    label: &'static str,
+    submitted: u32,
+}
+
+impl State {
+    fn new() -> Self {
+        Self {
+            form: Form::new(),
+            name: String::new(),
+            label: "Name",
+            submitted: 0,
+        }
+    }
+}
+
+fn app_logic(state: &mut State) -> impl WidgetView<State> + use<> {
+    flex((
+        text_field(
+            state.label,
+            |state: &mut State| &mut state.form,
+            |state: &mut State| &mut state.name,
+        )
+        .validate(|name| {
+            if name.is_empty() {
+                Err(REQUIRED.into())
+            } else {
+                Ok(())
+            }
+        })
+        .validate_async(|name: String| async move {
+            tokio::time::sleep(Duration::from_secs(1)).await;
+            if name == "taken" {
+                Err(TAKEN.into())
+            } else {
+                Ok(())
+            }
+        }),
+        submit_button(
+            "Save",
+            |state: &mut State| &mut state.form,
+            |state: &mut State| {
+                state.submitted += 1;
+                state.form.reset();
+            },
+        ),
+    ))
+}
+
+fn find_text_area(widget: WidgetRef<'_, dyn Widget>) -> Option<WidgetId> {
+    if widget.downcast::<widgets::TextArea<true>>().is_some() {
+        return Some(widget.id());
+    }
+    widget.children().into_iter().find_map(find_text_area)
+}
+
+fn text_area(harness: &TestHarness) -> WidgetId {
+    find_text_area(harness.root_widget()).expect("the form should have a text input")
+}
+
+fn save_button(harness: &mut TestHarness) -> WidgetId {
+    harness
+        .find_widget_by_accessibility_name("Save")
+        .expect("the form should have a submit button")
+        .id()
+}
+
+#[test]
+fn dirty_and_touched() {
+    let mut harness = AppHarness::new_simple(State::new(), app_logic);
+    let field = harness.state().form.field("Name").unwrap();
+    assert!(!field.is_dirty());
+    assert!(!field.is_touched());
+    // Errors of untouched fields aren't shown.
+    assert_eq!(field.error(), Some(REQUIRED));
+    assert!(harness.find_widget_by_debug_text(REQUIRED).is_none());
+
+    let input = text_area(harness.masonry_harness());
+    harness.focus_on(Some(input));
+    harness.keyboard_type_chars("a");
+    assert_eq!(harness.state().name, "a");
+    let field = harness.state().form.field("Name").unwrap();
+    assert!(field.is_dirty());
+    assert!(field.is_touched());
+    assert!(field.is_validating());
+
+    // Going back to the initial value makes the field pristine, but it stays touched.
+    harness.edit_state(|state| state.name.clear());
+    let field = harness.state().form.field("Name").unwrap();
+    assert!(!field.is_dirty());
+    assert!(field.is_touched());
+    assert!(harness.find_widget_by_debug_text(REQUIRED).is_some());
+}
+
+#[test]
+fn reset() {
+    let mut harness = AppHarness::new_simple(State::new(), app_logic);
+    let input = text_area(harness.masonry_harness());
+    harness.focus_on(Some(input));
+    harness.keyboard_type_chars("Bob");
+
+    harness.edit_state(|state| state.form.reset());
+    let field = harness.state().form.field("Name").unwrap();
+    assert!(!field.is_dirty());
+    assert!(!field.is_touched());
+
+    // The value at the time of the reset is the new initial value.
+    harness.edit_state(|state| state.name.clear());
+    assert!(harness.state().form.field("Name").unwrap().is_dirty());
+}
+
+#[test]
+fn submit_gating() {
+    let mut harness = AppHarness::new_simple(State::new(), app_logic);
+    let save = save_button(harness.masonry_harness_mut());
+
+    // An invalid form isn't submitted, but shows all its errors.
+    harness.mouse_click_on(save);
+    assert_eq!(harness.state().submitted, 0);
+    assert!(harness.state().form.submit_attempted());
+    assert!(harness.find_widget_by_debug_text(REQUIRED).is_some());
+
+    // Neither is a form which is still being validated.
+    harness.edit_state(|state| state.name = "Bob".into());
+    assert!(harness.state().form.is_validating());
+    harness.mouse_click_on(save);
+    assert_eq!(harness.state().submitted, 0);
+
+    harness.advance_time(Duration::from_secs(2));
+    assert!(harness.state().form.is_valid());
+    harness.mouse_click_on(save);
+    assert_eq!(harness.state().submitted, 1);
+    assert!(!harness.state().form.submit_attempted());
+}
+
+#[test]
+fn relabeling_keeps_status() {
+    let mut harness = AppHarness::new_simple(State::new(), app_logic);
+    let input = text_area(harness.masonry_harness());
+    harness.focus_on(Some(input));
+    harness.keyboard_type_chars("Bob");
+
+    harness.edit_state(|state| state.label = "User name");
+    let form = &harness.state().form;
+    assert!(form.field("Name").is_none());
+    let field = form.field("User name").unwrap();
+    assert!(field.is_dirty());
+    assert!(field.is_touched());
+}
+
+#[test]
+fn stale_async_validations_are_cancelled() {
+    let mut harness = AppHarness::new_simple(State::new(), app_logic);
+    harness.edit_state(|state| state.name = "taken".into());
+    harness.advance_time(Duration::from_millis(500));
+    assert!(harness.state().form.is_validating());
+
+    // The validation of "taken" would finish during this time, but its result is outdated.
+    harness.edit_state(|state| state.name = "free".into());
+    harness.advance_time(Duration::from_millis(700));
+    assert!(harness.state().form.is_validating());
+    assert_eq!(harness.state().form.field("Name").unwrap().error(), None);
+
+    harness.advance_time(Duration::from_secs(1));
+    assert!(harness.state().form.is_valid());
+
+    harness.edit_state(|state| state.name = "taken".into());
+    harness.advance_time(Duration::from_secs(2));
+    assert_eq!(
+        harness.state().form.field("Name").unwrap().error(),
+        Some(TAKEN)
+    );
+}
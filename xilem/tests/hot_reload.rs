@@ -0,0 +1,82 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for [`Xilem::with_hot_reload`].
+
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use xilem::testing::AppHarness;
+use xilem::view::{button, flex, label};
+use xilem::{WidgetView, WindowOptions, Xilem};
+
+#[derive(Default, Serialize, Deserialize)]
+struct Counter {
+    count: u32,
+}
+
+fn app_logic(state: &mut Counter) -> impl WidgetView<Counter> + use<> {
+    flex((
+        label(format!("Count: {}", state.count)),
+        button("Increment", |state: &mut Counter| state.count += 1),
+    ))
+}
+
+struct TestFile(PathBuf);
+
+impl Drop for TestFile {
+    fn drop(&mut self) {
+        drop(std::fs::remove_file(&self.0));
+    }
+}
+
+#[test]
+fn state_is_restored_after_exit() {
+    let file = TestFile(
+        std::env::temp_dir().join(format!("xilem-hot-reload-app-{}.json", std::process::id())),
+    );
+    let app = Xilem::new_simple(Counter::default(), app_logic, WindowOptions::new("Test"))
+        .with_hot_reload(&file.0);
+    let mut harness = AppHarness::new(app);
+    let increment = harness
+        .find_widget_by_accessibility_name("Increment")
+        .unwrap()
+        .id();
+    harness.mouse_click_on(increment);
+    harness.mouse_click_on(increment);
+    assert!(!file.0.exists());
+
+    // The snapshot is saved when the app exits.
+    assert!(!harness.request_close());
+    assert!(file.0.exists());
+
+    let app = Xilem::new_simple(Counter::default(), app_logic, WindowOptions::new("Test"))
+        .with_hot_reload(&file.0);
+    let harness = AppHarness::new(app);
+    assert_eq!(harness.state().count, 2);
+    assert!(harness.find_widget_by_debug_text("Count: 2").is_some());
+}
+
+#[test]
+fn state_is_saved_when_the_app_exits_without_closing() {
+    let file = TestFile(
+        std::env::temp_dir().join(format!("xilem-hot-reload-exit-{}.json", std::process::id())),
+    );
+    let app = Xilem::new_simple(Counter::default(), app_logic, WindowOptions::new("Test"))
+        .with_hot_reload(&file.0);
+    let mut harness = AppHarness::new(app);
+    let increment = harness
+        .find_widget_by_accessibility_name("Increment")
+        .unwrap()
+        .id();
+    harness.mouse_click_on(increment);
+
+    // E.g. a widget asked the app to exit.
+    harness.exit();
+    assert!(file.0.exists());
+
+    let app = Xilem::new_simple(Counter::default(), app_logic, WindowOptions::new("Test"))
+        .with_hot_reload(&file.0);
+    let harness = AppHarness::new(app);
+    assert_eq!(harness.state().count, 1);
+}
@@ -0,0 +1,165 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for the [`navigator`] view.
+
+use masonry::core::keyboard::{Key, KeyboardEvent, Modifiers, NamedKey};
+use masonry::core::{TextEvent, WidgetId};
+use masonry::kurbo::Point;
+use masonry::widgets;
+use xilem::WidgetView;
+use xilem::core::Navigator;
+use xilem::testing::{AppHarness, TestHarness};
+use xilem::view::{button, flex, label, navigator, portal, sized_box};
+
+#[derive(Clone, Debug, PartialEq)]
+enum Route {
+    List,
+    Details(u32),
+}
+
+struct State {
+    navigator: Navigator<Route>,
+}
+
+fn app_logic(_: &mut State) -> impl WidgetView<State> + use<> {
+    navigator(
+        |state: &mut State| &mut state.navigator,
+        |_: &mut State, route: &Route| match route {
+            Route::List => portal(flex((
+                button("Open", |state: &mut State| {
+                    state.navigator.push(Route::Details(1));
+                }),
+                sized_box(label("Spacer")).height(2000.),
+            )))
+            .boxed(),
+            Route::Details(id) => {
+                let id = *id;
+                flex((
+                    label(format!("Details of {id}")),
+                    button("Next", move |state: &mut State| {
+                        state.navigator.push(Route::Details(id + 1));
+                    }),
+                    button("Replace", move |state: &mut State| {
+                        state.navigator.replace(Route::Details(id * 10));
+                    }),
+                    button("Back", |state: &mut State| {
+                        state.navigator.pop();
+                    }),
+                ))
+                .boxed()
+            }
+        },
+    )
+}
+
+impl State {
+    fn new() -> Self {
+        Self {
+            navigator: Navigator::new(Route::List),
+        }
+    }
+}
+
+fn named(harness: &mut TestHarness, name: &str) -> WidgetId {
+    harness
+        .find_widget_by_accessibility_name(name)
+        .unwrap_or_else(|| panic!("no widget named {name:?}"))
+        .id()
+}
+
+fn is_shown(harness: &TestHarness, text: &str) -> bool {
+    harness
+        .find_widget_by_debug_text(text)
+        .is_some_and(|widget| !widget.ctx().is_stashed())
+}
+
+/// The id of the portal showing the list route.
+fn list_portal(harness: &TestHarness) -> WidgetId {
+    harness.root_widget().children()[0].id()
+}
+
+fn viewport_pos(harness: &TestHarness, portal: WidgetId) -> Point {
+    harness
+        .get_widget(portal)
+        .downcast::<widgets::Portal<widgets::Flex>>()
+        .unwrap()
+        .get_viewport_pos()
+}
+
+#[test]
+fn push_and_pop() {
+    let mut harness = AppHarness::new_simple(State::new(), app_logic);
+    let button = named(harness.masonry_harness_mut(), "Open");
+    harness.mouse_click_on(button);
+    assert_eq!(harness.state().navigator.current(), &Route::Details(1));
+    assert!(is_shown(harness.masonry_harness(), "Details of 1"));
+
+    let button = named(harness.masonry_harness_mut(), "Next");
+    harness.mouse_click_on(button);
+    assert_eq!(harness.state().navigator.depth(), 3);
+    assert!(is_shown(harness.masonry_harness(), "Details of 2"));
+    assert!(!is_shown(harness.masonry_harness(), "Details of 1"));
+
+    let button = named(harness.masonry_harness_mut(), "Back");
+    harness.mouse_click_on(button);
+    assert_eq!(harness.state().navigator.current(), &Route::Details(1));
+    assert!(is_shown(harness.masonry_harness(), "Details of 1"));
+    assert!(harness.find_widget_by_debug_text("Details of 2").is_none());
+}
+
+#[test]
+fn replace() {
+    let mut harness = AppHarness::new_simple(State::new(), app_logic);
+    let button = named(harness.masonry_harness_mut(), "Open");
+    harness.mouse_click_on(button);
+    let button = named(harness.masonry_harness_mut(), "Replace");
+    harness.mouse_click_on(button);
+    assert_eq!(harness.state().navigator.depth(), 2);
+    assert_eq!(harness.state().navigator.current(), &Route::Details(10));
+    assert!(is_shown(harness.masonry_harness(), "Details of 10"));
+    assert!(harness.find_widget_by_debug_text("Details of 1").is_none());
+}
+
+#[test]
+fn back_shortcut() {
+    let mut harness = AppHarness::new_simple(State::new(), app_logic);
+    let button = named(harness.masonry_harness_mut(), "Open");
+    harness.mouse_click_on(button);
+    // The shortcut only applies while the focus is in the navigator.
+    let back = named(harness.masonry_harness_mut(), "Back");
+    harness.focus_on(Some(back));
+
+    harness.process_text_event(TextEvent::Keyboard(KeyboardEvent {
+        key: Key::Named(NamedKey::ArrowLeft),
+        modifiers: Modifiers::ALT,
+        ..Default::default()
+    }));
+    assert_eq!(harness.state().navigator.current(), &Route::List);
+    assert!(is_shown(harness.masonry_harness(), "Spacer"));
+}
+
+#[test]
+fn lower_routes_are_retained() {
+    let mut harness = AppHarness::new_simple(State::new(), app_logic);
+    let portal = list_portal(harness.masonry_harness());
+    harness
+        .masonry_harness_mut()
+        .edit_widget(portal, |mut portal| {
+            let mut portal = portal.downcast::<widgets::Portal<widgets::Flex>>();
+            widgets::Portal::set_viewport_pos(&mut portal, Point::new(0., 100.));
+        });
+
+    harness.edit_state(|state| state.navigator.push(Route::Details(1)));
+    assert!(harness.get_widget(portal).ctx().is_stashed());
+
+    harness.edit_state(|state| {
+        state.navigator.pop();
+    });
+    assert_eq!(list_portal(harness.masonry_harness()), portal);
+    assert!(!harness.get_widget(portal).ctx().is_stashed());
+    assert_eq!(
+        viewport_pos(harness.masonry_harness(), portal),
+        Point::new(0., 100.)
+    );
+}
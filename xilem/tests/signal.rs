@@ -0,0 +1,70 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for [`Signal`], which rebuilds the views reading it without running the app logic.
+
+use xilem::core::{lens, provides};
+use xilem::testing::AppHarness;
+use xilem::view::{flex, label, with_signal};
+use xilem::{Signal, WidgetView};
+
+#[derive(Default)]
+struct Progress {
+    progress: Signal<u32>,
+    logic_runs: u32,
+    sibling_builds: u32,
+    reader_builds: u32,
+}
+
+fn app_logic(state: &mut Progress) -> impl WidgetView<Progress> + use<> {
+    state.logic_runs += 1;
+    provides(
+        |state: &mut Progress| state.progress.clone(),
+        flex((
+            // `lens` calls its component on each rebuild, which lets us count them.
+            lens(
+                |builds: &mut u32| {
+                    *builds += 1;
+                    label("Downloading")
+                },
+                |state: &mut Progress| &mut state.sibling_builds,
+            ),
+            flex((with_signal(|progress: &u32, state: &mut Progress| {
+                state.reader_builds += 1;
+                label(format!("Progress: {progress}%"))
+            }),)),
+        )),
+    )
+}
+
+#[test]
+fn only_readers_are_rebuilt() {
+    let mut harness = AppHarness::new_simple(Progress::default(), app_logic);
+    assert_eq!(harness.state().logic_runs, 1);
+    assert_eq!(harness.state().sibling_builds, 1);
+    assert_eq!(harness.state().reader_builds, 1);
+    assert!(harness.find_widget_by_debug_text("Progress: 0%").is_some());
+
+    let progress = harness.state().progress.clone();
+    progress.set(50);
+    harness.run_tasks();
+    assert!(harness.find_widget_by_debug_text("Progress: 50%").is_some());
+    assert_eq!(harness.state().reader_builds, 2);
+    assert_eq!(harness.state().logic_runs, 1);
+    assert_eq!(harness.state().sibling_builds, 1);
+
+    // Signals can be set from other threads.
+    std::thread::spawn(move || progress.update(|progress| *progress += 25))
+        .join()
+        .unwrap();
+    harness.run_tasks();
+    assert!(harness.find_widget_by_debug_text("Progress: 75%").is_some());
+    assert_eq!(harness.state().reader_builds, 3);
+    assert_eq!(harness.state().sibling_builds, 1);
+
+    // Running the app logic still rebuilds everything.
+    harness.edit_state(|_| ());
+    assert_eq!(harness.state().logic_runs, 2);
+    assert_eq!(harness.state().sibling_builds, 2);
+    assert_eq!(harness.state().reader_builds, 4);
+}
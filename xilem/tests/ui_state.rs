@@ -0,0 +1,197 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for the [`ui_state`](xilem::ui_state) stores, and the views which save their state in them.
+
+use std::path::PathBuf;
+
+use masonry::core::WidgetId;
+use masonry::kurbo::Point;
+use masonry::widgets;
+use xilem::testing::{AppHarness, TestHarness};
+use xilem::ui_state::{JsonFileUiStateStore, MemoryUiStateStore, UiState, UiStateStore};
+use xilem::view::{flex, indexed_stack, label, portal, sized_box, split};
+use xilem::{WidgetView, WindowOptions, Xilem};
+
+type SplitWidget = widgets::Split<widgets::Portal<widgets::Flex>, widgets::IndexedStack>;
+
+struct Sidebar {
+    shown: bool,
+}
+
+fn app_logic(state: &mut Sidebar) -> impl WidgetView<Sidebar> + use<> {
+    let sidebar = state.shown.then(|| {
+        split(
+            portal(flex((sized_box(label("Tall")).height(2000.),))).persistence_key("list"),
+            indexed_stack((label("First"), label("Second"))).persistence_key("tabs"),
+        )
+        .persistence_key("sidebar")
+    });
+    flex((label("Header"), sidebar))
+}
+
+/// The ids of the split, the portal and the stack.
+fn sidebar(harness: &TestHarness) -> (WidgetId, WidgetId, WidgetId) {
+    let root = harness.root_widget();
+    let root_children = root.children();
+    let split = &root_children[1];
+    let children = split.children();
+    (split.id(), children[0].id(), children[1].id())
+}
+
+#[test]
+fn memory_store() {
+    let store = MemoryUiStateStore::default();
+    assert_eq!(store.load("sidebar"), None);
+    store.save("sidebar", UiState::SplitPoint(0.25));
+    store.save("tabs", UiState::ActiveIndex(1));
+    store.save("sidebar", UiState::SplitPoint(0.75));
+    assert_eq!(store.load("sidebar"), Some(UiState::SplitPoint(0.75)));
+    assert_eq!(store.load("tabs"), Some(UiState::ActiveIndex(1)));
+}
+
+struct TestFile(PathBuf);
+
+impl TestFile {
+    fn new(name: &str) -> Self {
+        Self(
+            std::env::temp_dir().join(format!("xilem-ui-state-{name}-{}.json", std::process::id())),
+        )
+    }
+}
+
+impl Drop for TestFile {
+    fn drop(&mut self) {
+        drop(std::fs::remove_file(&self.0));
+    }
+}
+
+#[test]
+fn json_file_store() {
+    let file = TestFile::new("round-trip");
+    let store = JsonFileUiStateStore::new(&file.0);
+    assert_eq!(store.load("list"), None);
+    store.save("list", UiState::ScrollOffset { x: 0., y: 100. });
+    store.save("tabs", UiState::ActiveIndex(1));
+    assert_eq!(store.load("tabs"), Some(UiState::ActiveIndex(1)));
+
+    // Pending changes are written when the store is dropped.
+    drop(store);
+    let store = JsonFileUiStateStore::new(&file.0);
+    assert_eq!(
+        store.load("list"),
+        Some(UiState::ScrollOffset { x: 0., y: 100. })
+    );
+    assert_eq!(store.load("tabs"), Some(UiState::ActiveIndex(1)));
+}
+
+#[test]
+fn json_file_store_ignores_invalid_files() {
+    let file = TestFile::new("invalid");
+    std::fs::write(&file.0, "not json").unwrap();
+    let store = JsonFileUiStateStore::new(&file.0);
+    assert_eq!(store.load("list"), None);
+
+    store.save("list", UiState::ScrollOffset { x: 0., y: 10. });
+    drop(store);
+    let store = JsonFileUiStateStore::new(&file.0);
+    assert_eq!(
+        store.load("list"),
+        Some(UiState::ScrollOffset { x: 0., y: 10. })
+    );
+}
+
+#[test]
+fn views_are_restored_after_teardown() {
+    let mut harness = AppHarness::new(
+        Xilem::new_simple(
+            Sidebar { shown: true },
+            app_logic,
+            WindowOptions::new("Test"),
+        )
+        .with_ui_state_store(MemoryUiStateStore::default()),
+    );
+    let (split, portal, stack) = sidebar(harness.masonry_harness());
+    harness
+        .masonry_harness_mut()
+        .edit_widget(split, |mut split| {
+            let mut split = split.downcast::<SplitWidget>();
+            widgets::Split::set_split_point(&mut split, 0.25);
+        });
+    harness
+        .masonry_harness_mut()
+        .edit_widget(portal, |mut portal| {
+            let mut portal = portal.downcast::<widgets::Portal<widgets::Flex>>();
+            widgets::Portal::set_viewport_pos(&mut portal, Point::new(0., 100.));
+        });
+    harness
+        .masonry_harness_mut()
+        .edit_widget(stack, |mut stack| {
+            let mut stack = stack.downcast::<widgets::IndexedStack>();
+            widgets::IndexedStack::set_active_child(&mut stack, 1);
+        });
+
+    harness.edit_state(|state| state.shown = false);
+    assert_eq!(harness.root_widget().children().len(), 1);
+    harness.edit_state(|state| state.shown = true);
+
+    let (new_split, portal, stack) = sidebar(harness.masonry_harness());
+    assert_ne!(new_split, split);
+    let split_point = harness
+        .get_widget(new_split)
+        .downcast::<SplitWidget>()
+        .unwrap()
+        .get_split_point();
+    assert_eq!(split_point, 0.25);
+    let viewport_pos = harness
+        .get_widget(portal)
+        .downcast::<widgets::Portal<widgets::Flex>>()
+        .unwrap()
+        .get_viewport_pos();
+    assert_eq!(viewport_pos, Point::new(0., 100.));
+    let active_child = harness
+        .get_widget(stack)
+        .downcast::<widgets::IndexedStack>()
+        .unwrap()
+        .active_child_index();
+    assert_eq!(active_child, 1);
+}
+
+#[test]
+fn views_are_restored_across_runs() {
+    let file = TestFile::new("app");
+    let mut harness = AppHarness::new(
+        Xilem::new_simple(
+            Sidebar { shown: true },
+            app_logic,
+            WindowOptions::new("Test"),
+        )
+        .with_ui_state_store(JsonFileUiStateStore::new(&file.0)),
+    );
+    let (split, ..) = sidebar(harness.masonry_harness());
+    harness
+        .masonry_harness_mut()
+        .edit_widget(split, |mut split| {
+            let mut split = split.downcast::<SplitWidget>();
+            widgets::Split::set_split_point(&mut split, 0.25);
+        });
+    // The state is saved when the views are rebuilt.
+    harness.edit_state(|_| ());
+    drop(harness);
+
+    let harness = AppHarness::new(
+        Xilem::new_simple(
+            Sidebar { shown: true },
+            app_logic,
+            WindowOptions::new("Test"),
+        )
+        .with_ui_state_store(JsonFileUiStateStore::new(&file.0)),
+    );
+    let (split, ..) = sidebar(harness.masonry_harness());
+    let split_point = harness
+        .get_widget(split)
+        .downcast::<SplitWidget>()
+        .unwrap()
+        .get_split_point();
+    assert_eq!(split_point, 0.25);
+}
@@ -23,7 +23,10 @@ pub struct Rebuild;
 struct EnvironmentItem {
     value: Box<dyn AnyDebug>,
     // TODO: Can we/do we want to make these share an allocation?
-    // TODO: How do we GC this?
+    /// The paths of the [`with_context`] views reading this value.
+    ///
+    /// Each reader removes its entry when it is torn down, and the entries which are
+    /// `None` are reused by later readers.
     change_listeners: Vec<Option<Arc<[ViewId]>>>,
 }
 
@@ -31,7 +34,9 @@ struct EnvironmentItem {
 struct Slot {
     item: Option<EnvironmentItem>,
     ref_count: u32,
-    // generation: u32,
+    /// Incremented each time this slot is freed, so that views holding onto a slot
+    /// can check that it still stores the same resource.
+    generation: u32,
 }
 
 /// A store of values which are accessible throughout the view tree.
@@ -45,14 +50,25 @@ struct Slot {
 /// This type must be made available by view contexts through the
 /// [`environment`](ViewPathTracker::environment)  method of `ViewPathTracker`, which
 /// they all implement.
+///
+/// # Partial rebuilds
+///
+/// The environment also tracks the views which need to be rebuilt without the rest of the
+/// view tree, e.g. because a value they read has changed.
+/// Drivers [queue](Self::queue_rebuild) the paths of these views, then rebuild the view tree
+/// between [`start_partial_rebuild`](Self::start_partial_rebuild) and
+/// [`finish_partial_rebuild`](Self::finish_partial_rebuild).
+/// During a partial rebuild, the views in a [`ViewSequence`](crate::ViewSequence) which aren't
+/// an ancestor or a descendant of a queued path aren't rebuilt.
 #[derive(Debug)]
 pub struct Environment {
     slots: Vec<Slot>,
     // We use u32 here so that we could move to a generation
     free_slots: Vec<u32>,
     types: HashMap<TypeId, u32>,
-    // TODO: Think about how to handle this.
-    // queued_rebuilds: Vec<Arc<[ViewId]>>,
+    queued_rebuilds: Vec<Arc<[ViewId]>>,
+    /// The paths being rebuilt, if a partial rebuild is running.
+    rebuild_targets: Option<Vec<Arc<[ViewId]>>>,
 }
 
 impl Environment {
@@ -66,10 +82,57 @@ impl Environment {
             slots: Vec::new(),
             free_slots: Vec::new(),
             types: HashMap::new(),
-            // queued_rebuilds: Vec::new(),
+            queued_rebuilds: Vec::new(),
+            rebuild_targets: None,
+        }
+    }
+
+    /// Get the value of type `Context` provided by the closest ancestor [`provides`] view.
+    ///
+    /// Returns `None` if no such value is provided.
+    /// This can be used by views which need access to a resource outside of [`with_context`],
+    /// e.g. to subscribe to its changes.
+    pub fn get_mut<Context>(&mut self) -> Option<&mut Context>
+    where
+        Context: Resource,
+    {
+        let slot = self.get_slot_for_type::<Context>()?;
+        let item = self.slots[usize::try_from(slot).unwrap()].item.as_mut()?;
+        item.value.downcast_mut::<Context>()
+    }
+
+    /// Queue a rebuild of the view at `path`, which will be done by the next partial rebuild.
+    ///
+    /// See the [type level documentation](Self#partial-rebuilds) for details.
+    pub fn queue_rebuild(&mut self, path: Arc<[ViewId]>) {
+        if !self.queued_rebuilds.contains(&path) {
+            self.queued_rebuilds.push(path);
         }
     }
 
+    /// Start a partial rebuild of the queued paths.
+    ///
+    /// Returns `false` if no rebuild is queued, in which case the view tree should be
+    /// rebuilt in full.
+    /// Otherwise, the driver should rebuild the view tree, then call
+    /// [`finish_partial_rebuild`](Self::finish_partial_rebuild).
+    pub fn start_partial_rebuild(&mut self) -> bool {
+        debug_assert!(
+            self.rebuild_targets.is_none(),
+            "A partial rebuild is already running"
+        );
+        if self.queued_rebuilds.is_empty() {
+            return false;
+        }
+        self.rebuild_targets = Some(core::mem::take(&mut self.queued_rebuilds));
+        true
+    }
+
+    /// Finish the partial rebuild started by [`start_partial_rebuild`](Self::start_partial_rebuild).
+    pub fn finish_partial_rebuild(&mut self) {
+        self.rebuild_targets = None;
+    }
+
     // TODO: Possibly reconsider the name here.
     fn create_slot_for_type<Context>(&mut self) -> u32
     where
@@ -95,6 +158,7 @@ impl Environment {
                     self.slots.push(Slot {
                         item: None,
                         ref_count: 0,
+                        generation: 0,
                     });
                     vacant_entry.insert(slot);
                     slot
@@ -109,6 +173,64 @@ impl Environment {
     {
         self.types.get(&TypeId::of::<Context>()).copied()
     }
+
+    fn slot_key(&self, index: u32) -> SlotKey {
+        SlotKey {
+            index,
+            generation: self.slots[usize::try_from(index).unwrap()].generation,
+        }
+    }
+
+    fn slot_mut(&mut self, key: SlotKey) -> &mut Slot {
+        let slot = &mut self.slots[usize::try_from(key.index).unwrap()];
+        assert_eq!(
+            slot.generation, key.generation,
+            "Tried to use environment slot {} after the resource it stored was removed.",
+            key.index
+        );
+        slot
+    }
+
+    /// The paths of the [`with_context`] views which read the value of type `Context`
+    /// provided by the closest ancestor [`provides`] view.
+    ///
+    /// This is empty if no such value is provided.
+    /// The readers of values provided by other `provides` views aren't included, even if
+    /// they have the same type.
+    pub fn change_listeners<Context>(&mut self) -> impl Iterator<Item = &Arc<[ViewId]>>
+    where
+        Context: Resource,
+    {
+        let item = self
+            .get_slot_for_type::<Context>()
+            .and_then(|slot| self.slots[usize::try_from(slot).unwrap()].item.as_ref());
+        item.into_iter()
+            .flat_map(|item| item.change_listeners.iter().flatten())
+    }
+}
+
+/// The location of a resource in the environment.
+///
+/// Slots are reused for other resources once all views providing a resource are torn down,
+/// so the generation is used to check that a slot still stores the same resource.
+#[derive(Debug, Clone, Copy)]
+struct SlotKey {
+    index: u32,
+    generation: u32,
+}
+
+/// Returns `true` if the view at the current path of `ctx` can skip being rebuilt,
+/// because a partial rebuild which doesn't involve it is running.
+pub(crate) fn skipped_by_partial_rebuild(ctx: &mut impl ViewPathTracker) -> bool {
+    let Some(targets) = ctx.environment().rebuild_targets.take() else {
+        return false;
+    };
+    let path = ctx.view_path();
+    let skipped = !targets
+        .iter()
+        .any(|target| target.starts_with(path) || path.starts_with(target));
+    ctx.environment().rebuild_targets = Some(targets);
+    skipped
 }
 
 impl Default for Environment {
@@ -177,7 +299,7 @@ pub struct Provides<State, Action, Context: Resource, InitialContext, ChildView>
 pub struct ProvidesState<ChildState> {
     child_state: ChildState,
     this_state: Option<EnvironmentItem>,
-    environment_slot: u32,
+    environment_slot: SlotKey,
 }
 
 impl<State, Action, Context, InitialContext, ChildView> ViewMarker
@@ -240,7 +362,7 @@ where
         let state = ProvidesState {
             child_state,
             this_state: Some(my_item),
-            environment_slot: pos,
+            environment_slot: env.slot_key(pos),
         };
         (child_element, state)
     }
@@ -255,7 +377,7 @@ where
     ) {
         // Use our value in the child rebuild.
         let env = ctx.environment();
-        let slot = &mut env.slots[usize::try_from(view_state.environment_slot).unwrap()];
+        let slot = env.slot_mut(view_state.environment_slot);
         debug_assert!(
             view_state.this_state.is_some(),
             "`Provides` should be providing something."
@@ -271,7 +393,7 @@ where
         );
 
         let env = ctx.environment();
-        let slot = &mut env.slots[usize::try_from(view_state.environment_slot).unwrap()];
+        let slot = env.slot_mut(view_state.environment_slot);
         core::mem::swap(&mut slot.item, &mut view_state.this_state);
         debug_assert!(
             view_state.this_state.is_some(),
@@ -288,14 +410,14 @@ where
     ) {
         // Make our value available in the child teardown.
         let env = ctx.environment();
-        let slot = &mut env.slots[usize::try_from(view_state.environment_slot).unwrap()];
+        let slot = env.slot_mut(view_state.environment_slot);
         core::mem::swap(&mut slot.item, &mut view_state.this_state);
 
         self.child
             .teardown(&mut view_state.child_state, ctx, element, app_state);
 
         let env = ctx.environment();
-        let slot = &mut env.slots[usize::try_from(view_state.environment_slot).unwrap()];
+        let slot = env.slot_mut(view_state.environment_slot);
         core::mem::swap(&mut slot.item, &mut view_state.this_state);
         slot.ref_count -= 1;
         if slot.ref_count == 0 {
@@ -303,7 +425,9 @@ where
                 slot.item.is_none(),
                 "Ref count for {slot:?} was not properly managed."
             );
-            env.free_slots.push(view_state.environment_slot);
+            // Any views still holding this slot are now stale.
+            slot.generation = slot.generation.wrapping_add(1);
+            env.free_slots.push(view_state.environment_slot.index);
             env.types.remove(&TypeId::of::<Context>());
         }
     }
@@ -373,8 +497,8 @@ pub struct WithContext<State, Action, Context: Resource, Child, ChildView> {
 pub struct WithContextState<ChildState, ChildView> {
     prev: ChildView,
     child_state: ChildState,
-    environment_slot: u32,
-    listener_index: Option<usize>,
+    environment_slot: SlotKey,
+    listener_index: usize,
 }
 
 const WITH_CONTEXT_CHILD: ViewId = ViewId::new(0);
@@ -409,8 +533,8 @@ where
                     core::any::type_name::<Context>()
                 );
             };
-            let slot_idx = usize::try_from(pos).unwrap();
-            let slot = &mut env.slots[slot_idx];
+            let env_slot = env.slot_key(pos);
+            let slot = env.slot_mut(env_slot);
             // TODO: Should this be &mut or just a shared ref?
             // If this gets modified, we won't rerun any other WithContexts for this value
             // But some types are "pure", i.e. they manage their own dependencies?
@@ -426,8 +550,6 @@ where
                 .downcast_mut::<Context>()
                 .expect("Environment's slots should have the correct types.");
 
-            let mut first_empty = None;
-            let mut needs_storing = true;
             // We store the path to this reader as a listener.
             // This is required so that we can be alerted of any changes, so that any parent
             // memoizing (or similar) views would correctly handle our value changing.
@@ -435,32 +557,16 @@ where
             // 1) There actually is such a parent view
             // 2) The path for rebuilding only needs to be the path to the closest such parent
             //
-            // The future changes required to enable that are already partially accounted for here (i.e. checking
-            // if the current listening path is already included).
+            // Each reader owns its entry, which it removes when it is torn down.
             //
             // Note also that there is currently no way to trigger these views!
-            for (idx, item) in value.change_listeners.iter().enumerate() {
-                if let Some(item) = item {
-                    if **item == *path {
-                        needs_storing = false;
-                        break;
-                    }
-                } else {
-                    first_empty.get_or_insert(idx);
-                }
-            }
-
-            let listener_index = if needs_storing {
-                if let Some(first_empty) = first_empty {
-                    value.change_listeners[first_empty] = Some(path);
-                    Some(first_empty)
-                } else {
-                    let idx = value.change_listeners.len();
-                    value.change_listeners.push(Some(path));
-                    Some(idx)
-                }
+            let listeners = &mut value.change_listeners;
+            let listener_index = if let Some(empty) = listeners.iter().position(Option::is_none) {
+                listeners[empty] = Some(path);
+                empty
             } else {
-                None
+                listeners.push(Some(path));
+                listeners.len() - 1
             };
 
             let child_view = (self.child)(context, app_state);
@@ -469,7 +575,7 @@ where
             let state = WithContextState {
                 prev: child_view,
                 child_state,
-                environment_slot: pos,
+                environment_slot: env_slot,
                 listener_index,
             };
             (child_element, state)
@@ -487,7 +593,7 @@ where
         ctx.with_id(WITH_CONTEXT_CHILD, |ctx| {
             // Use our value in the child rebuild.
             let env = ctx.environment();
-            let slot = &mut env.slots[usize::try_from(view_state.environment_slot).unwrap()];
+            let slot = env.slot_mut(view_state.environment_slot);
             let Some(value) = slot.item.as_mut() else {
                 panic!(
                     // TODO: Track caller for this view?
@@ -519,17 +625,30 @@ where
         element: crate::Mut<'_, Self::Element>,
         app_state: &mut State,
     ) {
-        if let Some(_listener_idx) = view_state.listener_index {
-            // TODO: Potentially garbage collect the listener.
-            // Note that it wouldn't be correct to just remove it here, because
-            // there could be multiple listeners under the same "memoization-style" view.
-        }
         ctx.with_id(WITH_CONTEXT_CHILD, |ctx| {
             // TODO: We will probably want some access to the context in teardown at some point.
             view_state
                 .prev
                 .teardown(&mut view_state.child_state, ctx, element, app_state);
         });
+
+        // Stop listening for changes. The ancestor `provides` view makes its value
+        // available during our teardown, so this is the same item we registered with.
+        let env = ctx.environment();
+        let slot = env.slot_mut(view_state.environment_slot);
+        let item = slot
+            .item
+            .as_mut()
+            .expect("The value read by `WithContext` should be provided during its teardown.");
+        let listeners = &mut item.change_listeners;
+        let listener = listeners[view_state.listener_index].take();
+        debug_assert!(
+            listener.is_some(),
+            "The listener of a `WithContext` should only be removed by its teardown."
+        );
+        while listeners.last().is_some_and(Option::is_none) {
+            listeners.pop();
+        }
     }
 
     fn message(
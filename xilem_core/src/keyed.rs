@@ -0,0 +1,310 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Support for sequences of views which are matched by key, rather than by position.
+
+use alloc::vec::Vec;
+use core::hash::Hash;
+
+use hashbrown::HashMap;
+
+use crate::{
+    AppendVec, DynMessage, ElementSplice, MessageResult, SuperElement, View, ViewElement, ViewId,
+    ViewMarker, ViewPathTracker, ViewSequence,
+};
+
+/// A sequence of views, where each view is identified by a key.
+///
+/// When the sequence is rebuilt, the views are matched with their previous version by key,
+/// rather than by position as for a [`Vec`].
+/// This means that inserting, removing or reordering items of a list only builds the views
+/// of new items and tears down the views of removed items.
+/// The elements of the other views are moved to their new position, and keep their state,
+/// such as focus, scroll position or text selection.
+///
+/// Each item is turned into a key using `key`, and into a view using `view`.
+/// Each view must create a single element.
+///
+/// # Panics
+///
+/// The sequence panics when it is built or rebuilt if two items have the same key.
+///
+/// # Examples
+///
+/// ```
+/// # use xilem_core::docs::DocsViewSequence as WidgetViewSequence;
+/// use xilem_core::{keyed, run_once};
+///
+/// struct Todo {
+///     id: u64,
+///     text: String,
+/// }
+///
+/// fn todo_list(todos: &[Todo]) -> impl WidgetViewSequence<AppState> + use<> {
+///     keyed(todos, |todo| todo.id, |todo| run_once(|| {}))
+/// }
+///
+/// # struct AppState;
+/// ```
+pub fn keyed<Item, K, V>(
+    items: impl IntoIterator<Item = Item>,
+    key: impl Fn(&Item) -> K,
+    view: impl Fn(Item) -> V,
+) -> Keyed<K, V>
+where
+    K: Hash + Eq + 'static,
+{
+    Keyed {
+        children: items
+            .into_iter()
+            .map(|item| (key(&item), view(item)))
+            .collect(),
+    }
+}
+
+/// The [`ViewSequence`] for [`keyed`], see its documentation for more context.
+#[must_use = "View values do nothing unless provided to Xilem."]
+#[derive(Debug)]
+pub struct Keyed<K, V> {
+    children: Vec<(K, V)>,
+}
+
+/// The state used to implement `ViewSequence` for [`Keyed`].
+///
+/// Each child gets a unique id when it is built, which is used as its [`ViewId`].
+/// Ids are never reused, so messages sent to a child which has been removed are stale.
+#[doc(hidden)]
+#[allow(unnameable_types)] // reason: Implementation detail, public because of trait visibility rules
+#[derive(Debug)]
+pub struct KeyedState<InnerState> {
+    /// The states of the children, in the same order as the views.
+    children: Vec<KeyedChildState<InnerState>>,
+    next_id: u64,
+}
+
+#[derive(Debug)]
+struct KeyedChildState<InnerState> {
+    id: u64,
+    state: InnerState,
+}
+
+impl<InnerState> KeyedState<InnerState> {
+    fn next_id(&mut self) -> u64 {
+        let id = self.next_id;
+        // Can never realistically overflow, scale is too large.
+        self.next_id = self.next_id.wrapping_add(1);
+        id
+    }
+}
+
+/// Map each key of `children` to its index, checking that the keys are unique.
+fn key_indices<K: Hash + Eq, V>(children: &[(K, V)]) -> HashMap<&K, usize> {
+    let mut indices = HashMap::with_capacity(children.len());
+    for (index, (key, _)) in children.iter().enumerate() {
+        if indices.insert(key, index).is_some() {
+            panic!("The keys of a `keyed` sequence must be unique");
+        }
+    }
+    indices
+}
+
+/// The existing elements of a keyed sequence which haven't been reached yet during a rebuild,
+/// identified by the index of their view in the previous sequence.
+///
+/// Elements are only ever removed, so the pending elements stay in the order of their indices.
+/// A Fenwick tree counts the pending elements before an index in `O(log n)`, which keeps
+/// reordering a sequence in `O(n log n)`.
+struct PendingElements {
+    /// The Fenwick tree of the number of pending elements, indexed from 1.
+    counts: Vec<usize>,
+    removed: Vec<bool>,
+    /// All the elements before this index have been removed.
+    first: usize,
+}
+
+impl PendingElements {
+    fn new(len: usize) -> Self {
+        Self {
+            // Each node counts the elements in a range as long as its lowest set bit.
+            counts: (0..=len).map(|node| node & node.wrapping_neg()).collect(),
+            removed: alloc::vec![false; len],
+            first: 0,
+        }
+    }
+
+    /// The index of the first pending element, if any.
+    fn first(&mut self) -> Option<usize> {
+        while self.removed.get(self.first) == Some(&true) {
+            self.first += 1;
+        }
+        (self.first < self.removed.len()).then_some(self.first)
+    }
+
+    /// The number of pending elements before the element at `index`.
+    fn offset(&self, index: usize) -> usize {
+        let mut offset = 0;
+        let mut node = index;
+        while node > 0 {
+            offset += self.counts[node];
+            node &= node - 1;
+        }
+        offset
+    }
+
+    fn remove(&mut self, index: usize) {
+        debug_assert!(!self.removed[index], "Element {index} was already removed");
+        self.removed[index] = true;
+        let mut node = index + 1;
+        while node < self.counts.len() {
+            self.counts[node] -= 1;
+            node += node & node.wrapping_neg();
+        }
+    }
+}
+
+impl<State, Action, Context, Element, K, V> ViewSequence<State, Action, Context, Element>
+    for Keyed<K, V>
+where
+    Context: ViewPathTracker,
+    K: Hash + Eq + 'static,
+    V: View<State, Action, Context> + ViewMarker,
+    Element: SuperElement<V::Element, Context>,
+    V::Element: ViewElement,
+{
+    type SeqState = KeyedState<V::ViewState>;
+
+    fn seq_build(
+        &self,
+        ctx: &mut Context,
+        elements: &mut AppendVec<Element>,
+        app_state: &mut State,
+    ) -> Self::SeqState {
+        key_indices(&self.children);
+        let mut seq_state = KeyedState {
+            children: Vec::with_capacity(self.children.len()),
+            next_id: 0,
+        };
+        for (_, view) in &self.children {
+            let id = seq_state.next_id();
+            let state = ctx.with_id(ViewId::new(id), |ctx| {
+                view.seq_build(ctx, elements, app_state)
+            });
+            seq_state.children.push(KeyedChildState { id, state });
+        }
+        seq_state
+    }
+
+    fn seq_rebuild(
+        &self,
+        prev: &Self,
+        seq_state: &mut Self::SeqState,
+        ctx: &mut Context,
+        elements: &mut impl ElementSplice<Element>,
+        app_state: &mut State,
+    ) {
+        let new_indices = key_indices(&self.children);
+        let prev_indices: HashMap<&K, usize> = prev
+            .children
+            .iter()
+            .enumerate()
+            .map(|(index, (key, _))| (key, index))
+            .collect();
+        let mut prev_states: Vec<_> = core::mem::take(&mut seq_state.children)
+            .into_iter()
+            .map(Some)
+            .collect();
+        // The existing elements which haven't been reached yet.
+        let mut pending = PendingElements::new(prev.children.len());
+
+        let teardown = |prev_index: usize,
+                        prev_states: &mut Vec<Option<KeyedChildState<V::ViewState>>>,
+                        ctx: &mut Context,
+                        elements: &mut _,
+                        app_state: &mut State| {
+            let mut child = prev_states[prev_index].take().unwrap();
+            let (_, prev_view) = &prev.children[prev_index];
+            ctx.with_id(ViewId::new(child.id), |ctx| {
+                prev_view.seq_teardown(&mut child.state, ctx, elements, app_state);
+            });
+        };
+
+        for (key, view) in &self.children {
+            // Remove the elements of the views which don't exist anymore as they are reached,
+            // so that the elements which are kept line up with the new views.
+            while let Some(next) = pending.first() {
+                if new_indices.contains_key(&prev.children[next].0) {
+                    break;
+                }
+                pending.remove(next);
+                teardown(next, &mut prev_states, ctx, elements, app_state);
+            }
+
+            if let Some(&prev_index) = prev_indices.get(key) {
+                let offset = pending.offset(prev_index);
+                if offset != 0 {
+                    elements.move_from(offset);
+                }
+                pending.remove(prev_index);
+                let mut child = prev_states[prev_index].take().unwrap();
+                let (_, prev_view) = &prev.children[prev_index];
+                ctx.with_id(ViewId::new(child.id), |ctx| {
+                    view.seq_rebuild(prev_view, &mut child.state, ctx, elements, app_state);
+                });
+                seq_state.children.push(child);
+            } else {
+                let id = seq_state.next_id();
+                let state = ctx.with_id(ViewId::new(id), |ctx| {
+                    elements.with_scratch(|elements| view.seq_build(ctx, elements, app_state))
+                });
+                seq_state.children.push(KeyedChildState { id, state });
+            }
+        }
+
+        // All the elements which are left belong to views which don't exist anymore.
+        while let Some(next) = pending.first() {
+            pending.remove(next);
+            teardown(next, &mut prev_states, ctx, elements, app_state);
+        }
+    }
+
+    fn seq_teardown(
+        &self,
+        seq_state: &mut Self::SeqState,
+        ctx: &mut Context,
+        elements: &mut impl ElementSplice<Element>,
+        app_state: &mut State,
+    ) {
+        for ((_, view), child) in self.children.iter().zip(&mut seq_state.children) {
+            ctx.with_id(ViewId::new(child.id), |ctx| {
+                view.seq_teardown(&mut child.state, ctx, elements, app_state);
+            });
+        }
+    }
+
+    fn seq_message(
+        &self,
+        seq_state: &mut Self::SeqState,
+        id_path: &[ViewId],
+        message: DynMessage,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        let (start, rest) = id_path
+            .split_first()
+            .expect("Id path has elements for Keyed");
+        let Some(index) = seq_state
+            .children
+            .iter()
+            .position(|child| child.id == start.routing_id())
+        else {
+            // The message was sent to a view which has since been removed
+            return MessageResult::Stale(message);
+        };
+        let (_, view) = &self.children[index];
+        view.seq_message(
+            &mut seq_state.children[index].state,
+            rest,
+            message,
+            app_state,
+        )
+    }
+}
@@ -69,8 +69,9 @@ pub use view::{View, ViewId, ViewMarker, ViewPathTracker};
 
 mod views;
 pub use views::{
-    Fork, Frozen, Lens, MapMessage, MapState, Memoize, OrphanView, RunOnce, fork, frozen, lens,
-    map_action, map_message, map_state, memoize, one_of, run_once, run_once_raw,
+    Component, Fork, Frozen, Lens, MapMessage, MapState, Memoize, OrphanView, RunOnce, component,
+    fork, frozen, lens, map_action, map_message, map_state, memoize, one_of, run_once,
+    run_once_raw,
 };
 
 mod message;
@@ -85,4 +86,10 @@ pub use any_view::{AnyView, AnyViewState};
 mod sequence;
 pub use sequence::{AppendVec, ElementSplice, ViewSequence, WithoutElements, without_elements};
 
+mod keyed;
+pub use keyed::{Keyed, keyed};
+
+mod navigator;
+pub use navigator::{Navigator, RouteEntry, RouteKey};
+
 pub mod docs;
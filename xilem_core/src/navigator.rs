@@ -0,0 +1,207 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
+
+/// A stack of typed routes, which is the state of a navigator view.
+///
+/// The last route of the stack is the current one, and the first is the root,
+/// which can't be popped.
+/// Routes are usually an enum of the screens of the app, with the data they need:
+///
+/// ```
+/// use xilem_core::Navigator;
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// enum Route {
+///     Timeline,
+///     Thread { status_id: String },
+/// }
+///
+/// let mut navigator = Navigator::new(Route::Timeline);
+/// navigator.push(Route::Thread { status_id: "1".into() });
+/// assert_eq!(navigator.depth(), 2);
+/// navigator.pop();
+/// assert_eq!(navigator.current(), &Route::Timeline);
+/// // Like in a browser, popped routes can be visited again until another route is pushed.
+/// assert!(navigator.forward());
+/// ```
+///
+/// Each entry of the stack has a [`RouteKey`], which navigator views use to keep the
+/// state of each screen separately.
+/// This state is kept while the entry is in the stack, so that e.g. the scroll position of
+/// a screen is restored when the screens above it are popped.
+///
+/// The navigator views are `navigator` in Xilem, which shows the current route and
+/// handles the back button, and `navigator` in Xilem Web, which maps the stack onto the
+/// browser history.
+#[derive(Clone, Debug)]
+pub struct Navigator<R> {
+    entries: Vec<RouteEntry<R>>,
+    /// The entries which were popped, the most recent last.
+    forward: Vec<RouteEntry<R>>,
+    next_key: u64,
+}
+
+/// An entry of a [`Navigator`]'s stack.
+#[derive(Clone, Debug)]
+pub struct RouteEntry<R> {
+    key: RouteKey,
+    route: R,
+}
+
+/// Identifies an entry of a [`Navigator`]'s stack.
+///
+/// Keys are unique within a navigator.
+/// Replacing a route gives its entry a new key, whereas popping and visiting it again
+/// using [`Navigator::forward`] keeps its key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RouteKey(u64);
+
+impl<R> Navigator<R> {
+    /// Create a navigator whose only route is `root`.
+    pub fn new(root: R) -> Self {
+        Self {
+            entries: alloc::vec![RouteEntry {
+                key: RouteKey(0),
+                route: root,
+            }],
+            forward: Vec::new(),
+            next_key: 1,
+        }
+    }
+
+    /// The current route.
+    pub fn current(&self) -> &R {
+        &self.current_entry().route
+    }
+
+    /// The current route, mutably, e.g. to update its data.
+    pub fn current_mut(&mut self) -> &mut R {
+        &mut self.entries.last_mut().unwrap().route
+    }
+
+    /// The entry of the current route.
+    pub fn current_entry(&self) -> &RouteEntry<R> {
+        self.entries.last().unwrap()
+    }
+
+    /// The entries of the stack, from the root to the current route.
+    pub fn entries(&self) -> &[RouteEntry<R>] {
+        &self.entries
+    }
+
+    /// The number of routes in the stack, which is at least one.
+    pub fn depth(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether there is a route to go back to.
+    pub fn can_pop(&self) -> bool {
+        self.entries.len() > 1
+    }
+
+    /// Whether there is a popped route to visit again.
+    pub fn can_go_forward(&self) -> bool {
+        !self.forward.is_empty()
+    }
+
+    /// Make `route` the current route, on top of the previous one.
+    ///
+    /// This forgets the popped routes.
+    pub fn push(&mut self, route: R) {
+        self.forward.clear();
+        let key = self.next_key();
+        self.entries.push(RouteEntry { key, route });
+    }
+
+    /// Go back to the previous route, and return the route which was current.
+    ///
+    /// Returns `None` if the current route is the root.
+    /// The popped route can be visited again using [`forward`](Self::forward).
+    pub fn pop(&mut self) -> Option<&R> {
+        if !self.can_pop() {
+            return None;
+        }
+        let entry = self.entries.pop().unwrap();
+        self.forward.push(entry);
+        self.forward.last().map(|entry| &entry.route)
+    }
+
+    /// Go back to the root route.
+    pub fn pop_to_root(&mut self) {
+        while self.pop().is_some() {}
+    }
+
+    /// Replace the current route with `route`, and return the replaced route.
+    ///
+    /// The new route isn't a continuation of the replaced one: its entry gets a new key.
+    pub fn replace(&mut self, route: R) -> R {
+        let key = self.next_key();
+        let entry = self.entries.last_mut().unwrap();
+        entry.key = key;
+        core::mem::replace(&mut entry.route, route)
+    }
+
+    /// Visit the last popped route again.
+    ///
+    /// Returns `false` if there is no popped route.
+    pub fn forward(&mut self) -> bool {
+        let Some(entry) = self.forward.pop() else {
+            return false;
+        };
+        self.entries.push(entry);
+        true
+    }
+
+    /// Go back or forward until the current route is at `index` in the stack.
+    ///
+    /// Returns `false` if there weren't enough popped routes to reach it.
+    pub fn go_to_index(&mut self, index: usize) -> bool {
+        while self.entries.len() > index + 1 && self.pop().is_some() {}
+        while self.entries.len() < index + 1 {
+            if !self.forward() {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn next_key(&mut self) -> RouteKey {
+        let key = RouteKey(self.next_key);
+        self.next_key += 1;
+        key
+    }
+}
+
+impl<R: Default> Default for Navigator<R> {
+    fn default() -> Self {
+        Self::new(R::default())
+    }
+}
+
+impl<R> RouteEntry<R> {
+    /// The key of this entry.
+    pub fn key(&self) -> RouteKey {
+        self.key
+    }
+
+    /// The route of this entry.
+    pub fn route(&self) -> &R {
+        &self.route
+    }
+}
+
+impl RouteKey {
+    /// Returns the integer value of the key.
+    pub fn to_raw(self) -> u64 {
+        self.0
+    }
+}
+
+impl Display for RouteKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "#{}", self.0)
+    }
+}
@@ -8,6 +8,7 @@ use core::marker::PhantomData;
 use core::sync::atomic::{AtomicBool, Ordering};
 
 use crate::element::NoElement;
+use crate::environment::skipped_by_partial_rebuild;
 use crate::{
     DynMessage, MessageResult, SuperElement, View, ViewElement, ViewId, ViewMarker, ViewPathTracker,
 };
@@ -74,6 +75,8 @@ impl<T> Default for AppendVec<T> {
 ///    Note that this will have persistent allocation with size proportional
 ///    to the *longest* `Vec` which is ever provided in the View tree, as this
 ///    uses a generational indexing scheme.
+///    Views are matched with their previous version by position, so
+///    [`keyed`](crate::keyed) should be preferred for lists whose items can be reordered.
 ///  - An [`array`] of `ViewSequence` values.
 ///  - Tuples of `ViewSequences` with up to 15 elements.
 ///    These can be nested if an ad-hoc sequence of more than 15 sequences is needed.
@@ -148,6 +151,25 @@ pub trait ElementSplice<Element: ViewElement> {
     fn skip(&mut self, n: usize);
     /// Delete the next existing element, after running a function on it.
     fn delete<R>(&mut self, f: impl FnOnce(Element::Mut<'_>) -> R) -> R;
+    /// Move the existing element which is `offset` elements after the next existing element,
+    /// so that it becomes the next existing element.
+    ///
+    /// The elements in between are shifted back by one, and the moved element keeps
+    /// its state. `move_from(0)` doesn't make any changes.
+    ///
+    /// This is used by [`keyed`](crate::keyed) sequences to preserve the identity of
+    /// elements whose views are reordered.
+    ///
+    /// # Panics
+    ///
+    /// The default implementation panics if `offset` isn't 0, which means that
+    /// `keyed` sequences can't be reordered within this splice.
+    fn move_from(&mut self, offset: usize) {
+        assert_eq!(
+            offset, 0,
+            "this `ElementSplice` doesn't support moving elements, which reordering a `keyed` sequence requires"
+        );
+    }
 }
 
 impl<State, Action, Context, V, Element> ViewSequence<State, Action, Context, Element> for V
@@ -177,6 +199,10 @@ where
         elements: &mut impl ElementSplice<Element>,
         app_state: &mut State,
     ) {
+        if skipped_by_partial_rebuild(ctx) {
+            elements.skip(1);
+            return;
+        }
         // Mutate the item we added in `seq_build`
         elements.mutate(|this_element| {
             Element::with_downcast(this_element, |element| {
@@ -875,6 +901,8 @@ impl ElementSplice<NoElement> for NoElements {
     fn delete<R>(&mut self, f: impl FnOnce(<NoElement as crate::ViewElement>::Mut<'_>) -> R) -> R {
         f(())
     }
+
+    fn move_from(&mut self, _: usize) {}
 }
 
 /// The [`ViewSequence`] for [`without_elements`], see its documentation for more context.
@@ -0,0 +1,214 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use core::any::type_name;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+use crate::{DynMessage, MessageResult, Mut, View, ViewId, ViewMarker, ViewPathTracker};
+
+/// The View for [`component`].
+///
+/// See its documentation for more context.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct Component<Props, Init, Logic, State, Local, Message, Context> {
+    props: Props,
+    init: Init,
+    logic: Logic,
+    phantom: PhantomData<fn(State) -> (Local, Message, Context)>,
+}
+
+impl<Props, Init, Logic, State, Local, Message, Context> Debug
+    for Component<Props, Init, Logic, State, Local, Message, Context>
+where
+    Props: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Component")
+            .field("props", &self.props)
+            .field("local", &type_name::<Local>())
+            .finish_non_exhaustive()
+    }
+}
+
+/// A reusable part of the view tree, which owns private state.
+///
+/// Plain functions over the app state, combined with [`lens`](crate::lens) or
+/// [`map_state`](crate::map_state), are the simplest way to split up an app.
+/// However, the state of these functions must be stored in the app state.
+/// A component instead stores its own `Local` state, such as whether a dropdown is open,
+/// which persists as long as the component is in the view tree.
+///
+/// The parameters of this view are:
+/// - `props`: The inputs of the component, which are given to `init` and `logic`.
+/// - `init`: Creates the local state when the component is first built.
+/// - `logic`: Creates the view of the component from its props and local state,
+///   each time the component is rebuilt.
+///
+/// The view created by `logic` operates on the local state, and its actions are
+/// `Option<Message>`:
+/// - `None` means that only the local state has changed.
+///   The component is then rebuilt, without changing the app state.
+/// - `Some(message)` is passed to the parent as an action of type `Message`.
+///   The parent can handle it using [`map_action`](crate::map_action) or
+///   [`map_message`](crate::map_message).
+///
+/// Note that the local state isn't available to the parent, and is reset if the
+/// component is removed from the view tree and added back.
+///
+/// # Examples
+///
+/// (From the Xilem implementation)
+///
+/// ```ignore
+/// enum DropdownMessage {
+///     Selected(usize),
+/// }
+///
+/// fn dropdown<State: 'static>(options: Vec<String>) -> impl WidgetView<State, DropdownMessage> {
+///     component(
+///         options,
+///         |_| false,
+///         |options, open| {
+///             let toggle = button("Select", |open: &mut bool| {
+///                 *open = !*open;
+///                 None
+///             });
+///             let options = open.then(|| {
+///                 options
+///                     .iter()
+///                     .enumerate()
+///                     .map(|(idx, option)| {
+///                         button(option.clone(), move |open: &mut bool| {
+///                             *open = false;
+///                             Some(DropdownMessage::Selected(idx))
+///                         })
+///                     })
+///                     .collect::<Vec<_>>()
+///             });
+///             flex((toggle, options))
+///         },
+///     )
+/// }
+///
+/// fn app_logic(state: &mut AppState) -> impl WidgetView<AppState> {
+///     map_action(
+///         dropdown(state.options.clone()),
+///         |state: &mut AppState, DropdownMessage::Selected(idx)| state.selected = idx,
+///     )
+/// }
+/// ```
+pub fn component<Props, Init, Logic, State, Local, Message, Context, V>(
+    props: Props,
+    init: Init,
+    logic: Logic,
+) -> Component<Props, Init, Logic, State, Local, Message, Context>
+where
+    Init: Fn(&Props) -> Local,
+    Logic: Fn(&Props, &mut Local) -> V,
+    V: View<Local, Option<Message>, Context>,
+    Context: ViewPathTracker,
+{
+    Component {
+        props,
+        init,
+        logic,
+        phantom: PhantomData,
+    }
+}
+
+/// The state used to implement `View` for [`Component`].
+#[doc(hidden)]
+#[expect(
+    unnameable_types,
+    reason = "Implementation detail, public because of trait visibility rules"
+)]
+#[derive(Debug)]
+pub struct ComponentState<Local, V, ViewState> {
+    local: Local,
+    view: V,
+    view_state: ViewState,
+}
+
+impl<Props, Init, Logic, State, Local, Message, Context> ViewMarker
+    for Component<Props, Init, Logic, State, Local, Message, Context>
+{
+}
+impl<Props, Init, Logic, State, Local, Message, Context, V> View<State, Message, Context>
+    for Component<Props, Init, Logic, State, Local, Message, Context>
+where
+    Props: 'static,
+    Init: Fn(&Props) -> Local + 'static,
+    Logic: Fn(&Props, &mut Local) -> V + 'static,
+    V: View<Local, Option<Message>, Context>,
+    State: 'static,
+    Local: 'static,
+    Message: 'static,
+    Context: ViewPathTracker + 'static,
+{
+    type ViewState = ComponentState<Local, V, V::ViewState>;
+    type Element = V::Element;
+
+    fn build(&self, ctx: &mut Context, _: &mut State) -> (Self::Element, Self::ViewState) {
+        let mut local = (self.init)(&self.props);
+        let view = (self.logic)(&self.props, &mut local);
+        let (element, view_state) = view.build(ctx, &mut local);
+        let state = ComponentState {
+            local,
+            view,
+            view_state,
+        };
+        (element, state)
+    }
+
+    fn rebuild(
+        &self,
+        _prev: &Self,
+        state: &mut Self::ViewState,
+        ctx: &mut Context,
+        element: Mut<'_, Self::Element>,
+        _: &mut State,
+    ) {
+        let view = (self.logic)(&self.props, &mut state.local);
+        view.rebuild(
+            &state.view,
+            &mut state.view_state,
+            ctx,
+            element,
+            &mut state.local,
+        );
+        state.view = view;
+    }
+
+    fn teardown(
+        &self,
+        state: &mut Self::ViewState,
+        ctx: &mut Context,
+        element: Mut<'_, Self::Element>,
+        _: &mut State,
+    ) {
+        state
+            .view
+            .teardown(&mut state.view_state, ctx, element, &mut state.local);
+    }
+
+    fn message(
+        &self,
+        state: &mut Self::ViewState,
+        id_path: &[ViewId],
+        message: DynMessage,
+        _: &mut State,
+    ) -> MessageResult<Message> {
+        match state
+            .view
+            .message(&mut state.view_state, id_path, message, &mut state.local)
+        {
+            MessageResult::Action(Some(message)) => MessageResult::Action(message),
+            // Only our local state has changed, so only we need to be rebuilt.
+            MessageResult::Action(None) => MessageResult::RequestRebuild,
+            MessageResult::RequestRebuild => MessageResult::RequestRebuild,
+            MessageResult::Nop => MessageResult::Nop,
+            MessageResult::Stale(message) => MessageResult::Stale(message),
+        }
+    }
+}
@@ -19,6 +19,9 @@ pub use fork::{Fork, fork};
 mod memoize;
 pub use memoize::{Frozen, Memoize, frozen, memoize};
 
+mod component;
+pub use component::{Component, component};
+
 pub mod one_of;
 
 mod orphan;
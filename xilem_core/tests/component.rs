@@ -0,0 +1,125 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for the behaviour of [`component`].
+
+mod common;
+use common::*;
+use xilem_core::{
+    DynMessage, MessageResult, Mut, View, ViewId, ViewMarker, ViewPathTracker, component,
+};
+
+/// A view which records its operations like [`OperationView`], and which changes the local state
+/// of its component when it gets a `()` message, or emits a `u32` message to the parent.
+struct LocalView(u32);
+
+impl ViewMarker for LocalView {}
+impl View<u32, Option<u32>, TestCtx> for LocalView {
+    type Element = TestElement;
+
+    type ViewState = ();
+
+    fn build(&self, ctx: &mut TestCtx, _: &mut u32) -> (Self::Element, Self::ViewState) {
+        (
+            TestElement {
+                operations: vec![Operation::Build(self.0)],
+                view_path: ctx.view_path().to_vec(),
+                children: None,
+            },
+            (),
+        )
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        (): &mut Self::ViewState,
+        _: &mut TestCtx,
+        element: Mut<'_, Self::Element>,
+        _: &mut u32,
+    ) {
+        element.operations.push(Operation::Rebuild {
+            from: prev.0,
+            to: self.0,
+        });
+    }
+
+    fn teardown(
+        &self,
+        (): &mut Self::ViewState,
+        _: &mut TestCtx,
+        element: Mut<'_, Self::Element>,
+        _: &mut u32,
+    ) {
+        element.operations.push(Operation::Teardown(self.0));
+    }
+
+    fn message(
+        &self,
+        (): &mut Self::ViewState,
+        _: &[ViewId],
+        message: DynMessage,
+        local: &mut u32,
+    ) -> MessageResult<Option<u32>> {
+        match message.downcast::<u32>() {
+            Ok(message) => MessageResult::Action(Some(*message)),
+            Err(_) => {
+                *local += 1;
+                MessageResult::Action(None)
+            }
+        }
+    }
+}
+
+/// A component whose local state starts as `props`, and whose view is `props + local`.
+fn counter(props: u32) -> impl View<(), u32, TestCtx, Element = TestElement> {
+    component(
+        props,
+        |props: &u32| *props,
+        |props: &u32, local: &mut u32| LocalView(props + *local),
+    )
+}
+
+#[test]
+fn local_state_persists() {
+    let view = counter(10);
+    let mut ctx = TestCtx::default();
+    let (mut element, mut state) = view.build(&mut ctx, &mut ());
+    ctx.assert_empty();
+    assert_eq!(element.operations, &[Operation::Build(20)]);
+
+    // Changing the local state only requests a rebuild.
+    let result = view.message(&mut state, &[], DynMessage::new(()), &mut ());
+    assert!(matches!(result, MessageResult::RequestRebuild));
+    view.rebuild(&view, &mut state, &mut ctx, &mut element, &mut ());
+    assert_eq!(
+        element.operations.last(),
+        Some(&Operation::Rebuild { from: 20, to: 21 })
+    );
+
+    // New props don't reset the local state.
+    let view2 = counter(5);
+    view2.rebuild(&view, &mut state, &mut ctx, &mut element, &mut ());
+    ctx.assert_empty();
+    assert_eq!(
+        element.operations.last(),
+        Some(&Operation::Rebuild { from: 21, to: 16 })
+    );
+
+    view2.teardown(&mut state, &mut ctx, &mut element, &mut ());
+    ctx.assert_empty();
+    assert_eq!(element.operations.last(), Some(&Operation::Teardown(16)));
+}
+
+#[test]
+fn messages_reach_parent() {
+    let view = counter(0);
+    let mut ctx = TestCtx::default();
+    let (_, mut state) = view.build(&mut ctx, &mut ());
+
+    let result = view.message(&mut state, &[], DynMessage::new(7_u32), &mut ());
+    let MessageResult::Action(message) = result else {
+        panic!("Expected an action, got {result:?}");
+    };
+    assert_eq!(message, 7);
+}
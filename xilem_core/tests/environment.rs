@@ -0,0 +1,222 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for the lifecycle of values in the [`Environment`](xilem_core::Environment),
+//! which are made available using [`provides`] and read using [`with_context`].
+
+mod common;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use common::*;
+use xilem_core::{
+    DynMessage, MessageResult, Mut, Resource, View, ViewId, ViewMarker, ViewPathTracker, provides,
+    with_context,
+};
+
+#[derive(Debug)]
+struct Theme(u32);
+impl Resource for Theme {}
+
+#[derive(Debug)]
+struct Locale(u32);
+impl Resource for Locale {}
+
+/// A view which records the readers of the [`Theme`] available to it,
+/// each time it is built or rebuilt.
+#[derive(Clone, Default)]
+struct ListenerProbe(Rc<RefCell<Vec<Vec<Vec<ViewId>>>>>);
+
+impl ListenerProbe {
+    fn record(&self, ctx: &mut TestCtx) {
+        let listeners = ctx
+            .environment()
+            .change_listeners::<Theme>()
+            .map(|path| path.to_vec())
+            .collect();
+        self.0.borrow_mut().push(listeners);
+    }
+
+    fn last(&self) -> Vec<Vec<ViewId>> {
+        self.0.borrow().last().unwrap().clone()
+    }
+}
+
+impl ViewMarker for ListenerProbe {}
+impl View<(), Action, TestCtx> for ListenerProbe {
+    type Element = TestElement;
+
+    type ViewState = ();
+
+    fn build(&self, ctx: &mut TestCtx, (): &mut ()) -> (Self::Element, Self::ViewState) {
+        self.record(ctx);
+        (
+            TestElement {
+                operations: vec![],
+                view_path: ctx.view_path().to_vec(),
+                children: None,
+            },
+            (),
+        )
+    }
+
+    fn rebuild(
+        &self,
+        _: &Self,
+        (): &mut Self::ViewState,
+        ctx: &mut TestCtx,
+        _: Mut<'_, Self::Element>,
+        (): &mut (),
+    ) {
+        self.record(ctx);
+    }
+
+    fn teardown(
+        &self,
+        (): &mut Self::ViewState,
+        _: &mut TestCtx,
+        _: Mut<'_, Self::Element>,
+        (): &mut (),
+    ) {
+    }
+
+    fn message(
+        &self,
+        (): &mut Self::ViewState,
+        _: &[ViewId],
+        message: DynMessage,
+        (): &mut (),
+    ) -> MessageResult<Action> {
+        MessageResult::Stale(message)
+    }
+}
+
+fn theme_reader() -> impl View<(), Action, TestCtx, Element = TestElement> {
+    with_context(|theme: &mut Theme, (): &mut ()| OperationView::<0>(theme.0))
+}
+
+/// Provides a [`Theme`] to `readers` readers, followed by `probe`.
+fn themed(
+    theme: u32,
+    readers: usize,
+    probe: &ListenerProbe,
+) -> impl View<(), Action, TestCtx, Element = TestElement> {
+    provides(
+        move |(): &mut ()| Theme(theme),
+        sequence(
+            0,
+            (
+                (0..readers).map(|_| theme_reader()).collect::<Vec<_>>(),
+                probe.clone(),
+            ),
+        ),
+    )
+}
+
+#[test]
+fn listeners_are_removed_on_teardown() {
+    let probe = ListenerProbe::default();
+    let view = themed(1, 3, &probe);
+    let mut ctx = TestCtx::default();
+    let (mut element, mut state) = view.build(&mut ctx, &mut ());
+    ctx.assert_empty();
+
+    let readers = element.children.as_ref().unwrap();
+    assert_eq!(readers.active[0].operations, &[Operation::Build(1)]);
+    let listeners = probe.last();
+    assert_eq!(listeners.len(), 3);
+    // The listeners are the paths of the `with_context` views, which are the parents
+    // of the elements they create.
+    for (listener, reader) in listeners.iter().zip(&readers.active) {
+        assert!(reader.view_path.starts_with(listener));
+    }
+
+    let view2 = themed(1, 1, &probe);
+    view2.rebuild(&view, &mut state, &mut ctx, &mut element, &mut ());
+    ctx.assert_empty();
+    assert_eq!(probe.last(), &listeners[..1]);
+
+    // The freed listener entries are reused.
+    let view3 = themed(1, 2, &probe);
+    view3.rebuild(&view2, &mut state, &mut ctx, &mut element, &mut ());
+    ctx.assert_empty();
+    assert_eq!(probe.last().len(), 2);
+
+    view3.teardown(&mut state, &mut ctx, &mut element, &mut ());
+    ctx.assert_empty();
+    assert!(ctx.environment().get_mut::<Theme>().is_none());
+    assert_eq!(ctx.environment().change_listeners::<Theme>().count(), 0);
+}
+
+#[test]
+fn nested_values_are_independent() {
+    let outer_probe = ListenerProbe::default();
+    let inner_probe = ListenerProbe::default();
+    let view = provides(
+        |(): &mut ()| Theme(1),
+        sequence(
+            0,
+            (
+                theme_reader(),
+                themed(2, 2, &inner_probe),
+                outer_probe.clone(),
+            ),
+        ),
+    );
+    let mut ctx = TestCtx::default();
+    let (mut element, mut state) = view.build(&mut ctx, &mut ());
+    ctx.assert_empty();
+
+    let children = element.children.as_ref().unwrap();
+    assert_eq!(children.active[0].operations, &[Operation::Build(1)]);
+    let inner_readers = children.active[1].children.as_ref().unwrap();
+    assert_eq!(inner_readers.active[0].operations, &[Operation::Build(2)]);
+    assert_eq!(inner_probe.last().len(), 2);
+    assert_eq!(outer_probe.last().len(), 1);
+
+    view.rebuild(&view, &mut state, &mut ctx, &mut element, &mut ());
+    ctx.assert_empty();
+    assert_eq!(inner_probe.last().len(), 2);
+    assert_eq!(outer_probe.last().len(), 1);
+
+    view.teardown(&mut state, &mut ctx, &mut element, &mut ());
+    ctx.assert_empty();
+    assert!(ctx.environment().get_mut::<Theme>().is_none());
+}
+
+#[test]
+fn freed_slots_are_reused_by_other_types() {
+    let probe = ListenerProbe::default();
+    let view = themed(1, 1, &probe);
+    let mut ctx = TestCtx::default();
+    let (mut element, mut state) = view.build(&mut ctx, &mut ());
+    view.teardown(&mut state, &mut ctx, &mut element, &mut ());
+
+    let view = provides(
+        |(): &mut ()| Locale(2),
+        with_context(|locale: &mut Locale, (): &mut ()| OperationView::<0>(locale.0)),
+    );
+    let (mut element, mut state) = view.build(&mut ctx, &mut ());
+    ctx.assert_empty();
+    assert_eq!(element.operations, &[Operation::Build(2)]);
+    view.rebuild(&view, &mut state, &mut ctx, &mut element, &mut ());
+    view.teardown(&mut state, &mut ctx, &mut element, &mut ());
+    ctx.assert_empty();
+    assert!(ctx.environment().get_mut::<Theme>().is_none());
+    assert!(ctx.environment().get_mut::<Locale>().is_none());
+
+    // The type which was removed can be provided again.
+    let view = themed(3, 1, &probe);
+    let (element, _) = view.build(&mut ctx, &mut ());
+    let readers = element.children.as_ref().unwrap();
+    assert_eq!(readers.active[0].operations, &[Operation::Build(3)]);
+    assert_eq!(probe.last().len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "hasn't been provided")]
+fn missing_value() {
+    let view = theme_reader();
+    let mut ctx = TestCtx::default();
+    let _ = view.build(&mut ctx, &mut ());
+}
@@ -0,0 +1,168 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for [`SequenceView`] with keyed sequences.
+
+mod common;
+use common::*;
+use xilem_core::{DynMessage, Keyed, MessageResult, View, keyed};
+
+/// A keyed sequence of [`OperationView`]s, from `(key, id)` pairs.
+fn keyed_ops(items: &[(u32, u32)]) -> Keyed<u32, OperationView<0>> {
+    keyed(
+        items.iter().copied(),
+        |&(key, _)| key,
+        |(_, id)| OperationView(id),
+    )
+}
+
+#[test]
+fn insert_at_front() {
+    let view = sequence(0, keyed_ops(&[(1, 1), (2, 2)]));
+    let mut ctx = TestCtx::default();
+    let (mut element, mut state) = view.build(&mut ctx, &mut ());
+    ctx.assert_empty();
+
+    let seq_children = element.children.as_ref().unwrap();
+    assert_eq!(seq_children.active.len(), 2);
+    let first_path = seq_children.active[0].view_path.clone();
+
+    let view2 = sequence(1, keyed_ops(&[(0, 10), (1, 11), (2, 12)]));
+    view2.rebuild(&view, &mut state, &mut ctx, &mut element, &mut ());
+    ctx.assert_empty();
+
+    let seq_children = element.children.as_ref().unwrap();
+    assert!(seq_children.deleted.is_empty());
+    assert_eq!(seq_children.active.len(), 3);
+    assert_eq!(seq_children.active[0].operations, &[Operation::Build(10)]);
+    assert_eq!(
+        seq_children.active[1].operations,
+        &[Operation::Build(1), Operation::Rebuild { from: 1, to: 11 }]
+    );
+    assert_eq!(seq_children.active[1].view_path, first_path);
+    assert_eq!(
+        seq_children.active[2].operations,
+        &[Operation::Build(2), Operation::Rebuild { from: 2, to: 12 }]
+    );
+
+    view2.teardown(&mut state, &mut ctx, &mut element, &mut ());
+    ctx.assert_empty();
+    let seq_children = element.children.as_ref().unwrap();
+    assert!(seq_children.active.is_empty());
+    assert_eq!(seq_children.deleted.len(), 3);
+}
+
+#[test]
+fn reorder_and_remove() {
+    let view = sequence(0, keyed_ops(&[(1, 1), (2, 2), (3, 3)]));
+    let mut ctx = TestCtx::default();
+    let (mut element, mut state) = view.build(&mut ctx, &mut ());
+    ctx.assert_empty();
+
+    let view2 = sequence(1, keyed_ops(&[(3, 13), (1, 11)]));
+    view2.rebuild(&view, &mut state, &mut ctx, &mut element, &mut ());
+    ctx.assert_empty();
+
+    let seq_children = element.children.as_ref().unwrap();
+    assert_eq!(seq_children.active.len(), 2);
+    assert_eq!(
+        seq_children.active[0].operations,
+        &[Operation::Build(3), Operation::Rebuild { from: 3, to: 13 }]
+    );
+    assert_eq!(
+        seq_children.active[1].operations,
+        &[Operation::Build(1), Operation::Rebuild { from: 1, to: 11 }]
+    );
+    assert_eq!(seq_children.deleted.len(), 1);
+    let (_, deleted) = &seq_children.deleted[0];
+    assert_eq!(
+        deleted.operations,
+        &[Operation::Build(2), Operation::Teardown(2)]
+    );
+}
+
+#[test]
+fn reverse_and_interleave() {
+    let items: Vec<_> = (0..20).map(|key| (key, key)).collect();
+    let view = sequence(0, keyed_ops(&items));
+    let mut ctx = TestCtx::default();
+    let (mut element, mut state) = view.build(&mut ctx, &mut ());
+    ctx.assert_empty();
+    let paths: Vec<_> = element
+        .children
+        .as_ref()
+        .unwrap()
+        .active
+        .iter()
+        .map(|child| child.view_path.clone())
+        .collect();
+
+    // Reverse the items, removing the odd keys and adding new ones in between.
+    let items2: Vec<_> = (0..20)
+        .rev()
+        .filter(|key| key % 2 == 0)
+        .flat_map(|key| [(key, key + 100), (key + 20, key + 120)])
+        .collect();
+    let view2 = sequence(1, keyed_ops(&items2));
+    view2.rebuild(&view, &mut state, &mut ctx, &mut element, &mut ());
+    ctx.assert_empty();
+
+    let seq_children = element.children.as_ref().unwrap();
+    assert_eq!(seq_children.active.len(), items2.len());
+    assert_eq!(seq_children.deleted.len(), 10);
+    for (child, &(key, id)) in seq_children.active.iter().zip(&items2) {
+        if key < 20 {
+            assert_eq!(child.view_path, paths[key as usize]);
+            assert_eq!(
+                child.operations,
+                &[
+                    Operation::Build(key),
+                    Operation::Rebuild { from: key, to: id }
+                ]
+            );
+        } else {
+            assert_eq!(child.operations, &[Operation::Build(id)]);
+        }
+    }
+}
+
+#[test]
+fn messages_follow_keys() {
+    let view = sequence(0, keyed_ops(&[(1, 1), (2, 2)]));
+    let mut ctx = TestCtx::default();
+    let (mut element, mut state) = view.build(&mut ctx, &mut ());
+    ctx.assert_empty();
+
+    let seq_children = element.children.as_ref().unwrap();
+    let first_path = seq_children.active[0].view_path.clone();
+    let second_path = seq_children.active[1].view_path.clone();
+
+    let view2 = sequence(0, keyed_ops(&[(2, 12), (1, 11)]));
+    view2.rebuild(&view, &mut state, &mut ctx, &mut element, &mut ());
+    ctx.assert_empty();
+
+    let result = view2.message(&mut state, &first_path, DynMessage::new(()), &mut ());
+    assert_action(result, 11);
+    let result = view2.message(&mut state, &second_path, DynMessage::new(()), &mut ());
+    assert_action(result, 12);
+
+    // Removing a key and adding it back creates a new view.
+    let view3 = sequence(0, keyed_ops(&[(2, 22)]));
+    view3.rebuild(&view2, &mut state, &mut ctx, &mut element, &mut ());
+    let view4 = sequence(0, keyed_ops(&[(1, 31), (2, 32)]));
+    view4.rebuild(&view3, &mut state, &mut ctx, &mut element, &mut ());
+    ctx.assert_empty();
+
+    let result = view4.message(&mut state, &first_path, DynMessage::new(()), &mut ());
+    assert!(matches!(result, MessageResult::Stale(_)));
+    let result = view4.message(&mut state, &second_path, DynMessage::new(()), &mut ());
+    assert_action(result, 32);
+}
+
+#[test]
+#[should_panic(expected = "must be unique")]
+fn duplicate_keys() {
+    let view = sequence(0, keyed_ops(&[(1, 1), (1, 2)]));
+    let mut ctx = TestCtx::default();
+    let _ = view.build(&mut ctx, &mut ());
+}
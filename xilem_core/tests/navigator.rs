@@ -0,0 +1,73 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for the route stack of [`Navigator`].
+
+use xilem_core::Navigator;
+
+#[test]
+fn push_pop_and_forward() {
+    let mut navigator = Navigator::new("root");
+    assert!(!navigator.can_pop());
+    assert_eq!(navigator.pop(), None);
+
+    navigator.push("a");
+    navigator.push("b");
+    let keys: Vec<_> = navigator
+        .entries()
+        .iter()
+        .map(|entry| entry.key())
+        .collect();
+    assert_eq!(navigator.depth(), 3);
+    assert_eq!(navigator.current(), &"b");
+
+    assert_eq!(navigator.pop(), Some(&"b"));
+    assert_eq!(navigator.pop(), Some(&"a"));
+    assert_eq!(navigator.current(), &"root");
+    assert!(navigator.can_go_forward());
+
+    // Visiting popped routes again keeps their keys.
+    assert!(navigator.forward());
+    assert!(navigator.forward());
+    assert!(!navigator.forward());
+    let forward_keys: Vec<_> = navigator
+        .entries()
+        .iter()
+        .map(|entry| entry.key())
+        .collect();
+    assert_eq!(keys, forward_keys);
+}
+
+#[test]
+fn push_forgets_popped_routes() {
+    let mut navigator = Navigator::new("root");
+    navigator.push("a");
+    navigator.pop();
+    navigator.push("b");
+    assert!(!navigator.can_go_forward());
+    assert_eq!(navigator.current(), &"b");
+}
+
+#[test]
+fn replace_changes_key() {
+    let mut navigator = Navigator::new("root");
+    navigator.push("a");
+    let key = navigator.current_entry().key();
+    assert_eq!(navigator.replace("b"), "a");
+    assert_eq!(navigator.current(), &"b");
+    assert_eq!(navigator.depth(), 2);
+    assert_ne!(navigator.current_entry().key(), key);
+}
+
+#[test]
+fn go_to_index() {
+    let mut navigator = Navigator::new("root");
+    navigator.push("a");
+    navigator.push("b");
+    assert!(navigator.go_to_index(0));
+    assert_eq!(navigator.current(), &"root");
+    assert!(navigator.go_to_index(2));
+    assert_eq!(navigator.current(), &"b");
+    assert!(!navigator.go_to_index(3));
+    assert_eq!(navigator.depth(), 3);
+}
@@ -0,0 +1,46 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for partial rebuilds, driven by [`Environment::queue_rebuild`].
+
+mod common;
+use common::*;
+use xilem_core::{View, ViewPathTracker};
+
+fn record_ops(id: u32) -> OperationView<0> {
+    OperationView(id)
+}
+
+#[test]
+fn only_targets_are_rebuilt() {
+    let view = sequence(0, vec![record_ops(0), record_ops(1), record_ops(2)]);
+    let mut ctx = TestCtx::default();
+    let (mut element, mut state) = view.build(&mut ctx, &mut ());
+    ctx.assert_empty();
+
+    let seq_children = element.children.as_ref().unwrap();
+    let second_path = seq_children.active[1].view_path.clone();
+
+    ctx.environment().queue_rebuild(second_path.into());
+    assert!(ctx.environment().start_partial_rebuild());
+    view.rebuild(&view, &mut state, &mut ctx, &mut element, &mut ());
+    ctx.environment().finish_partial_rebuild();
+    ctx.assert_empty();
+
+    let seq_children = element.children.as_ref().unwrap();
+    assert_eq!(seq_children.active[0].operations, &[Operation::Build(0)]);
+    assert_eq!(
+        seq_children.active[1].operations,
+        &[Operation::Build(1), Operation::Rebuild { from: 1, to: 1 }]
+    );
+    assert_eq!(seq_children.active[2].operations, &[Operation::Build(2)]);
+
+    // Without any queued rebuild, the whole tree is rebuilt.
+    assert!(!ctx.environment().start_partial_rebuild());
+    view.rebuild(&view, &mut state, &mut ctx, &mut element, &mut ());
+    let seq_children = element.children.as_ref().unwrap();
+    assert_eq!(
+        seq_children.active[0].operations,
+        &[Operation::Build(0), Operation::Rebuild { from: 0, to: 0 }]
+    );
+}
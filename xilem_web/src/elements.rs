@@ -227,6 +227,18 @@ impl ElementSplice<AnyPod> for DomChildrenSplice<'_, '_, '_, '_> {
         }
         f(child)
     }
+
+    fn move_from(&mut self, offset: usize) {
+        if offset == 0 {
+            return;
+        }
+        let (moved, next) = self.children.move_from(offset);
+        if !self.parent_was_removed {
+            self.parent
+                .insert_before(moved.node.as_ref(), next.map(|p| p.node.as_ref()))
+                .unwrap_throw();
+        }
+    }
 }
 
 /// Used in all the basic DOM elements as [`View::ViewState`]
@@ -51,6 +51,7 @@ mod app;
 mod attribute_value;
 mod context;
 mod dom_helpers;
+mod navigator;
 mod one_of;
 mod optional_action;
 mod pod;
@@ -77,6 +78,7 @@ pub use self::attribute_value::{AttributeValue, IntoAttributeValue};
 pub use self::context::{MessageThunk, ViewCtx};
 pub use self::core::DynMessage;
 pub use self::dom_helpers::{document, document_body, get_element_by_id, input_event_target_value};
+pub use self::navigator::{NavigatorView, navigator};
 pub use self::optional_action::{Action, OptionalAction};
 pub use self::pod::{AnyPod, Pod, PodFlags, PodMut};
 pub use self::pointer::{Pointer, PointerDetails, PointerMsg};
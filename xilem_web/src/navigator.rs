@@ -0,0 +1,283 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{JsCast, JsValue, UnwrapThrowExt};
+
+use crate::core::{
+    MessageResult, Mut, Navigator, RouteKey, View, ViewId, ViewMarker, ViewPathTracker,
+};
+use crate::{DomView, DynMessage, Pod, ViewCtx};
+
+/// A view which shows the current route of a [`Navigator`], and keeps the browser history
+/// in sync with its stack.
+///
+/// `navigator` projects the navigator out of the app state, and `route_view` creates the view
+/// of a route.
+/// Pushing a route adds an entry to the browser history, and popping routes goes back in it,
+/// so that the back and forward buttons of the browser pop the route and visit it again.
+/// Replacing a route replaces the current history entry.
+///
+/// Each history entry stores the index of its route in the stack, so there should only be one
+/// navigator in the page.
+/// The URL of the page isn't changed.
+///
+/// Unlike the `navigator` of Xilem, only the view of the current route is kept:
+/// the view of a route is built again when the routes above it are popped.
+///
+/// # Examples
+///
+/// ```
+/// use xilem_web::core::Navigator;
+/// use xilem_web::elements::html::{button, div};
+/// use xilem_web::interfaces::Element;
+/// use xilem_web::{DomView, navigator};
+///
+/// #[derive(Clone)]
+/// enum Route {
+///     Home,
+///     Details(u32),
+/// }
+///
+/// fn app_logic(_: &mut Navigator<Route>) -> impl DomView<Navigator<Route>> {
+///     navigator(
+///         |navigator: &mut Navigator<Route>| navigator,
+///         |_: &mut Navigator<Route>, route: &Route| match route {
+///             Route::Home => button("Show details")
+///                 .on_click(|navigator: &mut Navigator<Route>, _| {
+///                     navigator.push(Route::Details(1));
+///                 })
+///                 .boxed(),
+///             Route::Details(id) => div(format!("Details of {id}")).boxed(),
+///         },
+///     )
+/// }
+/// ```
+pub fn navigator<State, Action, R, N, F, V>(
+    navigator: N,
+    route_view: F,
+) -> NavigatorView<N, F, State, Action, R>
+where
+    State: 'static,
+    Action: 'static,
+    N: Fn(&mut State) -> &mut Navigator<R> + 'static,
+    F: Fn(&mut State, &R) -> V + 'static,
+    V: DomView<State, Action>,
+    R: Clone + 'static,
+{
+    NavigatorView {
+        navigator,
+        route_view,
+        phantom: PhantomData,
+    }
+}
+
+/// The view for [`navigator`].
+pub struct NavigatorView<N, F, State, Action, R> {
+    navigator: N,
+    route_view: F,
+    phantom: PhantomData<fn(State, R) -> Action>,
+}
+
+#[expect(
+    unnameable_types,
+    reason = "Implementation detail, public because of trait visibility rules"
+)]
+pub struct NavigatorState<V, RouteState> {
+    key: RouteKey,
+    view: V,
+    view_state: RouteState,
+    /// The index of the current route in the stack, when the browser history was last updated.
+    history_index: usize,
+    // Closures are retained so they can be called by environment
+    popstate_fn: Closure<dyn FnMut(web_sys::PopStateEvent)>,
+}
+
+/// The message sent when the user goes back or forward in the browser history.
+///
+/// This is the index stored in the new history entry, if it's one of the navigator's.
+#[derive(Debug)]
+struct HistoryChanged(Option<usize>);
+
+fn history() -> web_sys::History {
+    web_sys::window().unwrap_throw().history().unwrap_throw()
+}
+
+fn history_state(index: usize) -> JsValue {
+    JsValue::from_f64(index as f64)
+}
+
+fn route_view_id(key: RouteKey) -> ViewId {
+    ViewId::new(key.to_raw())
+}
+
+impl<N, F, State, Action, R> ViewMarker for NavigatorView<N, F, State, Action, R> {}
+
+impl<N, F, State, Action, R, V> View<State, Action, ViewCtx>
+    for NavigatorView<N, F, State, Action, R>
+where
+    State: 'static,
+    Action: 'static,
+    N: Fn(&mut State) -> &mut Navigator<R> + 'static,
+    F: Fn(&mut State, &R) -> V + 'static,
+    V: DomView<State, Action>,
+    R: Clone + 'static,
+{
+    type Element = Pod<V::DomNode>;
+
+    type ViewState = NavigatorState<V, V::ViewState>;
+
+    fn build(&self, ctx: &mut ViewCtx, app_state: &mut State) -> (Self::Element, Self::ViewState) {
+        let navigator = (self.navigator)(app_state);
+        let history_index = navigator.depth() - 1;
+        let key = navigator.current_entry().key();
+        let route = navigator.current().clone();
+
+        let view = (self.route_view)(app_state, &route);
+        let (element, view_state) =
+            ctx.with_id(route_view_id(key), |ctx| view.build(ctx, app_state));
+
+        let thunk = ctx.message_thunk();
+        let popstate_fn = Closure::new(move |event: web_sys::PopStateEvent| {
+            let index = event.state().as_f64().map(|index| index as usize);
+            thunk.push_message(HistoryChanged(index));
+        });
+        web_sys::window()
+            .unwrap_throw()
+            .add_event_listener_with_callback("popstate", popstate_fn.as_ref().unchecked_ref())
+            .unwrap_throw();
+        history()
+            .replace_state(&history_state(history_index), "")
+            .unwrap_throw();
+
+        let state = NavigatorState {
+            key,
+            view,
+            view_state,
+            history_index,
+            popstate_fn,
+        };
+        (element, state)
+    }
+
+    fn rebuild(
+        &self,
+        _prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: &mut State,
+    ) {
+        let navigator = (self.navigator)(app_state);
+        let index = navigator.depth() - 1;
+        let key = navigator.current_entry().key();
+        let route = navigator.current().clone();
+
+        let history = history();
+        if index > view_state.history_index {
+            for index in view_state.history_index + 1..=index {
+                history.push_state(&history_state(index), "").unwrap_throw();
+            }
+        } else if index < view_state.history_index {
+            // The browser sends a `popstate` event once it went back, which is then ignored,
+            // as the navigator is already at that index.
+            let delta = (view_state.history_index - index) as i32;
+            history.go_with_delta(-delta).unwrap_throw();
+        } else if key != view_state.key {
+            history
+                .replace_state(&history_state(index), "")
+                .unwrap_throw();
+        }
+        view_state.history_index = index;
+
+        let view = (self.route_view)(app_state, &route);
+        if key == view_state.key {
+            ctx.with_id(route_view_id(key), |ctx| {
+                view.rebuild(
+                    &view_state.view,
+                    &mut view_state.view_state,
+                    ctx,
+                    element.reborrow_mut(),
+                    app_state,
+                );
+            });
+        } else {
+            // The new route doesn't continue the previous one, so its view is built from scratch.
+            ctx.with_id(route_view_id(view_state.key), |ctx| {
+                view_state.view.teardown(
+                    &mut view_state.view_state,
+                    ctx,
+                    element.reborrow_mut(),
+                    app_state,
+                );
+            });
+            let (mut new_element, new_view_state) =
+                ctx.with_id(route_view_id(key), |ctx| view.build(ctx, app_state));
+            new_element.apply_changes();
+            if let Some(parent) = element.parent {
+                parent
+                    .replace_child(new_element.node.as_ref(), element.node.as_ref())
+                    .unwrap_throw();
+            }
+            *element.node = new_element.node;
+            *element.props = new_element.props;
+            *element.flags = new_element.flags;
+            view_state.key = key;
+            view_state.view_state = new_view_state;
+        }
+        view_state.view = view;
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        element: Mut<'_, Self::Element>,
+        app_state: &mut State,
+    ) {
+        web_sys::window()
+            .unwrap_throw()
+            .remove_event_listener_with_callback(
+                "popstate",
+                view_state.popstate_fn.as_ref().unchecked_ref(),
+            )
+            .unwrap_throw();
+        ctx.with_id(route_view_id(view_state.key), |ctx| {
+            view_state
+                .view
+                .teardown(&mut view_state.view_state, ctx, element, app_state);
+        });
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        id_path: &[ViewId],
+        message: DynMessage,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        match id_path.split_first() {
+            Some((first, rest)) if *first == route_view_id(view_state.key) => view_state
+                .view
+                .message(&mut view_state.view_state, rest, message, app_state),
+            Some(_) => MessageResult::Stale(message),
+            None => {
+                let HistoryChanged(index) = *message.downcast::<HistoryChanged>().unwrap_throw();
+                // Entries of the history which weren't added by the navigator are ignored.
+                let Some(index) = index else {
+                    return MessageResult::Nop;
+                };
+                let navigator = (self.navigator)(app_state);
+                if navigator.depth() - 1 == index {
+                    return MessageResult::Nop;
+                }
+                navigator.go_to_index(index);
+                // The browser is already at this entry.
+                view_state.history_index = index;
+                MessageResult::RequestRebuild
+            }
+        }
+    }
+}
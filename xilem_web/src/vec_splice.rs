@@ -41,6 +41,22 @@ impl<'v, 's, T> VecSplice<'v, 's, T> {
         self.ix += 1;
     }
 
+    /// Moves the element `offset` elements after the next one, so that it becomes the next one.
+    ///
+    /// Returns the moved element, and the element which now follows it.
+    pub fn move_from(&mut self, offset: usize) -> (&T, Option<&T>) {
+        self.clear_tail();
+        // `scratch` is in reverse order, the next element is the last one.
+        let ix = self.scratch.len() - 1 - offset;
+        let value = self.scratch.remove(ix);
+        self.scratch.push(value);
+        let (moved, rest) = self
+            .scratch
+            .split_last()
+            .expect("This is a bug, please report an issue about `ElementSplice::move_from`");
+        (moved, rest.last())
+    }
+
     pub fn next_mut(&mut self) -> Option<&mut T> {
         self.v
             .get_mut(self.ix + 1)